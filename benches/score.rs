@@ -1,7 +1,9 @@
-use bevy::prelude::{BuildChildren, Commands, World};
+use bevy::prelude::World;
 use bevy_observed_utility::{
-    event::RunScoring,
-    scoring::{AllOrNothing, FixedScore, Score, ScoringPlugin},
+    event::{RunPicking, RunScoring},
+    picking::PickingPlugin,
+    scoring::ScoringPlugin,
+    stress::{StressPicker, StressScenario},
 };
 use criterion::{criterion_group, criterion_main, Bencher, Criterion};
 
@@ -18,10 +20,10 @@ fn score(c: &mut Criterion) {
     c.bench_function("score/deep-3/many-100", |b| {
         bench_scoring(b, 3, 100);
     });
-    c.bench_function("score/deep-3/many-100", |b| {
+    c.bench_function("score/deep-10/many-100", |b| {
         bench_scoring(b, 10, 100);
     });
-    c.bench_function("score/deep-3/many-100", |b| {
+    c.bench_function("score/deep-25/many-100", |b| {
         bench_scoring(b, 25, 100);
     });
     c.bench_function("score/deep-3/many-10000", |b| {
@@ -33,35 +35,61 @@ fn score(c: &mut Criterion) {
     c.bench_function("score/deep-25/many-10000", |b| {
         bench_scoring(b, 25, 10_000);
     });
+    c.bench_function("score/wide-100", |b| {
+        bench_wide_scoring(b, 100, 1);
+    });
+    c.bench_function("score/wide-10000", |b| {
+        bench_wide_scoring(b, 10_000, 1);
+    });
+    c.bench_function("score/wide-100/many-100", |b| {
+        bench_wide_scoring(b, 100, 100);
+    });
+}
+
+fn pick(c: &mut Criterion) {
+    c.bench_function("pick/highest/many-100", |b| {
+        bench_picking(b, StressPicker::Highest, 100);
+    });
+    c.bench_function("pick/highest/many-10000", |b| {
+        bench_picking(b, StressPicker::Highest, 10_000);
+    });
+    c.bench_function("pick/dual-utility/many-10000", |b| {
+        bench_picking(b, StressPicker::DualUtility, 10_000);
+    });
 }
 
 fn bench_scoring(b: &mut Bencher, scoring_depth: usize, num_trees: usize) {
+    bench_scenario(
+        b,
+        StressScenario::new(num_trees)
+            .with_depth(scoring_depth)
+            .with_branching(1),
+    );
+}
+
+fn bench_wide_scoring(b: &mut Bencher, width: usize, num_trees: usize) {
+    bench_scenario(b, StressScenario::new(num_trees).with_depth(2).with_branching(width));
+}
+
+fn bench_scenario(b: &mut Bencher, scenario: StressScenario) {
     let mut world = World::new();
     world.observe(ScoringPlugin::run_scoring_post_order_dfs);
-    for _ in 0..num_trees {
-        build_deep_tree(world.commands(), scoring_depth);
-    }
-    world.flush();
+    scenario.spawn(&mut world);
     b.iter(|| {
         world.trigger(RunScoring);
     });
 }
 
-fn build_deep_tree(mut commands: Commands, depth: usize) {
-    let root = commands.spawn((AllOrNothing::new(0.5), Score::default())).id();
-
-    let mut last = root;
-    for _ in 0..depth - 2 {
-        last = commands
-            .spawn((AllOrNothing::new(0.5), Score::default()))
-            .set_parent(last)
-            .id();
-    }
-
-    commands
-        .spawn((FixedScore::new(0.5), Score::default()))
-        .set_parent(last);
+fn bench_picking(b: &mut Bencher, picker: StressPicker, num_actors: usize) {
+    let mut world = World::new();
+    world.observe(ScoringPlugin::run_scoring_post_order_dfs);
+    world.observe(PickingPlugin::run_picking);
+    StressScenario::new(num_actors).with_picker(picker).spawn(&mut world);
+    world.trigger(RunScoring);
+    b.iter(|| {
+        world.trigger(RunPicking);
+    });
 }
 
-criterion_group!(benches, score);
+criterion_group!(benches, score, pick);
 criterion_main!(benches);
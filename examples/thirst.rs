@@ -9,13 +9,6 @@ pub struct Thirst {
     pub per_second: f32,
 }
 
-/// This impl allows us to use [`score_ancestor`] to score thirst.
-impl From<&Thirst> for Score {
-    fn from(thirst: &Thirst) -> Self {
-        Score::new(thirst.value / 100.)
-    }
-}
-
 pub fn get_thirsty_over_time(time: Res<Time<Fixed>>, mut thirsts: Query<&mut Thirst>) {
     for mut thirst in thirsts.iter_mut() {
         thirst.value = (thirst.value + thirst.per_second * time.delta_seconds()).min(100.);
@@ -23,7 +16,9 @@ pub fn get_thirsty_over_time(time: Res<Time<Fixed>>, mut thirsts: Query<&mut Thi
     }
 }
 
-#[derive(Component)]
+/// This derive allows us to use [`score_ancestor`] to score thirst, through [`AddScorerExt::add_scorer`].
+#[derive(Component, Scorer)]
+#[scorer(source = Thirst, score = |thirst| thirst.value / 100.)]
 pub struct Thirsty;
 
 pub fn spawn_entities(mut commands: Commands, actions: Res<ActionIds>) {
@@ -32,7 +27,7 @@ pub fn spawn_entities(mut commands: Commands, actions: Res<ActionIds>) {
     commands
         .spawn((
             Name::new("Actor"),
-            Picker::new(actions.idle).with(thirst, actions.drink),
+            Picker::new(actions.idle).with_action::<Drinking>(thirst),
             Thirst {
                 value: 0.,
                 per_second: 4.,
@@ -64,7 +59,6 @@ impl Default for Drinking {
 
 pub fn quench_thirst(
     mut commands: Commands,
-    actions: Res<ActionIds>,
     time: Res<Time<Fixed>>,
     mut drinking: Query<(Entity, &mut Thirst, &Drinking)>,
 ) {
@@ -72,13 +66,7 @@ pub fn quench_thirst(
         thirst.value = (thirst.value - drink.per_second * time.delta_seconds()).max(0.);
         info!("DRINKING!");
         if thirst.value <= drink.until {
-            commands.trigger_targets(
-                OnActionEnded {
-                    action: actions.drink,
-                    reason: ActionEndReason::Completed,
-                },
-                TargetedAction(actor, actions.drink),
-            )
+            commands.trigger_action(OnActionEnded::completed_for::<Drinking>(), actor);
         }
     }
 }
@@ -89,14 +77,12 @@ pub struct Idle;
 
 #[derive(Resource)]
 pub struct ActionIds {
-    drink: ComponentId,
     idle: ComponentId,
 }
 
 impl FromWorld for ActionIds {
     fn from_world(world: &mut World) -> Self {
         Self {
-            drink: world.init_component::<Drinking>(),
             idle: world.init_component::<Idle>(),
         }
     }
@@ -112,9 +98,9 @@ fn main() {
         .add_plugins(ObservedUtilityPlugins::RealTime)
         .init_resource::<ActionIds>()
         .init_resource::<Drinking>()
+        .add_scorer::<Thirst, Thirsty>()
         .add_systems(Startup, spawn_entities)
         .add_systems(FixedUpdate, (get_thirsty_over_time, quench_thirst).chain())
-        .observe(score_ancestor::<Thirst, Thirsty>)
         .observe(on_action_initiated_insert_from_resource::<Drinking>)
         .observe(on_action_ended_remove::<Drinking>)
         .run();
@@ -0,0 +1,81 @@
+//! Derive macros for `bevy_observed_utility`.
+//!
+//! Use the re-exports from `bevy_observed_utility` instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Error, Expr, Path};
+
+/// Derives `From<&Source> for Score` for a score marker [`Component`] that's scored from its closest ancestor's
+/// `Source` [`Component`], for use with `score_ancestor` and `AddScorerExt::add_scorer`.
+///
+/// The `source` is the ancestor's [`Component`] type, and `score` is a closure from a reference to it
+/// to anything that converts into a `Score`, such as an [`f32`]:
+///
+/// ```rust,ignore
+/// #[derive(Component, Scorer)]
+/// #[scorer(source = Thirst, score = |thirst| thirst.value / 100.)]
+/// pub struct Thirsty;
+///
+/// app.add_scorer::<Thirst, Thirsty>();
+/// ```
+///
+/// Only one marker can be derived per `source` type, as they share the same `From` impl.
+/// Use `score_ancestor_with` for sources with multiple markers.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[proc_macro_derive(Scorer, attributes(scorer))]
+pub fn derive_scorer(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_scorer_impl(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive_scorer_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`Scorer` can't be derived for generic types",
+        ));
+    }
+
+    let mut source: Option<Path> = None;
+    let mut score: Option<Expr> = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("scorer")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("source") {
+                source = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("score") {
+                score = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `source` or `score`"))
+            }
+        })?;
+    }
+
+    let missing = |name: &str| {
+        Error::new(
+            input.ident.span(),
+            format!("missing `#[scorer({name} = ...)]` attribute"),
+        )
+    };
+    let source = source.ok_or_else(|| missing("source"))?;
+    let score = score.ok_or_else(|| missing("score"))?;
+
+    Ok(quote! {
+        impl ::core::convert::From<&#source> for ::bevy_observed_utility::scoring::Score {
+            fn from(source: &#source) -> Self {
+                fn score<R: ::core::convert::Into<::bevy_observed_utility::scoring::Score>>(
+                    source: &#source,
+                    score: impl ::core::ops::FnOnce(&#source) -> R,
+                ) -> ::bevy_observed_utility::scoring::Score {
+                    score(source).into()
+                }
+                score(source, #score)
+            }
+        }
+    })
+}
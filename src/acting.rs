@@ -7,58 +7,217 @@
 //! - [`OnActionInitiated`] event to indicate that an action has been initiated. This should be listened to by action observers.
 //! - [`OnActionEnded`] event to indicate that an action has completed or been cancelled. This should be listened to by action observers.
 //! - [`CurrentAction`] component to store the current action being performed by an actor entity, for easy access.
+//! - [`ActionState`] component to track the lifecycle state of the [`CurrentAction`], maintained by the [`ActionPlugin`].
+//! - [`CurrentActions`] component to store all actions being performed concurrently by a [`PickMultiple`] actor entity.
+//! - [`MinimumRunTime`] component to prevent freshly initiated actions from being cancelled too soon.
+//! - [`Interruptible`] component to configure whether and when an actor's running action can be cancelled by requests.
+//! - [`ActionProgress`] component to store the latest [`OnActionProgress`] reported by the [`CurrentAction`].
+//! - [`ActionPriority`] component to store the priority the [`CurrentAction`] was requested with.
+//! - [`CurrentActionMeta`] component to store when the [`CurrentAction`] was requested, and which choice it was picked from.
+//! - [`ActionCooldowns`] component to prevent actions from being picked again too soon after they end.
+//! - [`StartsCooldown`] component to configure an action's cooldown on its [`Picker`] choice score entity.
+//! - [`ActionQueue`] component to initiate an ordered list of actions one after another, instead of re-picking in between.
+//! - [`FailurePolicy`] component or resource to configure how actors react to failed actions.
+//! - [`ActionChannel`] component for child entities that perform actions independently of their actor, such as locomotion and hands.
+//! - [`ActionRegistry`] resource to map actions to stable string names, registered with [`RegisterActionExt::register_action`].
+//! - [`ActionStateMap`] resource to mirror actions into per-entity state components, mapped with [`ActionStatesExt::map_action_state`].
 //!
 //! And, these observers:
 //! - [`on_action_initiated_insert_default`] to insert a default instance of an action component when it is initiated.
 //!     - This can then be queried with a [`With<ActionT>`] query by action systems.
 //! - [`on_action_initiated_insert_from_resource`] to insert a clone of an action component from a resource when it is initiated.
 //!     - Same as above, but with a resource as the source.
+//! - [`on_action_initiated_insert_with`] to insert an action component built from arbitrary world state when it is initiated.
+//!     - Same as above, but with a closure as the source.
 //! - [`on_action_ended_remove`] to remove an action component when it is ended.
+//! - [`on_action_initiated_spawn_child`] to spawn a child entity with a default instance of an action component
+//!   and a [`PerformedBy`] link back to the actor when it is initiated, tracked in the actor's [`ActionEntities`].
+//!     - This lets actions carry their own state, timers, and targets on a dedicated entity.
+//! - [`on_action_ended_despawn_child`] to despawn the action's child entity when it is ended.
+//! - [`on_action_initiated_trigger_for`] and [`on_action_ended_trigger_for`] to trigger the strongly-typed
+//!   [`OnActionInitiatedFor`] and [`OnActionEndedFor`] events, added with [`RegisterActionExt::add_action_events`].
+//!
+//! [`PickMultiple`]: crate::picking::PickMultiple
+
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 
-use bevy::{ecs::component::ComponentId, prelude::*};
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        entity::{EntityMapper, MapEntities},
+        query::QueryFilter,
+    },
+    hierarchy::despawn_with_children_recursive,
+    prelude::*,
+    utils::HashMap,
+};
 
 use crate::{
-    ecs::TargetedAction,
-    event::{ActionEndReason, OnActionEnded, OnActionInitiated, RequestAction},
-    picking::Picker,
+    ecs::{TargetedAction, TriggerGetEntity},
+    event::{
+        ActionEndReason, OnActionEnded, OnActionEndedFor, OnActionInitiated, OnActionInitiatedFor, OnActionProgress,
+        OnActionStateChanged, PauseAction, RequestAction, ResumeAction,
+    },
+    picking::{PickedActions, Picker},
+    scoring::Score,
+    AiDisabled, AiPaused,
 };
 
+mod channel;
+mod registry;
+mod states;
+
+pub use channel::*;
+pub use registry::*;
+pub use states::*;
+
 /// [`Plugin`] that handles action lifecycle events.
 pub struct ActionPlugin;
 
 impl Plugin for ActionPlugin {
     fn build(&self, app: &mut App) {
         app.observe(Self::on_request_cancel_and_initiate)
-            .observe(Self::on_ended_request_again);
+            .observe(Self::on_request_sync_multiple)
+            .observe(Self::on_ended_request_again)
+            .observe(Self::on_ended_start_cooldown)
+            .observe(Self::on_initiated_set_running)
+            .observe(Self::on_ended_set_state)
+            .observe(Self::on_pause_set_paused)
+            .observe(Self::on_resume_set_running)
+            .observe(Self::on_progress_record)
+            .observe(Self::on_ended_clear_progress);
+
+        app.add_systems(First, Self::pause_actions_while_ai_paused);
+
+        app.init_resource::<ActionRegistry>();
 
-        app.register_type::<CurrentAction>();
+        app.register_type::<CurrentAction>()
+            .register_type::<CurrentTarget>()
+            .register_type::<ActionState>()
+            .register_type::<CurrentActions>()
+            .register_type::<MinimumRunTime>()
+            .register_type::<Interruptible>()
+            .register_type::<CurrentActionMeta>()
+            .register_type::<ActionPriority>()
+            .register_type::<ActionProgress>()
+            .register_type::<ActionQueue>()
+            .register_type::<ActionCooldowns>()
+            .register_type::<StartsCooldown>()
+            .register_type::<FailurePolicy>()
+            .register_type::<ActionRetries>()
+            .register_type::<ActionChannel>()
+            .register_type::<PerformedBy>()
+            .register_type::<ActionEntities>()
+            .register_type::<ActionRegistry>()
+            .register_type::<AiPaused>()
+            .register_type::<AiDisabled>();
 
         app.register_type::<RequestAction>()
             .register_type::<OnActionInitiated>()
-            .register_type::<OnActionEnded>();
+            .register_type::<OnActionEnded>()
+            .register_type::<PauseAction>()
+            .register_type::<ResumeAction>()
+            .register_type::<OnActionStateChanged>()
+            .register_type::<OnActionProgress>();
     }
 }
 
 impl ActionPlugin {
     /// [`System`] that listens for [`RequestAction`] events and cancels the current action
     /// and initiates the picked action for the target actor entity.
+    ///
+    /// If the actor has a [`MinimumRunTime`] that hasn't elapsed yet for the current action,
+    /// or its current action is [`Paused`](ActionState::Paused),
+    /// the request is ignored, unless it's an [interrupt](RequestAction::interrupt).
+    /// Requests that the actor's [`Interruptible`] policy rejects, or that have a lower [priority](RequestAction::priority)
+    /// than the running action's [`ActionPriority`], are always ignored.
+    ///
+    /// Actors with [`PickedActions`] are handled by [`ActionPlugin::on_request_sync_multiple`] instead.
+    #[allow(clippy::type_complexity)]
     pub fn on_request_cancel_and_initiate(
         trigger: Trigger<RequestAction>,
         mut commands: Commands,
-        mut actors: Query<(&Picker, Option<&CurrentAction>)>,
+        mut actors: Query<
+            (
+                &Picker,
+                Option<&CurrentAction>,
+                Option<&CurrentTarget>,
+                Option<&mut MinimumRunTime>,
+                Option<&ActionState>,
+                Option<&Interruptible>,
+                Option<&CurrentActionMeta>,
+                Option<&ActionPriority>,
+            ),
+            Without<PickedActions>,
+        >,
+        scores: Query<&Score>,
+        time: Option<Res<Time>>,
     ) {
+        utility_span!("ActionPlugin::on_request_cancel_and_initiate", entity = ?trigger.entity());
         let actor = trigger.entity();
-        let requested = trigger.event().action;
-        if let Ok((picker, current_action)) = actors.get_mut(actor) {
-            let current_action = current_action.map(|ca| ca.0);
-            let next_action = requested.unwrap_or(picker.picked);
-
-            if let Some(current_action) = current_action {
-                if next_action == current_action {
-                    // We don't need to re-initiate the same action
+        let RequestAction {
+            action: requested,
+            interrupt,
+            priority,
+        } = *trigger.event();
+        let now = time.map(|time| time.elapsed());
+        if let Ok((
+            picker,
+            current_action,
+            current_target,
+            mut minimum_run_time,
+            state,
+            interruptible,
+            meta,
+            current_priority,
+        )) = actors.get_mut(actor)
+        {
+            if !interrupt && state == Some(&ActionState::Paused) {
+                // Paused actions stay paused until they're resumed
+                return;
+            }
+            let Some(next_action) = requested.or(picker.picked) else {
+                // Nothing was picked, so keep the current action
+                return;
+            };
+            let score_entity = picker
+                .picked_score_entity
+                .filter(|_| requested.is_none() || requested == picker.picked);
+            let target = picker.picked_target.filter(|_| score_entity.is_some());
+
+            if let Some(&CurrentAction(current_action)) = current_action {
+                if next_action == current_action && target == current_target.map(|current_target| current_target.0) {
+                    // We don't need to re-initiate the same action on the same target
+                    return;
+                }
+
+                if let (Some(minimum_run_time), Some(now)) = (&minimum_run_time, now) {
+                    if !interrupt && minimum_run_time.is_committed(current_action, now) {
+                        // The current action hasn't run for long enough yet
+                        return;
+                    }
+                }
+
+                let running = state.is_some_and(|state| !state.is_finished());
+                if running && current_priority.is_some_and(|current_priority| priority < current_priority.0) {
+                    // The current action was requested with a higher priority
                     return;
                 }
 
+                if let Some(interruptible) = interruptible.filter(|_| running) {
+                    let elapsed = now.zip(meta).and_then(|(now, meta)| meta.elapsed(now));
+                    let score = picker
+                        .choices
+                        .iter()
+                        .filter(|&(_, action)| action == next_action)
+                        .filter_map(|(score_entity, _)| scores.get(score_entity).ok().copied())
+                        .reduce(|a, b| if b > a { b } else { a });
+                    if !interruptible.allows(elapsed, score) {
+                        // The current action can't be interrupted by this request
+                        return;
+                    }
+                }
+
                 // Cancel the current action
                 commands.trigger_targets(
                     OnActionEnded::cancelled(current_action),
@@ -67,29 +226,483 @@ impl ActionPlugin {
             }
 
             // Update the current action
-            commands.entity(actor).insert(CurrentAction(next_action));
+            let meta = CurrentActionMeta {
+                started: now,
+                score_entity,
+                score: score_entity.and_then(|score_entity| scores.get(score_entity).ok().copied()),
+            };
+            let mut entity = commands.entity(actor);
+            entity.insert((
+                CurrentAction(next_action),
+                ActionState::Requested,
+                ActionPriority(priority),
+                meta,
+            ));
+            match target {
+                Some(target) => entity.insert(CurrentTarget(target)),
+                None => entity.remove::<CurrentTarget>(),
+            };
+            commands.trigger_targets(
+                OnActionStateChanged {
+                    action: next_action,
+                    previous: None,
+                    state: ActionState::Requested,
+                },
+                TargetedAction(actor, next_action),
+            );
+            if let Some(minimum_run_time) = &mut minimum_run_time {
+                minimum_run_time.started = now;
+            }
             // Trigger the picked action
             commands.trigger_targets(
-                OnActionInitiated { action: next_action },
+                OnActionInitiated {
+                    action: next_action,
+                    target,
+                },
                 TargetedAction(actor, next_action),
             );
         }
     }
 
+    /// [`Observer`] that listens for [`RequestAction`] events for actor entities with [`PickedActions`],
+    /// cancelling the [`CurrentActions`] that are no longer picked and initiating the newly picked ones.
+    ///
+    /// If a specific action is requested, it's initiated alongside the [`CurrentActions`] instead.
+    /// [`MinimumRunTime`] is not supported for these actors, but [`Paused`](ActionState::Paused) ones ignore
+    /// non-interrupting requests.
+    pub fn on_request_sync_multiple(
+        trigger: Trigger<RequestAction>,
+        mut commands: Commands,
+        actors: Query<(&PickedActions, Option<&CurrentActions>, Option<&ActionState>)>,
+    ) {
+        utility_span!("ActionPlugin::on_request_sync_multiple", entity = ?trigger.entity());
+        let actor = trigger.entity();
+        let Ok((picked, current_actions, state)) = actors.get(actor) else {
+            return;
+        };
+        if !trigger.event().interrupt && state == Some(&ActionState::Paused) {
+            // Paused actions stay paused until they're resumed
+            return;
+        }
+        let current_actions = current_actions.map(|ca| ca.0.clone()).unwrap_or_default();
+
+        let next_actions = match trigger.event().action {
+            Some(requested) => {
+                let mut next_actions = current_actions.clone();
+                if !next_actions.contains(&requested) {
+                    next_actions.push(requested);
+                }
+                next_actions
+            }
+            None => picked.0.clone(),
+        };
+
+        // Cancel the actions that are no longer picked
+        for &action in current_actions.iter().filter(|action| !next_actions.contains(action)) {
+            commands.trigger_targets(OnActionEnded::cancelled(action), TargetedAction(actor, action));
+        }
+
+        // Update the current actions
+        let mut entity = commands.entity(actor);
+        if let Some(&primary) = next_actions.first() {
+            entity.insert(CurrentAction(primary));
+        }
+        entity
+            .insert(CurrentActions(next_actions.clone()))
+            .remove::<CurrentTarget>();
+
+        // Trigger the newly picked actions
+        for &action in next_actions.iter().filter(|action| !current_actions.contains(action)) {
+            commands.trigger_targets(
+                OnActionInitiated { action, target: None },
+                TargetedAction(actor, action),
+            );
+        }
+    }
+
     /// [`Observer`] that listens for [`OnActionEnded`] events and triggers a new [`RequestAction`] event for the target actor entity.
-    pub fn on_ended_request_again(trigger: Trigger<OnActionEnded>, mut commands: Commands) {
+    ///
+    /// If the actor has a non-empty [`ActionQueue`], the next queued action is requested instead of the picked action.
+    /// Failed actions are handled according to the actor's [`FailurePolicy`], or the [`FailurePolicy`] resource if any.
+    #[allow(clippy::too_many_arguments)]
+    pub fn on_ended_request_again(
+        trigger: Trigger<OnActionEnded>,
+        mut commands: Commands,
+        mut minimum_run_times: Query<&mut MinimumRunTime>,
+        mut current_actions: Query<&mut CurrentActions>,
+        mut queues: Query<&mut ActionQueue>,
+        mut retries: Query<&mut ActionRetries>,
+        policies: Query<(&Picker, Option<&FailurePolicy>)>,
+        global_policy: Option<Res<FailurePolicy>>,
+        targets: Query<(&CurrentAction, Option<&CurrentTarget>)>,
+    ) {
+        utility_span!("ActionPlugin::on_ended_request_again", entity = ?trigger.entity());
         let actor = trigger.entity();
+        let OnActionEnded { action, reason } = trigger.event();
+        let action = *action;
+
+        if *reason == ActionEndReason::Cancelled {
+            // Do nothing
+            return;
+        }
+
+        let (default, policy) = match policies.get(actor) {
+            Ok((picker, policy)) => (
+                picker.default,
+                policy.or(global_policy.as_deref()).copied().unwrap_or_default(),
+            ),
+            Err(_) => (None, FailurePolicy::default()),
+        };
 
-        match trigger.event().reason {
-            ActionEndReason::Completed => {
-                // Pick a new action
-                commands.trigger_targets(RequestAction { action: None }, actor);
+        if reason.is_failed() {
+            if let FailurePolicy::Retry(max_retries) = policy {
+                // Retry the same action, as long as it has retries left
+                let retried = retries.get(actor).map_or(0, |retries| retries.count(action));
+                if retried < max_retries {
+                    if let Ok(mut retries) = retries.get_mut(actor) {
+                        retries.record(action);
+                    } else {
+                        commands.entity(actor).insert(ActionRetries::new(action));
+                    }
+                    let target = targets
+                        .get(actor)
+                        .ok()
+                        .filter(|(current, _)| current.0 == action)
+                        .and_then(|(_, target)| target)
+                        .map(|target| target.0);
+                    commands.trigger_targets(OnActionInitiated { action, target }, TargetedAction(actor, action));
+                    return;
+                }
             }
-            ActionEndReason::Cancelled => {
-                // Do nothing
+        }
+
+        // An ended action can be retried again from scratch
+        if let Ok(mut retries) = retries.get_mut(actor) {
+            retries.reset();
+        }
+        // An ended action is no longer being performed concurrently
+        if let Ok(mut current_actions) = current_actions.get_mut(actor) {
+            current_actions.0.retain(|&current| current != action);
+        }
+        // An ended action no longer needs to be committed to
+        if let Ok(mut minimum_run_time) = minimum_run_times.get_mut(actor) {
+            minimum_run_time.started = None;
+        }
+
+        if reason.is_failed() {
+            // A failed action abandons the rest of the queue
+            if let Ok(mut queue) = queues.get_mut(actor) {
+                queue.clear();
             }
+            match (policy, default) {
+                (FailurePolicy::FallBackToDefault, Some(default)) => {
+                    commands.trigger_targets(RequestAction::action(default), actor);
+                }
+                _ => commands.trigger_targets(RequestAction::picked(), actor),
+            }
+        } else {
+            // Continue with the next queued action, or pick a new one
+            match queues.get_mut(actor).ok().and_then(|mut queue| queue.pop()) {
+                Some(next) => commands.trigger_targets(RequestAction::action(next), actor),
+                None => commands.trigger_targets(RequestAction::picked(), actor),
+            }
+        }
+    }
+
+    /// [`Observer`] that listens for [`OnActionInitiated`] events and sets the target actor entity's
+    /// [`ActionState`] to [`Running`](ActionState::Running), if it's their [`CurrentAction`].
+    ///
+    /// Retried actions transition straight from [`Failed`](ActionState::Failed) back to [`Running`](ActionState::Running).
+    pub fn on_initiated_set_running(
+        trigger: Trigger<OnActionInitiated>,
+        mut commands: Commands,
+        mut actors: Query<(&CurrentAction, &mut ActionState)>,
+    ) {
+        utility_span!("ActionPlugin::on_initiated_set_running", entity = ?trigger.entity());
+        let action = trigger.event().action;
+        transition(
+            &mut commands,
+            &mut actors,
+            trigger.entity(),
+            action,
+            ActionState::Running,
+            |state| matches!(state, ActionState::Requested | ActionState::Failed),
+        );
+    }
+
+    /// [`Observer`] that listens for [`OnActionEnded`] events and sets the target actor entity's
+    /// [`ActionState`] to the matching final state, if it's their [`CurrentAction`] and it hasn't ended yet.
+    pub fn on_ended_set_state(
+        trigger: Trigger<OnActionEnded>,
+        mut commands: Commands,
+        mut actors: Query<(&CurrentAction, &mut ActionState)>,
+    ) {
+        utility_span!("ActionPlugin::on_ended_set_state", entity = ?trigger.entity());
+        let OnActionEnded { action, reason } = trigger.event();
+        let action = *action;
+        let next = match reason {
+            ActionEndReason::Completed => ActionState::Succeeded,
+            ActionEndReason::Failed(_) => ActionState::Failed,
+            ActionEndReason::Cancelled => ActionState::Cancelled,
+        };
+        transition(&mut commands, &mut actors, trigger.entity(), action, next, |state| {
+            !state.is_finished()
+        });
+    }
+
+    /// [`Observer`] that listens for [`PauseAction`] events and sets the target actor entity's
+    /// [`ActionState`] to [`Paused`](ActionState::Paused), if it's [`Running`](ActionState::Running),
+    /// or pauses all actor entities if no target is specified.
+    ///
+    /// Pausing an actor entity also pauses its [`ActionChannel`]s.
+    pub fn on_pause_set_paused(
+        trigger: Trigger<PauseAction>,
+        mut commands: Commands,
+        mut actors: Query<(&CurrentAction, &mut ActionState)>,
+        all_actors: Query<Entity, With<ActionState>>,
+        channels: ActionChannels,
+    ) {
+        utility_span!("ActionPlugin::on_pause_set_paused", entity = ?trigger.entity());
+        for actor in pause_targets(trigger.get_entity(), &all_actors, &channels) {
+            let Ok((&CurrentAction(action), _)) = actors.get(actor) else {
+                continue;
+            };
+            transition(
+                &mut commands,
+                &mut actors,
+                actor,
+                action,
+                ActionState::Paused,
+                |state| state == ActionState::Running,
+            );
         }
     }
+
+    /// [`Observer`] that listens for [`ResumeAction`] events and sets the target actor entity's
+    /// [`ActionState`] back to [`Running`](ActionState::Running), if it's [`Paused`](ActionState::Paused),
+    /// or resumes all actor entities if no target is specified.
+    ///
+    /// Resuming an actor entity also resumes its [`ActionChannel`]s.
+    /// [`AiDisabled`] actor entities are only resumed when targeted.
+    pub fn on_resume_set_running(
+        trigger: Trigger<ResumeAction>,
+        mut commands: Commands,
+        mut actors: Query<(&CurrentAction, &mut ActionState)>,
+        all_actors: Query<Entity, (With<ActionState>, Without<AiDisabled>)>,
+        channels: ActionChannels,
+    ) {
+        utility_span!("ActionPlugin::on_resume_set_running", entity = ?trigger.entity());
+        for actor in pause_targets(trigger.get_entity(), &all_actors, &channels) {
+            let Ok((&CurrentAction(action), _)) = actors.get(actor) else {
+                continue;
+            };
+            transition(
+                &mut commands,
+                &mut actors,
+                actor,
+                action,
+                ActionState::Running,
+                |state| state == ActionState::Paused,
+            );
+        }
+    }
+
+    /// [`System`] that triggers [`PauseAction`] for all actor entities when [`AiPaused`] is inserted,
+    /// and [`ResumeAction`] when it's removed.
+    pub fn pause_actions_while_ai_paused(
+        mut commands: Commands,
+        paused: Option<Res<AiPaused>>,
+        mut was_paused: Local<bool>,
+    ) {
+        let is_paused = paused.is_some();
+        if is_paused == *was_paused {
+            return;
+        }
+        *was_paused = is_paused;
+        if is_paused {
+            commands.trigger(PauseAction);
+        } else {
+            commands.trigger(ResumeAction);
+        }
+    }
+
+    /// [`Observer`] that listens for [`OnActionProgress`] events and records the progress
+    /// in the target actor entity's [`ActionProgress`], if it's for their [`CurrentAction`] (or they have none).
+    pub fn on_progress_record(
+        trigger: Trigger<OnActionProgress>,
+        mut commands: Commands,
+        current_actions: Query<&CurrentAction>,
+    ) {
+        utility_span!("ActionPlugin::on_progress_record", entity = ?trigger.entity());
+        let actor = trigger.entity();
+        let OnActionProgress { action, progress } = *trigger.event();
+        if current_actions.get(actor).is_ok_and(|current| current.0 != action) {
+            // Stale progress from an action that is no longer current
+            return;
+        }
+        commands.entity(actor).insert(ActionProgress { action, progress });
+    }
+
+    /// [`Observer`] that listens for [`OnActionEnded`] events and removes the target actor entity's
+    /// [`ActionProgress`], if it's for the ended action.
+    pub fn on_ended_clear_progress(
+        trigger: Trigger<OnActionEnded>,
+        mut commands: Commands,
+        progress: Query<&ActionProgress>,
+    ) {
+        utility_span!("ActionPlugin::on_ended_clear_progress", entity = ?trigger.entity());
+        let actor = trigger.entity();
+        if progress
+            .get(actor)
+            .is_ok_and(|progress| progress.action == trigger.event().action)
+        {
+            commands.entity(actor).remove::<ActionProgress>();
+        }
+    }
+
+    /// [`Observer`] that listens for [`OnActionEnded`] events and starts the ended action's cooldown,
+    /// if the target actor entity has [`ActionCooldowns`] or one of its [`Picker`] choices for the action has [`StartsCooldown`].
+    ///
+    /// A [`StartsCooldown`] takes precedence over the configured cooldown, and inserts [`ActionCooldowns`] if missing.
+    pub fn on_ended_start_cooldown(
+        trigger: Trigger<OnActionEnded>,
+        mut commands: Commands,
+        mut cooldowns: Query<&mut ActionCooldowns>,
+        pickers: Query<&Picker>,
+        starts_cooldown: Query<&StartsCooldown>,
+        time: Option<Res<Time>>,
+    ) {
+        utility_span!("ActionPlugin::on_ended_start_cooldown", entity = ?trigger.entity());
+        let Some(time) = time else {
+            return;
+        };
+        let actor = trigger.entity();
+        let action = trigger.event().action;
+        let configured = pickers.get(actor).ok().and_then(|picker| {
+            picker
+                .choices
+                .iter()
+                .filter(|&(_, choice)| choice == action)
+                .find_map(|(score_entity, _)| starts_cooldown.get(score_entity).ok())
+        });
+
+        if let Ok(mut cooldowns) = cooldowns.get_mut(actor) {
+            if let Some(&StartsCooldown(cooldown)) = configured {
+                cooldowns.set_cooldown(action, cooldown);
+            }
+            cooldowns.start(action, time.elapsed());
+        } else if let Some(&StartsCooldown(cooldown)) = configured {
+            let mut cooldowns = ActionCooldowns::default().with_cooldown(action, cooldown);
+            cooldowns.start(action, time.elapsed());
+            commands.entity(actor).insert(cooldowns);
+        }
+    }
+}
+
+/// Returns the targeted actor entity along with its [`ActionChannel`]s, or all actor entities if there's no target.
+fn pause_targets<F: QueryFilter>(
+    target: Option<Entity>,
+    all_actors: &Query<Entity, F>,
+    channels: &ActionChannels,
+) -> Vec<Entity> {
+    match target {
+        Some(actor) => std::iter::once(actor)
+            .chain(channels.iter(actor).map(|(channel, _)| channel))
+            .collect(),
+        None => all_actors.iter().collect(),
+    }
+}
+
+/// Transitions the actor's [`ActionState`] to `next` if `action` is their [`CurrentAction`]
+/// and `allowed` accepts their current state, triggering [`OnActionStateChanged`].
+fn transition(
+    commands: &mut Commands,
+    actors: &mut Query<(&CurrentAction, &mut ActionState)>,
+    actor: Entity,
+    action: ComponentId,
+    next: ActionState,
+    allowed: impl FnOnce(ActionState) -> bool,
+) {
+    let Ok((current_action, mut state)) = actors.get_mut(actor) else {
+        return;
+    };
+    if current_action.0 != action || !allowed(*state) {
+        return;
+    }
+    let previous = std::mem::replace(&mut *state, next);
+    commands.trigger_targets(
+        OnActionStateChanged {
+            action,
+            previous: Some(previous),
+            state: next,
+        },
+        TargetedAction(actor, action),
+    );
+}
+
+/// [`Component`] for the lifecycle state of an actor entity's [`CurrentAction`], maintained by the [`ActionPlugin`].
+///
+/// Every transition triggers an [`OnActionStateChanged`] event:
+/// - [`Requested`](ActionState::Requested) when a [`RequestAction`] switches the [`CurrentAction`].
+/// - [`Running`](ActionState::Running) when the action is initiated, or resumed with [`ResumeAction`].
+/// - [`Paused`](ActionState::Paused) when a running action is paused with [`PauseAction`],
+///   e.g. for cutscenes, stuns, or menus. Non-interrupting [`RequestAction`]s are ignored while paused,
+///   so the action's progress isn't lost. Observe [`OnActionStateChanged`] targeting the action to react to it.
+/// - [`Succeeded`](ActionState::Succeeded), [`Failed`](ActionState::Failed), or [`Cancelled`](ActionState::Cancelled)
+///   when the action ends, depending on its [`ActionEndReason`].
+///
+/// Finished actions don't transition again, so cancelling an action that already succeeded keeps it succeeded.
+/// Actors with [`PickedActions`] only track the state of their primary action.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Drinking;
+///
+/// /// Only drink while the action is actually running, not while it's paused.
+/// fn drink(actors: Query<(&ActionState, &Drinking)>) {
+///     for (state, _drinking) in &actors {
+///         if !state.is_running() {
+///             continue;
+///         }
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[reflect(Component, PartialEq, Hash, Debug)]
+pub enum ActionState {
+    /// The action was requested, but hasn't been initiated yet.
+    Requested,
+    /// The action is being performed.
+    Running,
+    /// The action was paused, and should not be performed until it's resumed.
+    Paused,
+    /// The action completed successfully.
+    Succeeded,
+    /// The action failed.
+    Failed,
+    /// The action was cancelled.
+    Cancelled,
+}
+
+impl ActionState {
+    /// Returns `true` if the action is being performed.
+    #[must_use]
+    pub fn is_running(self) -> bool {
+        self == Self::Running
+    }
+
+    /// Returns `true` if the action has ended, whether it succeeded, failed, or was cancelled.
+    #[must_use]
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
 }
 
 /// [`Component`] for the current action picked by a [`Picker`].
@@ -97,12 +710,644 @@ impl ActionPlugin {
 /// This component is used by the [`ActionPlugin`] when switching actions so that
 /// the previous action can be cancelled before the new action is initiated.
 ///
+/// The entity the current action targets, if any, is stored separately in a [`CurrentTarget`].
+///
 /// [`Picker`]: crate::picking::Picker
 #[derive(Component, Reflect)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[reflect(Component)]
 pub struct CurrentAction(pub ComponentId);
 
+/// [`Component`] for the entity targeted by an actor entity's [`CurrentAction`].
+///
+/// This is the [`BestTarget`] of the winning choice's score entity when the action was picked.
+/// It's removed when the current action has no target, such as the default action,
+/// requested actions other than the picked one, and concurrent [`CurrentActions`].
+///
+/// [`BestTarget`]: crate::targeting::BestTarget
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[reflect(Component, PartialEq, Hash, Debug)]
+pub struct CurrentTarget(pub Entity);
+
+impl MapEntities for CurrentTarget {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// [`Component`] for all actions currently being performed concurrently by an actor entity
+/// with a [`PickMultiple`] [`Picker`].
+///
+/// [`CurrentAction`] is kept in sync with the first (highest scoring) action.
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct CurrentActions(pub Vec<ComponentId>);
+
+impl CurrentActions {
+    /// Returns `true` if these are exactly the given actions, in any order.
+    #[must_use]
+    pub fn matches(&self, actions: &[ComponentId]) -> bool {
+        self.0.len() == actions.len() && actions.iter().all(|action| self.0.contains(action))
+    }
+}
+
+/// How an actor entity reacts when its action ends with [`ActionEndReason::Failed`].
+///
+/// Can be inserted as a [`Component`] on an actor entity, or as a [`Resource`] for all actors.
+/// An actor's component takes precedence over the resource.
+/// In all cases, a failed action abandons the actor's [`ActionQueue`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct OpenDoor;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let open_door = world.init_component::<OpenDoor>();
+/// let idle = world.init_component::<Idle>();
+///
+/// // Try opening the door up to 2 more times before giving up.
+/// let actor = world
+///     .spawn((Picker::new(idle), Highest, CurrentAction(open_door), FailurePolicy::Retry(2)))
+///     .id();
+///
+/// let locked = OnActionEnded::failed_with(open_door, String::from("locked"));
+/// world.trigger_targets(locked, TargetedAction(actor, open_door));
+/// # world.flush();
+/// # assert_eq!(open_door, world.get::<CurrentAction>(actor).unwrap().0);
+/// # assert_eq!(1, world.get::<ActionRetries>(actor).unwrap().count(open_door));
+/// ```
+#[derive(Component, Resource, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, Resource, PartialEq, Debug, Default)]
+pub enum FailurePolicy {
+    /// Request the picked action, as if the action had completed.
+    #[default]
+    Repick,
+    /// Initiate the same action again, up to the given number of times in a row, then re-pick.
+    Retry(u32),
+    /// Request the [`Picker`]'s default action.
+    FallBackToDefault,
+}
+
+/// [`Component`] that tracks how many times in a row an actor entity's action was retried
+/// by a [`FailurePolicy::Retry`]. Inserted automatically on the first retry.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct ActionRetries {
+    /// The action being retried, if any.
+    action: Option<ComponentId>,
+    /// The number of times it was retried.
+    count: u32,
+}
+
+impl ActionRetries {
+    /// Creates a new [`ActionRetries`] that has retried the given action once.
+    #[must_use]
+    pub fn new(action: ComponentId) -> Self {
+        Self {
+            action: Some(action),
+            count: 1,
+        }
+    }
+
+    /// Returns the number of times in a row the given action was retried.
+    #[must_use]
+    pub fn count(&self, action: ComponentId) -> u32 {
+        if self.action == Some(action) {
+            self.count
+        } else {
+            0
+        }
+    }
+
+    /// Records a retry of the given action.
+    pub fn record(&mut self, action: ComponentId) {
+        if self.action == Some(action) {
+            self.count += 1;
+        } else {
+            *self = Self::new(action);
+        }
+    }
+
+    /// Resets the retry count.
+    pub fn reset(&mut self) {
+        self.action = None;
+        self.count = 0;
+    }
+}
+
+/// [`Component`] for actor entities that holds an ordered list of actions to perform one after another,
+/// for simple multi-step behaviors such as "walk to the fridge, then drink".
+///
+/// Whenever the current action completes, the next queued action is requested instead of the picked action.
+/// Once the queue is empty, the actor goes back to requesting its picked action.
+/// Cancelled actions don't advance the queue, so an interrupting action resumes the queue once it completes;
+/// [`clear`](ActionQueue::clear) it to abandon the plan instead.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct WalkToFridge;
+/// #[derive(Component)]
+/// pub struct Drink;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let walk = world.init_component::<WalkToFridge>();
+/// let drink = world.init_component::<Drink>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let actor = world
+///     .spawn((Picker::new(idle), Highest, CurrentAction(walk), ActionQueue::default().with(drink)))
+///     .id();
+///
+/// // Once the actor reaches the fridge, they'll drink.
+/// world.trigger_targets(OnActionEnded::completed(walk), TargetedAction(actor, walk));
+/// # world.flush();
+/// # assert_eq!(drink, world.get::<CurrentAction>(actor).unwrap().0);
+/// # assert!(world.get::<ActionQueue>(actor).unwrap().is_empty());
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ActionQueue(VecDeque<ComponentId>);
+
+impl ActionQueue {
+    /// Appends the given action to the end of the queue.
+    #[must_use]
+    pub fn with(mut self, action: ComponentId) -> Self {
+        self.push(action);
+        self
+    }
+
+    /// Appends the given action to the end of the queue.
+    pub fn push(&mut self, action: ComponentId) {
+        self.0.push_back(action);
+    }
+
+    /// Removes and returns the next action in the queue, if any.
+    pub fn pop(&mut self) -> Option<ComponentId> {
+        self.0.pop_front()
+    }
+
+    /// Returns the next action in the queue without removing it, if any.
+    #[must_use]
+    pub fn peek(&self) -> Option<ComponentId> {
+        self.0.front().copied()
+    }
+
+    /// Removes all queued actions.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns the number of queued actions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no actions are queued.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the queued actions, in order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = ComponentId> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl Extend<ComponentId> for ActionQueue {
+    fn extend<T: IntoIterator<Item = ComponentId>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl FromIterator<ComponentId> for ActionQueue {
+    fn from_iter<T: IntoIterator<Item = ComponentId>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// [`Component`] for actor entities that prevents a freshly initiated action from being cancelled
+/// by a [`RequestAction`] until it has been running for a minimum amount of time,
+/// unless the request is an [interrupt](RequestAction::interrupt).
+///
+/// The minimum run time can be overridden per action. Requires the [`Time`] resource.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Attack;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let attack = world.init_component::<Attack>();
+/// let idle = world.init_component::<Idle>();
+///
+/// world.spawn((
+///     Picker::new(idle),
+///     Highest,
+///     // Commit to each action for at least a second, but to attacks for two seconds.
+///     MinimumRunTime::new(Duration::from_secs(1)).with_action(attack, Duration::from_secs(2)),
+/// ));
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct MinimumRunTime {
+    /// The minimum run time for actions without an override.
+    duration: Duration,
+    /// Per-action overrides of the minimum run time.
+    overrides: HashMap<ComponentId, Duration>,
+    /// The [`Time::elapsed`] at which the current action was initiated, if any.
+    started: Option<Duration>,
+}
+
+impl MinimumRunTime {
+    /// Creates a new [`MinimumRunTime`] with the given minimum run time for all actions.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            overrides: HashMap::default(),
+            started: None,
+        }
+    }
+
+    /// Overrides the minimum run time for the given action.
+    #[must_use]
+    pub fn with_action(mut self, action: ComponentId, duration: Duration) -> Self {
+        self.overrides.insert(action, duration);
+        self
+    }
+
+    /// Returns the minimum run time for the given action.
+    #[must_use]
+    pub fn duration(&self, action: ComponentId) -> Duration {
+        self.overrides.get(&action).copied().unwrap_or(self.duration)
+    }
+
+    /// Sets the minimum run time for actions without an override.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Sets the minimum run time override for the given action.
+    pub fn set_action_duration(&mut self, action: ComponentId, duration: Duration) {
+        self.overrides.insert(action, duration);
+    }
+
+    /// Returns `true` if the given current action was initiated less than its minimum run time before `now`.
+    #[must_use]
+    pub fn is_committed(&self, action: ComponentId, now: Duration) -> bool {
+        self.started
+            .is_some_and(|started| now.saturating_sub(started) < self.duration(action))
+    }
+}
+
+/// [`Component`] for actor entities that decides whether a [`RequestAction`] may cancel their running [`CurrentAction`].
+///
+/// Unlike [`MinimumRunTime`], this policy also applies to [interrupt](RequestAction::interrupt) requests.
+/// Actions that already ended can always be replaced, and actors without an [`Interruptible`] behave like
+/// [`Interruptible::Always`]. Not supported for actors with [`PickedActions`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Casting;
+/// #[derive(Component)]
+/// pub struct Flee;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let casting = world.init_component::<Casting>();
+/// let flee = world.init_component::<Flee>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let flee_scorer = world.spawn(FixedScore::new(0.5)).id();
+/// // Only let fleeing interrupt spells if it scores above 0.9.
+/// let actor = world
+///     .spawn((
+///         Picker::new(idle).with(flee_scorer, flee),
+///         Highest,
+///         Interruptible::ByPriorityAbove(Score::new(0.9)),
+///     ))
+///     .add_child(flee_scorer)
+///     .id();
+/// world.trigger_targets(RequestAction::action(casting), actor);
+/// # world.flush();
+/// world.trigger_targets(RequestAction::action(flee).interrupting(), actor);
+/// # world.flush();
+/// assert_eq!(casting, world.get::<CurrentAction>(actor).unwrap().0);
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub enum Interruptible {
+    /// Any request may cancel the running action.
+    #[default]
+    Always,
+    /// No request may cancel the running action. It has to end on its own.
+    Never,
+    /// Requests may only cancel the running action after it has been running for the given number of seconds.
+    /// Measured like [`MinimumRunTime`], but also holds back [interrupt](RequestAction::interrupt) requests.
+    /// Requires the [`Time`] resource, and behaves like [`Interruptible::Always`] without it.
+    AfterSeconds(f32),
+    /// Requests may only cancel the running action if the requested action scores above the given [`Score`]
+    /// on any of the actor's [`Picker`] choices.
+    ByPriorityAbove(Score),
+}
+
+impl Interruptible {
+    /// Returns `true` if a request may cancel the running action that has been running for `elapsed`,
+    /// for a requested action with the given highest [`Score`], if any.
+    #[must_use]
+    pub fn allows(&self, elapsed: Option<Duration>, score: Option<Score>) -> bool {
+        match *self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::AfterSeconds(seconds) => elapsed.map_or(true, |elapsed| elapsed.as_secs_f32() >= seconds),
+            Self::ByPriorityAbove(threshold) => score.is_some_and(|score| score > threshold),
+        }
+    }
+}
+
+/// [`Component`] for the latest [`OnActionProgress`] reported by an actor entity's [`CurrentAction`],
+/// maintained by the [`ActionPlugin`] and removed when the action ends.
+///
+/// Converts into a [`Score`] of the progress, so it can be scored with [`score_ancestor`](crate::scoring::score_ancestor).
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct ActionProgress {
+    /// The action that reported the progress.
+    action: ComponentId,
+    /// How far along the action is, from 0 to 1.
+    progress: f32,
+}
+
+impl ActionProgress {
+    /// Returns the action that reported the progress.
+    #[must_use]
+    pub fn action(&self) -> ComponentId {
+        self.action
+    }
+
+    /// Returns how far along the action is, from 0 to 1.
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+}
+
+impl From<&ActionProgress> for Score {
+    fn from(progress: &ActionProgress) -> Self {
+        Score::new(progress.progress)
+    }
+}
+
+/// [`Component`] for the [priority](RequestAction::priority) an actor entity's [`CurrentAction`] was requested with,
+/// maintained by the [`ActionPlugin`].
+///
+/// Requests with a lower priority can't cancel the action until it ends.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[reflect(Component, PartialEq, Hash, Debug, Default)]
+pub struct ActionPriority(pub i32);
+
+/// [`Component`] for bookkeeping about an actor entity's [`CurrentAction`], maintained by the [`ActionPlugin`]
+/// whenever the [`CurrentAction`] is switched.
+///
+/// Useful for behaviors such as "give up after 10 seconds" or "re-evaluate if confidence dropped".
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Searching;
+///
+/// /// Give up searching after 10 seconds.
+/// fn give_up_searching(
+///     mut commands: Commands,
+///     time: Res<Time>,
+///     actors: Query<(Entity, &CurrentActionMeta), With<Searching>>,
+/// ) {
+///     for (actor, meta) in &actors {
+///         if meta.elapsed(time.elapsed()) >= Some(Duration::from_secs(10)) {
+///             commands.trigger_action(OnActionEnded::failed_for::<Searching>(), actor);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct CurrentActionMeta {
+    /// The [`Time::elapsed`] at which the action was requested, if the [`Time`] resource exists.
+    pub started: Option<Duration>,
+    /// The [`Picker`] choice score entity that won the pick, if the action was picked from a choice
+    /// rather than requested directly or picked as the default action.
+    pub score_entity: Option<Entity>,
+    /// The winning score entity's [`Score`] when the action was requested.
+    pub score: Option<Score>,
+}
+
+impl CurrentActionMeta {
+    /// Returns how long the action has been running at `now`, if it's known when it was requested.
+    #[must_use]
+    pub fn elapsed(&self, now: Duration) -> Option<Duration> {
+        self.started.map(|started| now.saturating_sub(started))
+    }
+}
+
+/// [`Component`] for [`Picker`] choice score entities that starts a cooldown for their action whenever it ends,
+/// tracked in the actor entity's [`ActionCooldowns`] (inserted if missing).
+///
+/// Pickers skip actions that are cooling down, and [`CooldownScore`] scores their progress.
+///
+/// [`CooldownScore`]: crate::scoring::CooldownScore
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # app.init_resource::<Time>();
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Fireball;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let fireball = world.init_component::<Fireball>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let scorer = world.spawn((FixedScore::new(1.), StartsCooldown(Duration::from_secs(5)))).id();
+/// let actor = world
+///     .spawn((Picker::new(idle).with(scorer, fireball), Highest))
+///     .add_child(scorer)
+///     .id();
+///
+/// world.trigger_targets(OnActionEnded::completed(fireball), TargetedAction(actor, fireball));
+/// # world.flush();
+/// # let cooldowns = world.get::<ActionCooldowns>(actor).unwrap();
+/// # assert_eq!(Some(Duration::from_secs(5)), cooldowns.cooldown(fireball));
+/// # assert!(cooldowns.is_cooling_down(fireball, Duration::ZERO));
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct StartsCooldown(pub Duration);
+
+/// [`Component`] for actor entities that tracks per-action cooldowns.
+///
+/// When an action with a configured cooldown ends (completed or cancelled), it starts cooling down,
+/// and the built-in pickers skip choices for that action until the cooldown has elapsed.
+/// Requires the [`Time`] resource.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # app.init_resource::<Time>();
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Fireball;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let fireball = world.init_component::<Fireball>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let scorer = world.spawn(FixedScore::new(1.)).id();
+/// let actor = world
+///     .spawn((
+///         Picker::new(idle).with(scorer, fireball),
+///         Highest,
+///         ActionCooldowns::default().with_cooldown(fireball, Duration::from_secs(5)),
+///     ))
+///     .add_child(scorer)
+///     .id();
+///
+/// // Once the fireball ends, it can't be picked for 5 seconds.
+/// world.trigger_targets(OnActionEnded::completed(fireball), TargetedAction(actor, fireball));
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(idle), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ActionCooldowns {
+    /// The cooldown duration per action.
+    cooldowns: HashMap<ComponentId, Duration>,
+    /// The [`Time::elapsed`] at which each cooling down action becomes available again.
+    ready_at: HashMap<ComponentId, Duration>,
+}
+
+impl ActionCooldowns {
+    /// Sets the cooldown duration for the given action.
+    #[must_use]
+    pub fn with_cooldown(mut self, action: ComponentId, cooldown: Duration) -> Self {
+        self.cooldowns.insert(action, cooldown);
+        self
+    }
+
+    /// Returns the cooldown duration for the given action, if any.
+    #[must_use]
+    pub fn cooldown(&self, action: ComponentId) -> Option<Duration> {
+        self.cooldowns.get(&action).copied()
+    }
+
+    /// Sets the cooldown duration for the given action.
+    pub fn set_cooldown(&mut self, action: ComponentId, cooldown: Duration) {
+        self.cooldowns.insert(action, cooldown);
+    }
+
+    /// Starts the cooldown for the given action at `now`, if it has a cooldown duration.
+    pub fn start(&mut self, action: ComponentId, now: Duration) {
+        if let Some(cooldown) = self.cooldown(action) {
+            self.ready_at.insert(action, now + cooldown);
+        }
+    }
+
+    /// Resets the cooldown for the given action, making it available immediately.
+    pub fn reset(&mut self, action: ComponentId) {
+        self.ready_at.remove(&action);
+    }
+
+    /// Returns the remaining cooldown for the given action at `now`, or [`Duration::ZERO`] if it's available.
+    #[must_use]
+    pub fn remaining(&self, action: ComponentId, now: Duration) -> Duration {
+        self.ready_at
+            .get(&action)
+            .map_or(Duration::ZERO, |ready_at| ready_at.saturating_sub(now))
+    }
+
+    /// Returns `true` if the given action is still cooling down at `now`.
+    #[must_use]
+    pub fn is_cooling_down(&self, action: ComponentId, now: Duration) -> bool {
+        !self.remaining(action, now).is_zero()
+    }
+}
+
 /// [`Observer`] that listens for [`OnActionInitiated`] events targeting
 /// the specified `Action` [`Component`] and inserts a [`Default`] instance of it
 /// onto the actor entity.
@@ -112,6 +1357,11 @@ pub fn on_action_initiated_insert_default<Action: Component + Default>(
     trigger: Trigger<OnActionInitiated, Action>,
     mut commands: Commands,
 ) {
+    utility_span!(
+        "on_action_initiated_insert_default",
+        entity = ?trigger.entity(),
+        action = std::any::type_name::<Action>()
+    );
     let actor = trigger.entity();
     commands.entity(actor).insert(Action::default());
 }
@@ -126,13 +1376,550 @@ pub fn on_action_initiated_insert_from_resource<Action: Component + Resource + C
     mut commands: Commands,
     resource: Res<Action>,
 ) {
+    utility_span!(
+        "on_action_initiated_insert_from_resource",
+        entity = ?trigger.entity(),
+        action = std::any::type_name::<Action>()
+    );
     let actor = trigger.entity();
     commands.entity(actor).insert(resource.clone());
 }
 
+/// Creates an [`Observer`] that listens for [`OnActionInitiated`] events targeting
+/// the specified `Action` [`Component`] and inserts the instance built by `build` onto the actor entity.
+///
+/// `build` is called with the actor entity and exclusive access to the [`World`], so it can build the action
+/// from arbitrary world state, such as targeting the nearest water source.
+///
+/// Alternatively, use [`on_action_initiated_insert_default`] or [`on_action_initiated_insert_from_resource`]
+/// for simpler cases.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct WaterSource;
+///
+/// #[derive(Component)]
+/// pub struct Drinking {
+///     pub from: Option<Entity>,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.observe(on_action_initiated_insert_with(|actor, world| {
+///     let position = world.get::<Transform>(actor).map_or(Vec3::ZERO, |transform| transform.translation);
+///     let from = world
+///         .query_filtered::<(Entity, &Transform), With<WaterSource>>()
+///         .iter(world)
+///         .min_by(|(_, a), (_, b)| {
+///             let a = a.translation.distance_squared(position);
+///             let b = b.translation.distance_squared(position);
+///             a.total_cmp(&b)
+///         })
+///         .map(|(water, _)| water);
+///     Drinking { from }
+/// }));
+/// # let world = app.world_mut();
+/// # let water = world.spawn((WaterSource, Transform::default())).id();
+/// # let actor = world.spawn(Transform::default()).id();
+/// # let drinking = world.init_component::<Drinking>();
+/// # world.trigger_targets(OnActionInitiated { action: drinking, target: None }, TargetedAction(actor, drinking));
+/// # world.flush();
+/// # assert_eq!(Some(water), world.get::<Drinking>(actor).unwrap().from);
+/// ```
+pub fn on_action_initiated_insert_with<Action: Component>(
+    build: impl Fn(Entity, &mut World) -> Action + Send + Sync + 'static,
+) -> impl Fn(Trigger<OnActionInitiated, Action>, Commands) + Send + Sync + 'static {
+    let build = Arc::new(build);
+    move |trigger: Trigger<OnActionInitiated, Action>, mut commands: Commands| {
+        utility_span!(
+            "on_action_initiated_insert_with",
+            entity = ?trigger.entity(),
+            action = std::any::type_name::<Action>()
+        );
+        let actor = trigger.entity();
+        let build = Arc::clone(&build);
+        commands.add(move |world: &mut World| {
+            let action = build(actor, world);
+            if let Some(mut actor) = world.get_entity_mut(actor) {
+                actor.insert(action);
+            }
+        });
+    }
+}
+
 /// [`Observer`] that listens for [`OnActionEnded`] events targeting
 /// the specified `Action` [`Component`] and removes the component from the actor entity.
 pub fn on_action_ended_remove<Action: Component>(trigger: Trigger<OnActionEnded, Action>, mut commands: Commands) {
+    utility_span!("on_action_ended_remove", entity = ?trigger.entity(), action = std::any::type_name::<Action>());
     let actor = trigger.entity();
     commands.entity(actor).remove::<Action>();
 }
+
+/// [`Component`] for action entities spawned by [`on_action_initiated_spawn_child`],
+/// linking back to the actor entity performing the action.
+///
+/// Action entities are children of the actor entity, but aren't part of its score hierarchy,
+/// so score traversals such as [`ScoreHierarchy`](crate::scoring::ScoreHierarchy) skip them.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct PerformedBy(pub Entity);
+
+/// [`Component`] for actor entities that stores the child action entity spawned for each action
+/// by [`on_action_initiated_spawn_child`], so it can be despawned directly when the action ends.
+///
+/// The action entities are despawned along with this component, such as when the actor entity is despawned.
+#[derive(Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ActionEntities(HashMap<ComponentId, Entity>);
+
+impl ActionEntities {
+    /// Returns the action entity spawned for the given action, if any.
+    #[must_use]
+    pub fn get(&self, action: ComponentId) -> Option<Entity> {
+        self.0.get(&action).copied()
+    }
+
+    /// Returns an iterator over the actions and their action entities.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, Entity)> + '_ {
+        self.0.iter().map(|(&action, &entity)| (action, entity))
+    }
+}
+
+impl Component for ActionEntities {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_remove(|mut world, entity, _component| {
+            let Some(actions) = world.get::<ActionEntities>(entity) else {
+                return;
+            };
+            let actions: Vec<Entity> = actions.0.values().copied().collect();
+            let mut commands = world.commands();
+            for action in actions {
+                if let Some(action) = commands.get_entity(action) {
+                    action.despawn_recursive();
+                }
+            }
+        });
+    }
+}
+
+impl MapEntities for ActionEntities {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for entity in self.0.values_mut() {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+    }
+}
+
+/// [`Observer`] that listens for [`OnActionInitiated`] events targeting
+/// the specified `Action` [`Component`] and spawns a child entity of the actor entity
+/// with a [`Default`] instance of it and a [`PerformedBy`] link back to the actor,
+/// tracked in the actor's [`ActionEntities`].
+/// Action systems can then query for `(&Action, &PerformedBy)` to perform the action,
+/// keeping the action's state off the actor entity. Pair it with [`on_action_ended_despawn_child`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// pub struct Patrolling {
+///     pub waypoint: usize,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.observe(on_action_initiated_spawn_child::<Patrolling>)
+///     .observe(on_action_ended_despawn_child::<Patrolling>);
+///
+/// fn patrol(mut actions: Query<(&mut Patrolling, &PerformedBy)>, mut actors: Query<&mut Transform>) {
+///     for (mut patrolling, &PerformedBy(actor)) in &mut actions {
+///         if let Ok(mut transform) = actors.get_mut(actor) {
+///             // Move towards the current waypoint...
+///             transform.translation.x += 1.;
+///             patrolling.waypoint += 1;
+///         }
+///     }
+/// }
+/// # let world = app.world_mut();
+/// # let actor = world.spawn_empty().id();
+/// # let patrolling = world.init_component::<Patrolling>();
+/// # world.trigger_targets(OnActionInitiated { action: patrolling, target: None }, TargetedAction(actor, patrolling));
+/// # world.flush();
+/// # let mut actions = world.query_filtered::<(Entity, &PerformedBy, &Parent), With<Patrolling>>();
+/// # let (action_entity, performed_by, parent) = actions.single(world);
+/// # assert_eq!((actor, actor), (performed_by.0, parent.get()));
+/// # assert_eq!(Some(action_entity), world.get::<ActionEntities>(actor).unwrap().get(patrolling));
+/// # world.trigger_action(OnActionEnded::completed_for::<Patrolling>(), actor);
+/// # world.flush();
+/// # assert!(world.query::<&Patrolling>().iter(world).next().is_none());
+/// # world.trigger_targets(OnActionInitiated { action: patrolling, target: None }, TargetedAction(actor, patrolling));
+/// # world.flush();
+/// # world.despawn(actor);
+/// # world.flush();
+/// # assert!(world.query::<&Patrolling>().iter(world).next().is_none());
+/// ```
+pub fn on_action_initiated_spawn_child<Action: Component + Default>(
+    trigger: Trigger<OnActionInitiated, Action>,
+    mut commands: Commands,
+) {
+    utility_span!(
+        "on_action_initiated_spawn_child",
+        entity = ?trigger.entity(),
+        action = std::any::type_name::<Action>()
+    );
+    let actor = trigger.entity();
+    let action = trigger.event().action;
+    let action_entity = commands.spawn((Action::default(), PerformedBy(actor))).id();
+    commands.add(move |world: &mut World| {
+        let Some(mut actor) = world.get_entity_mut(actor) else {
+            // The actor was despawned before its action entity could be stored
+            despawn_with_children_recursive(world, action_entity);
+            return;
+        };
+        actor.add_child(action_entity);
+        let previous = if let Some(mut actions) = actor.get_mut::<ActionEntities>() {
+            actions.0.insert(action, action_entity)
+        } else {
+            let mut actions = ActionEntities::default();
+            actions.0.insert(action, action_entity);
+            actor.insert(actions);
+            None
+        };
+        if let Some(previous) = previous {
+            // The action was initiated again without ending
+            despawn_with_children_recursive(world, previous);
+        }
+    });
+}
+
+/// [`Observer`] that listens for [`OnActionEnded`] events targeting
+/// the specified `Action` [`Component`] and despawns the actor entity's child action entity for it,
+/// as spawned by [`on_action_initiated_spawn_child`] and tracked in its [`ActionEntities`].
+pub fn on_action_ended_despawn_child<Action: Component>(
+    trigger: Trigger<OnActionEnded, Action>,
+    mut commands: Commands,
+    mut actors: Query<&mut ActionEntities>,
+) {
+    utility_span!("on_action_ended_despawn_child", entity = ?trigger.entity(), action = std::any::type_name::<Action>());
+    let actor = trigger.entity();
+    let Ok(mut actions) = actors.get_mut(actor) else {
+        return;
+    };
+    let Some(action) = actions.0.remove(&trigger.event().action) else {
+        return;
+    };
+    if let Some(action) = commands.get_entity(action) {
+        action.despawn_recursive();
+    }
+}
+
+/// [`Observer`] that listens for [`OnActionInitiated`] events targeting
+/// the specified `Action` [`Component`] and triggers [`OnActionInitiatedFor<Action>`] for the actor entity.
+///
+/// Usually added with [`RegisterActionExt::add_action_events`].
+pub fn on_action_initiated_trigger_for<Action: Component>(
+    trigger: Trigger<OnActionInitiated, Action>,
+    mut commands: Commands,
+) {
+    utility_span!(
+        "on_action_initiated_trigger_for",
+        entity = ?trigger.entity(),
+        action = std::any::type_name::<Action>()
+    );
+    let actor = trigger.entity();
+    commands.trigger_targets(OnActionInitiatedFor::<Action>::new(), actor);
+}
+
+/// [`Observer`] that listens for [`OnActionEnded`] events targeting
+/// the specified `Action` [`Component`] and triggers [`OnActionEndedFor<Action>`] for the actor entity.
+///
+/// Usually added with [`RegisterActionExt::add_action_events`].
+pub fn on_action_ended_trigger_for<Action: Component>(trigger: Trigger<OnActionEnded, Action>, mut commands: Commands) {
+    utility_span!("on_action_ended_trigger_for", entity = ?trigger.entity(), action = std::any::type_name::<Action>());
+    let actor = trigger.entity();
+    commands.trigger_targets(OnActionEndedFor::<Action>::new(trigger.event().reason.clone()), actor);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{
+        ecs::{component::ComponentId, system::RunSystemOnce},
+        prelude::*,
+    };
+
+    use crate::{
+        acting::{
+            on_action_ended_despawn_child, on_action_initiated_spawn_child, ActionEntities, ActionProgress,
+            ActionState, CurrentAction, CurrentActionMeta, MinimumRunTime, PerformedBy, TargetedAction,
+        },
+        event::{OnActionEnded, OnActionProgress, OnActionStateChanged, PauseAction, RequestAction, ResumeAction},
+        picking::{Highest, Picker},
+        scoring::{FixedScore, Score, ScoreHierarchy},
+        WorldUtilityExt,
+    };
+
+    #[derive(Component)]
+    struct Attack;
+
+    #[derive(Component)]
+    struct Flee;
+
+    #[derive(Component)]
+    struct Idle;
+
+    /// The action [`ComponentId`]s registered by [`fixture`].
+    struct Actions {
+        attack: ComponentId,
+        flee: ComponentId,
+        idle: ComponentId,
+    }
+
+    /// Returns a turn-based app with a [`Time`] resource and the registered test actions.
+    fn fixture() -> (App, Actions) {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased)
+            .init_resource::<Time>();
+        let world = app.world_mut();
+        let actions = Actions {
+            attack: world.init_component::<Attack>(),
+            flee: world.init_component::<Flee>(),
+            idle: world.init_component::<Idle>(),
+        };
+        (app, actions)
+    }
+
+    /// Spawns an actor entity that idles by default and picks the highest of the given scorer choices,
+    /// with the scorers as its children.
+    fn spawn_actor(world: &mut World, idle: ComponentId, choices: &[(Entity, ComponentId)]) -> Entity {
+        let picker = choices.iter().fold(Picker::new(idle), |picker, &(scorer, action)| {
+            picker.with(scorer, action)
+        });
+        let scorers: Vec<Entity> = choices.iter().map(|&(scorer, _)| scorer).collect();
+        world.spawn((picker, Highest)).push_children(&scorers).id()
+    }
+
+    /// Scores and picks for the given actor entity, then requests the picked action.
+    fn think(world: &mut World, actor: Entity) {
+        world.score_and_pick(actor);
+        world.trigger_targets(RequestAction::picked(), actor);
+        world.flush();
+    }
+
+    #[test]
+    fn minimum_run_time_refuses_better_picks_until_elapsed() {
+        let (mut app, Actions { attack, flee, idle }) = fixture();
+        let world = app.world_mut();
+
+        let attack_scorer = world.spawn(FixedScore::new(0.7)).id();
+        let flee_scorer = world.spawn(FixedScore::new(0.2)).id();
+        let actor = spawn_actor(world, idle, &[(attack_scorer, attack), (flee_scorer, flee)]);
+        world
+            .entity_mut(actor)
+            .insert(MinimumRunTime::new(Duration::from_secs(1)));
+        think(world, actor);
+        assert_eq!(Some(&CurrentAction(attack)), world.get::<CurrentAction>(actor));
+
+        // Fleeing now scores higher, but attacking hasn't run for long enough yet.
+        world.entity_mut(flee_scorer).insert(FixedScore::new(0.9));
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(500));
+        think(world, actor);
+        assert_eq!(Some(flee), world.get::<Picker>(actor).unwrap().picked);
+        assert_eq!(Some(&CurrentAction(attack)), world.get::<CurrentAction>(actor));
+
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(500));
+        think(world, actor);
+        assert_eq!(Some(&CurrentAction(flee)), world.get::<CurrentAction>(actor));
+    }
+
+    #[test]
+    fn action_state_transitions() {
+        #[derive(Resource, Default)]
+        struct Changes(Vec<OnActionStateChanged>);
+
+        let (mut app, Actions { attack, flee, idle }) = fixture();
+        app.init_resource::<Changes>().observe(
+            |trigger: Trigger<OnActionStateChanged>, mut changes: ResMut<Changes>| {
+                changes.0.push(*trigger.event());
+            },
+        );
+        let world = app.world_mut();
+
+        let actor = spawn_actor(world, idle, &[]);
+        world.trigger_targets(RequestAction::action(attack), actor);
+        world.flush();
+        assert_eq!(Some(&ActionState::Running), world.get::<ActionState>(actor));
+
+        // Switching actions cancels the running one.
+        world.trigger_targets(RequestAction::action(flee), actor);
+        world.flush();
+        // Ending the action re-picks the default action.
+        world.trigger_targets(OnActionEnded::completed(flee), TargetedAction(actor, flee));
+        world.flush();
+        // Finished actions don't transition again.
+        world.trigger_targets(OnActionEnded::cancelled(attack), TargetedAction(actor, attack));
+        world.flush();
+
+        let changes = |action| {
+            world
+                .resource::<Changes>()
+                .0
+                .iter()
+                .filter(|change| change.action == action)
+                .map(|change| (change.previous, change.state))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![
+                (None, ActionState::Requested),
+                (Some(ActionState::Requested), ActionState::Running),
+                (Some(ActionState::Running), ActionState::Cancelled),
+            ],
+            changes(attack)
+        );
+        assert_eq!(
+            vec![
+                (None, ActionState::Requested),
+                (Some(ActionState::Requested), ActionState::Running),
+                (Some(ActionState::Running), ActionState::Succeeded),
+            ],
+            changes(flee)
+        );
+        assert_eq!(
+            vec![
+                (None, ActionState::Requested),
+                (Some(ActionState::Requested), ActionState::Running),
+            ],
+            changes(idle)
+        );
+        assert_eq!(Some(&ActionState::Running), world.get::<ActionState>(actor));
+    }
+
+    #[test]
+    fn paused_actions_ignore_requests_until_resumed() {
+        let (mut app, Actions { attack, flee, idle }) = fixture();
+        let world = app.world_mut();
+
+        let actor = spawn_actor(world, idle, &[]);
+        world.trigger_targets(RequestAction::action(attack), actor);
+        world.flush();
+
+        world.trigger_targets(PauseAction, actor);
+        world.flush();
+        assert_eq!(Some(&ActionState::Paused), world.get::<ActionState>(actor));
+
+        // Paused actions ignore regular requests.
+        world.trigger_targets(RequestAction::action(flee), actor);
+        world.flush();
+        assert_eq!(Some(&CurrentAction(attack)), world.get::<CurrentAction>(actor));
+        assert_eq!(Some(&ActionState::Paused), world.get::<ActionState>(actor));
+
+        world.trigger_targets(ResumeAction, actor);
+        world.flush();
+        assert_eq!(Some(&CurrentAction(attack)), world.get::<CurrentAction>(actor));
+        assert_eq!(Some(&ActionState::Running), world.get::<ActionState>(actor));
+
+        // But not interrupts.
+        world.trigger_targets(PauseAction, actor);
+        world.flush();
+        world.trigger_targets(RequestAction::action(flee).interrupting(), actor);
+        world.flush();
+        assert_eq!(Some(&CurrentAction(flee)), world.get::<CurrentAction>(actor));
+        assert_eq!(Some(&ActionState::Running), world.get::<ActionState>(actor));
+    }
+
+    #[test]
+    fn progress_is_recorded_for_the_current_action_until_it_ends() {
+        let (mut app, Actions { attack, flee, idle }) = fixture();
+        let world = app.world_mut();
+
+        let actor = spawn_actor(world, idle, &[]);
+        world.trigger_targets(RequestAction::action(attack), actor);
+        world.flush();
+
+        world.trigger_targets(OnActionProgress::new(attack, 0.4), TargetedAction(actor, attack));
+        world.flush();
+        let progress = world.get::<ActionProgress>(actor).unwrap();
+        assert_eq!(attack, progress.action());
+        assert_eq!(0.4, progress.progress());
+
+        // Progress from other actions is stale.
+        world.trigger_targets(OnActionProgress::new(flee, 0.9), TargetedAction(actor, flee));
+        world.flush();
+        assert_eq!(0.4, world.get::<ActionProgress>(actor).unwrap().progress());
+
+        world.trigger_targets(OnActionEnded::completed(attack), TargetedAction(actor, attack));
+        world.flush();
+        assert!(world.get::<ActionProgress>(actor).is_none());
+    }
+
+    #[test]
+    fn current_action_meta_tracks_the_winning_choice() {
+        let (mut app, Actions { attack, flee, idle }) = fixture();
+        let world = app.world_mut();
+
+        let attack_scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = spawn_actor(world, idle, &[(attack_scorer, attack)]);
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(2));
+        think(world, actor);
+        let meta = *world.get::<CurrentActionMeta>(actor).unwrap();
+        assert_eq!(Some(Duration::from_secs(2)), meta.started);
+        assert_eq!(Some(attack_scorer), meta.score_entity);
+        assert_eq!(Some(Score::new(0.7)), meta.score);
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(3));
+        let now = world.resource::<Time>().elapsed();
+        assert_eq!(Some(Duration::from_secs(3)), meta.elapsed(now));
+
+        // Directly requested actions weren't picked from a choice.
+        world.trigger_targets(RequestAction::action(flee), actor);
+        world.flush();
+        let meta = *world.get::<CurrentActionMeta>(actor).unwrap();
+        assert_eq!(Some(Duration::from_secs(5)), meta.started);
+        assert_eq!(None, meta.score_entity);
+        assert_eq!(None, meta.score);
+    }
+
+    #[test]
+    fn action_children_stay_out_of_the_score_tree() {
+        #[derive(Component, Default)]
+        struct Attacking;
+
+        let (mut app, Actions { idle, .. }) = fixture();
+        app.observe(on_action_initiated_spawn_child::<Attacking>)
+            .observe(on_action_ended_despawn_child::<Attacking>);
+        let world = app.world_mut();
+
+        let attacking = world.init_component::<Attacking>();
+
+        let attack_scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = spawn_actor(world, idle, &[(attack_scorer, attacking)]);
+        think(world, actor);
+        let action = world.get::<ActionEntities>(actor).unwrap().get(attacking).unwrap();
+        assert_eq!(Some(actor), world.get::<Parent>(action).map(Parent::get));
+        assert_eq!(Some(&PerformedBy(actor)), world.get::<PerformedBy>(action));
+
+        // The action child is neither scored nor picked from.
+        let children =
+            world.run_system_once(move |hierarchy: ScoreHierarchy| hierarchy.children(actor).collect::<Vec<_>>());
+        assert_eq!(vec![attack_scorer], children);
+        assert!(world.get::<Score>(action).is_none());
+        assert_eq!(Some(attacking), world.score_and_pick(actor));
+
+        world.trigger_targets(OnActionEnded::completed(attacking), TargetedAction(actor, attacking));
+        world.flush();
+        assert!(world.get_entity(action).is_none());
+    }
+}
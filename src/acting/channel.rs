@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+
+use bevy::{
+    ecs::{component::ComponentId, system::SystemParam},
+    prelude::*,
+};
+
+use crate::acting::CurrentAction;
+
+/// [`Component`] for child entities of an actor entity that perform actions independently of the actor
+/// and of each other, such as locomotion, hands, and voice.
+///
+/// A channel entity is an actor entity in its own right: it has its own [`Picker`](crate::picking::Picker)
+/// and score children (or just a different set of choices), its own [`CurrentAction`] and [`ActionState`](crate::acting::ActionState),
+/// and receives its own action events. Switching actions on a channel only cancels that channel's action,
+/// so an actor can walk, wave, and talk all at once.
+///
+/// Scorers on channels can still read the actor's view of the world with [`AncestorQuery`](crate::scoring::AncestorQuery)
+/// or [`score_ancestor`](crate::scoring::score_ancestor), since the actor is their ancestor.
+/// Use [`ActionChannels`] to find an actor's channels by name, and vice versa.
+///
+/// Pausing or resuming an actor entity with [`PauseAction`](crate::event::PauseAction) or
+/// [`ResumeAction`](crate::event::ResumeAction) also pauses or resumes all of its channels.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Walking;
+/// #[derive(Component)]
+/// pub struct Waving;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let walking = world.init_component::<Walking>();
+/// let waving = world.init_component::<Waving>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let actor = world.spawn_empty().id();
+/// let locomotion = world
+///     .spawn((ActionChannel::new("locomotion"), Picker::new(idle), Highest))
+///     .set_parent(actor)
+///     .id();
+/// let hands = world
+///     .spawn((ActionChannel::new("hands"), Picker::new(idle), Highest))
+///     .set_parent(actor)
+///     .id();
+///
+/// world.trigger_targets(RequestAction::action(walking), locomotion);
+/// world.trigger_targets(RequestAction::action(waving), hands);
+/// # world.flush();
+/// // Waving didn't cancel walking, as they're on different channels.
+/// assert_eq!(walking, world.get::<CurrentAction>(locomotion).unwrap().0);
+/// assert_eq!(waving, world.get::<CurrentAction>(hands).unwrap().0);
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[reflect(Component, PartialEq, Hash, Debug)]
+pub struct ActionChannel {
+    /// The name of the channel, unique among its actor's channels.
+    name: Cow<'static, str>,
+}
+
+impl ActionChannel {
+    /// Creates a new [`ActionChannel`] with the given name.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Returns the name of the channel.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// [`SystemParam`] for looking up an actor entity's [`ActionChannel`]s.
+#[derive(SystemParam)]
+pub struct ActionChannels<'w, 's> {
+    /// The children of actor entities.
+    children: Query<'w, 's, &'static Children>,
+    /// The channel entities, their actors, and their current actions.
+    channels: Query<'w, 's, (&'static ActionChannel, &'static Parent, Option<&'static CurrentAction>)>,
+}
+
+impl<'w, 's> ActionChannels<'w, 's> {
+    /// Returns the actor entity's channel entity with the given name, if any.
+    #[must_use]
+    pub fn get(&self, actor: Entity, name: &str) -> Option<Entity> {
+        self.iter(actor)
+            .find(|&(_, channel)| channel.name() == name)
+            .map(|(entity, _)| entity)
+    }
+
+    /// Returns the current action of the actor entity's channel with the given name, if any.
+    #[must_use]
+    pub fn current_action(&self, actor: Entity, name: &str) -> Option<ComponentId> {
+        let channel = self.get(actor, name)?;
+        let (_, _, current_action) = self.channels.get(channel).ok()?;
+        current_action.map(|current_action| current_action.0)
+    }
+
+    /// Returns the actor entity that the given channel entity belongs to, if it's a channel.
+    #[must_use]
+    pub fn actor(&self, channel: Entity) -> Option<Entity> {
+        self.channels.get(channel).ok().map(|(_, parent, _)| parent.get())
+    }
+
+    /// Returns an iterator over the actor entity's channel entities and their [`ActionChannel`]s.
+    pub fn iter(&self, actor: Entity) -> impl Iterator<Item = (Entity, &ActionChannel)> + '_ {
+        self.children
+            .get(actor)
+            .into_iter()
+            .flatten()
+            .filter_map(|&child| self.channels.get(child).ok().map(|(channel, _, _)| (child, channel)))
+    }
+}
@@ -0,0 +1,140 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use bevy::{ecs::component::ComponentId, prelude::*, utils::HashMap};
+
+use crate::acting::{on_action_ended_trigger_for, on_action_initiated_trigger_for};
+
+/// [`Resource`] that maps action [`ComponentId`]s to stable string names, and back.
+///
+/// [`ComponentId`]s depend on registration order, so they aren't stable across runs.
+/// Use the names instead when action identities need to be persisted or sent elsewhere,
+/// such as asset-driven pickers, save games, and network replication.
+///
+/// Actions are registered with [`RegisterActionExt::register_action`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Drinking;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.register_action::<Drinking>("drink");
+///
+/// # let world = app.world_mut();
+/// let drinking = world.init_component::<Drinking>();
+/// let registry = world.resource::<ActionRegistry>();
+/// assert_eq!(Some(drinking), registry.id("drink"));
+/// assert_eq!(Some("drink"), registry.name(drinking));
+/// ```
+#[derive(Resource, Reflect)]
+#[derive(Clone, PartialEq, Debug, Default)]
+#[reflect(Resource, PartialEq, Debug, Default)]
+pub struct ActionRegistry {
+    /// Action [`ComponentId`]s by name.
+    ids: HashMap<Cow<'static, str>, ComponentId>,
+    /// Action names by [`ComponentId`].
+    names: HashMap<ComponentId, Cow<'static, str>>,
+}
+
+impl ActionRegistry {
+    /// Registers the given action under the given name, replacing any previous registration of either.
+    pub fn register(&mut self, action: ComponentId, name: impl Into<Cow<'static, str>>) {
+        let name = name.into();
+        if let Some(previous) = self.ids.insert(name.clone(), action) {
+            if previous != action {
+                warn!("action name `{name}` was re-registered for a different action");
+                self.names.remove(&previous);
+            }
+        }
+        if let Some(previous) = self.names.insert(action, name.clone()) {
+            if previous != name {
+                self.ids.remove(&previous);
+            }
+        }
+    }
+
+    /// Unregisters the given action, returning its name if it was registered.
+    pub fn unregister(&mut self, action: ComponentId) -> Option<Cow<'static, str>> {
+        let name = self.names.remove(&action)?;
+        self.ids.remove(&name);
+        Some(name)
+    }
+
+    /// Returns the action [`ComponentId`] registered under the given name, if any.
+    #[must_use]
+    pub fn id(&self, name: &str) -> Option<ComponentId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the name of the given action, if it's registered.
+    #[must_use]
+    pub fn name(&self, action: ComponentId) -> Option<&str> {
+        self.names.get(&action).map(AsRef::as_ref)
+    }
+
+    /// Returns `true` if the given action is registered.
+    #[must_use]
+    pub fn contains(&self, action: ComponentId) -> bool {
+        self.names.contains_key(&action)
+    }
+
+    /// Returns an iterator over the registered actions and their names, in arbitrary order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (ComponentId, &str)> + '_ {
+        self.names.iter().map(|(&action, name)| (action, name.as_ref()))
+    }
+
+    /// Returns the number of registered actions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if no actions are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// [`App`] extension trait for registering actions.
+pub trait RegisterActionExt {
+    /// Registers the action [`Component`] `A` under the given stable name in the [`ActionRegistry`],
+    /// initializing both if needed.
+    fn register_action<A: Component>(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self;
+
+    /// Adds the [`Observer`]s that trigger the strongly-typed [`OnActionInitiatedFor<A>`] and [`OnActionEndedFor<A>`]
+    /// events for the action [`Component`] `A`. Adding them more than once does nothing.
+    ///
+    /// [`OnActionInitiatedFor<A>`]: crate::event::OnActionInitiatedFor
+    /// [`OnActionEndedFor<A>`]: crate::event::OnActionEndedFor
+    fn add_action_events<A: Component>(&mut self) -> &mut Self;
+}
+
+/// [`Resource`] marking that the typed action events for the action [`Component`] `A` were added.
+#[derive(Resource)]
+struct ActionEventsAdded<A: Component>(PhantomData<fn() -> A>);
+
+impl RegisterActionExt for App {
+    fn register_action<A: Component>(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        let world = self.world_mut();
+        let action = world.init_component::<A>();
+        world
+            .get_resource_or_insert_with(ActionRegistry::default)
+            .register(action, name);
+        self
+    }
+
+    fn add_action_events<A: Component>(&mut self) -> &mut Self {
+        if self.world().contains_resource::<ActionEventsAdded<A>>() {
+            return self;
+        }
+        self.insert_resource(ActionEventsAdded::<A>(PhantomData))
+            .observe(on_action_initiated_trigger_for::<A>)
+            .observe(on_action_ended_trigger_for::<A>)
+    }
+}
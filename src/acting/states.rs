@@ -0,0 +1,164 @@
+use bevy::{ecs::component::ComponentId, prelude::*, utils::HashMap};
+
+use crate::{
+    acting::CurrentAction,
+    event::{OnEnterActionState, OnExitActionState},
+};
+
+/// [`Resource`] that maps action [`ComponentId`]s to the per-entity state [`Component`] `S`
+/// mirrored onto actor entities while that action is their [`CurrentAction`].
+///
+/// Actions are mapped with [`ActionStatesExt::map_action_state`].
+#[derive(Resource)]
+pub struct ActionStateMap<S: Component + Clone + PartialEq> {
+    /// States by action [`ComponentId`].
+    states: HashMap<ComponentId, S>,
+}
+
+impl<S: Component + Clone + PartialEq> ActionStateMap<S> {
+    /// Maps the given action to the given state, replacing any previous mapping.
+    pub fn insert(&mut self, action: ComponentId, state: S) {
+        self.states.insert(action, state);
+    }
+
+    /// Unmaps the given action, returning its state if it was mapped.
+    pub fn remove(&mut self, action: ComponentId) -> Option<S> {
+        self.states.remove(&action)
+    }
+
+    /// Returns the state mapped to the given action, if any.
+    #[must_use]
+    pub fn get(&self, action: ComponentId) -> Option<&S> {
+        self.states.get(&action)
+    }
+
+    /// [`Observer`] that mirrors the [`CurrentAction`] of the target actor entity into its state `S`.
+    ///
+    /// If the state changes, [`OnExitActionState`] is triggered with the previous state,
+    /// and [`OnEnterActionState`] with the next state. Actions without a mapped state remove it.
+    pub fn on_current_action_inserted(
+        trigger: Trigger<OnInsert, CurrentAction>,
+        mut commands: Commands,
+        map: Res<ActionStateMap<S>>,
+        actors: Query<(&CurrentAction, Option<&S>)>,
+    ) {
+        utility_span!(
+            "ActionStateMap::on_current_action_inserted",
+            entity = ?trigger.entity(),
+            state = std::any::type_name::<S>()
+        );
+        let actor = trigger.entity();
+        let Ok((current, previous)) = actors.get(actor) else {
+            return;
+        };
+        let next = map.get(current.0);
+        if previous == next {
+            // Switching between actions with the same state isn't a transition
+            return;
+        }
+
+        if let Some(previous) = previous {
+            commands.trigger_targets(OnExitActionState(previous.clone()), actor);
+        }
+        match next {
+            Some(next) => {
+                commands.entity(actor).insert(next.clone());
+                commands.trigger_targets(OnEnterActionState(next.clone()), actor);
+            }
+            None => {
+                commands.entity(actor).remove::<S>();
+            }
+        }
+    }
+
+    /// [`Observer`] that removes the state `S` of the target actor entity once it has no [`CurrentAction`],
+    /// triggering [`OnExitActionState`].
+    pub fn on_current_action_removed(
+        trigger: Trigger<OnRemove, CurrentAction>,
+        mut commands: Commands,
+        actors: Query<&S, With<CurrentAction>>,
+    ) {
+        utility_span!(
+            "ActionStateMap::on_current_action_removed",
+            entity = ?trigger.entity(),
+            state = std::any::type_name::<S>()
+        );
+        let actor = trigger.entity();
+        let Ok(previous) = actors.get(actor) else {
+            return;
+        };
+        commands.trigger_targets(OnExitActionState(previous.clone()), actor);
+        commands.entity(actor).remove::<S>();
+    }
+}
+
+impl<S: Component + Clone + PartialEq> Default for ActionStateMap<S> {
+    fn default() -> Self {
+        Self {
+            states: HashMap::default(),
+        }
+    }
+}
+
+/// [`App`] extension trait for mirroring actions into per-entity states.
+pub trait ActionStatesExt {
+    /// Maps the action [`Component`] `A` to the given state, so that actor entities whose [`CurrentAction`] is `A`
+    /// get a clone of the state inserted, and [`OnExitActionState`] and [`OnEnterActionState`] triggered
+    /// when it changes. This lets animation and UI code built around states consume AI decisions directly.
+    ///
+    /// The state [`Component`] `S` is managed entirely by the mapping: it's removed while the [`CurrentAction`]
+    /// isn't mapped, and shouldn't be inserted manually.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// pub enum Animation {
+    ///     Standing,
+    ///     Sipping,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// pub struct Drinking;
+    /// #[derive(Component)]
+    /// pub struct Idle;
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservedUtilityPlugins::TurnBased)
+    ///     .map_action_state::<Idle, _>(Animation::Standing)
+    ///     .map_action_state::<Drinking, _>(Animation::Sipping)
+    ///     .observe(|trigger: Trigger<OnEnterActionState<Animation>>| {
+    ///         info!("{:?} started {:?}", trigger.entity(), trigger.event().0);
+    ///     });
+    /// # let mut world = app.world_mut();
+    /// let idle = world.init_component::<Idle>();
+    /// let drinking = world.init_component::<Drinking>();
+    ///
+    /// let actor = world.spawn((Picker::new(idle), Highest)).id();
+    /// # world.flush();
+    /// assert_eq!(Some(&Animation::Standing), world.get::<Animation>(actor));
+    ///
+    /// world.trigger_targets(RequestAction::action(drinking), actor);
+    /// # world.flush();
+    /// assert_eq!(Some(&Animation::Sipping), world.get::<Animation>(actor));
+    /// ```
+    fn map_action_state<A: Component, S: Component + Clone + PartialEq>(&mut self, state: S) -> &mut Self;
+}
+
+impl ActionStatesExt for App {
+    fn map_action_state<A: Component, S: Component + Clone + PartialEq>(&mut self, state: S) -> &mut Self {
+        if !self.world().contains_resource::<ActionStateMap<S>>() {
+            self.init_resource::<ActionStateMap<S>>()
+                .observe(ActionStateMap::<S>::on_current_action_inserted)
+                .observe(ActionStateMap::<S>::on_current_action_removed);
+        }
+        let action = self.world_mut().init_component::<A>();
+        self.world_mut()
+            .resource_mut::<ActionStateMap<S>>()
+            .insert(action, state);
+        self
+    }
+}
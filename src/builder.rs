@@ -0,0 +1,329 @@
+//! Fluent builders for declaring whole score trees in one expression.
+//!
+//! Manually spawning score entities bottom-up and wiring up the [`Picker`]'s choices by hand is error-prone,
+//! so [`ScoreTreeExt::with_score_tree`] does both at once:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_observed_utility::prelude::*;
+//!
+//! #[derive(Component)]
+//! pub struct Thirsty;
+//! #[derive(Component)]
+//! pub struct Drinking;
+//! #[derive(Component)]
+//! pub struct Idle;
+//!
+//! # let mut app = App::new();
+//! # app.add_plugins(ObservedUtilityPlugins::RealTime);
+//! # let mut world = app.world_mut();
+//! let idle = world.init_component::<Idle>();
+//! let drinking = world.init_component::<Drinking>();
+//!
+//! # let mut commands = world.commands();
+//! let actor = commands
+//!     .spawn(Highest)
+//!     .with_score_tree(idle, |tree| {
+//!         tree.choice_with(drinking, Sum::new(0.2), |sum| {
+//!             sum.score(Thirsty);
+//!             sum.score(FixedScore::new(0.1));
+//!         });
+//!     })
+//!     .id();
+//! # world.flush();
+//! # let picker = world.get::<Picker>(actor).unwrap();
+//! # assert_eq!(picker.choices.len(), 1);
+//! # assert_eq!(world.get::<CurrentAction>(actor).unwrap().0, idle);
+//! ```
+//!
+//! To spawn a new actor entity along with its score tree, use [`SpawnActorExt::spawn_actor`] instead.
+//!
+//! [`Picker`]: crate::picking::Picker
+
+use bevy::{
+    ecs::{component::ComponentId, system::EntityCommands},
+    prelude::*,
+};
+
+use crate::{acting::CurrentAction, picking::Picker, scoring::Score};
+
+/// [`EntityCommands`] extension trait for building score trees under actor entities.
+pub trait ScoreTreeExt {
+    /// Spawns the score tree built by `build` as children of this entity,
+    /// and inserts a [`Picker`] with the built choices and the given default action, if any,
+    /// along with a [`CurrentAction`] set to the default action.
+    ///
+    /// Without a default action, the [`Picker`] is created with [`Picker::without_default`]
+    /// and no [`CurrentAction`] is inserted.
+    ///
+    /// Every spawned score entity gets a default [`Score`], unless the given bundle already provides one.
+    fn with_score_tree(
+        &mut self,
+        default: impl Into<Option<ComponentId>>,
+        build: impl FnOnce(&mut ChoiceBuilder),
+    ) -> &mut Self;
+}
+
+impl ScoreTreeExt for EntityCommands<'_> {
+    fn with_score_tree(
+        &mut self,
+        default: impl Into<Option<ComponentId>>,
+        build: impl FnOnce(&mut ChoiceBuilder),
+    ) -> &mut Self {
+        let default = default.into();
+        let mut picker = default.map_or_else(Picker::without_default, Picker::new);
+
+        self.with_children(|parent| {
+            let mut builder = ChoiceBuilder {
+                tree: ScoreTreeBuilder { parent },
+                choices: Vec::new(),
+            };
+            build(&mut builder);
+
+            picker.choices.extend(builder.choices);
+        });
+
+        match default {
+            Some(default) => self.insert((picker, CurrentAction(default))),
+            None => self.insert(picker),
+        }
+    }
+}
+
+/// [`Commands`] extension trait for spawning whole actor entities in one go.
+pub trait SpawnActorExt {
+    /// Spawns an actor entity with the given bundle (usually a picker kind such as [`Highest`]),
+    /// along with the score tree built by `build` as its children,
+    /// a [`Picker`] with the built choices and the given default action, if any,
+    /// and a [`CurrentAction`] set to the default action.
+    ///
+    /// Unlike spawning the score entities bottom-up and inserting the [`Picker`] last,
+    /// the [`Picker`]'s choices can't get out of sync with the actor's children.
+    ///
+    /// [`Highest`]: crate::picking::Highest
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// pub struct Thirsty;
+    /// #[derive(Component)]
+    /// pub struct Drinking;
+    /// #[derive(Component)]
+    /// pub struct Idle;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+    /// # let mut world = app.world_mut();
+    /// let idle = world.init_component::<Idle>();
+    /// let drinking = world.init_component::<Drinking>();
+    ///
+    /// # let mut commands = world.commands();
+    /// let actor = commands
+    ///     .spawn_actor((Name::new("Actor"), Highest), idle, |tree| {
+    ///         tree.choice(drinking, Thirsty);
+    ///     })
+    ///     .id();
+    /// # world.flush();
+    /// # assert_eq!(world.get::<Picker>(actor).unwrap().choices.len(), 1);
+    /// # assert_eq!(world.get::<CurrentAction>(actor).unwrap().0, idle);
+    /// ```
+    fn spawn_actor(
+        &mut self,
+        bundle: impl Bundle,
+        default: impl Into<Option<ComponentId>>,
+        build: impl FnOnce(&mut ChoiceBuilder),
+    ) -> EntityCommands<'_>;
+}
+
+impl SpawnActorExt for Commands<'_, '_> {
+    fn spawn_actor(
+        &mut self,
+        bundle: impl Bundle,
+        default: impl Into<Option<ComponentId>>,
+        build: impl FnOnce(&mut ChoiceBuilder),
+    ) -> EntityCommands<'_> {
+        let mut actor = self.spawn(bundle);
+        actor.with_score_tree(default, build);
+        actor
+    }
+}
+
+/// Builder for the top-level score entities of an actor, each of which is a [`Picker`] choice for an action.
+///
+/// See [`ScoreTreeExt::with_score_tree`].
+pub struct ChoiceBuilder<'a, 'b> {
+    tree: ScoreTreeBuilder<'a, 'b>,
+    choices: Vec<(Entity, ComponentId)>,
+}
+
+impl ChoiceBuilder<'_, '_> {
+    /// Spawns a score entity with the given bundle, which is picked for the given action.
+    pub fn choice(&mut self, action: ComponentId, scorer: impl Bundle) -> &mut Self {
+        self.choice_with(action, scorer, |_| {})
+    }
+
+    /// Spawns a score entity with the given bundle and children, which is picked for the given action.
+    pub fn choice_with(
+        &mut self,
+        action: ComponentId,
+        scorer: impl Bundle,
+        children: impl FnOnce(&mut ScoreTreeBuilder),
+    ) -> &mut Self {
+        let entity = self.tree.spawn(scorer, children);
+        self.choices.push((entity, action));
+        self
+    }
+}
+
+/// Builder for nested score entities. See [`ScoreTreeExt::with_score_tree`].
+pub struct ScoreTreeBuilder<'a, 'b> {
+    parent: &'a mut ChildBuilder<'b>,
+}
+
+impl ScoreTreeBuilder<'_, '_> {
+    /// Spawns a child score entity with the given bundle.
+    pub fn score(&mut self, scorer: impl Bundle) -> &mut Self {
+        self.score_with(scorer, |_| {})
+    }
+
+    /// Spawns a child score entity with the given bundle and its own children.
+    pub fn score_with(&mut self, scorer: impl Bundle, children: impl FnOnce(&mut ScoreTreeBuilder)) -> &mut Self {
+        self.spawn(scorer, children);
+        self
+    }
+
+    /// Returns the parent score (or actor) entity that children are being spawned under.
+    #[must_use]
+    pub fn parent_entity(&self) -> Entity {
+        self.parent.parent_entity()
+    }
+
+    fn spawn(&mut self, scorer: impl Bundle, children: impl FnOnce(&mut ScoreTreeBuilder)) -> Entity {
+        // Insert the default score first, so that the bundle can override it.
+        let mut entity = self.parent.spawn(Score::default());
+        entity.insert(scorer);
+        entity.with_children(|parent| children(&mut ScoreTreeBuilder { parent }));
+        entity.id()
+    }
+}
+
+/// Declares a whole score tree under an actor entity, expanding into [`ScoreTreeExt::with_score_tree`] calls,
+/// and returns the actor [`Entity`].
+///
+/// The syntax is `score_tree!(entity_commands, default_action, { choices })`, where each choice is
+/// `action => scorer` or `action => scorer => [ children ]`, and each child is `scorer` or `scorer => [ children ]`.
+///
+/// Scorers written literally as `Evaluated::new(..)` are checked at compile time to have exactly one child.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Thirsty;
+/// #[derive(Component)]
+/// pub struct Drinking;
+/// #[derive(Component)]
+/// pub struct Wandering;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// let idle = world.init_component::<Idle>();
+/// let drinking = world.init_component::<Drinking>();
+/// let wandering = world.init_component::<Wandering>();
+///
+/// # let mut commands = world.commands();
+/// let actor = score_tree!(commands.spawn(Highest), idle, {
+///     drinking => Sum::new(0.2) => [
+///         Thirsty,
+///         Evaluated::new(PowerEvaluator::default()) => [ FixedScore::new(0.5) ],
+///     ],
+///     wandering => FixedScore::new(0.3),
+/// });
+/// # world.flush();
+/// # assert_eq!(world.get::<Picker>(actor).unwrap().choices.len(), 2);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use bevy::prelude::*;
+/// # use bevy_observed_utility::prelude::*;
+/// # let mut world = World::new();
+/// # let idle = world.init_component::<Score>();
+/// # let mut commands = world.commands();
+/// // Evaluated needs exactly one child.
+/// score_tree!(commands.spawn(Highest), idle, {
+///     idle => Evaluated::new(PowerEvaluator::default()) => [ FixedScore::new(0.1), FixedScore::new(0.2) ],
+/// });
+/// ```
+#[macro_export]
+macro_rules! score_tree {
+    // Top-level choices
+    (@choices $tree:ident; $(,)?) => {};
+    (@choices $tree:ident; $action:expr => Evaluated::new($($args:tt)*) => [ $($children:tt)* ] $(, $($rest:tt)*)?) => {
+        $crate::score_tree!(@one_child $($children)*);
+        $tree.choice_with($action, $crate::scoring::Evaluated::new($($args)*), |tree| {
+            $crate::score_tree!(@scores tree; $($children)*);
+        });
+        $crate::score_tree!(@choices $tree; $($($rest)*)?);
+    };
+    (@choices $tree:ident; $action:expr => Evaluated::new($($args:tt)*) $(, $($rest:tt)*)?) => {
+        ::core::compile_error!("`Evaluated` score entities must have exactly one child");
+    };
+    (@choices $tree:ident; $action:expr => $scorer:expr => [ $($children:tt)* ] $(, $($rest:tt)*)?) => {
+        $tree.choice_with($action, $scorer, |tree| {
+            $crate::score_tree!(@scores tree; $($children)*);
+        });
+        $crate::score_tree!(@choices $tree; $($($rest)*)?);
+    };
+    (@choices $tree:ident; $action:expr => $scorer:expr $(, $($rest:tt)*)?) => {
+        $tree.choice($action, $scorer);
+        $crate::score_tree!(@choices $tree; $($($rest)*)?);
+    };
+
+    // Nested scores
+    (@scores $tree:ident; $(,)?) => {};
+    (@scores $tree:ident; Evaluated::new($($args:tt)*) => [ $($children:tt)* ] $(, $($rest:tt)*)?) => {
+        $crate::score_tree!(@one_child $($children)*);
+        $tree.score_with($crate::scoring::Evaluated::new($($args)*), |tree| {
+            $crate::score_tree!(@scores tree; $($children)*);
+        });
+        $crate::score_tree!(@scores $tree; $($($rest)*)?);
+    };
+    (@scores $tree:ident; Evaluated::new($($args:tt)*) $(, $($rest:tt)*)?) => {
+        ::core::compile_error!("`Evaluated` score entities must have exactly one child");
+    };
+    (@scores $tree:ident; $scorer:expr => [ $($children:tt)* ] $(, $($rest:tt)*)?) => {
+        $tree.score_with($scorer, |tree| {
+            $crate::score_tree!(@scores tree; $($children)*);
+        });
+        $crate::score_tree!(@scores $tree; $($($rest)*)?);
+    };
+    (@scores $tree:ident; $scorer:expr $(, $($rest:tt)*)?) => {
+        $tree.score($scorer);
+        $crate::score_tree!(@scores $tree; $($($rest)*)?);
+    };
+
+    // Compile-time child count check
+    (@one_child $child:expr $(=> [ $($grandchildren:tt)* ])? $(,)?) => {};
+    (@one_child $($children:tt)*) => {
+        ::core::compile_error!("`Evaluated` score entities must have exactly one child");
+    };
+
+    // Entry point
+    ($entity:expr, $default:expr, { $($choices:tt)* }) => {{
+        let mut entity = $entity;
+        $crate::builder::ScoreTreeExt::with_score_tree(&mut entity, $default, |tree| {
+            $crate::score_tree!(@choices tree; $($choices)*);
+        });
+        entity.id()
+    }};
+}
@@ -0,0 +1,7 @@
+//! Bridges for using `bevy_observed_utility` alongside other AI techniques.
+//!
+//! - [`behavior_tree`]: A utility selector node and a blackboard scorer, for mixing behavior trees with utility AI.
+//! - [`big_brain`]: A `Thinker`-like builder and a mapping of `big-brain` concepts, for migrating existing projects.
+
+pub mod behavior_tree;
+pub mod big_brain;
@@ -0,0 +1,396 @@
+//! Interop with behavior trees, for using behavior trees for structure and utility AI for selection.
+//!
+//! This module doesn't depend on any particular behavior tree crate. Instead, it provides the pieces of the bridge
+//! that such crates have in common, so the remaining glue is a few lines:
+//!
+//! - [`UtilitySelector`]: A node that scores, picks, and performs an action for an actor entity,
+//!   and reports a [`SelectorStatus`] on each tick. Call [`UtilitySelector::tick`] from your behavior tree crate's
+//!   node or task, and convert the [`SelectorStatus`] into its equivalent.
+//! - [`BlackboardScore`]: A scorer that reads a value from the actor entity's blackboard.
+//!   Implement [`Blackboard`] for your behavior tree crate's blackboard [`Component`] to use it.
+//!
+//! Since the behavior tree drives scoring and picking, use it with [`ObservedUtilityPlugins::TurnBased`],
+//! or otherwise without the [`RealtimeLifecyclePlugin`], so actors aren't also picked for automatically.
+//!
+//! [`ObservedUtilityPlugins::TurnBased`]: crate::ObservedUtilityPlugins::TurnBased
+//! [`RealtimeLifecyclePlugin`]: crate::RealtimeLifecyclePlugin
+
+use std::{borrow::Cow, marker::PhantomData};
+
+use bevy::{
+    ecs::component::{ComponentHooks, ComponentId, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    acting::{ActionState, CurrentAction},
+    ecs::{CommandsExt, InsertIfMissing},
+    event::{OnScore, RequestAction},
+    scoring::{AncestorQuery, Evaluator, Score, ScorerComponents, ScorerKind},
+    WorldUtilityExt,
+};
+
+/// The result of ticking a [`UtilitySelector`], to be converted into the node status of your behavior tree crate.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SelectorStatus {
+    /// The selected action is still being performed.
+    Running,
+    /// The selected action completed successfully.
+    Success,
+    /// Nothing was picked, or the selected action failed, was cancelled, or was replaced by another action.
+    Failure,
+}
+
+/// A behavior tree node that selects an action with the actor entity's [`Picker`](crate::picking::Picker),
+/// like a selector node that chooses its child by utility instead of by order.
+///
+/// On the first tick, the actor entity's score trees are scored, an action is picked and requested,
+/// and the actor entity is marked with [`UtilitySelected`]. Later ticks report the [`ActionState`] of that action,
+/// until it ends and the marker is removed, so the next tick selects again.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Attack;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// let attack = world.init_component::<Attack>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let scorer = world.spawn(FixedScore::new(0.7)).id();
+/// let actor = world
+///     .spawn((Picker::new(idle).with(scorer, attack), Highest))
+///     .add_child(scorer)
+///     .id();
+///
+/// // In your behavior tree crate's node or task:
+/// let selector = UtilitySelector::new();
+/// assert_eq!(SelectorStatus::Running, selector.tick(&mut world, actor));
+/// assert_eq!(attack, world.get::<CurrentAction>(actor).unwrap().0);
+///
+/// world.trigger_targets(OnActionEnded::completed(attack), actor);
+/// # world.flush();
+/// assert_eq!(SelectorStatus::Success, selector.tick(&mut world, actor));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct UtilitySelector;
+
+impl UtilitySelector {
+    /// Creates a new [`UtilitySelector`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Ticks the node for the given actor entity, selecting an action if none is selected,
+    /// and returns the [`SelectorStatus`] of the selected action.
+    pub fn tick(&self, world: &mut World, actor: Entity) -> SelectorStatus {
+        world.flush();
+        let Some(mut entity) = world.get_entity_mut(actor) else {
+            return SelectorStatus::Failure;
+        };
+
+        if entity.get::<UtilitySelected>().is_none() {
+            if entity.get::<ActionState>().is_some_and(|state| state.is_finished()) {
+                // Picking the same action as last time should perform it again
+                entity.remove::<CurrentAction>();
+            }
+            let Some(action) = world.score_and_pick(actor) else {
+                return SelectorStatus::Failure;
+            };
+            world.entity_mut(actor).insert(UtilitySelected(action));
+            world.trigger_targets(RequestAction::picked(), actor);
+            world.flush();
+        }
+
+        Self::status(world, actor)
+    }
+
+    /// Aborts the selected action of the given actor entity, if any, such as when a higher priority branch
+    /// of the behavior tree takes over. The action itself isn't cancelled, as the next request will replace it.
+    pub fn abort(&self, world: &mut World, actor: Entity) {
+        if let Some(mut entity) = world.get_entity_mut(actor) {
+            entity.remove::<UtilitySelected>();
+        }
+    }
+
+    /// Returns the [`SelectorStatus`] of the given actor entity's selected action,
+    /// removing [`UtilitySelected`] once it has ended.
+    fn status(world: &mut World, actor: Entity) -> SelectorStatus {
+        let mut entity = world.entity_mut(actor);
+        let Some(&UtilitySelected(selected)) = entity.get::<UtilitySelected>() else {
+            return SelectorStatus::Failure;
+        };
+        let current = entity.get::<CurrentAction>().map(|current| current.0);
+        let status = match entity.get::<ActionState>() {
+            _ if current != Some(selected) => SelectorStatus::Failure,
+            Some(ActionState::Succeeded) => SelectorStatus::Success,
+            Some(ActionState::Failed | ActionState::Cancelled) => SelectorStatus::Failure,
+            Some(ActionState::Requested | ActionState::Running | ActionState::Paused) | None => {
+                return SelectorStatus::Running;
+            }
+        };
+        entity.remove::<UtilitySelected>();
+        status
+    }
+}
+
+/// [`Component`] for actor entities whose action was selected by a [`UtilitySelector`] and hasn't ended yet.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct UtilitySelected(pub ComponentId);
+
+/// Trait for behavior tree blackboard [`Component`]s on actor entities, read by [`BlackboardScore`].
+pub trait Blackboard: Component {
+    /// Returns the value stored under the given key as a number, if any.
+    fn value(&self, key: &str) -> Option<f32>;
+}
+
+/// [`Score`] [`Component`] that scores based on a value of the closest ancestor entity's (usually the actor's)
+/// [`Blackboard`] `B`, passed through the [`Evaluator`].
+///
+/// If the blackboard or the value can't be found, the score is 0.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::{prelude::*, utils::HashMap};
+/// use bevy_observed_utility::prelude::*;
+///
+/// /// The blackboard of your behavior tree crate.
+/// #[derive(Component, Default)]
+/// pub struct Memory(HashMap<String, f32>);
+///
+/// impl Blackboard for Memory {
+///     fn value(&self, key: &str) -> Option<f32> {
+///         self.0.get(key).copied()
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// let scorer = world
+///     .spawn(BlackboardScore::<Memory>::new("threat", |threat: f32| threat))
+///     .id();
+/// let mut memory = Memory::default();
+/// memory.0.insert("threat".to_string(), 0.8);
+/// world.spawn(memory).add_child(scorer);
+/// # world.flush();
+///
+/// world.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// assert_eq!(0.8, world.get::<Score>(scorer).unwrap().get());
+/// ```
+pub struct BlackboardScore<B: Blackboard> {
+    /// The key of the value to read.
+    key: Cow<'static, str>,
+    /// The evaluator to use for scoring the value.
+    evaluator: Box<dyn Evaluator>,
+    /// The blackboard to read from.
+    blackboard: PhantomData<fn() -> B>,
+}
+
+impl<B: Blackboard> BlackboardScore<B> {
+    /// Creates a new [`BlackboardScore`] for the value with the given key and the given evaluator.
+    #[must_use]
+    pub fn new(key: impl Into<Cow<'static, str>>, evaluator: impl Evaluator) -> Self {
+        Self {
+            key: key.into(),
+            evaluator: Box::new(evaluator),
+            blackboard: PhantomData,
+        }
+    }
+
+    /// Returns the key of the value to read.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Sets the key of the value to read.
+    pub fn set_key(&mut self, key: impl Into<Cow<'static, str>>) {
+        self.key = key.into();
+    }
+
+    /// Returns the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn evaluator(&self) -> &dyn Evaluator {
+        self.evaluator.as_ref()
+    }
+
+    /// Sets the [`Evaluator`] used for scoring.
+    pub fn set_evaluator(&mut self, evaluator: impl Evaluator) {
+        self.evaluator = Box::new(evaluator);
+    }
+
+    /// [`Observer`] for [`BlackboardScore`] [`Score`] entities that scores the value of the actor's blackboard.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut target: Query<(&mut Score, &BlackboardScore<B>)>,
+        mut blackboards: AncestorQuery<&'static B>,
+    ) {
+        let scorer = trigger.entity();
+        let Ok((mut score, settings)) = target.get_mut(scorer) else {
+            // The entity is not scoring for this blackboard.
+            return;
+        };
+
+        let Some(value) = blackboards
+            .get(scorer)
+            .ok()
+            .and_then(|blackboard| blackboard.value(&settings.key))
+        else {
+            *score = Score::MIN;
+            return;
+        };
+        score.set(settings.evaluator.evaluate(value));
+    }
+}
+
+impl<B: Blackboard> Component for BlackboardScore<B> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::{
+        compat::behavior_tree::{SelectorStatus, UtilitySelected, UtilitySelector},
+        ecs::TargetedAction,
+        event::{OnActionEnded, RequestAction},
+        picking::{Highest, Picker},
+        scoring::FixedScore,
+    };
+
+    #[derive(Component)]
+    struct Attack;
+
+    #[derive(Component)]
+    struct Flee;
+
+    #[derive(Component)]
+    struct Idle;
+
+    /// A sequence node, as a behavior tree crate would provide, that ticks its children in order
+    /// until one of them is still running or fails.
+    struct Sequence {
+        children: Vec<UtilitySelector>,
+        current: usize,
+    }
+
+    impl Sequence {
+        fn tick(&mut self, world: &mut World, actor: Entity) -> SelectorStatus {
+            while let Some(child) = self.children.get(self.current) {
+                match child.tick(world, actor) {
+                    SelectorStatus::Running => return SelectorStatus::Running,
+                    SelectorStatus::Success => self.current += 1,
+                    SelectorStatus::Failure => {
+                        self.current = 0;
+                        return SelectorStatus::Failure;
+                    }
+                }
+            }
+            self.current = 0;
+            SelectorStatus::Success
+        }
+    }
+
+    #[test]
+    fn selector_reports_how_the_action_ended() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let attack = world.init_component::<Attack>();
+        let flee = world.init_component::<Flee>();
+        let idle = world.init_component::<Idle>();
+
+        let scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = world
+            .spawn((Picker::new(idle).with(scorer, attack), Highest))
+            .add_child(scorer)
+            .id();
+        let selector = UtilitySelector::new();
+
+        for (end, expected) in [
+            (OnActionEnded::completed(attack), SelectorStatus::Success),
+            (OnActionEnded::failed(attack), SelectorStatus::Failure),
+            (OnActionEnded::cancelled(attack), SelectorStatus::Failure),
+        ] {
+            assert_eq!(SelectorStatus::Running, selector.tick(world, actor));
+            assert_eq!(SelectorStatus::Running, selector.tick(world, actor));
+            world.trigger_targets(end, TargetedAction(actor, attack));
+            world.flush();
+            assert_eq!(expected, selector.tick(world, actor));
+            assert!(world.get::<UtilitySelected>(actor).is_none());
+        }
+
+        // Another action replacing the selected one fails the selector.
+        assert_eq!(SelectorStatus::Running, selector.tick(world, actor));
+        world.trigger_targets(RequestAction::action(flee), actor);
+        world.flush();
+        assert_eq!(SelectorStatus::Failure, selector.tick(world, actor));
+
+        // As does an actor that can't pick.
+        let no_picker = world.spawn_empty().id();
+        assert_eq!(SelectorStatus::Failure, selector.tick(world, no_picker));
+    }
+
+    #[test]
+    fn sequence_of_selectors_succeeds_only_if_every_action_does() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let attack = world.init_component::<Attack>();
+        let idle = world.init_component::<Idle>();
+
+        let scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = world
+            .spawn((Picker::new(idle).with(scorer, attack), Highest))
+            .add_child(scorer)
+            .id();
+        let mut sequence = Sequence {
+            children: vec![UtilitySelector::new(), UtilitySelector::new()],
+            current: 0,
+        };
+
+        assert_eq!(SelectorStatus::Running, sequence.tick(world, actor));
+        world.trigger_targets(OnActionEnded::completed(attack), TargetedAction(actor, attack));
+        world.flush();
+        // The first child succeeded, so the second one selects.
+        assert_eq!(SelectorStatus::Running, sequence.tick(world, actor));
+        assert_eq!(1, sequence.current);
+        world.trigger_targets(OnActionEnded::completed(attack), TargetedAction(actor, attack));
+        world.flush();
+        assert_eq!(SelectorStatus::Success, sequence.tick(world, actor));
+
+        assert_eq!(SelectorStatus::Running, sequence.tick(world, actor));
+        world.trigger_targets(OnActionEnded::failed(attack), TargetedAction(actor, attack));
+        world.flush();
+        assert_eq!(SelectorStatus::Failure, sequence.tick(world, actor));
+        assert_eq!(0, sequence.current);
+    }
+}
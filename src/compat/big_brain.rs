@@ -0,0 +1,266 @@
+//! Migration layer for projects coming from [`big-brain`](https://github.com/zkat/big-brain).
+//!
+//! The concepts map onto this crate as follows:
+//!
+//! | `big-brain`                            | `bevy_observed_utility`                                                        |
+//! |----------------------------------------|--------------------------------------------------------------------------------|
+//! | `Thinker::build()`                     | [`Thinker::build()`], or [`ScoreTreeExt`](crate::builder::ScoreTreeExt)        |
+//! | `Score`                                | [`Score`]                                                                      |
+//! | Scorer entity with `Actor`             | Child [`Score`] entity of the actor entity, with [`Actor`] for migrated code   |
+//! | Scorer system                          | [`OnScore`](crate::event::OnScore) [`Observer`], or [`score_ancestor`]         |
+//! | `FirstToScore`, `Highest`, ...         | [`FirstToScore`](crate::picking::FirstToScore), [`Highest`](crate::picking::Highest), ... |
+//! | Action entity with `ActionState`       | Action [`Component`] identified by its [`ComponentId`], with [`ActionState`](crate::acting::ActionState) on the actor entity |
+//! | Action system matching on `ActionState`| [`OnActionInitiated`](crate::event::OnActionInitiated) and [`OnActionEnded`](crate::event::OnActionEnded) [`Observer`]s |
+//!
+//! Existing scorer systems that query `(&Actor, &mut Score)` keep working with [`Actor`] and this crate's [`Score`],
+//! as long as they run before picking, so you can port them to [`Observer`]s one at a time.
+//!
+//! [`score_ancestor`]: crate::scoring::score_ancestor
+
+use bevy::{
+    ecs::component::{ComponentHooks, ComponentId, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    picking::{FirstToScore, Picker},
+    scoring::Score,
+};
+
+/// Entry point for building a [`Picker`] and its score entities the way `big-brain`'s `Thinker` does.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Thirst(f32);
+///
+/// impl From<&Thirst> for Score {
+///     fn from(thirst: &Thirst) -> Self {
+///         Score::new(thirst.0)
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// pub struct Thirsty;
+/// #[derive(Component)]
+/// pub struct Drink;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservedUtilityPlugins::TurnBased)
+///     .observe(score_ancestor::<Thirst, Thirsty>);
+/// # let mut world = app.world_mut();
+///
+/// let actor = world
+///     .spawn((
+///         Thirst(0.9),
+///         Thinker::build()
+///             .picker(FirstToScore::new(0.8))
+///             .when::<Drink>(Thirsty)
+///             .otherwise::<Idle>(),
+///     ))
+///     .id();
+/// # world.flush();
+///
+/// let drink = world.init_component::<Drink>();
+/// assert_eq!(Some(drink), world.score_and_pick(actor));
+/// ```
+pub struct Thinker;
+
+impl Thinker {
+    /// Creates a new [`ThinkerBuilder`].
+    #[must_use]
+    pub fn build() -> ThinkerBuilder {
+        ThinkerBuilder::default()
+    }
+}
+
+/// Function that applies a picker kind to the actor entity, used by [`ThinkerBuilder`].
+type PickerFn = Box<dyn FnOnce(&mut EntityWorldMut) + Send + Sync>;
+
+/// Function that spawns a choice's score entity under the actor entity, used by [`ThinkerBuilder`].
+type ChoiceFn = Box<dyn FnOnce(&mut World, Entity) -> (Entity, ComponentId) + Send + Sync>;
+
+/// [`Component`] that builds a [`Picker`] and its score entities when inserted on an actor entity, then removes itself.
+///
+/// Each choice's score entity is spawned as a child of the actor entity with a default [`Score`] and an [`Actor`].
+/// Without a picker kind, [`FirstToScore`] with a threshold of 0 is used, like in `big-brain`.
+/// Without an [`otherwise`](Self::otherwise) action, the [`Picker`] has no default action.
+///
+/// See [`Thinker`] for an example.
+#[derive(Default)]
+pub struct ThinkerBuilder {
+    /// The picker kind to insert on the actor entity.
+    picker: Option<PickerFn>,
+    /// The choices to spawn, in order.
+    choices: Vec<ChoiceFn>,
+    /// The default action to pick, if any.
+    otherwise: Option<fn(&mut World) -> ComponentId>,
+}
+
+impl ThinkerBuilder {
+    /// Sets the picker kind, such as [`FirstToScore`] or [`Highest`](crate::picking::Highest).
+    #[must_use]
+    pub fn picker(mut self, picker: impl Bundle) -> Self {
+        self.picker = Some(Box::new(move |actor: &mut EntityWorldMut| {
+            actor.insert(picker);
+        }));
+        self
+    }
+
+    /// Adds a choice that picks the action [`Component`] `A` based on a score entity spawned with the given bundle.
+    #[must_use]
+    pub fn when<A: Component>(mut self, scorer: impl Bundle) -> Self {
+        self.choices.push(Box::new(move |world: &mut World, actor: Entity| {
+            let action = world.init_component::<A>();
+            // Insert the default score first, so that the bundle can override it.
+            let score_entity = world
+                .spawn((Score::default(), Actor(actor)))
+                .insert(scorer)
+                .set_parent(actor)
+                .id();
+            (score_entity, action)
+        }));
+        self
+    }
+
+    /// Sets the action [`Component`] `A` to pick when none of the choices win.
+    #[must_use]
+    pub fn otherwise<A: Component>(mut self) -> Self {
+        self.otherwise = Some(|world| world.init_component::<A>());
+        self
+    }
+
+    /// Builds the [`Picker`] and score entities for the given actor entity.
+    fn apply(self, world: &mut World, actor: Entity) {
+        let mut picker = match self.otherwise {
+            Some(otherwise) => Picker::new(otherwise(world)),
+            None => Picker::without_default(),
+        };
+        for choice in self.choices {
+            let (score_entity, action) = choice(world, actor);
+            picker.choices.insert(score_entity, action);
+        }
+
+        let Some(mut entity) = world.get_entity_mut(actor) else {
+            return;
+        };
+        match self.picker {
+            Some(picker) => picker(&mut entity),
+            None => {
+                entity.insert(FirstToScore::new(0.));
+            }
+        }
+        entity.insert(picker);
+    }
+}
+
+impl Component for ThinkerBuilder {
+    const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, _component| {
+            world.commands().add(move |world: &mut World| {
+                let Some(builder) = world
+                    .get_entity_mut(entity)
+                    .and_then(|mut actor| actor.take::<ThinkerBuilder>())
+                else {
+                    // The actor was despawned in the meantime.
+                    return;
+                };
+                builder.apply(world, entity);
+            });
+        });
+    }
+}
+
+/// [`Component`] on score entities spawned by a [`ThinkerBuilder`], pointing to their actor entity
+/// like `big-brain`'s `Actor`, so migrated scorer systems can find it without walking the hierarchy.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[reflect(Component, PartialEq, Hash, Debug)]
+pub struct Actor(pub Entity);
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::{
+        compat::big_brain::{Actor, Thinker},
+        picking::{FirstToScore, Highest, Picker},
+        scoring::{FixedScore, Score},
+        WorldUtilityExt,
+    };
+
+    #[derive(Component)]
+    struct Drink;
+
+    #[derive(Component)]
+    struct Eat;
+
+    #[derive(Component)]
+    struct Idle;
+
+    #[test]
+    fn thinker_builds_the_picker_choices() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let actor = world
+            .spawn(
+                Thinker::build()
+                    .picker(Highest)
+                    .when::<Drink>(FixedScore::new(0.3))
+                    .when::<Eat>(FixedScore::new(0.6))
+                    .otherwise::<Idle>(),
+            )
+            .id();
+        world.flush();
+
+        let drink = world.init_component::<Drink>();
+        let eat = world.init_component::<Eat>();
+        let idle = world.init_component::<Idle>();
+
+        let picker = world.get::<Picker>(actor).unwrap();
+        assert_eq!(Some(idle), picker.default);
+        let choices: Vec<_> = picker.choices.iter().collect();
+        assert_eq!(
+            vec![drink, eat],
+            choices.iter().map(|&(_, action)| action).collect::<Vec<_>>()
+        );
+        for &(score_entity, _) in &choices {
+            assert_eq!(Some(actor), world.get::<Parent>(score_entity).map(Parent::get));
+            assert_eq!(Some(&Actor(actor)), world.get::<Actor>(score_entity));
+        }
+        assert!(world.get::<Highest>(actor).is_some());
+
+        assert_eq!(Some(eat), world.score_and_pick(actor));
+        assert_eq!(
+            Some(choices[1].0),
+            world.get::<Picker>(actor).unwrap().picked_score_entity
+        );
+        assert_eq!(Some(&Score::new(0.6)), world.get::<Score>(choices[1].0));
+    }
+
+    #[test]
+    fn thinker_defaults_like_big_brain() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let actor = world.spawn(Thinker::build().when::<Drink>(FixedScore::new(0.))).id();
+        world.flush();
+
+        // FirstToScore with a threshold of 0, and no default action.
+        assert_eq!(Score::MIN, world.get::<FirstToScore>(actor).unwrap().threshold());
+        assert_eq!(None, world.get::<Picker>(actor).unwrap().default);
+        let drink = world.init_component::<Drink>();
+        assert_eq!(Some(drink), world.score_and_pick(actor));
+    }
+}
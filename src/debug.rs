@@ -0,0 +1,551 @@
+//! Debugging utilities for finding misconfigured score trees and pickers.
+//!
+//! Requires the `debug` feature.
+//!
+//! Silent misconfiguration is the most common source of "my AI does nothing" bugs,
+//! so add the [`ValidateScoreTreesPlugin`] during development to be warned about:
+//! - [`Evaluated`] entities with zero or multiple child [`Score`] entities.
+//! - Combinators (e.g. [`Sum`](crate::scoring::Sum), [`Measured`](crate::scoring::Measured)) with no child [`Score`] entities.
+//! - Scorer components on entities that are missing the [`Score`] component.
+//!
+//! Scorer components are recognized through the [`ScorerComponents`] resource, which the built-in scorers
+//! register themselves in.
+//! - [`Picker`] choices that point at entities outside of the actor's tree.
+//! - Cycles in the score entity hierarchy.
+//!
+//! With the `debug_overlay` feature, add the [`DebugOverlayPlugin`] and mark actor entities with [`DebugAi`]
+//! to see their top choice scores and current action drawn above them in the world.
+//!
+//! To look at a whole score tree at once, [`dump_score_tree`] exports it as a Graphviz DOT graph.
+//!
+//! To trim bloated score trees, add the [`DeadScorerAnalysisPlugin`] to find the score entities
+//! that never influenced a pick over a play session.
+
+use std::fmt;
+
+use bevy::{
+    ecs::{
+        archetype::{ArchetypeEntity, Archetypes},
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        system::SystemParam,
+    },
+    prelude::*,
+    utils::HashSet,
+};
+
+use crate::{
+    picking::Picker,
+    scoring::{Evaluated, Score, ScoreHierarchy, ScorerComponents, ScorerKind},
+};
+
+mod dead;
+mod dot;
+#[cfg(feature = "debug_overlay")]
+mod overlay;
+pub use self::dead::*;
+pub use self::dot::*;
+#[cfg(feature = "debug_overlay")]
+pub use self::overlay::*;
+
+/// [`Plugin`] that validates score trees and pickers in the configured [`Schedule`] (default [`PostUpdate`]),
+/// and emits a warning for each newly found [`ScoreTreeProblem`].
+pub struct ValidateScoreTreesPlugin {
+    /// The [`ScheduleLabel`] to validate score trees in.
+    pub validate_in: InternedScheduleLabel,
+}
+
+impl Default for ValidateScoreTreesPlugin {
+    fn default() -> Self {
+        Self {
+            validate_in: PostUpdate.intern(),
+        }
+    }
+}
+
+impl Plugin for ValidateScoreTreesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.validate_in, Self::validate_score_trees);
+    }
+}
+
+impl ValidateScoreTreesPlugin {
+    /// [`System`] that warns about each [`ScoreTreeProblem`] once, until it's fixed and then reintroduced.
+    pub fn validate_score_trees(validator: ScoreTreeValidator, mut reported: Local<HashSet<ScoreTreeProblem>>) {
+        let problems: HashSet<ScoreTreeProblem> = validator.problems().into_iter().collect();
+
+        for problem in problems.difference(&reported) {
+            warn!("{}", validator.describe(problem));
+        }
+
+        *reported = problems;
+    }
+}
+
+/// A misconfiguration found by the [`ScoreTreeValidator`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ScoreTreeProblem {
+    /// An [`Evaluated`] entity doesn't have exactly one child [`Score`] entity.
+    EvaluatedChildCount {
+        /// The [`Evaluated`] entity.
+        entity: Entity,
+        /// The number of child [`Score`] entities it has.
+        count: usize,
+    },
+    /// A combinator entity has no child [`Score`] entities.
+    CombinatorWithoutChildren {
+        /// The combinator entity.
+        entity: Entity,
+    },
+    /// An entity has a scoring component, but no [`Score`] component.
+    MissingScore {
+        /// The entity missing the [`Score`] component.
+        entity: Entity,
+    },
+    /// A [`Picker`] choice points at an entity outside of the actor's tree.
+    ChoiceOutsideTree {
+        /// The actor entity with the [`Picker`].
+        actor: Entity,
+        /// The score entity referenced by the choice.
+        choice: Entity,
+    },
+    /// A score entity is its own ancestor.
+    HierarchyCycle {
+        /// The score entity in the cycle.
+        entity: Entity,
+    },
+}
+
+/// [`SystemParam`] that finds [`ScoreTreeProblem`]s in all score trees and pickers.
+///
+/// Scorer components are recognized through the [`ScorerComponents`], so custom scorers are checked too
+/// once they're registered there.
+#[derive(SystemParam)]
+pub struct ScoreTreeValidator<'w, 's> {
+    evaluated: Query<'w, 's, Entity, With<Evaluated>>,
+    scorers: Option<Res<'w, ScorerComponents>>,
+    archetypes: &'w Archetypes,
+    pickers: Query<'w, 's, (Entity, &'static Picker)>,
+    score_entities: Query<'w, 's, Entity, With<Score>>,
+    hierarchy: ScoreHierarchy<'w, 's>,
+    scores: Query<'w, 's, (), With<Score>>,
+    names: Query<'w, 's, &'static Name>,
+}
+
+impl ScoreTreeValidator<'_, '_> {
+    /// Returns all problems currently found in the world.
+    #[must_use]
+    pub fn problems(&self) -> Vec<ScoreTreeProblem> {
+        let mut problems = Vec::new();
+
+        for entity in self.evaluated.iter() {
+            let count = self.score_children(entity);
+            if count != 1 {
+                problems.push(ScoreTreeProblem::EvaluatedChildCount { entity, count });
+            }
+        }
+
+        for entity in self.scorer_entities(|kind| kind == ScorerKind::Combinator) {
+            if self.score_children(entity) == 0 {
+                problems.push(ScoreTreeProblem::CombinatorWithoutChildren { entity });
+            }
+        }
+
+        for entity in self.scorer_entities(|_| true) {
+            if !self.scores.contains(entity) {
+                problems.push(ScoreTreeProblem::MissingScore { entity });
+            }
+        }
+
+        for (actor, picker) in self.pickers.iter() {
+            for choice in picker.choices.keys().copied() {
+                if !self.is_descendant_of(choice, actor) {
+                    problems.push(ScoreTreeProblem::ChoiceOutsideTree { actor, choice });
+                }
+            }
+        }
+
+        for entity in self.score_entities.iter() {
+            let Some(parent) = self.hierarchy.parent(entity) else {
+                continue;
+            };
+            if self.is_descendant_of(parent, entity) {
+                problems.push(ScoreTreeProblem::HierarchyCycle { entity });
+            }
+        }
+
+        problems
+    }
+
+    /// Returns a human-readable description of the given problem, using entity [`Name`]s where available.
+    #[must_use]
+    pub fn describe(&self, problem: &ScoreTreeProblem) -> String {
+        match *problem {
+            ScoreTreeProblem::EvaluatedChildCount { entity, count } => format!(
+                "Evaluated score entity {} has {count} child score entities, but needs exactly 1.",
+                self.name(entity)
+            ),
+            ScoreTreeProblem::CombinatorWithoutChildren { entity } => format!(
+                "Combinator score entity {} has no child score entities.",
+                self.name(entity)
+            ),
+            ScoreTreeProblem::MissingScore { entity } => format!(
+                "Entity {} has a scoring component, but is missing the Score component.",
+                self.name(entity)
+            ),
+            ScoreTreeProblem::ChoiceOutsideTree { actor, choice } => format!(
+                "Picker on actor {} has a choice for score entity {}, which is not in the actor's tree.",
+                self.name(actor),
+                self.name(choice)
+            ),
+            ScoreTreeProblem::HierarchyCycle { entity } => {
+                format!("Score entity {} is its own ancestor.", self.name(entity))
+            }
+        }
+    }
+
+    /// Returns the entities with a registered scorer component of a kind that matches the predicate.
+    fn scorer_entities(&self, matches: impl Fn(ScorerKind) -> bool) -> Vec<Entity> {
+        let Some(scorers) = self.scorers.as_deref() else {
+            return Vec::new();
+        };
+        self.archetypes
+            .iter()
+            .filter(|archetype| {
+                archetype
+                    .components()
+                    .any(|component| scorers.get(component).is_some_and(&matches))
+            })
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+            .collect()
+    }
+
+    /// Counts the children that are [`Score`] entities.
+    fn score_children(&self, entity: Entity) -> usize {
+        self.scores.iter_many(self.hierarchy.children(entity)).count()
+    }
+
+    /// Returns `true` if `ancestor` is `entity` itself or one of its ancestors.
+    fn is_descendant_of(&self, entity: Entity, ancestor: Entity) -> bool {
+        let mut visited = HashSet::new();
+        let mut current = entity;
+        loop {
+            if current == ancestor {
+                return true;
+            }
+            if !visited.insert(current) {
+                // We've hit a cycle that doesn't contain the ancestor.
+                return false;
+            }
+            match self.hierarchy.parent(current) {
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Returns the [`Name`] of the entity alongside its id, or just its id.
+    fn name(&self, entity: Entity) -> EntityName {
+        EntityName(entity, self.names.get(entity).ok().cloned())
+    }
+}
+
+/// [`fmt::Display`] helper for entities with an optional [`Name`].
+struct EntityName(Entity, Option<Name>);
+
+impl fmt::Display for EntityName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.1 {
+            Some(name) => write!(f, "{name} ({})", self.0),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+    use crate::{
+        debug::{dump_score_tree, DeadScorerAnalysisPlugin, ScoreTreeProblem, ScoreTreeValidator, ScorerUsage},
+        event::{RunPicking, RunScoring},
+        picking::{Highest, Picker},
+        scoring::{
+            CooldownScore, Evaluated, FixedScore, PowerEvaluator, Score, ScoreLabel, ScoreOf, ScorerComponents, Sum,
+        },
+        WorldUtilityExt,
+    };
+
+    #[derive(Component)]
+    struct IdleAction;
+
+    #[derive(Component)]
+    struct OtherAction;
+
+    fn problems(world: &mut World) -> Vec<ScoreTreeProblem> {
+        world.run_system_once(|validator: ScoreTreeValidator| validator.problems())
+    }
+
+    #[test]
+    fn valid_tree() {
+        let mut world = World::new();
+        world.init_resource::<ScorerComponents>();
+
+        let idle = world.init_component::<IdleAction>();
+        let scorer = world
+            .spawn((Score::default(), Evaluated::new(PowerEvaluator::default())))
+            .with_children(|parent| {
+                parent.spawn((Score::default(), FixedScore::new(0.5)));
+            })
+            .id();
+        world.spawn(Picker::new(idle).with(scorer, idle)).add_child(scorer);
+
+        assert!(problems(&mut world).is_empty());
+    }
+
+    #[test]
+    fn misconfigured_tree() {
+        let mut world = World::new();
+        world.init_resource::<ScorerComponents>();
+
+        let idle = world.init_component::<IdleAction>();
+        let evaluated = world
+            .spawn((Score::default(), Evaluated::new(PowerEvaluator::default())))
+            .id();
+        let sum = world.spawn((Score::default(), Sum::new(0.5))).id();
+        let fixed = world.spawn(FixedScore::new(0.5)).id();
+        // Scoring components insert a Score when added, so it has to be removed afterwards
+        world.flush();
+        world.entity_mut(fixed).remove::<Score>();
+        let actor = world.spawn(Picker::new(idle).with(sum, idle)).id();
+
+        let problems = problems(&mut world);
+        assert!(problems.contains(&ScoreTreeProblem::EvaluatedChildCount {
+            entity: evaluated,
+            count: 0
+        }));
+        assert!(problems.contains(&ScoreTreeProblem::CombinatorWithoutChildren { entity: sum }));
+        assert!(problems.contains(&ScoreTreeProblem::MissingScore { entity: fixed }));
+        assert!(problems.contains(&ScoreTreeProblem::ChoiceOutsideTree { actor, choice: sum }));
+    }
+
+    #[test]
+    fn registered_scorer_missing_score() {
+        let mut world = World::new();
+        world.init_resource::<ScorerComponents>();
+
+        let idle = world.init_component::<IdleAction>();
+        let cooldown = world.spawn(CooldownScore::new(idle)).id();
+        world.flush();
+        world.entity_mut(cooldown).remove::<Score>();
+
+        assert_eq!(
+            problems(&mut world),
+            vec![ScoreTreeProblem::MissingScore { entity: cooldown }]
+        );
+    }
+
+    #[test]
+    fn dump_score_tree_lists_nodes_and_edges() {
+        let mut world = World::new();
+
+        let idle = world.init_component::<IdleAction>();
+        let fixed = world
+            .spawn((Name::new("Thirst"), Score::default(), FixedScore::new(0.5)))
+            .id();
+        let sum = world.spawn((Score::default(), Sum::new(0.25))).add_child(fixed).id();
+        let actor = world
+            .spawn((Name::new("Actor"), Picker::new(idle).with(sum, idle)))
+            .add_child(sum)
+            .id();
+
+        let dot = dump_score_tree(&world, actor);
+        assert!(dot.starts_with("digraph"));
+        assert!(dot.contains("Actor"));
+        assert!(dot.contains("Thirst"));
+        assert!(dot.contains(&format!("\"{actor}\" -> \"{sum}\" [label=\"IdleAction\"]")));
+        assert!(dot.contains(&format!("\"{sum}\" -> \"{fixed}\";")));
+    }
+
+    #[test]
+    fn dump_score_tree_follows_score_of_links() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let idle = world.init_component::<IdleAction>();
+        let other = world.init_component::<OtherAction>();
+        let actor = world.spawn((Name::new("Actor"), Highest)).id();
+        let sum = world
+            .spawn((ScoreLabel::new("Needs"), Sum::new(0.25), ScoreOf::new(actor)))
+            .id();
+        let thirst = world
+            .spawn((Name::new("Thirst"), FixedScore::new(0.5), ScoreOf::new(sum)))
+            .id();
+        let hunger = world
+            .spawn((Name::new("Hunger"), FixedScore::new(0.2)))
+            .set_parent(sum)
+            .id();
+        let rest = world
+            .spawn((Name::new("Rest"), FixedScore::new(0.1)))
+            .set_parent(actor)
+            .id();
+        world
+            .entity_mut(actor)
+            .insert(Picker::new(idle).with(sum, other).with(rest, idle));
+        world.score_and_pick(actor);
+
+        let dot = dump_score_tree(world, actor);
+        let lines: Vec<&str> = dot.lines().collect();
+        assert_eq!(Some(&"digraph score_tree {"), lines.first());
+        assert_eq!(Some(&"}"), lines.last());
+        assert!(lines.contains(
+            &format!(
+                "    \"{actor}\" [label=\"Actor ({actor})\\ncurrent: IdleAction\\ndefault: IdleAction\", style=bold];"
+            )
+            .as_str()
+        ));
+
+        // Both levels are linked, whether through ScoreOf or Parent, and the picked choice is highlighted.
+        let edges: Vec<&str> = lines.iter().copied().filter(|line| line.contains("->")).collect();
+        assert_eq!(
+            vec![
+                format!("    \"{actor}\" -> \"{rest}\" [label=\"IdleAction\"];"),
+                format!("    \"{actor}\" -> \"{sum}\" [label=\"OtherAction\", color=green, penwidth=2];"),
+                format!("    \"{sum}\" -> \"{hunger}\";"),
+                format!("    \"{sum}\" -> \"{thirst}\";"),
+            ],
+            edges
+        );
+        for (entity, label) in [(sum, "Needs"), (thirst, "Thirst"), (hunger, "Hunger"), (rest, "Rest")] {
+            assert!(
+                lines
+                    .iter()
+                    .any(|line| line.starts_with(&format!("    \"{entity}\" [label=\"{label} ({entity})\\nscore: "))),
+                "missing node {label}"
+            );
+        }
+        // Links aren't listed as components.
+        assert!(!dot.contains("ScoreOf"));
+        assert!(!dot.contains("LinkedScoreParent"));
+    }
+
+    #[test]
+    fn dead_scorers_never_influence_picks() {
+        let mut app = App::new();
+        app.add_plugins((crate::ObservedUtilityPlugins::TurnBased, DeadScorerAnalysisPlugin));
+        let world = app.world_mut();
+
+        let idle = world.init_component::<IdleAction>();
+        let other = world.init_component::<OtherAction>();
+        let live = world.spawn(FixedScore::new(0.6)).id();
+        let zero = world.spawn(FixedScore::new(0.)).id();
+        let winner = world.spawn(Sum::new(0.)).push_children(&[live, zero]).id();
+        let loser = world.spawn(FixedScore::new(0.2)).id();
+        world
+            .spawn((Picker::new(idle).with(winner, idle).with(loser, other), Highest))
+            .push_children(&[winner, loser]);
+
+        for _ in 0..2 {
+            world.trigger(RunScoring);
+            world.trigger(RunPicking);
+            world.flush();
+        }
+
+        let usage = world.resource::<ScorerUsage>();
+        let mut dead = vec![zero, loser];
+        dead.sort();
+        assert_eq!(dead, usage.dead_scorers());
+        assert_eq!(Some(2), usage.get(live).map(|stats| stats.influenced));
+        assert_eq!(Some(2), usage.get(loser).map(|stats| stats.picks));
+    }
+
+    #[test]
+    fn scorers_that_influence_any_pick_are_not_dead() {
+        let mut app = App::new();
+        app.add_plugins((crate::ObservedUtilityPlugins::TurnBased, DeadScorerAnalysisPlugin));
+        let world = app.world_mut();
+
+        let idle = world.init_component::<IdleAction>();
+        let other = world.init_component::<OtherAction>();
+        // Scores 0 on most picks, but once its branch wins with a non-zero score.
+        let rarely = world.spawn(FixedScore::new(0.)).id();
+        let winner = world.spawn(Sum::new(0.)).add_child(rarely).id();
+        let fallback = world.spawn(FixedScore::new(0.1)).id();
+        // Never part of a pick, so there's nothing to judge it by.
+        let detached = world.spawn(FixedScore::new(0.)).id();
+        world
+            .spawn((Picker::new(idle).with(winner, other).with(fallback, idle), Highest))
+            .push_children(&[winner, fallback]);
+
+        for score in [0., 0., 0.8, 0.] {
+            world.entity_mut(rarely).insert(FixedScore::new(score));
+            world.trigger(RunScoring);
+            world.trigger(RunPicking);
+            world.flush();
+        }
+
+        let usage = world.resource::<ScorerUsage>();
+        assert_eq!(Some(4), usage.get(rarely).map(|stats| stats.picks));
+        assert_eq!(Some(1), usage.get(rarely).map(|stats| stats.influenced));
+        assert_eq!(Some(1), usage.get(winner).map(|stats| stats.influenced));
+        assert_eq!(Some(3), usage.get(fallback).map(|stats| stats.influenced));
+        assert_eq!(None, usage.get(detached));
+        assert!(usage.dead_scorers().is_empty());
+    }
+
+    #[cfg(feature = "debug_overlay")]
+    #[test]
+    fn overlay_labels_show_current_action_and_top_choices() {
+        use crate::{
+            acting::CurrentAction,
+            debug::{DebugAi, DebugAiLabel, DebugOverlayPlugin, DebugOverlaySettings},
+        };
+
+        #[derive(Component)]
+        struct ThirdAction;
+
+        let mut world = World::new();
+        world.insert_resource(DebugOverlaySettings {
+            top_choices: 2,
+            ..default()
+        });
+        world.observe(DebugOverlayPlugin::on_debug_ai_removed_despawn_label);
+
+        let idle = world.init_component::<IdleAction>();
+        let other = world.init_component::<OtherAction>();
+        let third = world.init_component::<ThirdAction>();
+        let low = world.spawn(Score::new(0.2)).id();
+        let high = world.spawn(Score::new(0.7)).id();
+        let mid = world.spawn(Score::new(0.5)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle).with(low, idle).with(high, other).with(mid, third),
+                CurrentAction(other),
+                GlobalTransform::default(),
+                DebugAi,
+            ))
+            .push_children(&[low, high, mid])
+            .id();
+
+        world.run_system_once(DebugOverlayPlugin::update_labels);
+        let &DebugAiLabel(label) = world.get::<DebugAiLabel>(actor).unwrap();
+        assert_eq!(
+            "> OtherAction\nOtherAction: 0.70\nThirdAction: 0.50",
+            world.get::<Text>(label).unwrap().sections[0].value
+        );
+
+        // Labels follow the scores, and are hidden without a camera to project through.
+        world.entity_mut(mid).insert(Score::new(0.9));
+        world.run_system_once(DebugOverlayPlugin::update_labels);
+        assert_eq!(
+            "> OtherAction\nThirdAction: 0.90\nOtherAction: 0.70",
+            world.get::<Text>(label).unwrap().sections[0].value
+        );
+        assert_eq!(Some(&Visibility::Hidden), world.get::<Visibility>(label));
+
+        world.entity_mut(actor).remove::<DebugAi>();
+        world.flush();
+        assert!(world.get_entity(label).is_none());
+        assert!(world.get::<DebugAiLabel>(actor).is_none());
+    }
+}
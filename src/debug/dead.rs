@@ -0,0 +1,183 @@
+use bevy::{
+    app::AppExit,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::{
+    event::OnPicked,
+    scoring::{Score, ScoreHierarchy},
+};
+
+/// [`Plugin`] that tracks which score entities influence picks over a play session,
+/// and warns about the ones that never did when the app exits.
+///
+/// A score entity influences a pick when it's in the branch of the winning choice with a non-zero [`Score`].
+/// Score entities that never do (their choice never wins, or they always score 0) are dead weight
+/// that's still scored every tick, so they're good candidates for trimming.
+///
+/// Read the [`ScorerUsage`] resource to report them yourself at any time.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::{debug::{DeadScorerAnalysisPlugin, ScorerUsage}, prelude::*};
+///
+/// #[derive(Component)]
+/// pub struct Attack;
+/// #[derive(Component)]
+/// pub struct Flee;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::TurnBased, DeadScorerAnalysisPlugin));
+/// # let mut world = app.world_mut();
+/// let attack = world.init_component::<Attack>();
+/// let flee = world.init_component::<Flee>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let aggression = world.spawn(FixedScore::new(0.7)).id();
+/// let fear = world.spawn(FixedScore::new(0.2)).id();
+/// world
+///     .spawn((Picker::new(idle).with(aggression, attack).with(fear, flee), Highest))
+///     .push_children(&[aggression, fear]);
+///
+/// world.trigger(RunScoring);
+/// world.trigger(RunPicking);
+/// world.flush();
+///
+/// let usage = world.resource::<ScorerUsage>();
+/// assert_eq!(vec![fear], usage.dead_scorers());
+/// ```
+pub struct DeadScorerAnalysisPlugin;
+
+impl Plugin for DeadScorerAnalysisPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScorerUsage>()
+            .add_systems(Last, Self::report_dead_scorers_on_exit)
+            .observe(Self::on_picked_track_usage);
+    }
+}
+
+impl DeadScorerAnalysisPlugin {
+    /// [`Observer`] that counts, for each score entity in the picking actor's tree,
+    /// the picks it took part in and the ones it influenced.
+    ///
+    /// [Extra](OnPicked::extra) picks don't count as separate picks,
+    /// but the score entities on their branches still influence the pick they were made alongside.
+    pub fn on_picked_track_usage(
+        trigger: Trigger<OnPicked>,
+        hierarchy: ScoreHierarchy,
+        scores: Query<&Score>,
+        mut usage: ResMut<ScorerUsage>,
+    ) {
+        let usage = &mut *usage;
+        let OnPicked {
+            score_entity: winner,
+            extra,
+            ..
+        } = *trigger.event();
+
+        let mut visited = HashSet::new();
+        // The second element is whether the entity is on the winning choice's branch.
+        let mut stack: Vec<(Entity, bool)> = if extra {
+            // The actor's tree already took part in the pick this one was made alongside.
+            winner.map(|winner| (winner, true)).into_iter().collect()
+        } else {
+            hierarchy
+                .children(trigger.entity())
+                .map(|child| (child, false))
+                .collect()
+        };
+        while let Some((entity, winning)) = stack.pop() {
+            if !visited.insert(entity) {
+                // Don't loop forever on hierarchy cycles.
+                continue;
+            }
+            let Ok(score) = scores.get(entity) else {
+                continue;
+            };
+            let winning = winning || winner == Some(entity);
+
+            let stats = usage.stats.entry(entity).or_default();
+            if !extra {
+                stats.picks += 1;
+            }
+            if winning && score.get() > 0. {
+                stats.influenced += 1;
+            }
+
+            stack.extend(hierarchy.children(entity).map(|child| (child, winning)));
+        }
+    }
+
+    /// [`System`] that warns about all dead score entities when the app exits.
+    pub fn report_dead_scorers_on_exit(mut exits: EventReader<AppExit>, usage: Res<ScorerUsage>, names: Query<&Name>) {
+        if exits.read().last().is_none() {
+            return;
+        }
+
+        let dead = usage.dead_scorers();
+        if dead.is_empty() {
+            return;
+        }
+        let dead: Vec<String> = dead
+            .into_iter()
+            .map(|entity| match names.get(entity) {
+                Ok(name) => format!("{name} ({entity})"),
+                Err(_) => format!("{entity}"),
+            })
+            .collect();
+        warn!(
+            "{} score entities never influenced a pick this session: {}",
+            dead.len(),
+            dead.join(", ")
+        );
+    }
+}
+
+/// [`Resource`] for how often each score entity took part in and influenced picks,
+/// maintained by the [`DeadScorerAnalysisPlugin`].
+#[derive(Resource)]
+#[derive(Clone, Debug, Default)]
+pub struct ScorerUsage {
+    /// The usage of each score entity seen in a pick.
+    stats: HashMap<Entity, ScorerStats>,
+}
+
+impl ScorerUsage {
+    /// Returns the usage of the given score entity, if it took part in any pick.
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<ScorerStats> {
+        self.stats.get(&entity).copied()
+    }
+
+    /// Returns the score entities that took part in picks, but never influenced one, sorted.
+    #[must_use]
+    pub fn dead_scorers(&self) -> Vec<Entity> {
+        let mut dead: Vec<Entity> = self
+            .stats
+            .iter()
+            .filter(|(_, stats)| stats.influenced == 0)
+            .map(|(&entity, _)| entity)
+            .collect();
+        dead.sort();
+        dead
+    }
+
+    /// Forgets all usage, e.g. to start analyzing a new part of the session.
+    pub fn clear(&mut self) {
+        self.stats.clear();
+    }
+}
+
+/// How often a score entity took part in and influenced picks. See [`ScorerUsage`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ScorerStats {
+    /// The number of picks of the score entity's actor.
+    pub picks: u32,
+    /// The number of those picks where the score entity was on the winning choice's branch with a non-zero score.
+    pub influenced: u32,
+}
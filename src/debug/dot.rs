@@ -0,0 +1,187 @@
+use std::{any::TypeId, fmt::Write};
+
+use bevy::{
+    ecs::component::ComponentId,
+    prelude::*,
+    utils::{get_short_name, HashSet},
+};
+
+use crate::{
+    acting::{ActionRegistry, CurrentAction},
+    picking::{ChoiceBias, FirstToScore, HighestAboveThreshold, Picker},
+    scoring::{score_children, LastScored, LinkedScoreParent, Score, ScoreChildren, ScoreLabel, ScoreOf},
+};
+
+/// Exports the score tree of the given actor entity as a [Graphviz](https://graphviz.org/) DOT graph,
+/// to visualize it or attach it to bug reports.
+///
+/// Each score entity is a node listing its [`Score`] and its components. Components registered in the
+/// [`AppTypeRegistry`] with [`ReflectComponent`] show their settings (such as thresholds and weights),
+/// the others just their type name. [`Picker`] choices are edges labeled with their action and [`ChoiceBias`],
+/// and the picked choice is highlighted.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::{debug::dump_score_tree, prelude::*};
+///
+/// #[derive(Component)]
+/// pub struct Attack;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// let attack = world.init_component::<Attack>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let scorer = world.spawn((Name::new("Aggression"), FixedScore::new(0.7))).id();
+/// let actor = world
+///     .spawn((Name::new("Goblin"), Picker::new(idle).with(scorer, attack), Highest))
+///     .add_child(scorer)
+///     .id();
+///
+/// let dot = dump_score_tree(world, actor);
+/// assert!(dot.starts_with("digraph"));
+/// assert!(dot.contains("Aggression"));
+/// // std::fs::write("goblin.dot", dot).unwrap();
+/// ```
+#[must_use]
+pub fn dump_score_tree(world: &World, actor: Entity) -> String {
+    let mut dot = String::from("digraph score_tree {\n    node [shape=box];\n");
+
+    let mut label = vec![display_name(world, actor)];
+    if let Some(current) = world.get::<CurrentAction>(actor) {
+        label.push(format!("current: {}", action_name(world, current.0)));
+    }
+    let picker = world.get::<Picker>(actor);
+    if let Some(default) = picker.and_then(|picker| picker.default) {
+        label.push(format!("default: {}", action_name(world, default)));
+    }
+    if let Some(picker) = world.get::<FirstToScore>(actor) {
+        label.push(format!("FirstToScore: {:.3}", picker.threshold().get()));
+    }
+    if let Some(picker) = world.get::<HighestAboveThreshold>(actor) {
+        label.push(format!("HighestAboveThreshold: {:.3}", picker.threshold().get()));
+    }
+    write_node(&mut dot, actor, &label, true);
+
+    let mut visited = HashSet::default();
+    visited.insert(actor);
+    let mut stack: Vec<(Entity, Entity)> = score_children(world, actor).map(|child| (actor, child)).collect();
+    stack.reverse();
+    while let Some((parent, entity)) = stack.pop() {
+        let mut attributes = Vec::new();
+        if let Some(picker) = picker.filter(|_| parent == actor) {
+            if let Some(&action) = picker.choices.get(&entity) {
+                let mut edge = action_name(world, action);
+                let bias = picker.choices.bias(&entity);
+                if bias != ChoiceBias::NONE {
+                    edge.push_str(&format!(" (x{:.2} {:+.2})", bias.multiplier, bias.bias));
+                }
+                attributes.push(format!("label=\"{}\"", escape(&edge)));
+                if picker.picked_score_entity == Some(entity) {
+                    attributes.push("color=green, penwidth=2".to_string());
+                }
+            }
+        }
+        let _ = writeln!(
+            dot,
+            "    \"{parent}\" -> \"{entity}\"{};",
+            if attributes.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attributes.join(", "))
+            }
+        );
+
+        if !visited.insert(entity) {
+            // Don't loop forever on hierarchy cycles.
+            continue;
+        }
+        write_node(&mut dot, entity, &score_node_label(world, entity), false);
+        let children: Vec<(Entity, Entity)> = score_children(world, entity).map(|child| (entity, child)).collect();
+        stack.extend(children.into_iter().rev());
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Writes a node with the given label lines.
+fn write_node(dot: &mut String, entity: Entity, label: &[String], actor: bool) {
+    let label: Vec<String> = label.iter().map(|line| escape(line)).collect();
+    let style = if actor { ", style=bold" } else { "" };
+    let _ = writeln!(dot, "    \"{entity}\" [label=\"{}\"{style}];", label.join("\\n"));
+}
+
+/// Returns the label lines of a score entity: its name, [`Score`], and components.
+fn score_node_label(world: &World, entity: Entity) -> Vec<String> {
+    let mut label = vec![display_name(world, entity)];
+    if let Some(score) = world.get::<Score>(entity) {
+        label.push(format!("score: {:.3}", score.get()));
+    }
+
+    let hidden = [
+        TypeId::of::<Score>(),
+        TypeId::of::<Name>(),
+        TypeId::of::<ScoreLabel>(),
+        TypeId::of::<LastScored>(),
+        TypeId::of::<Parent>(),
+        TypeId::of::<Children>(),
+        TypeId::of::<ScoreOf>(),
+        TypeId::of::<ScoreChildren>(),
+        TypeId::of::<LinkedScoreParent>(),
+    ];
+    let registry = world.get_resource::<AppTypeRegistry>().map(|registry| registry.read());
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return label;
+    };
+    for info in world.inspect_entity(entity) {
+        if info.type_id().is_some_and(|type_id| hidden.contains(&type_id)) {
+            continue;
+        }
+        let reflected = info
+            .type_id()
+            .zip(registry.as_ref())
+            .and_then(|(type_id, registry)| registry.get_type_data::<ReflectComponent>(type_id))
+            .and_then(|reflect| reflect.reflect(entity_ref));
+        label.push(match reflected {
+            Some(component) => format!("{component:?}"),
+            None => get_short_name(info.name()),
+        });
+    }
+    label
+}
+
+/// Returns the [`Name`] or [`ScoreLabel`] of the given entity alongside its id, or just its id.
+fn display_name(world: &World, entity: Entity) -> String {
+    if let Some(name) = world.get::<Name>(entity) {
+        format!("{name} ({entity})")
+    } else if let Some(label) = world.get::<ScoreLabel>(entity) {
+        format!("{} ({entity})", label.as_str())
+    } else {
+        format!("{entity}")
+    }
+}
+
+/// Returns the name of the given action in the [`ActionRegistry`], or its short type name.
+fn action_name(world: &World, action: ComponentId) -> String {
+    if let Some(name) = world
+        .get_resource::<ActionRegistry>()
+        .and_then(|registry| registry.name(action))
+    {
+        return name.to_string();
+    }
+    world
+        .components()
+        .get_info(action)
+        .map_or_else(|| format!("{action:?}"), |info| get_short_name(info.name()))
+}
+
+/// Escapes the given text for use in a quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
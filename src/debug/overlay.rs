@@ -0,0 +1,249 @@
+use bevy::{
+    ecs::component::{ComponentId, Components},
+    prelude::*,
+    transform::TransformSystem,
+};
+
+use crate::{
+    acting::{ActionRegistry, CurrentAction},
+    picking::Picker,
+    scoring::Score,
+};
+
+/// [`Plugin`] that draws the top choice [`Score`]s and [`CurrentAction`] of actor entities marked with [`DebugAi`]
+/// in the world: a gizmo bar per choice above the actor, and a text label with the action names.
+///
+/// Requires the `debug_overlay` feature. Configure it at runtime with the [`DebugOverlaySettings`] resource.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::{
+///     debug::{DebugAi, DebugOverlayPlugin},
+///     prelude::*,
+/// };
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::RealTime, DebugOverlayPlugin));
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// world.spawn((Picker::new(idle), Highest, Transform::default(), GlobalTransform::default(), DebugAi));
+/// ```
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugOverlaySettings>()
+            .add_systems(
+                PostUpdate,
+                (Self::draw_score_bars, Self::update_labels).after(TransformSystem::TransformPropagate),
+            )
+            .observe(Self::on_debug_ai_removed_despawn_label);
+
+        app.register_type::<DebugAi>().register_type::<DebugOverlaySettings>();
+    }
+}
+
+impl DebugOverlayPlugin {
+    /// [`System`] that draws a gizmo bar for each of the top choices of [`DebugAi`] actor entities,
+    /// highlighting the picked choice.
+    pub fn draw_score_bars(
+        mut gizmos: Gizmos,
+        settings: Res<DebugOverlaySettings>,
+        actors: Query<(&GlobalTransform, &Picker), With<DebugAi>>,
+        scores: Query<&Score>,
+    ) {
+        for (transform, picker) in &actors {
+            let origin = transform.translation() + settings.offset;
+            for (index, (score_entity, _, score)) in top_choices(picker, &scores, settings.top_choices)
+                .into_iter()
+                .enumerate()
+            {
+                let start = origin - Vec3::Y * settings.bar_spacing * index as f32;
+                let color = if picker.picked_score_entity == Some(score_entity) {
+                    settings.picked_color
+                } else {
+                    settings.bar_color
+                };
+                gizmos.line(start, start + Vec3::X * settings.bar_length, settings.background_color);
+                gizmos.line(start, start + Vec3::X * settings.bar_length * score, color);
+            }
+        }
+    }
+
+    /// [`System`] that spawns, updates, and positions a text label for each [`DebugAi`] actor entity,
+    /// showing its [`CurrentAction`] and top choices, projected through the first active [`Camera`].
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::type_complexity)]
+    pub fn update_labels(
+        mut commands: Commands,
+        settings: Res<DebugOverlaySettings>,
+        components: &Components,
+        registry: Option<Res<ActionRegistry>>,
+        actors: Query<
+            (
+                Entity,
+                &GlobalTransform,
+                &Picker,
+                Option<&CurrentAction>,
+                Option<&DebugAiLabel>,
+            ),
+            With<DebugAi>,
+        >,
+        cameras: Query<(&Camera, &GlobalTransform)>,
+        scores: Query<&Score>,
+        mut labels: Query<(&mut Text, &mut Style, &mut Visibility)>,
+    ) {
+        let camera = cameras.iter().find(|(camera, _)| camera.is_active);
+        let name = |action: ComponentId| action_name(components, registry.as_deref(), action);
+
+        for (actor, transform, picker, current, label) in &actors {
+            let mut text = match current {
+                Some(current) => format!("> {}", name(current.0)),
+                None => "> (none)".to_string(),
+            };
+            for (_, action, score) in top_choices(picker, &scores, settings.top_choices) {
+                text.push_str(&format!("\n{}: {score:.2}", name(action)));
+            }
+            let position = camera.and_then(|(camera, camera_transform)| {
+                camera.world_to_viewport(camera_transform, transform.translation() + settings.offset)
+            });
+
+            let Some(&DebugAiLabel(label)) = label else {
+                let label = commands
+                    .spawn(
+                        TextBundle::from_section(
+                            text,
+                            TextStyle {
+                                font_size: settings.font_size,
+                                color: settings.text_color,
+                                ..default()
+                            },
+                        )
+                        .with_style(Style {
+                            position_type: PositionType::Absolute,
+                            ..default()
+                        }),
+                    )
+                    .id();
+                commands.entity(actor).insert(DebugAiLabel(label));
+                continue;
+            };
+            let Ok((mut label_text, mut style, mut visibility)) = labels.get_mut(label) else {
+                // The label was despawned by someone else, so spawn a new one next time.
+                commands.entity(actor).remove::<DebugAiLabel>();
+                continue;
+            };
+            if label_text.sections[0].value != text {
+                label_text.sections[0].value = text;
+            }
+            match position {
+                Some(position) => {
+                    style.left = Val::Px(position.x);
+                    style.top = Val::Px(position.y);
+                    *visibility = Visibility::Inherited;
+                }
+                None => {
+                    *visibility = Visibility::Hidden;
+                }
+            }
+        }
+    }
+
+    /// [`Observer`] that despawns the text label of actor entities that are no longer marked with [`DebugAi`].
+    pub fn on_debug_ai_removed_despawn_label(
+        trigger: Trigger<OnRemove, DebugAi>,
+        mut commands: Commands,
+        labels: Query<&DebugAiLabel>,
+    ) {
+        let actor = trigger.entity();
+        let Ok(&DebugAiLabel(label)) = labels.get(actor) else {
+            return;
+        };
+        if let Some(label) = commands.get_entity(label) {
+            label.despawn_recursive();
+        }
+        if let Some(mut actor) = commands.get_entity(actor) {
+            actor.remove::<DebugAiLabel>();
+        }
+    }
+}
+
+/// [`Component`] marker for actor entities whose scores and current action are drawn by the [`DebugOverlayPlugin`].
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[reflect(Component, PartialEq, Hash, Debug, Default)]
+pub struct DebugAi;
+
+/// [`Component`] for [`DebugAi`] actor entities that points to their text label,
+/// maintained by the [`DebugOverlayPlugin`].
+#[derive(Component)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DebugAiLabel(pub Entity);
+
+/// [`Resource`] for configuring the [`DebugOverlayPlugin`].
+#[derive(Resource, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[reflect(Resource, PartialEq, Debug, Default)]
+pub struct DebugOverlaySettings {
+    /// The number of highest scoring choices to show per actor.
+    pub top_choices: usize,
+    /// The world-space offset from the actor to draw at.
+    pub offset: Vec3,
+    /// The world-space length of a full score bar.
+    pub bar_length: f32,
+    /// The world-space distance between score bars.
+    pub bar_spacing: f32,
+    /// The color of score bars.
+    pub bar_color: Color,
+    /// The color of the picked choice's score bar.
+    pub picked_color: Color,
+    /// The color of the unfilled part of score bars.
+    pub background_color: Color,
+    /// The font size of text labels.
+    pub font_size: f32,
+    /// The color of text labels.
+    pub text_color: Color,
+}
+
+impl Default for DebugOverlaySettings {
+    fn default() -> Self {
+        Self {
+            top_choices: 3,
+            offset: Vec3::Y * 2.,
+            bar_length: 1.,
+            bar_spacing: 0.15,
+            bar_color: Color::srgb(1., 0.8, 0.2),
+            picked_color: Color::srgb(0.3, 1., 0.3),
+            background_color: Color::srgba(0.5, 0.5, 0.5, 0.5),
+            font_size: 14.,
+            text_color: Color::WHITE,
+        }
+    }
+}
+
+/// Returns up to `count` choices of the [`Picker`] with the highest [`Score`]s, highest first.
+fn top_choices(picker: &Picker, scores: &Query<&Score>, count: usize) -> Vec<(Entity, ComponentId, f32)> {
+    let mut choices: Vec<(Entity, ComponentId, f32)> = picker
+        .choices
+        .iter()
+        .filter_map(|(score_entity, action)| Some((score_entity, action, scores.get(score_entity).ok()?.get())))
+        .collect();
+    choices.sort_by(|(_, _, a), (_, _, b)| b.total_cmp(a));
+    choices.truncate(count);
+    choices
+}
+
+/// Returns the name of the given action in the [`ActionRegistry`], or its short type name.
+fn action_name(components: &Components, registry: Option<&ActionRegistry>, action: ComponentId) -> String {
+    if let Some(name) = registry.and_then(|registry| registry.name(action)) {
+        return name.to_string();
+    }
+    components.get_info(action).map_or_else(
+        || format!("{action:?}"),
+        |info| bevy::utils::get_short_name(info.name()),
+    )
+}
@@ -0,0 +1,204 @@
+//! [`bevy::diagnostic`] integration, for spotting performance regressions in the standard diagnostics overlay.
+//!
+//! Add the [`AiDiagnosticsPlugin`] to measure, each frame:
+//! - [`SCORERS_EVALUATED`](AiDiagnosticsPlugin::SCORERS_EVALUATED): the number of score entities scored.
+//! - [`PICKS`](AiDiagnosticsPlugin::PICKS): the number of [`OnPicked`] events, not counting [extra](OnPicked::extra) picks.
+//! - [`ACTION_SWITCHES`](AiDiagnosticsPlugin::ACTION_SWITCHES): the number of [`OnActionInitiated`] events per second.
+//! - [`SCORING_TIME`](AiDiagnosticsPlugin::SCORING_TIME): the time spent in [`RunScoring`] and [`RunScoringMany`],
+//!   including all [`OnScore`] observers.
+//! - [`PICKING_TIME`](AiDiagnosticsPlugin::PICKING_TIME): the time spent in [`RunPicking`] and [`RunPickingMany`],
+//!   including all [`OnPick`] observers.
+//!
+//! [`RunScoring`]: crate::event::RunScoring
+//! [`RunScoringMany`]: crate::event::RunScoringMany
+//! [`RunPicking`]: crate::event::RunPicking
+//! [`RunPickingMany`]: crate::event::RunPickingMany
+//! [`OnScore`]: crate::event::OnScore
+//! [`OnPick`]: crate::event::OnPick
+
+use std::time::Duration;
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    ecs::world::Command,
+    prelude::*,
+    utils::Instant,
+};
+
+use crate::event::{OnActionInitiated, OnPicked};
+
+/// [`Plugin`] that registers diagnostics for scoring, picking, and acting.
+/// See the [module docs](self) for more information.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::{
+///     diagnostic::{DiagnosticPath, DiagnosticsStore},
+///     prelude::*,
+/// };
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::TurnBased, AiDiagnosticsPlugin));
+/// # let mut world = app.world_mut();
+/// let idle = world.init_component::<Idle>();
+///
+/// let scorer = world.spawn(FixedScore::new(0.5)).id();
+/// world.spawn((Picker::new(idle).with(scorer, idle), Highest)).add_child(scorer);
+/// world.trigger(RunScoring);
+/// world.trigger(RunPicking);
+/// # world.flush();
+/// app.update();
+///
+/// let store = app.world().resource::<DiagnosticsStore>();
+/// let value = |path: &DiagnosticPath| store.get(path).and_then(|diagnostic| diagnostic.value());
+/// assert_eq!(Some(1.), value(&AiDiagnosticsPlugin::SCORERS_EVALUATED));
+/// assert_eq!(Some(1.), value(&AiDiagnosticsPlugin::PICKS));
+/// ```
+pub struct AiDiagnosticsPlugin;
+
+impl AiDiagnosticsPlugin {
+    /// Number of score entities scored per frame.
+    pub const SCORERS_EVALUATED: DiagnosticPath = DiagnosticPath::const_new("observed_utility/scorers_evaluated");
+    /// Number of [`OnPicked`] events per frame, not counting [extra](OnPicked::extra) picks.
+    pub const PICKS: DiagnosticPath = DiagnosticPath::const_new("observed_utility/picks");
+    /// Number of [`OnActionInitiated`] events per second.
+    pub const ACTION_SWITCHES: DiagnosticPath = DiagnosticPath::const_new("observed_utility/action_switches");
+    /// Milliseconds spent scoring per frame.
+    pub const SCORING_TIME: DiagnosticPath = DiagnosticPath::const_new("observed_utility/scoring_time");
+    /// Milliseconds spent picking per frame.
+    pub const PICKING_TIME: DiagnosticPath = DiagnosticPath::const_new("observed_utility/picking_time");
+}
+
+impl Plugin for AiDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::SCORERS_EVALUATED))
+            .register_diagnostic(Diagnostic::new(Self::PICKS))
+            .register_diagnostic(Diagnostic::new(Self::ACTION_SWITCHES).with_suffix("/s"))
+            .register_diagnostic(Diagnostic::new(Self::SCORING_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::PICKING_TIME).with_suffix("ms"));
+
+        app.init_resource::<AiDiagnosticsState>()
+            .add_systems(Last, Self::measure)
+            .observe(Self::on_picked_count)
+            .observe(Self::on_action_initiated_count);
+    }
+}
+
+impl AiDiagnosticsPlugin {
+    /// [`System`] that adds the measurements of the current frame, and resets them for the next.
+    pub fn measure(mut diagnostics: Diagnostics, mut state: ResMut<AiDiagnosticsState>, time: Option<Res<Time<Real>>>) {
+        let scored = std::mem::take(&mut state.scored) as f64;
+        let picks = std::mem::take(&mut state.picks) as f64;
+        let switches = std::mem::take(&mut state.switches) as f64;
+        let scoring = state.scoring.take_millis();
+        let picking = state.picking.take_millis();
+
+        diagnostics.add_measurement(&Self::SCORERS_EVALUATED, || scored);
+        diagnostics.add_measurement(&Self::PICKS, || picks);
+        if let Some(delta) = time.map(|time| time.delta_seconds_f64()).filter(|&delta| delta > 0.) {
+            diagnostics.add_measurement(&Self::ACTION_SWITCHES, || switches / delta);
+        }
+        diagnostics.add_measurement(&Self::SCORING_TIME, || scoring);
+        diagnostics.add_measurement(&Self::PICKING_TIME, || picking);
+    }
+
+    /// [`Observer`] that counts [`OnPicked`] events, skipping [extra](OnPicked::extra) picks.
+    pub fn on_picked_count(trigger: Trigger<OnPicked>, mut state: ResMut<AiDiagnosticsState>) {
+        if trigger.event().extra {
+            return;
+        }
+        state.picks += 1;
+    }
+
+    /// [`Observer`] that counts [`OnActionInitiated`] events.
+    pub fn on_action_initiated_count(_trigger: Trigger<OnActionInitiated>, mut state: ResMut<AiDiagnosticsState>) {
+        state.switches += 1;
+    }
+}
+
+/// [`Resource`] for the measurements of the current frame, maintained by the [`AiDiagnosticsPlugin`].
+#[derive(Resource, Default)]
+pub struct AiDiagnosticsState {
+    /// Time spent in [`RunScoring`].
+    scoring: PhaseTimer,
+    /// Time spent in [`RunPicking`].
+    picking: PhaseTimer,
+    /// Number of score entities scored.
+    scored: usize,
+    /// Number of non-[extra](OnPicked::extra) [`OnPicked`] events.
+    picks: usize,
+    /// Number of [`OnActionInitiated`] events.
+    switches: usize,
+}
+
+/// Accumulates the time spent in a possibly nested phase.
+#[derive(Default)]
+struct PhaseTimer {
+    /// How many times the phase was started but not stopped yet.
+    depth: u32,
+    /// When the outermost phase was started.
+    started: Option<Instant>,
+    /// Time spent in finished phases.
+    elapsed: Duration,
+}
+
+impl PhaseTimer {
+    fn start(&mut self) {
+        if self.depth == 0 {
+            self.started = Some(Instant::now());
+        }
+        self.depth += 1;
+    }
+
+    fn stop(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            if let Some(started) = self.started.take() {
+                self.elapsed += started.elapsed();
+            }
+        }
+    }
+
+    /// Returns the time spent in finished phases in milliseconds, and resets it.
+    fn take_millis(&mut self) -> f64 {
+        std::mem::take(&mut self.elapsed).as_secs_f64() * 1000.
+    }
+}
+
+/// [`Command`] that starts or stops timing scoring or picking for the [`AiDiagnosticsPlugin`], if it's added.
+///
+/// Queued around the [`Command`]s of [`RunScoring`] and [`RunPicking`] observers,
+/// so the time of all nested observers is included.
+/// Only queued while the [`AiDiagnosticsState`] [`Resource`] exists, so it costs nothing otherwise.
+pub(crate) enum MeasurePhase {
+    /// Starts timing scoring.
+    StartScoring,
+    /// Stops timing scoring, after scoring the given number of score entities.
+    StopScoring(usize),
+    /// Starts timing picking.
+    StartPicking,
+    /// Stops timing picking.
+    StopPicking,
+}
+
+impl Command for MeasurePhase {
+    fn apply(self, world: &mut World) {
+        let Some(mut state) = world.get_resource_mut::<AiDiagnosticsState>() else {
+            return;
+        };
+        match self {
+            MeasurePhase::StartScoring => state.scoring.start(),
+            MeasurePhase::StopScoring(scored) => {
+                state.scoring.stop();
+                state.scored += scored;
+            }
+            MeasurePhase::StartPicking => state.picking.start(),
+            MeasurePhase::StopPicking => state.picking.stop(),
+        }
+    }
+}
@@ -1,20 +1,21 @@
 //! [`bevy`] ECS utilities for implementing library functionality.
 
-use std::{collections::VecDeque, iter::FusedIterator, marker::PhantomData};
+use std::{any::TypeId, marker::PhantomData};
 
 use bevy::{
     ecs::{
         component::ComponentId,
-        entity::EntityHashMap,
         observer::TriggerTargets,
-        query::{QueryData, QueryEntityError, QueryFilter, ReadOnlyQueryData},
-        system::{IntoObserverSystem, SystemParam},
+        query::{QueryData, ReadOnlyQueryData},
+        system::IntoObserverSystem,
         world::Command,
     },
     prelude::*,
-    utils::Entry,
+    utils::HashMap,
 };
 
+pub use crate::scoring::{AncestorQuery, DFSPostTraversal, DFSPostTraversalIter};
+
 /// A [`TriggerTargets`] used by the action [`Event`]s to trigger an action [`ComponentId`] for a given entity.
 #[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
 #[reflect(PartialEq, Debug)]
@@ -32,6 +33,62 @@ impl TriggerTargets for TargetedAction {
     }
 }
 
+/// A [`TriggerTargets`] like [`TargetedAction`], but for several actor entities at once, such as a squad.
+///
+/// Triggering an action [`Event`] with this is the same as triggering it with a [`TargetedAction`]
+/// for each actor entity in order.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Charge;
+/// #[derive(Component)]
+/// pub struct Hold;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let charge = world.init_component::<Charge>();
+/// let hold = world.init_component::<Hold>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let squad: Vec<Entity> = (0..3)
+///     .map(|_| {
+///         world
+///             .spawn((Picker::new(idle), Highest, CurrentAction(charge), ActionQueue::default().with(hold)))
+///             .id()
+///     })
+///     .collect();
+///
+/// // The whole squad reached the enemy line at once, so they all hold.
+/// world.trigger_targets(OnActionEnded::completed(charge), TargetedActionMany(squad.clone(), charge));
+/// # world.flush();
+/// # for soldier in squad {
+/// #     assert_eq!(hold, world.get::<CurrentAction>(soldier).unwrap().0);
+/// # }
+/// ```
+#[derive(Reflect, Clone, PartialEq, Eq, Debug)]
+#[reflect(PartialEq, Debug)]
+pub struct TargetedActionMany(pub Vec<Entity>, pub ComponentId);
+
+impl TriggerTargets for TargetedActionMany {
+    #[inline]
+    fn components(&self) -> impl ExactSizeIterator<Item = ComponentId> {
+        std::iter::once(self.1)
+    }
+
+    #[inline]
+    fn entities(&self) -> impl ExactSizeIterator<Item = Entity> {
+        self.0.iter().copied()
+    }
+}
+
 /// [`Trigger`] extension trait that provides checked access to [`Trigger::entity`].
 pub trait TriggerGetEntity {
     /// Returns the triggered [`Entity`], but only if it's not [`Entity::PLACEHOLDER`].
@@ -46,113 +103,33 @@ impl<E, B: Bundle> TriggerGetEntity for Trigger<'_, E, B> {
     }
 }
 
-/// A [`Query`] wrapper that finds the closest ancestor entity with a given component.
-/// Uses a cache to speed up subsequent queries.
-#[derive(SystemParam)]
-pub struct AncestorQuery<'w, 's, T: ReferenceType> {
-    /// The query to find the component, crawling up the hierarchy if necessary.
-    check: Query<'w, 's, (<T as ReferenceType>::Has, Option<&'static Parent>)>,
-    /// The query to grab the component. This query wouldn't be necessary if rust wouldn't complain!
-    fetch: Query<'w, 's, T>,
-    /// Caches a given entity's closest ancestor entity with the component T.
-    cache: Local<'s, EntityHashMap<Entity>>,
-}
-
-impl<'w, 's, T: ReferenceType> AncestorQuery<'w, 's, T> {
-    /// Crawls up the hierarchy to find the closest ancestor entity with the component `T`.
-    fn find(&mut self, start: Entity) -> Result<Entity, QueryEntityError> {
-        // Crawl up the hierarchy
-        let mut current = start;
-        loop {
-            match self.check.get(current) {
-                Ok((true, _)) => {
-                    // Found the component, cache it and return
-                    self.cache.insert(start, current);
-                    return Ok(current);
-                }
-                Ok((false, Some(parent))) => {
-                    // Continue searching up the hierarchy
-                    current = **parent;
-                }
-                Ok((false, None)) | Err(_) => {
-                    // No parent with the component found
-                    return Err(QueryEntityError::NoSuchEntity(current));
-                }
-            }
-        }
-    }
-
-    /// Clears the cache to free up memory, if necessary.
-    pub fn clear_cache(&mut self) {
-        self.cache.clear();
-    }
-}
-
-impl<'w, 's, T: Component> AncestorQuery<'w, 's, &'static T> {
-    /// Returns a readonly reference to the [`Component`] `T` on the closest ancestor entity, if any.
-    ///
-    /// # Errors
-    ///
-    /// If the entity does not exist or the component is not found.
-    pub fn get(&mut self, start: Entity) -> Result<&T, QueryEntityError> {
-        // Check the cache first
-        if let Entry::Occupied(entry) = self.cache.entry(start) {
-            if self.fetch.contains(*entry.get()) {
-                // Cache hit
-                return self.fetch.get(*entry.get());
-            }
-
-            // Cache miss
-            entry.remove();
-        }
-
-        self.find(start).and_then(|found| self.fetch.get(found))
-    }
-}
-
-impl<'w, 's, T: Component> AncestorQuery<'w, 's, &'static mut T> {
-    /// Returns a mutable reference to the [`Component`] `T` on the closest ancestor entity, if any.
-    ///
-    /// # Errors
-    ///
-    /// If the entity does not exist or the component is not found.
-    pub fn get_mut(&mut self, start: Entity) -> Result<Mut<T>, QueryEntityError> {
-        // Check the cache first
-        if let Entry::Occupied(entry) = self.cache.entry(start) {
-            if self.fetch.contains(*entry.get()) {
-                // Cache hit
-                return self.fetch.get_mut(*entry.get());
-            }
-
-            // Cache miss
-            entry.remove();
-        }
-
-        self.find(start).and_then(|found| self.fetch.get_mut(found))
-    }
-}
-
 /// A [`QueryData`] supertrait for `&T` and `&mut T` reference types.
 pub trait ReferenceType: QueryData + 'static {
+    /// The referenced [`Component`] type.
+    type Component: Component;
     /// The [`Has`] type for this reference type.
     type Has: for<'a> ReadOnlyQueryData<Item<'a> = bool>;
 }
 
 impl<T: Component> ReferenceType for &'static T {
+    type Component = T;
     type Has = Has<T>;
 }
 
 impl<T: Component> ReferenceType for &'static mut T {
+    type Component = T;
     type Has = Has<T>;
 }
 
 /// [`Command`] that runs a given command only if the [`Resource`] `R` has not been inserted into the [`World`] yet.
 /// After running the command, the resource is inserted into the world.
+#[deprecated(note = "use `CommandsExt::observe_once` to spawn observers once instead")]
 pub struct Once<R: Resource + Default, C: Command> {
     _type: PhantomData<R>,
     command: C,
 }
 
+#[allow(deprecated)]
 impl<R: Resource + Default, C: Command> Command for Once<R, C> {
     fn apply(self, world: &mut World) {
         if world.contains_resource::<R>() {
@@ -164,14 +141,45 @@ impl<R: Resource + Default, C: Command> Command for Once<R, C> {
     }
 }
 
+/// [`Command`] that inserts a [`Component`] onto an entity, but only if the entity doesn't already have one.
+///
+/// Component hooks use this to make sure the components they depend on are present,
+/// without overwriting components that were inserted alongside them.
+pub struct InsertIfMissing<C: Component> {
+    entity: Entity,
+    component: C,
+}
+
+impl<C: Component> InsertIfMissing<C> {
+    /// Creates a new [`InsertIfMissing`] command for the given entity and component.
+    #[must_use]
+    pub fn new(entity: Entity, component: C) -> Self {
+        Self { entity, component }
+    }
+}
+
+impl<C: Component> Command for InsertIfMissing<C> {
+    fn apply(self, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(self.entity) else {
+            // The entity was despawned in the meantime.
+            return;
+        };
+        if !entity.contains::<C>() {
+            entity.insert(self.component);
+        }
+    }
+}
+
 /// A [`Commands`] wrapper that provides a way to run commands only, based on the presence of [`Resource`] `R`.
 ///
 /// See [`CommandsExt::once`] for more information.
+#[deprecated(note = "use `CommandsExt::observe_once` to spawn observers once instead")]
 pub struct OnceCommands<'w, 's, R: Resource + Default> {
     commands: Commands<'w, 's>,
     _type: PhantomData<R>,
 }
 
+#[allow(deprecated)]
 impl<'w, 's, R: Resource + Default> OnceCommands<'w, 's, R> {
     fn new(commands: Commands<'w, 's>) -> Self {
         Self {
@@ -180,111 +188,164 @@ impl<'w, 's, R: Resource + Default> OnceCommands<'w, 's, R> {
         }
     }
 
-    /// Adds the specified [`Observer`] system if and only if the [`Resource`] `R` has not been inserted into the [`World`] yet.
-    /// After running the command, the resource is inserted into the world.
-    pub fn observe<E: Event, B: Bundle, M>(mut self, observer: impl IntoObserverSystem<E, B, M>) {
-        self.commands.add(Once::<R, _> {
-            _type: PhantomData,
-            command: |world: &mut World| {
-                world.observe(observer);
-            },
-        });
+    /// Adds the specified [`Observer`] system with [`CommandsExt::observe_once`],
+    /// and inserts the [`Resource`] `R` into the [`World`] if it isn't there yet.
+    pub fn observe<E: Event, B: Bundle, M, S: IntoObserverSystem<E, B, M>>(mut self, observer: S) {
+        self.commands.init_resource::<R>();
+        self.commands.observe_once(observer);
+    }
+}
+
+/// [`Resource`] that tracks the [`Observer`] entities spawned with [`CommandsExt::observe_once`],
+/// by the [`TypeId`] of their observer system, along with how many times each is in use.
+#[derive(Resource, Default)]
+pub struct OnceObservers {
+    /// The [`Observer`] entity spawned for each observer system.
+    entities: HashMap<TypeId, Entity>,
+    /// The number of [`CommandsExt::observe_once`] calls for each observer system
+    /// that weren't balanced by a [`CommandsExt::unobserve_if_unused`] call yet.
+    users: HashMap<TypeId, usize>,
+}
+
+impl OnceObservers {
+    /// Returns the [`Observer`] entity spawned for the observer system with the given [`TypeId`], if any.
+    #[must_use]
+    pub fn get(&self, system: TypeId) -> Option<Entity> {
+        self.entities.get(&system).copied()
     }
 }
 
+/// Marker [`Resource`] that enables despawning the [`Observer`]s of built-in components once the last entity
+/// with the component is gone, instead of keeping them forever. See [`CommandsExt::unobserve_if_unused`].
+///
+/// Useful for long-running apps that churn through many different score tree and picker components.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::{ecs::CleanupUnusedObservers, prelude::*};
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservedUtilityPlugins::RealTime)
+///     .insert_resource(CleanupUnusedObservers);
+/// # let mut world = app.world_mut();
+/// # let mut observers = world.query::<&Observer<OnScore, ()>>();
+/// # let before = observers.iter(world).count();
+/// let scorer = world.spawn(FixedScore::new(0.5)).id();
+/// # world.flush();
+/// # assert_eq!(before + 1, observers.iter(world).count());
+/// world.despawn(scorer);
+/// # world.flush();
+/// // The FixedScore observer is gone.
+/// # assert_eq!(before, observers.iter(world).count());
+/// ```
+#[derive(Resource, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Resource, PartialEq, Debug, Default)]
+pub struct CleanupUnusedObservers;
+
 /// [`Commands`] extension trait for library-specific commands.
 pub trait CommandsExt {
     /// Returns a [`Commands`] wrapper that provides a way to run commands only once, based on the presence of [`Resource`] `R`.
+    #[deprecated(note = "use `CommandsExt::observe_once` to spawn observers once instead")]
+    #[allow(deprecated)]
     #[must_use]
     fn once<R: Resource + Default>(&mut self) -> OnceCommands<'_, '_, R>;
+
+    /// Spawns the given [`Observer`] system, unless an observer with the same system type was already spawned
+    /// with this method. Tracked in the [`OnceObservers`] [`Resource`].
+    ///
+    /// Useful for spawning a component's [`Observer`] lazily from its `on_add` hook:
+    ///
+    /// ```rust
+    /// use bevy::{
+    ///     ecs::component::{ComponentHooks, StorageType},
+    ///     prelude::*,
+    /// };
+    /// use bevy_observed_utility::{ecs::CommandsExt, prelude::*};
+    ///
+    /// /// Always scores the maximum.
+    /// pub struct Eager;
+    ///
+    /// impl Eager {
+    ///     fn observer(trigger: Trigger<OnScore>, mut scores: Query<&mut Score, With<Eager>>) {
+    ///         if let Ok(mut score) = scores.get_mut(trigger.entity()) {
+    ///             *score = Score::MAX;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl Component for Eager {
+    ///     const STORAGE_TYPE: StorageType = StorageType::Table;
+    ///
+    ///     fn register_component_hooks(hooks: &mut ComponentHooks) {
+    ///         hooks.on_add(|mut world, _entity, _component| {
+    ///             world.commands().observe_once(Self::observer);
+    ///         });
+    ///     }
+    /// }
+    /// #
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+    /// # let mut world = app.world_mut();
+    /// # let scorer = world.spawn((Eager, Score::default())).id();
+    /// # world.spawn((Eager, Score::default()));
+    /// # world.flush();
+    /// # world.trigger_targets(RunScoring, scorer);
+    /// # world.flush();
+    /// # assert_eq!(Score::MAX, *world.get::<Score>(scorer).unwrap());
+    /// ```
+    fn observe_once<E: Event, B: Bundle, M, S: IntoObserverSystem<E, B, M>>(&mut self, observer: S);
+
+    /// Releases one use of the [`Observer`] spawned with [`CommandsExt::observe_once`] for the given observer system,
+    /// and despawns it once every [`CommandsExt::observe_once`] call for it has been released,
+    /// if [`CleanupUnusedObservers`] is present.
+    /// The observer is spawned again the next time [`CommandsExt::observe_once`] is called for it.
+    ///
+    /// Meant to be called from the `on_remove` hook of the component whose `on_add` hook spawned the observer,
+    /// with the same observer system, so that each component instance holds one use.
+    fn unobserve_if_unused<E: Event, B: Bundle, M, S: IntoObserverSystem<E, B, M>>(&mut self, observer: S);
 }
 
 impl CommandsExt for Commands<'_, '_> {
+    #[allow(deprecated)]
     fn once<R: Resource + Default>(&mut self) -> OnceCommands<'_, '_, R> {
         OnceCommands::new(self.reborrow())
     }
-}
-
-/// [`SystemParam`] that provides a depth-first search post-order traversal of the entity hierarchy,
-/// starting from a given root [`Entity`].
-#[derive(SystemParam)]
-pub struct DFSPostTraversal<'w, 's, F: QueryFilter + 'static = ()> {
-    children: Query<'w, 's, &'static Children, F>,
-    queue: Local<'s, VecDeque<(usize, Entity)>>,
-}
 
-impl<'w, 's, F: QueryFilter + 'static> DFSPostTraversal<'w, 's, F> {
-    /// Returns an iterator that provides a depth-first search post-order traversal of the entity hierarchy,
-    /// starting from a given root [`Entity`].
-    ///
-    /// The deepest children are visited first, followed by their parents.
-    #[must_use = "iterators are lazy and do nothing unless consumed"]
-    pub fn iter(&mut self, root: Entity) -> DFSPostTraversalIter<'_, 'w, 's, F> {
-        DFSPostTraversalIter::new(self, root)
-    }
-}
-
-/// [`Iterator`] type returned by [`DFSPostTraversal::iter`].
-pub struct DFSPostTraversalIter<'a, 'w, 's, F: QueryFilter + 'static> {
-    param: &'a mut DFSPostTraversal<'w, 's, F>,
-    visited: usize,
-    current_depth: usize,
-}
-
-impl<'a, 'w, 's, F: QueryFilter + 'static> DFSPostTraversalIter<'a, 'w, 's, F> {
-    fn new(param: &'a mut DFSPostTraversal<'w, 's, F>, root: Entity) -> Self {
-        param.queue.clear();
-        param.queue.push_back((0, root));
-
-        Self {
-            param,
-            visited: 0,
-            current_depth: 0,
-        }
+    fn observe_once<E: Event, B: Bundle, M, S: IntoObserverSystem<E, B, M>>(&mut self, observer: S) {
+        let system = TypeId::of::<S>();
+        self.add(move |world: &mut World| {
+            let mut observers = world.get_resource_or_insert_with(OnceObservers::default);
+            *observers.users.entry(system).or_default() += 1;
+            if observers.entities.contains_key(&system) {
+                // We've already spawned the observer.
+                return;
+            }
+            let entity = world.spawn(Observer::new(observer)).id();
+            world.resource_mut::<OnceObservers>().entities.insert(system, entity);
+        });
     }
-}
-
-impl<F: QueryFilter + 'static> Iterator for DFSPostTraversalIter<'_, '_, '_, F> {
-    type Item = Entity;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.param.queue.is_empty() {
-            return None;
-        }
-
-        // Exhaust all children for the first branch
-        loop {
-            let i = self.visited;
-            let Some(&(depth, entity)) = self.param.queue.get(i) else {
-                break;
+    fn unobserve_if_unused<E: Event, B: Bundle, M, S: IntoObserverSystem<E, B, M>>(&mut self, _observer: S) {
+        let system = TypeId::of::<S>();
+        self.add(move |world: &mut World| {
+            let cleanup = world.contains_resource::<CleanupUnusedObservers>();
+            let Some(mut observers) = world.get_resource_mut::<OnceObservers>() else {
+                return;
             };
-
-            // This node is not a child nor a sibling
-            if self.current_depth > depth {
-                break;
-            }
-
-            self.visited += 1;
-            self.current_depth = depth;
-
-            let Ok(entity_children) = self.param.children.get(entity) else {
-                // No children
-                break;
+            let Some(users) = observers.users.get_mut(&system) else {
+                return;
             };
-
-            // TODO: can we replace this with some kind of `extend_at`?
-            for (j, child) in entity_children.into_iter().copied().enumerate() {
-                self.param.queue.insert(i + j + 1, (depth + 1, child));
+            *users = users.saturating_sub(1);
+            if *users > 0 || !cleanup {
+                return;
             }
-        }
-
-        let (depth, entity) = self.param.queue.remove(self.visited - 1)?;
-
-        self.visited -= 1;
-        self.current_depth = depth;
-
-        Some(entity)
+            let Some(entity) = observers.entities.remove(&system) else {
+                return;
+            };
+            world.despawn(entity);
+        });
     }
 }
-
-impl<F: QueryFilter + 'static> FusedIterator for DFSPostTraversalIter<'_, '_, '_, F> {}
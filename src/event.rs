@@ -1,23 +1,29 @@
 //! Events that define the lifecycle of the library.
 //!
-//! These events are split into three categories:
+//! These events are split into four categories:
 //! - Scoring events
 //! - Picking events
 //! - Acting events
+//! - Turn events
 //!
 //! Generally speaking, events that start with `On` should only be listened to,
 //! while other events should only be triggered.
 //!
 //! # Scoring events
 //!
-//! [`RunScoring`] can be triggered to score a specific entity or all entities with the [`Score`] component.
+//! [`RunScoring`] can be triggered to score a specific entity or all entities with the [`Score`] component,
+//! [`RunScoringMany`] to score a given set of entities, and [`RunScoring::for_marker`] to score every entity
+//! with a marker component.
 //! [`Score`] entities with [`Score`] children will be scored after their children, to ensure correct scoring.
 //! This will trigger the [`OnScore`] event for the target entity, which should be listened to by scoring [`Observer`]s
 //! to calculate the [`Score`] for a given entity.
+//! Once a whole score tree is scored, [`OnScoringComplete`] is triggered for its root entity.
 //!
 //! # Picking events
 //!
-//! [`RunPicking`] can be triggered to make a specific entity or all entities with the [`Picker`] component pick an action.
+//! [`RunPicking`] can be triggered to make a specific entity or all entities with the [`Picker`] component pick an action,
+//! [`RunPickingMany`] to make a given set of entities pick, and [`RunPicking::for_marker`] to make every entity
+//! with a marker component pick.
 //! This will trigger the [`OnPick`] event for the target entity, which should be listened to by picking [`Observer`]s and
 //! which will trigger the [`OnPicked`] event with the picked action.
 //!
@@ -27,18 +33,31 @@
 //! This will trigger the [`OnActionInitiated`] event for the target entity, using the action picked by their [`Picker`].
 //! The [`OnActionEnded`] event is triggered by action lifecycle or actions themselves to indicate that they have completed or been cancelled.
 //! In between these two previous events, the action should be executed.
+//! Long-running actions can report how far along they are with [`OnActionProgress`].
+//! Actions mapped to per-entity states trigger [`OnExitActionState`] and [`OnEnterActionState`] when the state changes.
+//!
+//! # Turn events
+//!
+//! With the [`TurnBasedPlugin`](crate::turn::TurnBasedPlugin), [`StartTurn`] starts an actor entity's turn
+//! and [`EndTurn`] ends it.
 //!
-//! [`Score`]: crate::scoring::Score
 //! [`Picker`]: crate::picking::Picker
 
-use bevy::{ecs::component::ComponentId, prelude::*};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+use bevy::{
+    ecs::{component::ComponentId, query::QueryFilter, world::Command},
+    prelude::*,
+};
+
+use crate::{acting::ActionState, ecs::TargetedAction, scoring::Score};
 
 ////////////////////////////////////////////////////////////
 // Scoring events
 ////////////////////////////////////////////////////////////
 
-/// Trigger this [`Event`] to score the targeted entity,
-/// or all entities if no target is specified.
+/// Trigger this [`Event`] to score the targeted entity, or all entities if no target is specified.
+/// Targeting an entity without a [`Score`], such as an actor entity, scores all of its child score trees.
 ///
 /// Entities are scored in depth-first post-order traversal,
 /// ensuring that all children are scored before their parents.
@@ -47,12 +66,184 @@ use bevy::{ecs::component::ComponentId, prelude::*};
 #[reflect(Component, PartialEq, Debug, Default)]
 pub struct RunScoring;
 
+impl RunScoring {
+    /// Returns a [`Command`] that scores every entity with the marker [`Component`] `M`,
+    /// such as all actors of a faction, as if [`RunScoring`] targeted each of them.
+    /// Equivalent to [`RunScoring::for_filter::<With<M>>()`](RunScoring::for_filter).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// pub struct EnemyFaction;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+    /// # let mut world = app.world_mut();
+    /// let enemy = world.spawn((EnemyFaction, FixedScore::new(0.5))).id();
+    /// let ally = world.spawn(FixedScore::new(0.5)).id();
+    /// # world.flush();
+    ///
+    /// world.commands().add(RunScoring::for_marker::<EnemyFaction>());
+    /// # world.flush();
+    /// # assert_eq!(0.5, world.get::<Score>(enemy).unwrap().get());
+    /// # assert_eq!(0., world.get::<Score>(ally).unwrap().get());
+    /// ```
+    #[must_use]
+    pub fn for_marker<M: Component>() -> RunScoringFor<With<M>> {
+        Self::for_filter()
+    }
+
+    /// Returns a [`Command`] that scores every entity matching the [`QueryFilter`] `F`,
+    /// as if [`RunScoring`] targeted each of them. See [`RunScoringFor`].
+    #[must_use]
+    pub fn for_filter<F: QueryFilter + 'static>() -> RunScoringFor<F> {
+        RunScoringFor(PhantomData)
+    }
+}
+
+/// [`Command`] that triggers [`RunScoringMany`] for every entity matching the [`QueryFilter`] `F`,
+/// created with [`RunScoring::for_marker`] or [`RunScoring::for_filter`].
+///
+/// Matching actor entities have all of their child score trees scored, and matching score entities are scored
+/// along with their children. So mark either the actors or the score roots, but not both,
+/// or the same trees will be scored twice.
+pub struct RunScoringFor<F>(PhantomData<fn() -> F>);
+
+impl<F: QueryFilter + 'static> Command for RunScoringFor<F> {
+    fn apply(self, world: &mut World) {
+        let targets: Vec<Entity> = world.query_filtered::<Entity, F>().iter(world).collect();
+        world.trigger(RunScoringMany(targets));
+    }
+}
+
+/// Trigger this [`Event`] to score the given entities in a single trigger, such as the active faction's actors
+/// in a turn-based game. Each entity is scored as if [`RunScoring`] targeted it.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// let red = world.spawn(FixedScore::new(0.5)).id();
+/// let blue = world.spawn(FixedScore::new(0.5)).id();
+/// # world.flush();
+///
+/// world.trigger(RunScoringMany(vec![red]));
+/// # world.flush();
+/// # assert_eq!(0.5, world.get::<Score>(red).unwrap().get());
+/// # assert_eq!(0., world.get::<Score>(blue).unwrap().get());
+/// ```
+#[derive(Event, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct RunScoringMany(pub Vec<Entity>);
+
 /// This [`Event`] is listened to by scoring systems to calculate the score(s) for a given entity.
 /// DO NOT TRIGGER MANUALLY, trigger [`RunScoring`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Health(f32);
+///
+/// #[derive(Component)]
+/// pub struct Wounded;
+///
+/// // The actor is resolved once per score tree, so there's no need to crawl up the hierarchy.
+/// fn score_wounded(trigger: Trigger<OnScore>, mut scores: Query<&mut Score, With<Wounded>>, health: Query<&Health>) {
+///     let Ok(mut score) = scores.get_mut(trigger.entity()) else {
+///         return;
+///     };
+///     let Some(health) = trigger.event().actor.and_then(|actor| health.get(actor).ok()) else {
+///         return;
+///     };
+///     score.set(1. - health.0);
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservedUtilityPlugins::TurnBased).observe(score_wounded);
+/// # let mut world = app.world_mut();
+/// let scorer = world.spawn((Wounded, Score::default())).id();
+/// let actor = world.spawn(Health(0.25)).add_child(scorer).id();
+///
+/// world.trigger_targets(RunScoring, actor);
+/// # world.flush();
+/// # assert_eq!(0.75, world.get::<Score>(scorer).unwrap().get());
+/// ```
 #[derive(Event, Reflect)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 #[reflect(Component, PartialEq, Debug, Default)]
-pub struct OnScore;
+pub struct OnScore {
+    /// The actor entity that owns the score tree, that is the closest ancestor entity without a [`Score`],
+    /// or `None` if the score tree has no actor entity.
+    pub actor: Option<Entity>,
+    /// The time elapsed since the score entity was last scored, according to [`Time`],
+    /// or zero the first time it's scored.
+    /// Only tracked for score entities with a [`LastScored`](crate::scoring::LastScored), and zero otherwise.
+    ///
+    /// Useful for time-aware scorers, such as ones that decay or smooth their score,
+    /// so they work correctly under variable tick rates without their own timers.
+    pub delta: Duration,
+}
+
+/// Listen to this [`Event`] to run custom logic once a score tree has finished a scoring pass,
+/// such as picking for just that actor entity right away, without guessing at command ordering.
+///
+/// This [`Event`] is triggered for the root score entity of each scored tree, after all of its [`OnScore`]s,
+/// including with the [`BatchedScoringPlugin`](crate::scoring::BatchedScoringPlugin).
+/// Actor entities with several score trees complete once per tree.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservedUtilityPlugins::TurnBased).observe(
+///     |trigger: Trigger<OnScoringComplete>, mut commands: Commands| {
+///         if let Some(actor) = trigger.event().actor {
+///             commands.trigger_targets(RunPicking, actor);
+///         }
+///     },
+/// );
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Attack;
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let attack = world.init_component::<Attack>();
+/// # let idle = world.init_component::<Idle>();
+/// let scorer = world.spawn(FixedScore::new(0.7)).id();
+/// let actor = world
+///     .spawn((Picker::new(idle).with(scorer, attack), Highest))
+///     .add_child(scorer)
+///     .id();
+/// # world.flush();
+///
+/// world.trigger_targets(RunScoring, actor);
+/// # world.flush();
+/// assert_eq!(Some(attack), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct OnScoringComplete {
+    /// The actor entity that owns the score tree, as in [`OnScore::actor`].
+    pub actor: Option<Entity>,
+}
 
 ////////////////////////////////////////////////////////////
 // Picking events
@@ -65,6 +256,78 @@ pub struct OnScore;
 #[reflect(Component, PartialEq, Debug, Default)]
 pub struct RunPicking;
 
+impl RunPicking {
+    /// Returns a [`Command`] that makes every actor entity with the marker [`Component`] `M` pick,
+    /// such as the actors shown in the UI, as if [`RunPicking`] targeted each of them.
+    /// Equivalent to [`RunPicking::for_filter::<With<M>>()`](RunPicking::for_filter).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// pub struct OnScreen;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+    /// # let mut world = app.world_mut();
+    /// # #[derive(Component)]
+    /// # pub struct Idle;
+    /// # #[derive(Component)]
+    /// # pub struct Wander;
+    /// # let idle = world.init_component::<Idle>();
+    /// # let action = world.init_component::<Wander>();
+    /// let spawn_actor = |world: &mut World| {
+    ///     let scorer = world.spawn(FixedScore::new(0.7)).id();
+    ///     world
+    ///         .spawn((Picker::new(idle).with(scorer, action), Highest))
+    ///         .add_child(scorer)
+    ///         .id()
+    /// };
+    /// let visible = spawn_actor(world);
+    /// let hidden = spawn_actor(world);
+    /// world.entity_mut(visible).insert(OnScreen);
+    /// world.trigger(RunScoring);
+    /// # world.flush();
+    ///
+    /// world.commands().add(RunPicking::for_marker::<OnScreen>());
+    /// # world.flush();
+    /// # assert_eq!(Some(action), world.get::<Picker>(visible).unwrap().picked);
+    /// # assert_eq!(Some(idle), world.get::<Picker>(hidden).unwrap().picked);
+    /// ```
+    #[must_use]
+    pub fn for_marker<M: Component>() -> RunPickingFor<With<M>> {
+        Self::for_filter()
+    }
+
+    /// Returns a [`Command`] that makes every actor entity matching the [`QueryFilter`] `F` pick,
+    /// as if [`RunPicking`] targeted each of them. See [`RunPickingFor`].
+    #[must_use]
+    pub fn for_filter<F: QueryFilter + 'static>() -> RunPickingFor<F> {
+        RunPickingFor(PhantomData)
+    }
+}
+
+/// [`Command`] that triggers [`RunPickingMany`] for every entity matching the [`QueryFilter`] `F`,
+/// created with [`RunPicking::for_marker`] or [`RunPicking::for_filter`].
+pub struct RunPickingFor<F>(PhantomData<fn() -> F>);
+
+impl<F: QueryFilter + 'static> Command for RunPickingFor<F> {
+    fn apply(self, world: &mut World) {
+        let targets: Vec<Entity> = world.query_filtered::<Entity, F>().iter(world).collect();
+        world.trigger(RunPickingMany(targets));
+    }
+}
+
+/// Trigger this [`Event`] to make the given actor entities pick an action in a single trigger,
+/// such as the active faction's actors in a turn-based game. Each entity picks as if [`RunPicking`] targeted it.
+#[derive(Event, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct RunPickingMany(pub Vec<Entity>);
+
 /// Listen to this [`Event`] to handle picking an action for the target actor entity.
 #[derive(Event, Reflect)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -76,11 +339,99 @@ pub struct OnPick;
 ///
 /// [`Picker`]: crate::picking::Picker
 #[derive(Event, Reflect)]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 #[reflect(Component, PartialEq, Debug)]
 pub struct OnPicked {
-    /// [`ComponentId`] of the action that was picked.
+    /// [`ComponentId`] of the action that was picked,
+    /// or `None` if nothing won and the [`Picker`](crate::picking::Picker) has no default action.
+    pub action: Option<ComponentId>,
+    /// The winning [`Score`] entity, or `None` if the default action (or nothing) was picked because nothing won.
+    pub score_entity: Option<Entity>,
+    /// The [`Score`] of the winning [`Score`] entity at pick time, if any.
+    pub score: Option<Score>,
+    /// `true` if the picked action differs from the previously picked action.
+    pub changed: bool,
+    /// The entity the picked action targets: the [`BestTarget`] of the winning [`Score`] entity, if any.
+    ///
+    /// [`BestTarget`]: crate::targeting::BestTarget
+    pub target: Option<Entity>,
+    /// `true` for the actions picked alongside the highest scoring one by a [`PickMultiple`] [`Picker`],
+    /// which are triggered after its [`OnPicked`]. Skip these to count one pick per picking pass.
+    ///
+    /// [`PickMultiple`]: crate::picking::PickMultiple
+    pub extra: bool,
+}
+
+/// Listen to this [`Event`] to inspect why an action was picked for the target actor entity, for example in devtools
+/// or balancing dashboards. This [`Event`] is triggered after each pick for actor entities with
+/// [`ReportPicks`](crate::picking::ReportPicks), by [`Picker`] kinds that implement
+/// [`PickStrategy`](crate::picking::PickStrategy).
+///
+/// [`Picker`]: crate::picking::Picker
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Attack;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let attack = world.init_component::<Attack>();
+/// let idle = world.init_component::<Idle>();
+/// let scorer = world.spawn(FixedScore::new(0.3)).id();
+/// let actor = world
+///     .spawn((Picker::new(idle).with(scorer, attack), FirstToScore::new(0.5), ReportPicks))
+///     .add_child(scorer)
+///     .id();
+/// # world.flush();
+///
+/// world.observe(|trigger: Trigger<PickReport>| {
+///     let report = trigger.event();
+///     for candidate in &report.candidates {
+///         info!("{:?} scored {:?} against {:?}", candidate.score_entity, candidate.adjusted, report.thresholds);
+///     }
+/// });
+/// # world.trigger_targets(RunScoring, actor);
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// ```
+#[derive(Event, Reflect)]
+#[derive(Clone, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct PickReport {
+    /// [`ComponentId`] of the action that was picked, or `None` if nothing was picked.
+    pub action: Option<ComponentId>,
+    /// The winning [`Score`] entity, or `None` if the default action (or nothing) was picked because nothing won.
+    pub score_entity: Option<Entity>,
+    /// Every choice of the actor's [`Picker`](crate::picking::Picker), in [`PickerChoices`] order.
+    ///
+    /// [`PickerChoices`]: crate::picking::PickerChoices
+    pub candidates: Vec<PickCandidate>,
+    /// The thresholds applied by the picker kind, in the order they were tried. Empty if it doesn't use any.
+    pub thresholds: Vec<Score>,
+}
+
+/// A choice considered by a [`Picker`](crate::picking::Picker), as reported by [`PickReport`].
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[reflect(PartialEq, Debug)]
+pub struct PickCandidate {
+    /// The choice's [`Score`] entity.
+    pub score_entity: Entity,
+    /// [`ComponentId`] of the choice's action.
     pub action: ComponentId,
+    /// The stored [`Score`] of the choice, if it has one.
+    pub score: Option<Score>,
+    /// The score the picker compared, with pick-time modifiers applied,
+    /// or `None` if the action was unavailable (masked or cooling down).
+    pub adjusted: Option<f32>,
 }
 
 ////////////////////////////////////////////////////////////
@@ -89,6 +440,8 @@ pub struct OnPicked {
 
 /// Trigger this [`Event`] to request a specific action or the picked action to be initiated for the target actor entity.
 ///
+/// Requesting the picked action when the actor's [`Picker`](crate::picking::Picker) picked nothing keeps its current action.
+///
 /// This event SHOULD NOT be triggered without a target entity.
 #[derive(Event, Reflect)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -96,6 +449,91 @@ pub struct OnPicked {
 pub struct RequestAction {
     /// The [`ComponentId`] of the action that was requested, if any.
     pub action: Option<ComponentId>,
+    /// Whether this request is a high-priority interrupt that ignores the actor's
+    /// [`MinimumRunTime`](crate::acting::MinimumRunTime).
+    pub interrupt: bool,
+    /// The priority of the requested action. Requests with a lower priority than the actor's running action's
+    /// [`ActionPriority`](crate::acting::ActionPriority) are ignored.
+    ///
+    /// Defaults to `0`, which is also the priority of the automatic re-requests made by the library.
+    pub priority: i32,
+}
+
+impl RequestAction {
+    /// Creates a new [`RequestAction`] for the actor's picked action.
+    #[must_use]
+    pub fn picked() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`RequestAction`] for the given action.
+    #[must_use]
+    pub fn action(action: ComponentId) -> Self {
+        Self {
+            action: Some(action),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`ActionOf`] [`RequestAction`] for the action [`Component`] `A`.
+    /// Trigger it with [`TriggerActionExt::trigger_action`].
+    #[must_use]
+    pub fn of<A: Component>() -> ActionOf<A, Self> {
+        ActionOf::new(Self::action)
+    }
+
+    /// Marks this request as a high-priority interrupt that ignores the actor's
+    /// [`MinimumRunTime`](crate::acting::MinimumRunTime).
+    #[must_use]
+    pub fn interrupting(mut self) -> Self {
+        self.interrupt = true;
+        self
+    }
+
+    /// Sets the priority of this request, so that lower priority requests can't cancel the requested action
+    /// while it's running, such as to temporarily override the utility brain with scripted behaviors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+    /// # let mut world = app.world_mut();
+    /// #[derive(Component)]
+    /// pub struct FollowPlayer;
+    /// #[derive(Component)]
+    /// pub struct Idle;
+    ///
+    /// let follow_player = world.init_component::<FollowPlayer>();
+    /// let idle = world.init_component::<Idle>();
+    ///
+    /// let actor = world.spawn((Picker::new(idle), Highest)).id();
+    /// # world.flush();
+    /// // A cutscene makes the actor follow the player, until the action ends.
+    /// world.trigger_targets(RequestAction::action(follow_player).with_priority(10), actor);
+    /// # world.flush();
+    /// // The utility brain can't cancel it in the meantime.
+    /// world.trigger_targets(RequestAction::picked(), actor);
+    /// # world.flush();
+    /// assert_eq!(follow_player, world.get::<CurrentAction>(actor).unwrap().0);
+    /// ```
+    #[must_use]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl<A: Component> ActionOf<A, RequestAction> {
+    /// Marks this request as a high-priority interrupt that ignores the actor's
+    /// [`MinimumRunTime`](crate::acting::MinimumRunTime).
+    #[must_use]
+    pub fn interrupting(self) -> Self {
+        Self::new(|action| RequestAction::action(action).interrupting())
+    }
 }
 
 /// This [`Event`] is triggered by action lifecycle to indicate that they have been initiated.
@@ -105,6 +543,45 @@ pub struct RequestAction {
 pub struct OnActionInitiated {
     /// [`ComponentId`] of the action that was initiated.
     pub action: ComponentId,
+    /// The entity the action targets, if any. See [`CurrentAction`](crate::acting::CurrentAction).
+    pub target: Option<Entity>,
+}
+
+/// Strongly-typed version of [`OnActionInitiated`] for the action [`Component`] `A`, triggered for the actor entity
+/// alongside [`OnActionInitiated`] once added with [`RegisterActionExt::add_action_events`].
+///
+/// [`RegisterActionExt::add_action_events`]: crate::acting::RegisterActionExt::add_action_events
+#[derive(Event)]
+pub struct OnActionInitiatedFor<A: Component> {
+    _marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Component> OnActionInitiatedFor<A> {
+    /// Creates a new [`OnActionInitiatedFor`] event.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<A: Component> Default for OnActionInitiatedFor<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Component> Clone for OnActionInitiatedFor<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Component> Copy for OnActionInitiatedFor<A> {}
+
+impl<A: Component> std::fmt::Debug for OnActionInitiatedFor<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OnActionInitiatedFor<{}>", std::any::type_name::<A>())
+    }
 }
 
 /// This [`Event`] is triggered by action lifecycle or actions themselves to indicate
@@ -112,7 +589,7 @@ pub struct OnActionInitiated {
 ///
 /// An action will be cancelled if a different action is [requested][`RequestAction`] before it completes.
 #[derive(Event, Reflect)]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 #[reflect(Component, PartialEq, Debug)]
 pub struct OnActionEnded {
     /// [`ComponentId`] of the action that was finished.
@@ -139,15 +616,390 @@ impl OnActionEnded {
             reason: ActionEndReason::Cancelled,
         }
     }
+
+    /// Creates a new [`Failed`][`ActionEndReason::Failed`] [`OnActionEnded`] event with the given action.
+    #[must_use]
+    pub fn failed(action: ComponentId) -> Self {
+        Self {
+            action,
+            reason: ActionEndReason::Failed(None),
+        }
+    }
+
+    /// Creates a new [`Failed`][`ActionEndReason::Failed`] [`OnActionEnded`] event with the given action,
+    /// carrying the given error value for diagnostics.
+    #[must_use]
+    pub fn failed_with(action: ComponentId, error: impl Reflect) -> Self {
+        Self {
+            action,
+            reason: ActionEndReason::Failed(Some(ActionError::new(error))),
+        }
+    }
+
+    /// Creates a new [`Completed`][`ActionEndReason::Completed`] [`ActionOf`] [`OnActionEnded`] event
+    /// for the action [`Component`] `A`. Trigger it with [`TriggerActionExt::trigger_action`].
+    #[must_use]
+    pub fn completed_for<A: Component>() -> ActionOf<A, Self> {
+        ActionOf::new(Self::completed)
+    }
+
+    /// Creates a new [`Failed`][`ActionEndReason::Failed`] [`ActionOf`] [`OnActionEnded`] event
+    /// for the action [`Component`] `A`. Trigger it with [`TriggerActionExt::trigger_action`].
+    #[must_use]
+    pub fn failed_for<A: Component>() -> ActionOf<A, Self> {
+        ActionOf::new(Self::failed)
+    }
+
+    /// Creates a new [`Cancelled`][`ActionEndReason::Cancelled`] [`ActionOf`] [`OnActionEnded`] event
+    /// for the action [`Component`] `A`. Trigger it with [`TriggerActionExt::trigger_action`].
+    #[must_use]
+    pub fn cancelled_for<A: Component>() -> ActionOf<A, Self> {
+        ActionOf::new(Self::cancelled)
+    }
+}
+
+/// Strongly-typed version of [`OnActionEnded`] for the action [`Component`] `A`, triggered for the actor entity
+/// alongside [`OnActionEnded`] once added with [`RegisterActionExt::add_action_events`].
+///
+/// This lets observers be written without comparing [`ComponentId`]s inside their body.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Drinking;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.add_action_events::<Drinking>();
+/// app.observe(|trigger: Trigger<OnActionEndedFor<Drinking>>| {
+///     if trigger.event().reason == ActionEndReason::Completed {
+///         info!("{} finished drinking", trigger.entity());
+///     }
+/// });
+/// # let world = app.world_mut();
+/// # let actor = world.spawn_empty().id();
+/// # world.trigger_action(OnActionEnded::completed_for::<Drinking>(), actor);
+/// ```
+///
+/// [`RegisterActionExt::add_action_events`]: crate::acting::RegisterActionExt::add_action_events
+#[derive(Event)]
+pub struct OnActionEndedFor<A: Component> {
+    /// The reason the action was finished.
+    pub reason: ActionEndReason,
+    _marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Component> OnActionEndedFor<A> {
+    /// Creates a new [`OnActionEndedFor`] event with the given reason.
+    #[must_use]
+    pub fn new(reason: ActionEndReason) -> Self {
+        Self {
+            reason,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Component> Clone for OnActionEndedFor<A> {
+    fn clone(&self) -> Self {
+        Self::new(self.reason.clone())
+    }
+}
+
+impl<A: Component> PartialEq for OnActionEndedFor<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.reason == other.reason
+    }
+}
+
+impl<A: Component> std::fmt::Debug for OnActionEndedFor<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!("OnActionEndedFor<{}>", std::any::type_name::<A>()))
+            .field("reason", &self.reason)
+            .finish()
+    }
 }
 
 /// The reason [`OnActionEnded`] was triggered.
+///
+/// This isn't [`Copy`], as [`Failed`](ActionEndReason::Failed) carries a shared [`ActionError`],
+/// so neither is [`OnActionEnded`]: clone them instead.
 #[derive(Reflect)]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 #[reflect(PartialEq, Debug)]
 pub enum ActionEndReason {
     /// The action was completed successfully.
     Completed,
+    /// The action failed, e.g. its target became unreachable, optionally with an error value for diagnostics.
+    ///
+    /// How the actor reacts is configured with a [`FailurePolicy`](crate::acting::FailurePolicy).
+    Failed(Option<ActionError>),
     /// The action was cancelled.
     Cancelled,
 }
+
+impl ActionEndReason {
+    /// Returns `true` if the action failed.
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+
+    /// Returns the error value the action failed with, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<&ActionError> {
+        match self {
+            Self::Failed(error) => error.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// A shared, reflected error value carried by a [`Failed`](ActionEndReason::Failed) [`OnActionEnded`] event.
+///
+/// Reflected as an opaque value, so it's cloned as a whole.
+/// Errors are equal if they share the same value, or if their values are reflectively equal.
+#[derive(Reflect)]
+#[derive(Clone)]
+#[reflect_value(PartialEq, Debug)]
+pub struct ActionError(Arc<dyn Reflect>);
+
+impl ActionError {
+    /// Creates a new [`ActionError`] from the given error value.
+    #[must_use]
+    pub fn new(error: impl Reflect) -> Self {
+        Self(Arc::new(error))
+    }
+
+    /// Returns the error value.
+    #[must_use]
+    pub fn get(&self) -> &dyn Reflect {
+        &*self.0
+    }
+
+    /// Returns the error value if it's of type `T`.
+    #[must_use]
+    pub fn downcast_ref<T: Reflect>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl PartialEq for ActionError {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.reflect_partial_eq(other.get()).unwrap_or(false)
+    }
+}
+
+impl Eq for ActionError {}
+
+impl std::fmt::Debug for ActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.debug(f)
+    }
+}
+
+/// Trigger this [`Event`] to pause the current action of the target actor entity, or all actor entities if no target is specified,
+/// setting its [`ActionState`] to [`Paused`](ActionState::Paused) if it's [`Running`](ActionState::Running).
+///
+/// Paused actions ignore non-interrupting [`RequestAction`]s until they're resumed with [`ResumeAction`].
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct PauseAction;
+
+/// Trigger this [`Event`] to resume the paused current action of the target actor entity,
+/// or all actor entities if no target is specified, setting its [`ActionState`] back to [`Running`](ActionState::Running).
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ResumeAction;
+
+/// This [`Event`] is triggered by action lifecycle whenever the [`ActionState`] of an actor entity's current action changes.
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct OnActionStateChanged {
+    /// [`ComponentId`] of the action whose state changed.
+    pub action: ComponentId,
+    /// The previous state, or `None` if the action was just requested.
+    pub previous: Option<ActionState>,
+    /// The new state.
+    pub state: ActionState,
+}
+
+/// Trigger this [`Event`] from long-running actions to report how far along they are,
+/// targeting the actor entity and the action [`ComponentId`] with [`TargetedAction`].
+///
+/// The [`ActionPlugin`] records the latest progress in the actor's [`ActionProgress`] [`Component`],
+/// which UI can read, and which can be scored with [`score_ancestor`], e.g. to avoid interrupting actions that are almost done.
+///
+/// [`ActionPlugin`]: crate::acting::ActionPlugin
+/// [`ActionProgress`]: crate::acting::ActionProgress
+/// [`score_ancestor`]: crate::scoring::score_ancestor
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Drinking {
+///     pub sips: u32,
+///     pub sips_total: u32,
+/// }
+///
+/// fn drink(mut commands: Commands, mut actors: Query<(Entity, &mut Drinking)>, actions: Res<ActionIds>) {
+///     for (actor, mut drinking) in &mut actors {
+///         drinking.sips += 1;
+///         let progress = drinking.sips as f32 / drinking.sips_total as f32;
+///         commands.trigger_targets(
+///             OnActionProgress::new(actions.drinking, progress),
+///             TargetedAction(actor, actions.drinking),
+///         );
+///     }
+/// }
+/// # #[derive(Resource)]
+/// # pub struct ActionIds {
+/// #     drinking: bevy::ecs::component::ComponentId,
+/// # }
+/// ```
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct OnActionProgress {
+    /// [`ComponentId`] of the action that progressed.
+    pub action: ComponentId,
+    /// How far along the action is, from 0 to 1.
+    pub progress: f32,
+}
+
+impl OnActionProgress {
+    /// Creates a new [`OnActionProgress`] event for the given action, clamping the progress to `[0, 1]`.
+    #[must_use]
+    pub fn new(action: ComponentId, progress: f32) -> Self {
+        Self {
+            action,
+            progress: progress.clamp(0., 1.),
+        }
+    }
+}
+
+/// This [`Event`] is triggered for actor entities when they enter the state `S` mirrored from their
+/// [`CurrentAction`](crate::acting::CurrentAction), once mapped with
+/// [`ActionStatesExt::map_action_state`](crate::acting::ActionStatesExt::map_action_state).
+/// The state is already inserted when it's triggered.
+#[derive(Event)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct OnEnterActionState<S>(pub S);
+
+/// This [`Event`] is triggered for actor entities when they exit the state `S` mirrored from their
+/// [`CurrentAction`](crate::acting::CurrentAction), before [`OnEnterActionState`] for the next state, if any.
+/// The state is still inserted when it's triggered.
+#[derive(Event)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct OnExitActionState<S>(pub S);
+
+/// An action [`Event`] `E` for the action [`Component`] `A`,
+/// whose [`ComponentId`] is resolved when it's triggered with [`TriggerActionExt::trigger_action`].
+///
+/// Created with typed constructors such as [`RequestAction::of`] and [`OnActionEnded::completed_for`],
+/// to avoid having to store action [`ComponentId`]s in a [`Resource`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Drinking;
+///
+/// fn finish_drinking(mut commands: Commands, actors: Query<Entity, With<Drinking>>) {
+///     for actor in &actors {
+///         commands.trigger_action(OnActionEnded::completed_for::<Drinking>(), actor);
+///     }
+/// }
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # app.add_systems(Update, finish_drinking);
+/// # app.update();
+/// ```
+pub struct ActionOf<A: Component, E: Event> {
+    /// Creates the event from the resolved action [`ComponentId`].
+    event: fn(ComponentId) -> E,
+    _marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Component, E: Event> ActionOf<A, E> {
+    /// Creates a new [`ActionOf`] that creates the event from the resolved action [`ComponentId`].
+    #[must_use]
+    pub fn new(event: fn(ComponentId) -> E) -> Self {
+        Self {
+            event,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Resolves the action [`ComponentId`], registering the action [`Component`] if needed,
+    /// and returns it alongside the event.
+    #[must_use]
+    pub fn resolve(&self, world: &mut World) -> (ComponentId, E) {
+        let action = world.init_component::<A>();
+        (action, (self.event)(action))
+    }
+}
+
+impl<A: Component, E: Event> Clone for ActionOf<A, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Component, E: Event> Copy for ActionOf<A, E> {}
+
+/// Extension trait for triggering [`ActionOf`] events.
+pub trait TriggerActionExt {
+    /// Triggers the [`ActionOf`] event for the given actor entity, targeting the resolved action [`ComponentId`].
+    fn trigger_action<A: Component, E: Event>(&mut self, event: ActionOf<A, E>, actor: Entity);
+}
+
+impl TriggerActionExt for World {
+    fn trigger_action<A: Component, E: Event>(&mut self, event: ActionOf<A, E>, actor: Entity) {
+        let (action, event) = event.resolve(self);
+        self.trigger_targets(event, TargetedAction(actor, action));
+    }
+}
+
+impl TriggerActionExt for Commands<'_, '_> {
+    fn trigger_action<A: Component, E: Event>(&mut self, event: ActionOf<A, E>, actor: Entity) {
+        self.add(move |world: &mut World| world.trigger_action(event, actor));
+    }
+}
+
+////////////////////////////////////////////////////////////
+// Turn events
+////////////////////////////////////////////////////////////
+
+/// Trigger this [`Event`] to start the given actor entity's turn with the [`TurnBasedPlugin`],
+/// out of [`TurnQueue`] order. The [`TurnBasedPlugin`] also triggers it whenever it starts the next turn.
+///
+/// [`TurnBasedPlugin`]: crate::turn::TurnBasedPlugin
+/// [`TurnQueue`]: crate::turn::TurnQueue
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct StartTurn(pub Entity);
+
+/// Trigger this [`Event`] to end the active turn with the [`TurnBasedPlugin`], such as when a player-controlled
+/// actor is done. The [`TurnBasedPlugin`] also triggers it when the active actor entity's action ends.
+///
+/// [`TurnBasedPlugin`]: crate::turn::TurnBasedPlugin
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct EndTurn;
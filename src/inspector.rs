@@ -0,0 +1,286 @@
+//! In-game inspector for balancing score trees at runtime.
+//!
+//! Requires the `inspector` feature.
+//!
+//! Add the [`ScoreTreeInspectorPlugin`] to open a [`bevy_egui`] window that:
+//! - Lists all actor entities with a [`Picker`].
+//! - Renders the selected actor's score tree with live [`Score`]s, highlighting the branch of the picked choice.
+//! - Lets you edit picker thresholds, choice [biases](crate::picking::ChoiceBias), and the values
+//!   and thresholds of [tunable](TunableComponents) scorers in place.
+//! - Copies the tuned values of the selected actor to the clipboard as RON keyed by [`ScoreLabel`](crate::scoring::ScoreLabel),
+//!   see [`export_tuning`]. Load them back with [`apply_tuning`] or [`TuningExt::with_tuning`].
+//!
+//! Entities are shown by their [`Name`] or [`ScoreLabel`], if any,
+//! so name your score entities to make them easier to find.
+
+use bevy::{ecs::component::ComponentId, prelude::*, window::PrimaryWindow};
+use bevy_egui::{egui, EguiContext, EguiPlugin};
+
+use crate::{
+    acting::{ActionRegistry, CurrentAction},
+    picking::{FirstToScore, HighestAboveThreshold, Picker},
+    scoring::{score_children, AllOrNothing, FixedScore, Product, Score, ScoreLabel, Sum, Weighted, Winning},
+};
+
+mod tuning;
+pub use self::tuning::*;
+
+/// [`Plugin`] for the score tree inspector window. See the [module docs](self) for more information.
+///
+/// Adds the [`EguiPlugin`] if it isn't added yet.
+pub struct ScoreTreeInspectorPlugin;
+
+impl Plugin for ScoreTreeInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+
+        app.init_resource::<ScoreTreeInspector>()
+            .init_resource::<TunableComponents>()
+            .add_systems(Update, Self::inspector_ui);
+
+        app.register_type::<ScoreTreeInspector>().register_type::<Name>();
+    }
+}
+
+impl ScoreTreeInspectorPlugin {
+    /// Exclusive [`System`] that draws the inspector window on the primary window,
+    /// while it's [open](ScoreTreeInspector::open).
+    pub fn inspector_ui(world: &mut World) {
+        let mut inspector = *world.resource::<ScoreTreeInspector>();
+        if !inspector.open {
+            return;
+        }
+        let Ok(mut context) = world
+            .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+            .get_single_mut(world)
+        else {
+            return;
+        };
+        let context = context.get_mut().clone();
+
+        let mut actors: Vec<Entity> = world.query_filtered::<Entity, With<Picker>>().iter(world).collect();
+        actors.sort();
+
+        egui::Window::new("Score Trees")
+            .open(&mut inspector.open)
+            .show(&context, |ui| {
+                ui.horizontal_top(|ui| {
+                    ui.vertical(|ui| {
+                        ui.set_min_width(160.);
+                        egui::ScrollArea::vertical().id_source("actors").show(ui, |ui| {
+                            for &actor in &actors {
+                                let selected = inspector.selected == Some(actor);
+                                if ui.selectable_label(selected, entity_name(world, actor)).clicked() {
+                                    inspector.selected = Some(actor);
+                                }
+                            }
+                        });
+                    });
+                    ui.separator();
+                    ui.vertical(|ui| match inspector.selected.filter(|actor| actors.contains(actor)) {
+                        Some(actor) => {
+                            egui::ScrollArea::vertical()
+                                .id_source("tree")
+                                .show(ui, |ui| actor_ui(ui, world, actor));
+                        }
+                        None => {
+                            ui.label("Select an actor to inspect its score tree.");
+                        }
+                    });
+                });
+            });
+
+        *world.resource_mut::<ScoreTreeInspector>() = inspector;
+    }
+}
+
+/// [`Resource`] for the state of the [`ScoreTreeInspectorPlugin`] window.
+#[derive(Resource, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Resource, PartialEq, Debug, Default)]
+pub struct ScoreTreeInspector {
+    /// Whether the window is shown. Closing the window sets this to `false`.
+    pub open: bool,
+    /// The actor entity whose score tree is shown, if any.
+    pub selected: Option<Entity>,
+}
+
+impl Default for ScoreTreeInspector {
+    fn default() -> Self {
+        Self {
+            open: true,
+            selected: None,
+        }
+    }
+}
+
+/// Draws the picker settings and score tree of the given actor entity.
+fn actor_ui(ui: &mut egui::Ui, world: &mut World, actor: Entity) {
+    ui.horizontal(|ui| {
+        ui.heading(entity_name(world, actor));
+        if ui.button("Copy tuning").clicked() {
+            match export_tuning(world, actor) {
+                Ok(ron) => ui.output_mut(|output| output.copied_text = ron),
+                Err(error) => warn!("Failed to export the tuning of actor {actor}: {error}"),
+            }
+        }
+    });
+    if let Some(current) = world.get::<CurrentAction>(actor) {
+        ui.label(format!("Current action: {}", action_name(world, current.0)));
+    }
+
+    let tunable = world.get_resource::<TunableComponents>().cloned().unwrap_or_default();
+    if tunable.contains::<FirstToScore>() {
+        if let Some(mut picker) = world.get_mut::<FirstToScore>(actor) {
+            if let Some(threshold) = score_slider(ui, "First to score threshold", picker.threshold()) {
+                picker.set_threshold(threshold);
+            }
+        }
+    }
+    if tunable.contains::<HighestAboveThreshold>() {
+        if let Some(mut picker) = world.get_mut::<HighestAboveThreshold>(actor) {
+            if let Some(threshold) = score_slider(ui, "Highest above threshold", picker.threshold()) {
+                picker.set_threshold(threshold);
+            }
+        }
+    }
+    ui.separator();
+
+    let Some(picker) = world.get::<Picker>(actor) else {
+        return;
+    };
+    let picked = picker.picked_score_entity;
+    let roots: Vec<Entity> = score_children(world, actor).collect();
+    for root in roots {
+        node_ui(ui, world, &tunable, actor, root, picked == Some(root));
+    }
+}
+
+/// Draws the given score entity and its children, recursively.
+/// Choices of the actor's [`Picker`] also show their action and [`ChoiceBias`](crate::picking::ChoiceBias).
+fn node_ui(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    tunable: &TunableComponents,
+    actor: Entity,
+    entity: Entity,
+    picked: bool,
+) {
+    let score = world.get::<Score>(entity).map_or(0., Score::get);
+    let choice = world
+        .get::<Picker>(actor)
+        .and_then(|picker| Some((*picker.choices.get(&entity)?, picker.choices.bias(&entity))));
+
+    let mut title = format!("{}: {score:.3}", entity_name(world, entity));
+    if let Some((action, _)) = choice {
+        title.push_str(&format!(" -> {}", action_name(world, action)));
+    }
+    let mut title = egui::RichText::new(title);
+    if picked {
+        title = title.strong().color(egui::Color32::LIGHT_GREEN);
+    }
+
+    let children: Vec<Entity> = score_children(world, entity).collect();
+    egui::CollapsingHeader::new(title)
+        .id_source(entity)
+        .default_open(true)
+        .show(ui, |ui| {
+            ui.add(egui::ProgressBar::new(score).desired_width(160.));
+
+            if let Some((_, bias)) = choice {
+                let mut next = bias;
+                ui.horizontal(|ui| {
+                    ui.label("Multiplier");
+                    ui.add(egui::DragValue::new(&mut next.multiplier).speed(0.01));
+                    ui.label("Bias");
+                    ui.add(egui::DragValue::new(&mut next.bias).speed(0.01));
+                });
+                if next != bias {
+                    if let Some(mut picker) = world.get_mut::<Picker>(actor) {
+                        picker.choices.set_bias(&entity, next);
+                    }
+                }
+            }
+            if tunable.contains::<FixedScore>() {
+                if let Some(mut fixed) = world.get_mut::<FixedScore>(entity) {
+                    if let Some(value) = score_slider(ui, "Value", fixed.value()) {
+                        fixed.set_value(value);
+                    }
+                }
+            }
+            if tunable.contains::<Weighted>() {
+                if let Some(mut weighted) = world.get_mut::<Weighted>(entity) {
+                    if let Some(weight) = score_slider(ui, "Weight", weighted.get()) {
+                        weighted.set(weight);
+                    }
+                }
+            }
+            if tunable.contains::<Sum>() {
+                if let Some(mut sum) = world.get_mut::<Sum>(entity) {
+                    if let Some(threshold) = score_slider(ui, "Threshold", sum.threshold()) {
+                        sum.set_threshold(threshold);
+                    }
+                }
+            }
+            if tunable.contains::<Product>() {
+                if let Some(mut product) = world.get_mut::<Product>(entity) {
+                    if let Some(threshold) = score_slider(ui, "Threshold", product.threshold()) {
+                        product.set_threshold(threshold);
+                    }
+                }
+            }
+            if tunable.contains::<Winning>() {
+                if let Some(mut winning) = world.get_mut::<Winning>(entity) {
+                    if let Some(threshold) = score_slider(ui, "Threshold", winning.threshold()) {
+                        winning.set_threshold(Score::new(threshold));
+                    }
+                }
+            }
+            if tunable.contains::<AllOrNothing>() {
+                if let Some(mut all_or_nothing) = world.get_mut::<AllOrNothing>(entity) {
+                    if let Some(threshold) = score_slider(ui, "Threshold", all_or_nothing.threshold()) {
+                        all_or_nothing.set_threshold(threshold);
+                    }
+                }
+            }
+
+            for child in children {
+                node_ui(ui, world, tunable, actor, child, picked);
+            }
+        });
+}
+
+/// Draws a slider for the given [`Score`], returning the new value if it was changed.
+fn score_slider(ui: &mut egui::Ui, text: &str, score: Score) -> Option<f32> {
+    let mut value = score.get();
+    ui.add(egui::Slider::new(&mut value, 0.0..=1.0).text(text))
+        .changed()
+        .then_some(value)
+}
+
+/// Returns the [`Name`] or [`ScoreLabel`] of the given entity, or its id.
+fn entity_name(world: &World, entity: Entity) -> String {
+    if let Some(name) = world.get::<Name>(entity) {
+        name.to_string()
+    } else if let Some(label) = world.get::<ScoreLabel>(entity) {
+        label.as_str().to_string()
+    } else {
+        format!("{entity}")
+    }
+}
+
+/// Returns the name of the given action in the [`ActionRegistry`], or its short type name.
+fn action_name(world: &World, action: ComponentId) -> String {
+    if let Some(name) = world
+        .get_resource::<ActionRegistry>()
+        .and_then(|registry| registry.name(action))
+    {
+        return name.to_string();
+    }
+    world.components().get_info(action).map_or_else(
+        || format!("{action:?}"),
+        |info| bevy::utils::get_short_name(info.name()),
+    )
+}
@@ -0,0 +1,432 @@
+use std::{any::TypeId, collections::BTreeMap, fmt};
+
+use bevy::{
+    ecs::{reflect::ReflectComponent, system::EntityCommands},
+    prelude::*,
+    reflect::{
+        serde::{TypedReflectDeserializer, TypedReflectSerializer},
+        GetTypeRegistration, TypeRegistry,
+    },
+    scene::ron,
+    utils::HashSet,
+};
+use serde::{
+    de::{DeserializeSeed, Error as _, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    picking::{FirstToScore, HighestAboveThreshold},
+    scoring::{score_children, AllOrNothing, FixedScore, Product, ScoreLabel, Sum, Weighted, Winning},
+};
+
+/// [`Resource`] for the [`Component`]s that the [`ScoreTreeInspectorPlugin`](super::ScoreTreeInspectorPlugin)
+/// lets you edit, and that [`export_tuning`] saves.
+///
+/// By default, this contains the thresholds and values of the built-in scorers and pickers:
+/// [`FixedScore`], [`Sum`], [`Product`], [`Winning`], [`AllOrNothing`], [`Weighted`], [`FirstToScore`],
+/// and [`HighestAboveThreshold`]. Mark your own reflected components as tunable with
+/// [`RegisterTunableExt::register_tunable`] to include them in exports.
+#[derive(Resource)]
+#[derive(Clone, Debug)]
+pub struct TunableComponents {
+    /// The [`TypeId`]s of the tunable components.
+    types: HashSet<TypeId>,
+}
+
+impl Default for TunableComponents {
+    fn default() -> Self {
+        let mut tunable = Self { types: HashSet::new() };
+        tunable.insert::<FixedScore>();
+        tunable.insert::<Sum>();
+        tunable.insert::<Product>();
+        tunable.insert::<Winning>();
+        tunable.insert::<AllOrNothing>();
+        tunable.insert::<Weighted>();
+        tunable.insert::<FirstToScore>();
+        tunable.insert::<HighestAboveThreshold>();
+        tunable
+    }
+}
+
+impl TunableComponents {
+    /// Marks the [`Component`] `C` as tunable.
+    pub fn insert<C: Component>(&mut self) {
+        self.types.insert(TypeId::of::<C>());
+    }
+
+    /// Unmarks the [`Component`] `C` as tunable, so it's neither editable nor exported.
+    pub fn remove<C: Component>(&mut self) {
+        self.types.remove(&TypeId::of::<C>());
+    }
+
+    /// Returns `true` if the [`Component`] `C` is tunable.
+    #[must_use]
+    pub fn contains<C: Component>(&self) -> bool {
+        self.contains_id(TypeId::of::<C>())
+    }
+
+    /// Returns `true` if the component with the given [`TypeId`] is tunable.
+    #[must_use]
+    pub fn contains_id(&self, type_id: TypeId) -> bool {
+        self.types.contains(&type_id)
+    }
+
+    /// Returns the [`TypeId`]s of all tunable components.
+    pub fn iter(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.types.iter().copied()
+    }
+}
+
+/// [`App`] extension trait for marking components as tunable.
+pub trait RegisterTunableExt {
+    /// Registers the reflected [`Component`] `C` in the type registry and marks it as tunable
+    /// in the [`TunableComponents`], initializing it if needed.
+    ///
+    /// `C` needs to reflect [`Component`] (`#[reflect(Component)]`) to be exported.
+    fn register_tunable<C: Component + GetTypeRegistration>(&mut self) -> &mut Self;
+}
+
+impl RegisterTunableExt for App {
+    fn register_tunable<C: Component + GetTypeRegistration>(&mut self) -> &mut Self {
+        self.register_type::<C>();
+        self.world_mut()
+            .get_resource_or_insert_with(TunableComponents::default)
+            .insert::<C>();
+        self
+    }
+}
+
+/// Exports the [tunable](TunableComponents) components of the given actor entity and its score tree to RON,
+/// to save values tuned in game back to data. Load them back with [`apply_tuning`] or [`TuningExt::with_tuning`].
+///
+/// Each entity is keyed by its [`ScoreLabel`], or else its [`Name`], so the values can be applied to
+/// a freshly built copy of the same score tree. Entities without either, or without tunable components, are left out.
+/// Only tunable components are exported, by their type path:
+///
+/// ```ron
+/// {
+///     "aggression": {
+///         "bevy_observed_utility::scoring::fixed::FixedScore": (
+///             value: (value: 0.7),
+///         ),
+///     },
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a tunable component can't be serialized.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::{inspector::export_tuning, prelude::*};
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// let scorer = world.spawn((ScoreLabel::new("aggression"), FixedScore::new(0.7))).id();
+/// let actor = world.spawn((Picker::new(idle), Highest)).add_child(scorer).id();
+///
+/// let ron = export_tuning(world, actor).unwrap();
+/// assert!(ron.contains("aggression"));
+/// // std::fs::write("assets/goblin.tuning.ron", ron).unwrap();
+/// ```
+pub fn export_tuning(world: &World, actor: Entity) -> Result<String, ron::Error> {
+    let tunable = world.get_resource::<TunableComponents>().cloned().unwrap_or_default();
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    let mut entries: BTreeMap<String, Vec<&dyn Reflect>> = BTreeMap::new();
+    for (key, entity) in keyed_entities(world, actor) {
+        let components: Vec<&dyn Reflect> = tunable
+            .iter()
+            .filter_map(|type_id| {
+                registry
+                    .get_type_data::<ReflectComponent>(type_id)?
+                    .reflect(world.entity(entity))
+            })
+            .collect();
+        if !components.is_empty() {
+            entries.entry(key).or_default().extend(components);
+        }
+    }
+
+    let config = ron::ser::PrettyConfig::default().indentor("    ".to_string());
+    ron::ser::to_string_pretty(
+        &TuningSerializer {
+            entries: &entries,
+            registry: &registry,
+        },
+        config,
+    )
+}
+
+/// Applies tuned values exported with [`export_tuning`] to the given actor entity and its score tree,
+/// matching entities by their [`ScoreLabel`], or else their [`Name`].
+///
+/// Components that aren't [tunable](TunableComponents) are skipped, and ones the entity doesn't have yet are inserted.
+///
+/// # Errors
+///
+/// Returns an error if the RON is malformed, or names a type that isn't registered.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::{inspector::apply_tuning, prelude::*};
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// let scorer = world.spawn((ScoreLabel::new("aggression"), FixedScore::new(0.7))).id();
+/// let actor = world.spawn((Picker::new(idle), Highest)).add_child(scorer).id();
+///
+/// let ron = r#"{ "aggression": { "bevy_observed_utility::scoring::fixed::FixedScore": (value: (value: 0.4)) } }"#;
+/// apply_tuning(world, actor, ron).unwrap();
+/// assert_eq!(&FixedScore::new(0.4), world.get::<FixedScore>(scorer).unwrap());
+/// ```
+pub fn apply_tuning(world: &mut World, actor: Entity, ron: &str) -> Result<(), ron::Error> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let mut deserializer = ron::Deserializer::from_str(ron).map_err(|error| error.code)?;
+    let mut entries = TuningDeserializer { registry: &registry }.deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    let tunable = world.get_resource::<TunableComponents>().cloned().unwrap_or_default();
+    let keyed: Vec<(String, Entity)> = keyed_entities(world, actor).collect();
+    for (key, entity) in keyed {
+        let Some(components) = entries.remove(&key) else {
+            continue;
+        };
+        for (type_id, component) in components {
+            if !tunable.contains_id(type_id) {
+                continue;
+            }
+            let Some(reflect_component) = registry.get_type_data::<ReflectComponent>(type_id) else {
+                continue;
+            };
+            reflect_component.apply_or_insert(&mut world.entity_mut(entity), &*component, &registry);
+        }
+    }
+    Ok(())
+}
+
+/// [`EntityCommands`] extension trait for applying tuned values to actor entities as they're built.
+pub trait TuningExt {
+    /// Applies the tuned values exported with [`export_tuning`] to this actor entity and its score tree,
+    /// once the commands queued before this one (such as [`ScoreTreeExt::with_score_tree`]) have been applied.
+    /// Logs a warning if the tuning can't be applied. See [`apply_tuning`].
+    ///
+    /// [`ScoreTreeExt::with_score_tree`]: crate::builder::ScoreTreeExt::with_score_tree
+    fn with_tuning(&mut self, ron: impl Into<String>) -> &mut Self;
+}
+
+impl TuningExt for EntityCommands<'_> {
+    fn with_tuning(&mut self, ron: impl Into<String>) -> &mut Self {
+        let ron = ron.into();
+        self.add(move |actor: Entity, world: &mut World| {
+            if let Err(error) = apply_tuning(world, actor, &ron) {
+                warn!("Failed to apply the tuning of actor {actor}: {error}");
+            }
+        })
+    }
+}
+
+/// Returns the entities of the given actor entity's score tree with a [`ScoreLabel`] or [`Name`], keyed by it.
+fn keyed_entities(world: &World, actor: Entity) -> impl Iterator<Item = (String, Entity)> + '_ {
+    let mut visited = HashSet::new();
+    let mut stack = vec![actor];
+    std::iter::from_fn(move || loop {
+        let entity = stack.pop()?;
+        if !visited.insert(entity) {
+            // Don't loop forever on hierarchy cycles.
+            continue;
+        }
+        stack.extend(score_children(world, entity));
+        let key = world
+            .get::<ScoreLabel>(entity)
+            .map(|label| label.as_str().to_string())
+            .or_else(|| world.get::<Name>(entity).map(|name| name.as_str().to_string()));
+        if let Some(key) = key {
+            return Some((key, entity));
+        }
+    })
+}
+
+/// Serializes tuned values as a map of entity keys to maps of component type paths to values.
+struct TuningSerializer<'a> {
+    entries: &'a BTreeMap<String, Vec<&'a dyn Reflect>>,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for TuningSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+        for (key, components) in self.entries {
+            map.serialize_entry(
+                key,
+                &ComponentsSerializer {
+                    components,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes the tunable components of one entity as a map of type paths to values.
+struct ComponentsSerializer<'a> {
+    components: &'a [&'a dyn Reflect],
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for ComponentsSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.components.len()))?;
+        for &component in self.components {
+            map.serialize_entry(
+                component.reflect_type_path(),
+                &TypedReflectSerializer::new(component, self.registry),
+            )?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes tuned values serialized by [`TuningSerializer`].
+struct TuningDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for TuningDeserializer<'_> {
+    type Value = BTreeMap<String, Vec<(TypeId, Box<dyn Reflect>)>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for TuningDeserializer<'_> {
+    type Value = BTreeMap<String, Vec<(TypeId, Box<dyn Reflect>)>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of entity keys to tuned components")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = BTreeMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let components = map.next_value_seed(ComponentsDeserializer {
+                registry: self.registry,
+            })?;
+            entries.insert(key, components);
+        }
+        Ok(entries)
+    }
+}
+
+/// Deserializes the tunable components of one entity serialized by [`ComponentsSerializer`].
+struct ComponentsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ComponentsDeserializer<'_> {
+    type Value = Vec<(TypeId, Box<dyn Reflect>)>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ComponentsDeserializer<'_> {
+    type Value = Vec<(TypeId, Box<dyn Reflect>)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of component type paths to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut components = Vec::new();
+        while let Some(type_path) = map.next_key::<String>()? {
+            let registration = self
+                .registry
+                .get_with_type_path(&type_path)
+                .ok_or_else(|| A::Error::custom(format!("no registration found for `{type_path}`")))?;
+            let component = map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            components.push((registration.type_id(), component));
+        }
+        Ok(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::{
+        inspector::{apply_tuning, export_tuning, TuningExt},
+        picking::{Highest, Picker},
+        scoring::{FixedScore, ScoreLabel, Sum},
+    };
+
+    #[derive(Component)]
+    struct Idle;
+
+    fn spawn_actor(world: &mut World) -> (Entity, Entity) {
+        let idle = world.init_component::<Idle>();
+        let scorer = world.spawn((ScoreLabel::new("aggression"), FixedScore::new(0.7))).id();
+        let sum = world.spawn((Name::new("total"), Sum::new(0.5))).add_child(scorer).id();
+        let actor = world.spawn((Picker::new(idle), Highest)).add_child(sum).id();
+        world.flush();
+        (actor, scorer)
+    }
+
+    #[test]
+    fn tuned_values_round_trip() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let (actor, scorer) = spawn_actor(world);
+        world.get_mut::<FixedScore>(scorer).unwrap().set_value(0.25);
+        let ron = export_tuning(world, actor).unwrap();
+
+        // Only tunable components are exported, keyed by label or name.
+        assert!(ron.contains("\"aggression\""));
+        assert!(ron.contains("\"total\""));
+        assert!(ron.contains("FixedScore"));
+        assert!(!ron.contains("scoring::Score\""), "non-tunable Score exported: {ron}");
+        assert!(!ron.contains("Picker"), "non-tunable Picker exported: {ron}");
+
+        let (fresh, fresh_scorer) = spawn_actor(world);
+        assert_eq!(&FixedScore::new(0.7), world.get::<FixedScore>(fresh_scorer).unwrap());
+        apply_tuning(world, fresh, &ron).unwrap();
+        assert_eq!(&FixedScore::new(0.25), world.get::<FixedScore>(fresh_scorer).unwrap());
+
+        let (built, built_scorer) = spawn_actor(world);
+        world.commands().entity(built).with_tuning(ron);
+        world.flush();
+        assert_eq!(&FixedScore::new(0.25), world.get::<FixedScore>(built_scorer).unwrap());
+    }
+
+    #[test]
+    fn unknown_types_are_rejected() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let (actor, _) = spawn_actor(world);
+        assert!(apply_tuning(world, actor, r#"{ "aggression": { "not::a::Type": () } }"#).is_err());
+    }
+}
@@ -103,11 +103,11 @@
 //! #[derive(Component)]
 //! pub struct Idle;
 //!
-//! /// We'll also need ComponentIds for these actions to later identify and perform lifecycle events on them.
+//! /// Most of the library's action APIs are typed, like `OnActionEnded::completed_for::<Drinking>()`,
+//! /// but the Picker's default action needs a ComponentId to later identify and perform lifecycle events on it.
 //! #[derive(Resource)]
 //! pub struct ActionIds {
 //!     pub idle: ComponentId,
-//!     pub drinking: ComponentId,
 //! }
 //!
 //! /// We'll need to initialize the action ids somewhere later on.
@@ -116,7 +116,6 @@
 //!     fn from_world(world: &mut World) -> Self {
 //!         Self {
 //!             idle: world.init_component::<Idle>(),
-//!             drinking: world.init_component::<Drinking>(),
 //!         }
 //!     }
 //! }
@@ -135,18 +134,14 @@
 //!     mut commands: Commands,
 //!     time: Res<Time<Fixed>>,
 //!     mut actors: Query<(Entity, &mut Thirst, &Drinking)>,
-//!     actions: Res<ActionIds>
 //! ) {
 //!     for (actor, mut thirst, drinking) in actors.iter_mut() {
 //!         // Quench the thirst a bit.
 //!         thirst.value = (thirst.value - drinking.per_second * time.delta_seconds()).max(0.);
 //!         // If the thirst is low enough, finish drinking.
 //!         if thirst.value <= drinking.until {
-//!             /// We'll need that ActionIds resource we created earlier to identify the action.
-//!             commands.trigger_targets(
-//!                 OnActionEnded::completed(actions.drinking),
-//!                 TargetedAction(actor, actions.drinking),
-//!             );
+//!             // The action's ComponentId is resolved for us when the event is triggered.
+//!             commands.trigger_action(OnActionEnded::completed_for::<Drinking>(), actor);
 //!         }
 //!     }
 //! }
@@ -178,7 +173,7 @@
 //!             // All pickers need a default action to perform when they're not doing anything else.
 //!             Picker::new(actions.idle)
 //!                 // When the actor gets thirsty enough, they'll drink.
-//!                 .with(thirst, actions.drinking),
+//!                 .with_action::<Drinking>(thirst),
 //!             // To configure the picker's selection behavior, we insert a component that handles that.
 //!             // In this case, we'll insert the FirstToScore component,
 //!             // which picks the first action that scores above a certain threshold.
@@ -204,51 +199,170 @@
 //! While this library does its best to be performant, there are a few ways to improve performance in your game:
 //! - Run scoring and picking systems at a slower fixed rate than default.
 //!     - This will induce latency in the AI, but will reduce overall frame time.
+//! - Give less important actors an [`AiTickRate`], so they're scored and picked for less often than the rest.
+//! - Insert the [`AiCohorts`] resource to spread scoring and picking for all actors over several ticks.
+//! - Use [`ReactiveUtilityExt`] to only score and pick for actors when their view of the world changes.
+//! - Add the [`BatchedScoringPlugin`](crate::scoring::BatchedScoringPlugin) to score without one trigger per score entity.
+//! - Add the [`DirectPickingPlugin`](crate::picking::DirectPickingPlugin) to pick without an [`OnPick`](crate::event::OnPick) trigger per actor for built-in pickers.
 //! - Replace deeply nested scoring hierarchies with shallow hand-written scoring observers.
+//! - Insert the [`CleanupUnusedObservers`](crate::ecs::CleanupUnusedObservers) resource to despawn observers
+//!   of built-in components once no entity uses them anymore.
+//!
+//! To spot performance regressions, add the [`AiDiagnosticsPlugin`](crate::diagnostics::AiDiagnosticsPlugin)
+//! to see the number of scorers evaluated and picks per frame, action switches per second,
+//! and time spent scoring and picking in the standard diagnostics overlay.
+//!
+//! To measure how many actors your hardware and settings can handle, or to reproduce a performance issue,
+//! spawn a configurable population of actors and score trees with a [`StressScenario`](crate::stress::StressScenario).
+//!
+//! To find out where the time goes, enable the `trace` feature: the scoring traversal and the built-in scoring,
+//! picking, and acting observers are then instrumented with named `tracing` spans, which show up in Tracy captures.
+//!
+//! # Testing
+//!
+//! Enable the `proptest` feature to fuzz score trees: the `testing` module generates random valid score trees
+//! and input values, and checks that scores stay within `[0, 1]`, are calculated in post-order, and are idempotent.
 //!
 //! [`Score`]: crate::scoring::Score
 
 #![warn(missing_docs)]
 
+use std::time::Duration;
+
 use bevy::{
     app::PluginGroupBuilder,
-    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
     prelude::*,
 };
 
 use crate::{
-    acting::{ActionPlugin, CurrentAction},
-    event::{RequestAction, RunPicking, RunScoring},
-    picking::{Picker, PickingPlugin},
+    acting::{ActionPlugin, ActionState, CurrentAction, CurrentActions},
+    ecs::TriggerGetEntity,
+    event::{PauseAction, RequestAction, ResumeAction, RunPicking, RunScoring},
+    picking::{PickedActions, Picker, PickerGroup, PickingPlugin},
     scoring::ScoringPlugin,
 };
 
+/// Enters a `tracing` span with the given name and fields until the end of the current scope,
+/// if the `trace` feature is enabled. Otherwise, expands to nothing.
+macro_rules! utility_span {
+    ($($args:tt)*) => {
+        #[cfg(feature = "trace")]
+        let _span = bevy::utils::tracing::info_span!($($args)*).entered();
+    };
+}
+
 pub mod acting;
+pub mod builder;
+pub mod compat;
+#[cfg(feature = "debug")]
+pub mod debug;
+pub mod diagnostics;
 pub mod ecs;
 pub mod event;
+#[cfg(feature = "inspector")]
+pub mod inspector;
+#[cfg(feature = "spatial")]
+pub mod lod;
+pub mod memory;
 pub mod picking;
+#[cfg(feature = "planning")]
+pub mod planning;
+#[cfg(feature = "recording")]
+pub mod recording;
+#[cfg(feature = "replication")]
+pub mod replication;
+pub mod rng;
 pub mod scoring;
+pub mod stress;
+pub mod targeting;
+#[cfg(feature = "proptest")]
+pub mod testing;
+pub mod turn;
 
 pub mod prelude {
     //! Re-exports important traits and types.
     pub use crate::{
         acting::{
-            on_action_ended_remove, on_action_initiated_insert_default, on_action_initiated_insert_from_resource,
-            CurrentAction,
+            on_action_ended_despawn_child, on_action_ended_remove, on_action_ended_trigger_for,
+            on_action_initiated_insert_default, on_action_initiated_insert_from_resource,
+            on_action_initiated_insert_with, on_action_initiated_spawn_child, on_action_initiated_trigger_for,
+            ActionChannel, ActionChannels, ActionCooldowns, ActionEntities, ActionPriority, ActionProgress,
+            ActionQueue, ActionRegistry, ActionRetries, ActionState, ActionStateMap, ActionStatesExt, CurrentAction,
+            CurrentActionMeta, CurrentActions, CurrentTarget, FailurePolicy, Interruptible, MinimumRunTime,
+            PerformedBy, RegisterActionExt, StartsCooldown,
         },
-        ecs::{AncestorQuery, TargetedAction},
-        event::{ActionEndReason, OnActionEnded, OnActionInitiated, OnPick, OnPicked, OnScore, RunPicking, RunScoring},
-        picking::{FirstToScore, Highest, Picker},
+        builder::{ScoreTreeExt, SpawnActorExt},
+        compat::{
+            behavior_tree::{Blackboard, BlackboardScore, SelectorStatus, UtilitySelected, UtilitySelector},
+            big_brain::{Thinker, ThinkerBuilder},
+        },
+        diagnostics::AiDiagnosticsPlugin,
+        ecs::{TargetedAction, TargetedActionMany},
+        event::{
+            ActionEndReason, ActionError, ActionOf, EndTurn, OnActionEnded, OnActionEndedFor, OnActionInitiated,
+            OnActionInitiatedFor, OnActionProgress, OnActionStateChanged, OnEnterActionState, OnExitActionState,
+            OnPick, OnPicked, OnScore, OnScoringComplete, PauseAction, PickCandidate, PickReport, RequestAction,
+            ResumeAction, RunPicking, RunPickingFor, RunPickingMany, RunScoring, RunScoringFor, RunScoringMany,
+            StartTurn, TriggerActionExt,
+        },
+        memory::{MemoryEntry, MemoryMeasure, MemoryOf, MemoryPlugin, MemoryScore},
+        picking::{
+            mask_action_on, pick_with_strategy, unmask_action_on, ActionCosts, ActionFor, ActionMask, ActorBundle,
+            BrainExt, ChoiceBias, ChoiceScores, CostMode, DespawnScoreTreeWithActor, DirectPickingExt,
+            DirectPickingPlugin, FirstToScore, Highest, HighestAboveThreshold, PickDualUtility, PickJitter,
+            PickMultiple, PickStrategy, PickedActions, Picker, PickerChain, PickerChoices, PickerGroup,
+            RepickOnChoiceRemoved, ReportPicks, Sticky,
+        },
+        rng::{RngComponent, UtilityRng, UtilityRngs},
+        score_tree,
         scoring::{
-            score_ancestor, AllOrNothing, Evaluated, Evaluator, FixedScore, LinearEvaluator, Measure, Measured,
-            PowerEvaluator, Product, Score, SigmoidEvaluator, Sum, Weighted, WeightedMax, WeightedProduct, WeightedRMS,
-            WeightedSum, Winning,
+            sample_evaluator, score_ancestor, score_ancestor_with, score_component, score_resource, score_target,
+            AddScorerExt, AllOrNothing, AncestorQuery, BatchedScorerExt, BatchedScoringPlugin, Consideration,
+            CooldownScore, Evaluated, Evaluator, FixedScore, IdleTimeScore, LinearEvaluator, Measure, Measured,
+            Normalization, Normalized, PowerEvaluator, Product, Score, ScoreChildren, ScoreHierarchy, ScoreLabel,
+            ScoreOf, ScoreOverrides, ScorePriority, ScoreSimulator, ScoreSnapshot, ScoreTree, Scorer, ScorerBundle,
+            SigmoidEvaluator, Sum, TargetRef, Weighted, WeightedMax, WeightedProduct, WeightedRMS, WeightedSum,
+            Winning,
         },
-        ObservedUtilityPlugins,
+        targeting::{BestTarget, TargetCandidates, TargetConsideration, TargetContext, TargetSelector},
+        turn::{Initiative, TurnBasedPlugin, TurnQueue},
+        AiCohorts, AiDisabled, AiPaused, AiTickRate, CommandsUtilityExt, ObservedUtilityPlugins, ReactiveUtilityExt,
+        WorldUtilityExt,
     };
 
+    #[cfg(feature = "inspector")]
+    pub use crate::inspector::{RegisterTunableExt, ScoreTreeInspector, ScoreTreeInspectorPlugin, TunableComponents};
+
+    #[cfg(feature = "planning")]
+    pub use crate::planning::{Fact, Facts, OnPlanned, Plan, PlanAction, Planner, PlanningPlugin, RunPlanning};
+
+    #[cfg(feature = "recording")]
+    pub use crate::recording::{
+        DecisionEvent, DecisionLog, DecisionRecord, DecisionRecorder, DecisionRecorderPlugin, ReplayDecisionsExt,
+        ReplayMismatch,
+    };
+
+    #[cfg(feature = "replication")]
+    pub use crate::replication::{AiReplicationPlugin, ReplicatedCurrentAction, ReplicatedPicker, ReplicationRole};
+
     #[cfg(feature = "rand")]
-    pub use crate::{picking::PickRandom, scoring::RandomScore};
+    pub use crate::{
+        picking::PickRandom,
+        scoring::{NoiseScore, RandomScore},
+    };
+
+    #[cfg(feature = "plot")]
+    pub use crate::scoring::{evaluator_mesh, plot_evaluator};
+
+    #[cfg(feature = "spatial")]
+    pub use crate::{
+        lod::{AiLodFocus, AiLodPlugin, AiLodSettings},
+        scoring::DistanceScore,
+    };
 }
 
 /// [`PluginGroup`] for all standard plugins in `bevy_observed_utility`.
@@ -266,6 +380,9 @@ pub enum ObservedUtilityPlugins {
     /// To do so, trigger the [`RunScoring`] and [`RunPicking`] events un-targeted,
     /// which will score and pick actions for all entities with the appropriate components.
     /// Then trigger the [`RequestAction`] event targeted at an actor entity when you want them to perform an action.
+    ///
+    /// Alternatively, use [`WorldUtilityExt::score_and_pick`] to score and pick for a single actor in one call,
+    /// or add the [`TurnBasedPlugin`](crate::turn::TurnBasedPlugin) to take turns for a queue of actors.
     TurnBased,
 }
 
@@ -297,6 +414,8 @@ impl Plugin for RealtimeLifecyclePlugin {
             self.score_pick_perform_in,
             (Self::score_and_pick, Self::request_action_if_none_or_default),
         );
+
+        app.register_type::<AiTickRate>().register_type::<AiCohorts>();
     }
 }
 
@@ -310,21 +429,474 @@ impl Default for RealtimeLifecyclePlugin {
 
 impl RealtimeLifecyclePlugin {
     /// [`System`] that automatically runs scoring and picking [`Observer`]s.
-    pub fn score_and_pick(mut commands: Commands) {
-        commands.trigger(RunScoring);
-        commands.trigger(RunPicking);
+    ///
+    /// Actors with an [`AiTickRate`] are skipped by the global [`RunScoring`] and [`RunPicking`],
+    /// and are only scored and picked for when their timer finishes.
+    /// If the [`AiCohorts`] resource exists, the other actors are only scored and picked for when their cohort's turn comes.
+    /// While the resource exists, scoring and picking targets each actor entity instead of running globally,
+    /// so score entities that don't belong to an actor aren't scored automatically.
+    #[allow(clippy::type_complexity)]
+    pub fn score_and_pick(
+        mut commands: Commands,
+        time: Option<Res<Time>>,
+        cohorts: Option<ResMut<AiCohorts>>,
+        paused: Option<Res<AiPaused>>,
+        mut ticking: Query<(Entity, &mut AiTickRate), Without<AiDisabled>>,
+        actors: Query<
+            Entity,
+            (
+                With<Picker>,
+                Without<AiTickRate>,
+                Without<PickerGroup>,
+                Without<AiDisabled>,
+            ),
+        >,
+    ) {
+        if paused.is_some() {
+            return;
+        }
+
+        let cohorts = cohorts.map(|mut cohorts| {
+            let current = cohorts.advance();
+            (current, *cohorts)
+        });
+        if let Some((current, cohorts)) = cohorts {
+            for actor in actors.iter().filter(|&actor| cohorts.cohort_of(actor) == current) {
+                commands.trigger_targets(RunScoring, actor);
+                commands.trigger_targets(RunPicking, actor);
+            }
+        } else {
+            // Actors with an AiTickRate are skipped globally
+            commands.trigger(RunScoring);
+            commands.trigger(RunPicking);
+        }
+
+        let delta = time.map_or(Duration::ZERO, |time| time.delta());
+        let ready = ticking
+            .iter_mut()
+            .filter_map(|(actor, mut rate)| rate.tick(delta).then_some(actor));
+        for actor in ready {
+            commands.trigger_targets(RunScoring, actor);
+            commands.trigger_targets(RunPicking, actor);
+        }
     }
 
     /// [`System`] that requests a new action for an actor if they're currently "idling",
     /// i.e. performing their default action.
+    ///
+    /// Actors with [`PickedActions`] instead request whenever their [`CurrentActions`] don't match their picks.
+    /// Actors whose action is [`Paused`](ActionState::Paused) are skipped.
+    /// [`PickerGroup`]s aren't actors, so they're skipped too, as are [`AiDisabled`] actors and everything while [`AiPaused`].
+    #[allow(clippy::type_complexity)]
     pub fn request_action_if_none_or_default(
         mut commands: Commands,
-        actors: Query<(Entity, &Picker, Option<&CurrentAction>)>,
+        paused: Option<Res<AiPaused>>,
+        actors: Query<
+            (
+                Entity,
+                &Picker,
+                Option<&CurrentAction>,
+                Option<&PickedActions>,
+                Option<&CurrentActions>,
+                Option<&ActionState>,
+            ),
+            (Without<PickerGroup>, Without<AiDisabled>),
+        >,
     ) {
-        for (actor, picker, current_action) in actors.iter() {
-            if current_action.is_some_and(|ca| picker.is_default(ca.0)) || current_action.is_none() {
-                commands.trigger_targets(RequestAction { action: None }, actor);
+        if paused.is_some() {
+            return;
+        }
+
+        for (actor, picker, current_action, picked_actions, current_actions, state) in actors.iter() {
+            if state == Some(&ActionState::Paused) {
+                continue;
+            }
+            if let Some(picked_actions) = picked_actions {
+                // Actors performing multiple actions are synced whenever their picks change
+                if !current_actions.is_some_and(|ca| ca.matches(&picked_actions.0)) {
+                    commands.trigger_targets(RequestAction::picked(), actor);
+                }
+            } else if current_action.is_some_and(|ca| picker.is_default(ca.0)) || current_action.is_none() {
+                commands.trigger_targets(RequestAction::picked(), actor);
+            }
+        }
+    }
+}
+
+/// [`Component`] for actor entities that makes the [`RealtimeLifecyclePlugin`] score and pick for them
+/// at their own rate, rather than every time its systems run.
+///
+/// Useful for thinking less often for background NPCs than for bosses.
+/// Actors think on the first tick after it's inserted, and then once per interval.
+/// The global [`RunScoring`] and [`RunPicking`] skip these actors and their score trees,
+/// so trigger them targeting the actor entity to make it think in between.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// // Background NPCs only think every 2 seconds.
+/// world.spawn((Picker::new(idle), Highest, AiTickRate::new(Duration::from_secs(2))));
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct AiTickRate {
+    /// The repeating timer that decides when the actor thinks.
+    timer: Timer,
+}
+
+impl AiTickRate {
+    /// Creates a new [`AiTickRate`] that thinks once per the given interval.
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        let mut timer = Timer::new(interval, TimerMode::Repeating);
+        // Think on the first tick
+        timer.set_elapsed(interval);
+        Self { timer }
+    }
+
+    /// Returns the interval between thinks.
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Sets the interval between thinks.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.timer.set_duration(interval);
+    }
+
+    /// Returns `true` if the actor is dormant, and doesn't think at all.
+    #[must_use]
+    pub fn is_dormant(&self) -> bool {
+        self.timer.paused()
+    }
+
+    /// Sets whether the actor is dormant. Actors that wake up think on the next tick.
+    pub fn set_dormant(&mut self, dormant: bool) {
+        if dormant {
+            self.timer.pause();
+        } else {
+            self.timer.unpause();
+            self.timer.set_elapsed(self.timer.duration());
+        }
+    }
+
+    /// Advances the timer by `delta`, returning `true` if the actor should think.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        self.timer.tick(delta).just_finished()
+    }
+}
+
+/// [`Resource`] that makes the [`RealtimeLifecyclePlugin`] split actor entities into cohorts,
+/// and score and pick for only one cohort each time its systems run, in round-robin order.
+///
+/// This amortizes the cost of large numbers of actors over several ticks, while every actor
+/// still thinks exactly once per [`count`](AiCohorts::count) ticks.
+/// Actors are assigned to cohorts by their entity index, so the assignment is deterministic.
+/// Actors with an [`AiTickRate`] ignore their cohort, and think at their own rate instead.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// // Each actor thinks every 4th tick, with a quarter of them thinking each tick.
+/// app.insert_resource(AiCohorts::new(4));
+/// ```
+#[derive(Resource, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Resource, PartialEq, Debug, Default)]
+pub struct AiCohorts {
+    /// The number of cohorts.
+    count: u32,
+    /// The cohort to score and pick for next.
+    next: u32,
+}
+
+impl Default for AiCohorts {
+    /// A single cohort, so that every actor thinks every tick.
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl AiCohorts {
+    /// Creates a new [`AiCohorts`] with the given number of cohorts, starting with the first.
+    ///
+    /// A `count` of 0 is treated as 1.
+    #[must_use]
+    pub fn new(count: u32) -> Self {
+        Self {
+            count: count.max(1),
+            next: 0,
+        }
+    }
+
+    /// Returns the number of cohorts, which is also the number of ticks between each actor's thinks.
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Sets the number of cohorts. A `count` of 0 is treated as 1.
+    pub fn set_count(&mut self, count: u32) {
+        self.count = count.max(1);
+        self.next %= self.count;
+    }
+
+    /// Returns the cohort that will be scored and picked for next.
+    #[must_use]
+    pub fn next(&self) -> u32 {
+        self.next
+    }
+
+    /// Returns the cohort that the given actor entity belongs to.
+    #[must_use]
+    pub fn cohort_of(&self, actor: Entity) -> u32 {
+        actor.index() % self.count
+    }
+
+    /// Returns the cohort to score and pick for, and moves on to the next one.
+    pub fn advance(&mut self) -> u32 {
+        let current = self.next;
+        self.next = (self.next + 1) % self.count;
+        current
+    }
+}
+
+/// [`Resource`] that pauses all AI while it exists, such as for pause menus and cutscenes.
+///
+/// While paused, nothing is scored or picked for by [`RunScoring`] and [`RunPicking`],
+/// and the [`RealtimeLifecyclePlugin`] doesn't request any actions.
+/// The [`ActionPlugin`] also triggers [`PauseAction`] for all actor entities when it's inserted,
+/// and [`ResumeAction`] for all actor entities except [`AiDisabled`] ones when it's removed.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// // Open the pause menu.
+/// app.insert_resource(AiPaused);
+/// # app.update();
+/// // Close the pause menu.
+/// app.world_mut().remove_resource::<AiPaused>();
+/// # app.update();
+/// ```
+#[derive(Resource, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Resource, PartialEq, Debug, Default)]
+pub struct AiPaused;
+
+/// [`Component`] for actor entities that disables their AI while it's present, such as for possession mechanics.
+///
+/// Disabled actors aren't scored or picked for by [`RunScoring`] and [`RunPicking`], including their score trees,
+/// and the [`RealtimeLifecyclePlugin`] doesn't request actions for them.
+/// [`PauseAction`] is triggered for the actor entity when it's added, and [`ResumeAction`] when it's removed.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// # let scorer = world.spawn(FixedScore::new(0.5)).id();
+/// let actor = world.spawn((Picker::new(idle), Highest)).add_child(scorer).id();
+/// // The player possesses the actor.
+/// world.entity_mut(actor).insert(AiDisabled);
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # assert_eq!(0., world.get::<Score>(scorer).unwrap().get());
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct AiDisabled;
+
+impl Component for AiDisabled {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks
+            .on_add(|mut world, entity, _component| {
+                world.commands().trigger_targets(PauseAction, entity);
+            })
+            .on_remove(|mut world, entity, _component| {
+                world.commands().trigger_targets(ResumeAction, entity);
+            });
+    }
+}
+
+/// [`App`] extension trait for scoring and picking reactively, instead of (or as well as) every tick.
+pub trait ReactiveUtilityExt {
+    /// Adds a [`System`] in [`PostUpdate`] that triggers [`RunScoring`] and then [`RunPicking`] targeted at
+    /// each actor entity whose [`Component`] `T` was added or changed since the last time it ran.
+    ///
+    /// Combined with [`ObservedUtilityPlugins::TurnBased`], this makes actors only think when their view
+    /// of the world changes, instead of polling every tick.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Thirst {
+    ///     value: f32,
+    /// }
+    ///
+    /// #[derive(Component, Scorer)]
+    /// #[scorer(source = Thirst, score = |thirst| thirst.value / 100.)]
+    /// pub struct Thirsty;
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservedUtilityPlugins::TurnBased)
+    ///     .add_scorer::<Thirst, Thirsty>()
+    ///     .rescore_when_changed::<Thirst>();
+    ///
+    /// # let mut world = app.world_mut();
+    /// # #[derive(Component)]
+    /// # pub struct Idle;
+    /// # let idle = world.init_component::<Idle>();
+    /// let scorer = world.spawn((Thirsty, Score::default())).id();
+    /// let actor = world
+    ///     .spawn((Thirst { value: 50. }, Picker::new(idle), Highest))
+    ///     .add_child(scorer)
+    ///     .id();
+    /// app.update();
+    /// # let world = app.world_mut();
+    /// assert_eq!(0.5, world.get::<Score>(scorer).unwrap().get());
+    ///
+    /// world.get_mut::<Thirst>(actor).unwrap().value = 80.;
+    /// app.update();
+    /// # let world = app.world_mut();
+    /// assert_eq!(0.8, world.get::<Score>(scorer).unwrap().get());
+    /// ```
+    fn rescore_when_changed<T: Component>(&mut self) -> &mut Self;
+
+    /// Adds an [`Observer`] that triggers [`RunScoring`] and then [`RunPicking`] whenever the [`Event`] `E` is triggered,
+    /// targeted at the same entity as `E`, or for all entities if `E` is un-targeted.
+    fn rescore_on<E: Event>(&mut self) -> &mut Self;
+}
+
+impl ReactiveUtilityExt for App {
+    fn rescore_when_changed<T: Component>(&mut self) -> &mut Self {
+        #[allow(clippy::type_complexity)]
+        fn rescore_changed<T: Component>(
+            mut commands: Commands,
+            actors: Query<Entity, (Changed<T>, With<Picker>, Without<PickerGroup>)>,
+        ) {
+            for actor in actors.iter() {
+                commands.trigger_targets(RunScoring, actor);
+                commands.trigger_targets(RunPicking, actor);
             }
         }
+
+        self.add_systems(PostUpdate, rescore_changed::<T>)
+    }
+
+    fn rescore_on<E: Event>(&mut self) -> &mut Self {
+        fn rescore<E: Event>(trigger: Trigger<E>, mut commands: Commands) {
+            if let Some(target) = trigger.get_entity() {
+                commands.trigger_targets(RunScoring, target);
+                commands.trigger_targets(RunPicking, target);
+            } else {
+                commands.trigger(RunScoring);
+                commands.trigger(RunPicking);
+            }
+        }
+
+        self.observe(rescore::<E>)
+    }
+}
+
+/// [`World`] extension trait for running the utility lifecycle synchronously, such as in turn-based games.
+pub trait WorldUtilityExt {
+    /// Scores and picks an action for the given actor entity, flushing in between,
+    /// and returns the picked action.
+    ///
+    /// Returns `None` if the actor entity has no [`Picker`], or it picked nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+    /// # let mut world = app.world_mut();
+    /// #[derive(Component)]
+    /// pub struct Attack;
+    /// #[derive(Component)]
+    /// pub struct Idle;
+    ///
+    /// let attack = world.init_component::<Attack>();
+    /// let idle = world.init_component::<Idle>();
+    ///
+    /// let scorer = world.spawn(FixedScore::new(0.7)).id();
+    /// let actor = world
+    ///     .spawn((Picker::new(idle).with(scorer, attack), Highest))
+    ///     .add_child(scorer)
+    ///     .id();
+    ///
+    /// assert_eq!(Some(attack), world.score_and_pick(actor));
+    /// ```
+    fn score_and_pick(&mut self, actor: Entity) -> Option<ComponentId>;
+}
+
+impl WorldUtilityExt for World {
+    fn score_and_pick(&mut self, actor: Entity) -> Option<ComponentId> {
+        self.flush();
+        self.trigger_targets(RunScoring, actor);
+        self.flush();
+        self.trigger_targets(RunPicking, actor);
+        self.flush();
+        self.get::<Picker>(actor).and_then(|picker| picker.picked)
+    }
+}
+
+/// [`Commands`] extension trait for running the utility lifecycle synchronously, such as in turn-based games.
+pub trait CommandsUtilityExt {
+    /// Queues scoring and picking an action for the given actor entity,
+    /// then calls `then` with the world, the actor entity and the picked action.
+    ///
+    /// `then` isn't called if the actor entity has no [`Picker`]. See [`WorldUtilityExt::score_and_pick`].
+    fn score_and_pick(&mut self, actor: Entity, then: impl FnOnce(&mut World, Entity, ComponentId) + Send + 'static);
+}
+
+impl CommandsUtilityExt for Commands<'_, '_> {
+    fn score_and_pick(&mut self, actor: Entity, then: impl FnOnce(&mut World, Entity, ComponentId) + Send + 'static) {
+        self.add(move |world: &mut World| {
+            if let Some(action) = world.score_and_pick(actor) {
+                then(world, actor, action);
+            }
+        });
     }
 }
@@ -0,0 +1,256 @@
+//! Distance-based level of detail for AI, so that big crowds of actors stay affordable.
+//!
+//! Requires the `spatial` feature.
+//!
+//! Add the [`AiLodPlugin`] alongside [`ObservedUtilityPlugins::RealTime`] and mark the player or camera with
+//! [`AiLodFocus`]. Each actor's [`AiTickRate`] is then kept in sync with its distance to the closest focus entity,
+//! according to the [`AiLodSettings`] resource. Actors beyond the farthest tier go dormant, and aren't scored
+//! or picked for at all until they're approached again.
+//!
+//! [`ObservedUtilityPlugins::RealTime`]: crate::ObservedUtilityPlugins::RealTime
+
+use std::time::Duration;
+
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    prelude::*,
+};
+
+use crate::{
+    picking::{Picker, PickerGroup},
+    AiTickRate, RealtimeLifecyclePlugin,
+};
+
+/// [`Plugin`] that scales each actor entity's [`AiTickRate`] with its distance to the closest [`AiLodFocus`] entity,
+/// in the configured [`Schedule`] (default [`FixedPostUpdate`]), before the [`RealtimeLifecyclePlugin`] runs.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::RealTime, AiLodPlugin::default()));
+/// # let world = app.world_mut();
+/// // The player's camera, for example.
+/// world.spawn((TransformBundle::default(), AiLodFocus));
+/// ```
+pub struct AiLodPlugin {
+    /// The [`ScheduleLabel`] to update tick rates in. Should match the [`RealtimeLifecyclePlugin`]'s.
+    pub update_in: InternedScheduleLabel,
+}
+
+impl Default for AiLodPlugin {
+    fn default() -> Self {
+        Self {
+            update_in: FixedPostUpdate.intern(),
+        }
+    }
+}
+
+impl Plugin for AiLodPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AiLodSettings>().add_systems(
+            self.update_in,
+            Self::update_tick_rates.before(RealtimeLifecyclePlugin::score_and_pick),
+        );
+
+        app.register_type::<AiLodFocus>().register_type::<AiLodSettings>();
+    }
+}
+
+impl AiLodPlugin {
+    /// [`System`] that updates each actor entity's [`AiTickRate`] from its distance to the closest [`AiLodFocus`] entity,
+    /// inserting one if missing. Actors are left alone while there are no focus entities.
+    #[allow(clippy::type_complexity)]
+    pub fn update_tick_rates(
+        mut commands: Commands,
+        settings: Res<AiLodSettings>,
+        focuses: Query<&GlobalTransform, With<AiLodFocus>>,
+        mut actors: Query<
+            (Entity, &GlobalTransform, Option<&mut AiTickRate>),
+            (With<Picker>, Without<PickerGroup>, Without<AiLodFocus>),
+        >,
+    ) {
+        if focuses.is_empty() {
+            return;
+        }
+
+        for (actor, transform, rate) in &mut actors {
+            let distance = focuses
+                .iter()
+                .map(|focus| focus.translation().distance(transform.translation()))
+                .fold(f32::INFINITY, f32::min);
+            let interval = settings.interval(distance);
+
+            match (rate, interval) {
+                (Some(mut rate), Some(interval)) => {
+                    if rate.interval() != interval {
+                        rate.set_interval(interval);
+                    }
+                    if rate.is_dormant() {
+                        rate.set_dormant(false);
+                    }
+                }
+                (Some(mut rate), None) => {
+                    if !rate.is_dormant() {
+                        rate.set_dormant(true);
+                    }
+                }
+                (None, Some(interval)) => {
+                    commands.entity(actor).insert(AiTickRate::new(interval));
+                }
+                (None, None) => {
+                    let mut rate = AiTickRate::new(Duration::ZERO);
+                    rate.set_dormant(true);
+                    commands.entity(actor).insert(rate);
+                }
+            }
+        }
+    }
+}
+
+/// [`Component`] for the entities that actors are distanced from by the [`AiLodPlugin`], such as the player or camera.
+///
+/// If there are multiple, the closest one is used.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct AiLodFocus;
+
+/// [`Resource`] for the distance tiers used by the [`AiLodPlugin`].
+///
+/// Each tier is a maximum distance and the interval between thinks for actors within it.
+/// Actors farther away than every tier are dormant.
+#[derive(Resource, Reflect)]
+#[derive(Clone, PartialEq, Debug)]
+#[reflect(Resource, PartialEq, Debug, Default)]
+pub struct AiLodSettings {
+    /// The tiers, sorted by ascending maximum distance.
+    tiers: Vec<(f32, Duration)>,
+}
+
+impl Default for AiLodSettings {
+    /// Thinks every tick within 25 units, every half second within 50 units,
+    /// every 2 seconds within 100 units, and goes dormant beyond that.
+    fn default() -> Self {
+        Self::new()
+            .with_tier(25., Duration::ZERO)
+            .with_tier(50., Duration::from_millis(500))
+            .with_tier(100., Duration::from_secs(2))
+    }
+}
+
+impl AiLodSettings {
+    /// Creates a new [`AiLodSettings`] without any tiers, which makes every actor dormant.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { tiers: Vec::new() }
+    }
+
+    /// Adds a tier for actors within the given maximum distance, which think once per the given interval.
+    #[must_use]
+    pub fn with_tier(mut self, max_distance: f32, interval: Duration) -> Self {
+        self.add_tier(max_distance, interval);
+        self
+    }
+
+    /// Adds a tier for actors within the given maximum distance, which think once per the given interval.
+    pub fn add_tier(&mut self, max_distance: f32, interval: Duration) {
+        let index = self.tiers.partition_point(|&(other, _)| other <= max_distance);
+        self.tiers.insert(index, (max_distance, interval));
+    }
+
+    /// Returns the tiers, sorted by ascending maximum distance.
+    #[must_use]
+    pub fn tiers(&self) -> &[(f32, Duration)] {
+        &self.tiers
+    }
+
+    /// Returns the interval between thinks for actors at the given distance, or `None` if they're dormant.
+    #[must_use]
+    pub fn interval(&self, distance: f32) -> Option<Duration> {
+        self.tiers
+            .iter()
+            .find(|&&(max_distance, _)| distance <= max_distance)
+            .map(|&(_, interval)| interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+    use crate::{
+        lod::{AiLodFocus, AiLodPlugin},
+        picking::{HighestAboveThreshold, Picker},
+        scoring::FixedScore,
+        AiTickRate, RealtimeLifecyclePlugin,
+    };
+
+    #[derive(Component)]
+    struct MyAction;
+
+    #[derive(Component)]
+    struct IdleAction;
+
+    #[test]
+    fn distant_actors_think_less_often() {
+        let mut app = App::new();
+        app.add_plugins((
+            crate::ObservedUtilityPlugins::TurnBased,
+            RealtimeLifecyclePlugin {
+                score_pick_perform_in: Update.intern(),
+            },
+            AiLodPlugin {
+                update_in: Update.intern(),
+            },
+        ));
+        app.init_resource::<Time>();
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        world.spawn((GlobalTransform::IDENTITY, AiLodFocus));
+        let mut spawn_actor = |distance: f32| {
+            let scorer = world.spawn(FixedScore::new(0.7)).id();
+            let actor = world
+                .spawn((
+                    Picker::new(idle_action).with(scorer, my_action),
+                    HighestAboveThreshold::new(0.5),
+                    GlobalTransform::from_translation(Vec3::X * distance),
+                ))
+                .add_child(scorer)
+                .id();
+            (actor, scorer)
+        };
+        // With the default settings: every tick, every 2 seconds, and dormant.
+        let actors @ [(near, _), (far, _), (dormant, _)] = [spawn_actor(10.), spawn_actor(75.), spawn_actor(500.)];
+
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(Duration::ZERO, world.get::<AiTickRate>(near).unwrap().interval());
+        assert_eq!(Duration::from_secs(2), world.get::<AiTickRate>(far).unwrap().interval());
+        assert!(world.get::<AiTickRate>(dormant).unwrap().is_dormant());
+        assert_eq!(Some(my_action), world.get::<Picker>(near).unwrap().picked);
+        assert_eq!(Some(my_action), world.get::<Picker>(far).unwrap().picked);
+        assert_eq!(Some(idle_action), world.get::<Picker>(dormant).unwrap().picked);
+
+        for (_, scorer) in actors {
+            world.entity_mut(scorer).insert(FixedScore::new(0.2));
+        }
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(500));
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(Some(idle_action), world.get::<Picker>(near).unwrap().picked);
+        assert_eq!(Some(my_action), world.get::<Picker>(far).unwrap().picked);
+
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(1500));
+        app.update();
+        assert_eq!(Some(idle_action), app.world().get::<Picker>(far).unwrap().picked);
+    }
+}
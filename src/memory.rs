@@ -0,0 +1,558 @@
+//! Perception memory, for behaviors that depend on what an actor *remembers*
+//! rather than what it can currently perceive, such as searching where an enemy was last seen.
+//!
+//! Perception systems record stimuli as timestamped facts about subject entities in the actor's [`MemoryOf<T>`]
+//! component, with `T` being the kind of fact (such as a last known position). Memories decay linearly
+//! over the memory's lifetime: their freshness drops from 1 when recorded to 0 when they expire,
+//! and their confidence with it. The [`MemoryPlugin<T>`] forgets expired memories,
+//! and [`MemoryScore<T>`] score entities read the freshness or confidence of the actor's memories.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use bevy::prelude::*;
+//! use bevy_observed_utility::prelude::*;
+//! # use approx::assert_relative_eq;
+//!
+//! /// The position an enemy was last seen at.
+//! #[derive(Clone, Debug)]
+//! pub struct LastSeen(Vec3);
+//!
+//! #[derive(Component)]
+//! pub struct Search;
+//! #[derive(Component)]
+//! pub struct Idle;
+//!
+//! # let mut app = App::new();
+//! app.add_plugins((ObservedUtilityPlugins::RealTime, MemoryPlugin::<LastSeen>::default()));
+//! # app.init_resource::<Time>();
+//! # let mut world = app.world_mut();
+//! let search = world.init_component::<Search>();
+//! let idle = world.init_component::<Idle>();
+//!
+//! // Search while the memory of any enemy is still fresh.
+//! let search_scorer = world.spawn(MemoryScore::<LastSeen>::freshness()).id();
+//! let actor = world
+//!     .spawn((
+//!         Picker::new(idle).with(search_scorer, search),
+//!         Highest,
+//!         MemoryOf::<LastSeen>::new(Duration::from_secs(10)),
+//!     ))
+//!     .add_child(search_scorer)
+//!     .id();
+//!
+//! // Usually done by a perception system, with the current elapsed time.
+//! let enemy = world.spawn_empty().id();
+//! let now = world.resource::<Time>().elapsed();
+//! world
+//!     .get_mut::<MemoryOf<LastSeen>>(actor)
+//!     .unwrap()
+//!     .record(enemy, LastSeen(Vec3::new(4., 0., 2.)), now);
+//!
+//! world.resource_mut::<Time>().advance_by(Duration::from_secs(4));
+//! assert_eq!(Some(search), world.score_and_pick(actor));
+//! assert_relative_eq!(0.6, world.get::<Score>(search_scorer).unwrap().get());
+//! ```
+
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, StorageType},
+        entity::{EntityMapper, MapEntities},
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    prelude::*,
+};
+
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{AncestorQuery, Evaluator, LinearEvaluator, Score, ScorerComponents, ScorerKind},
+    RealtimeLifecyclePlugin,
+};
+
+/// [`Plugin`] that forgets expired [`MemoryOf<T>`] memories of all actor entities
+/// in the configured [`Schedule`] (default [`FixedPostUpdate`]), before the [`RealtimeLifecyclePlugin`] runs.
+///
+/// Add one for each kind of fact `T`. [`MemoryScore<T>`]s score expired memories as 0 either way,
+/// so this only keeps memories from piling up.
+pub struct MemoryPlugin<T: Send + Sync + 'static> {
+    /// The [`ScheduleLabel`] to forget expired memories in.
+    pub update_in: InternedScheduleLabel,
+    /// The kind of fact remembered.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> MemoryPlugin<T> {
+    /// Creates a new [`MemoryPlugin`] that forgets expired memories in the given [`Schedule`].
+    #[must_use]
+    pub fn new(update_in: impl ScheduleLabel) -> Self {
+        Self {
+            update_in: update_in.intern(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// [`System`] that forgets the expired memories of all actor entities.
+    pub fn forget_expired(mut memories: Query<&mut MemoryOf<T>>, time: Option<Res<Time>>) {
+        let now = time.map(|time| time.elapsed()).unwrap_or_default();
+        for mut memory in &mut memories {
+            if memory.iter().any(|entry| memory.is_expired(entry, now)) {
+                memory.forget_expired(now);
+            }
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Default for MemoryPlugin<T> {
+    fn default() -> Self {
+        Self::new(FixedPostUpdate)
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for MemoryPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.update_in,
+            Self::forget_expired.before(RealtimeLifecyclePlugin::score_and_pick),
+        );
+
+        app.register_type::<MemoryMeasure>();
+    }
+}
+
+/// [`Component`] for actor entities that remembers facts of kind `T` about subject entities,
+/// at most one per subject. See the [module docs](self) for an example.
+///
+/// Each memory is recorded at an elapsed [`Time`] with a confidence, and decays linearly over the `lifetime`:
+/// its freshness is 1 when recorded and 0 once it expires, and its confidence is scaled by its freshness.
+#[derive(Component)]
+#[derive(Clone, Debug)]
+pub struct MemoryOf<T: Send + Sync + 'static> {
+    /// The remembered facts, in the order they were first recorded.
+    entries: Vec<MemoryEntry<T>>,
+    /// How long memories take to expire.
+    lifetime: Duration,
+}
+
+impl<T: Send + Sync + 'static> MemoryOf<T> {
+    /// Creates a new empty [`MemoryOf`] whose memories expire after the given lifetime.
+    #[must_use]
+    pub fn new(lifetime: Duration) -> Self {
+        Self {
+            entries: Vec::new(),
+            lifetime,
+        }
+    }
+
+    /// Returns how long memories take to expire.
+    #[must_use]
+    pub fn lifetime(&self) -> Duration {
+        self.lifetime
+    }
+
+    /// Sets how long memories take to expire.
+    pub fn set_lifetime(&mut self, lifetime: Duration) {
+        self.lifetime = lifetime;
+    }
+
+    /// Remembers the fact about the subject entity at the elapsed time `now` with full confidence,
+    /// replacing any older memory of the subject.
+    pub fn record(&mut self, subject: Entity, fact: T, now: Duration) {
+        self.record_with_confidence(subject, fact, 1., now);
+    }
+
+    /// Remembers the fact about the subject entity at the elapsed time `now` with the given confidence
+    /// (clamped to the range `[0, 1]`), such as a lower confidence for something only heard,
+    /// replacing any older memory of the subject.
+    pub fn record_with_confidence(&mut self, subject: Entity, fact: T, confidence: f32, now: Duration) {
+        let entry = MemoryEntry {
+            subject,
+            fact,
+            recorded_at: now,
+            confidence: confidence.clamp(0., 1.),
+        };
+        match self.entries.iter_mut().find(|entry| entry.subject == subject) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Returns the memory of the subject entity, if any, even if it's expired.
+    #[must_use]
+    pub fn get(&self, subject: Entity) -> Option<&MemoryEntry<T>> {
+        self.entries.iter().find(|entry| entry.subject == subject)
+    }
+
+    /// Returns an iterator over all memories, in the order they were first recorded.
+    pub fn iter(&self) -> impl Iterator<Item = &MemoryEntry<T>> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of memories, including expired ones that weren't forgotten yet.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no memories.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forgets the memory of the subject entity, returning it if there was one.
+    pub fn forget(&mut self, subject: Entity) -> Option<MemoryEntry<T>> {
+        let index = self.entries.iter().position(|entry| entry.subject == subject)?;
+        Some(self.entries.remove(index))
+    }
+
+    /// Forgets all memories that are expired at the elapsed time `now`, returning how many were forgotten.
+    pub fn forget_expired(&mut self, now: Duration) -> usize {
+        let before = self.entries.len();
+        let lifetime = self.lifetime;
+        self.entries.retain(|entry| entry.freshness(now, lifetime) > 0.);
+        before - self.entries.len()
+    }
+
+    /// Returns `true` if the memory is expired at the elapsed time `now`.
+    #[must_use]
+    pub fn is_expired(&self, entry: &MemoryEntry<T>, now: Duration) -> bool {
+        entry.freshness(now, self.lifetime) <= 0.
+    }
+
+    /// Returns the freshness of the memory of the subject entity at the elapsed time `now`,
+    /// or 0 if there's none.
+    #[must_use]
+    pub fn freshness(&self, subject: Entity, now: Duration) -> f32 {
+        self.get(subject)
+            .map_or(0., |entry| entry.freshness(now, self.lifetime))
+    }
+
+    /// Returns the decayed confidence of the memory of the subject entity at the elapsed time `now`,
+    /// or 0 if there's none.
+    #[must_use]
+    pub fn confidence(&self, subject: Entity, now: Duration) -> f32 {
+        self.get(subject)
+            .map_or(0., |entry| entry.decayed_confidence(now, self.lifetime))
+    }
+
+    /// Returns the freshest memory at the elapsed time `now`, if any isn't expired.
+    /// Ties go to the memory recorded first.
+    #[must_use]
+    pub fn freshest(&self, now: Duration) -> Option<&MemoryEntry<T>> {
+        self.most(now, MemoryEntry::freshness)
+    }
+
+    /// Returns the memory with the highest decayed confidence at the elapsed time `now`, if any isn't expired.
+    /// Ties go to the memory recorded first.
+    #[must_use]
+    pub fn most_confident(&self, now: Duration) -> Option<&MemoryEntry<T>> {
+        self.most(now, MemoryEntry::decayed_confidence)
+    }
+
+    /// Returns the memory with the highest non-zero value of `measure` at the elapsed time `now`.
+    fn most(&self, now: Duration, measure: fn(&MemoryEntry<T>, Duration, Duration) -> f32) -> Option<&MemoryEntry<T>> {
+        let mut best: Option<(&MemoryEntry<T>, f32)> = None;
+        for entry in &self.entries {
+            let value = measure(entry, now, self.lifetime);
+            if value > 0. && best.map_or(true, |(_, best)| value > best) {
+                best = Some((entry, value));
+            }
+        }
+        best.map(|(entry, _)| entry)
+    }
+}
+
+impl<T: Send + Sync + 'static> MapEntities for MemoryOf<T> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for entry in &mut self.entries {
+            entry.subject = entity_mapper.map_entity(entry.subject);
+        }
+    }
+}
+
+/// A fact remembered in a [`MemoryOf`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct MemoryEntry<T> {
+    /// The entity the fact is about.
+    pub subject: Entity,
+    /// The remembered fact.
+    pub fact: T,
+    /// The elapsed [`Time`] the fact was recorded at.
+    pub recorded_at: Duration,
+    /// How confident the actor was in the fact when it was recorded, in the range `[0, 1]`.
+    pub confidence: f32,
+}
+
+impl<T> MemoryEntry<T> {
+    /// Returns how long ago the fact was recorded at the elapsed time `now`.
+    #[must_use]
+    pub fn age(&self, now: Duration) -> Duration {
+        now.saturating_sub(self.recorded_at)
+    }
+
+    /// Returns the freshness of the fact at the elapsed time `now` for the given memory lifetime:
+    /// 1 when recorded, decaying linearly to 0 once expired.
+    #[must_use]
+    pub fn freshness(&self, now: Duration, lifetime: Duration) -> f32 {
+        let lifetime = lifetime.as_secs_f32();
+        if lifetime <= 0. {
+            return 0.;
+        }
+        (1. - self.age(now).as_secs_f32() / lifetime).clamp(0., 1.)
+    }
+
+    /// Returns the recorded confidence scaled by the freshness at the elapsed time `now`.
+    #[must_use]
+    pub fn decayed_confidence(&self, now: Duration, lifetime: Duration) -> f32 {
+        self.confidence * self.freshness(now, lifetime)
+    }
+}
+
+/// What a [`MemoryScore`] reads from a memory.
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[reflect(PartialEq, Hash, Debug, Default)]
+pub enum MemoryMeasure {
+    /// The memory's freshness, from 1 when recorded to 0 when expired.
+    #[default]
+    Freshness,
+    /// The memory's recorded confidence, scaled by its freshness.
+    Confidence,
+}
+
+/// [`Score`] [`Component`] that scores the freshness or confidence of a memory in the closest ancestor
+/// actor entity's [`MemoryOf<T>`], passed through an [`Evaluator`].
+/// See the [module docs](self) for an example.
+///
+/// Scores the memory of a specific subject entity if one is set, or else the highest value among all memories.
+/// Scores 0 if the actor has no [`MemoryOf<T>`] or no matching memory.
+pub struct MemoryScore<T: Send + Sync + 'static> {
+    /// What to read from the memory.
+    measure: MemoryMeasure,
+    /// The subject entity whose memory to score, or `None` for the highest value among all memories.
+    subject: Option<Entity>,
+    /// The evaluator to use for scoring the measured value.
+    evaluator: Box<dyn Evaluator>,
+    /// The kind of fact remembered.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> MemoryScore<T> {
+    /// Creates a new [`MemoryScore`] that reads the given [`MemoryMeasure`], with a linear evaluator.
+    #[must_use]
+    pub fn new(measure: MemoryMeasure) -> Self {
+        Self {
+            measure,
+            subject: None,
+            evaluator: Box::new(LinearEvaluator::default()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new [`MemoryScore`] that reads the [freshness](MemoryMeasure::Freshness) of memories.
+    #[must_use]
+    pub fn freshness() -> Self {
+        Self::new(MemoryMeasure::Freshness)
+    }
+
+    /// Creates a new [`MemoryScore`] that reads the [confidence](MemoryMeasure::Confidence) of memories.
+    #[must_use]
+    pub fn confidence() -> Self {
+        Self::new(MemoryMeasure::Confidence)
+    }
+
+    /// Only scores the memory of the given subject entity.
+    #[must_use]
+    pub fn with_subject(mut self, subject: Entity) -> Self {
+        self.subject = Some(subject);
+        self
+    }
+
+    /// Sets the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn with_evaluator(mut self, evaluator: impl Evaluator) -> Self {
+        self.evaluator = Box::new(evaluator);
+        self
+    }
+
+    /// Returns what is read from the memory.
+    #[must_use]
+    pub fn measure(&self) -> MemoryMeasure {
+        self.measure
+    }
+
+    /// Returns the subject entity whose memory is scored, if any.
+    #[must_use]
+    pub fn subject(&self) -> Option<Entity> {
+        self.subject
+    }
+
+    /// Sets the subject entity whose memory is scored, or `None` to score the highest value among all memories.
+    pub fn set_subject(&mut self, subject: Option<Entity>) {
+        self.subject = subject;
+    }
+
+    /// Returns the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn evaluator(&self) -> &dyn Evaluator {
+        self.evaluator.as_ref()
+    }
+
+    /// Returns the evaluated value of the matching memory at the elapsed time `now`, or 0 if there's none.
+    #[must_use]
+    pub fn evaluate(&self, memory: &MemoryOf<T>, now: Duration) -> f32 {
+        let entry = match (self.subject, self.measure) {
+            (Some(subject), _) => memory.get(subject),
+            (None, MemoryMeasure::Freshness) => memory.freshest(now),
+            (None, MemoryMeasure::Confidence) => memory.most_confident(now),
+        };
+        let value = entry.map_or(0., |entry| match self.measure {
+            MemoryMeasure::Freshness => entry.freshness(now, memory.lifetime()),
+            MemoryMeasure::Confidence => entry.decayed_confidence(now, memory.lifetime()),
+        });
+        if value <= 0. {
+            return 0.;
+        }
+        self.evaluator.evaluate(value)
+    }
+
+    /// [`Observer`] for [`MemoryScore`] [`Score`] entities that scores the actor's memories.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut target: Query<(&mut Score, &MemoryScore<T>)>,
+        mut memories: AncestorQuery<&'static MemoryOf<T>>,
+        time: Option<Res<Time>>,
+    ) {
+        utility_span!("MemoryScore::observer", entity = ?trigger.entity());
+        let scorer = trigger.entity();
+        let Ok((mut score, settings)) = target.get_mut(scorer) else {
+            // The entity is not scoring for memories.
+            return;
+        };
+        let now = time.map(|time| time.elapsed()).unwrap_or_default();
+
+        match memories.get(scorer) {
+            Ok(memory) => score.set(settings.evaluate(memory, now)),
+            Err(_) => *score = Score::MIN,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Component for MemoryScore<T> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    use crate::{
+        memory::{MemoryOf, MemoryPlugin, MemoryScore},
+        picking::{Highest, Picker},
+        scoring::Score,
+        WorldUtilityExt,
+    };
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Heard;
+
+    #[derive(Component)]
+    struct Search;
+
+    #[derive(Component)]
+    struct Idle;
+
+    #[test]
+    fn memories_decay_over_their_lifetime() {
+        let mut world = World::new();
+        let [loud, quiet] = [(); 2].map(|()| world.spawn_empty().id());
+        let secs = Duration::from_secs_f32;
+
+        let mut memory = MemoryOf::<Heard>::new(secs(10.));
+        memory.record(loud, Heard, secs(0.));
+        memory.record_with_confidence(quiet, Heard, 0.3, secs(5.));
+
+        assert_relative_eq!(0.75, memory.freshness(loud, secs(2.5)));
+        assert_relative_eq!(0.75, memory.confidence(loud, secs(2.5)));
+        assert_relative_eq!(0.5, memory.freshness(quiet, secs(10.)));
+        assert_relative_eq!(0.15, memory.confidence(quiet, secs(10.)));
+
+        // The older memory is less fresh, but still more confident.
+        assert_eq!(Some(quiet), memory.freshest(secs(6.)).map(|entry| entry.subject));
+        assert_eq!(Some(loud), memory.most_confident(secs(6.)).map(|entry| entry.subject));
+
+        // Recording again refreshes the memory.
+        memory.record(quiet, Heard, secs(8.));
+        assert_relative_eq!(1., memory.confidence(quiet, secs(8.)));
+        assert_eq!(2, memory.len());
+    }
+
+    #[test]
+    fn expired_memories_score_nothing_and_are_forgotten() {
+        let mut app = App::new();
+        app.add_plugins((
+            crate::ObservedUtilityPlugins::TurnBased,
+            MemoryPlugin::<Heard>::new(Update),
+        ))
+        .init_resource::<Time>();
+        let world = app.world_mut();
+
+        let search = world.init_component::<Search>();
+        let idle = world.init_component::<Idle>();
+        let scorer = world.spawn(MemoryScore::<Heard>::freshness()).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle).with(scorer, search),
+                Highest,
+                MemoryOf::<Heard>::new(Duration::from_secs(10)),
+            ))
+            .add_child(scorer)
+            .id();
+        let [old, new] = [(); 2].map(|()| world.spawn_empty().id());
+        let mut memory = world.get_mut::<MemoryOf<Heard>>(actor).unwrap();
+        memory.record(old, Heard, Duration::ZERO);
+        memory.record(new, Heard, Duration::from_secs(6));
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(8));
+        app.update();
+        let memory = app.world().get::<MemoryOf<Heard>>(actor).unwrap();
+        assert_eq!(2, memory.len());
+
+        // The old memory expires, and the new one is only fresh until 16 seconds.
+        let world = app.world_mut();
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(4));
+        app.update();
+        let world = app.world_mut();
+        let memory = world.get::<MemoryOf<Heard>>(actor).unwrap();
+        assert_eq!(vec![new], memory.iter().map(|entry| entry.subject).collect::<Vec<_>>());
+        assert_eq!(Some(search), world.score_and_pick(actor));
+        assert_relative_eq!(0.4, world.get::<Score>(scorer).unwrap().get());
+
+        // Expired memories score 0 even before they're forgotten.
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(4));
+        world.score_and_pick(actor);
+        assert_eq!(Some(&Score::MIN), world.get::<Score>(scorer));
+        assert_eq!(1, world.get::<MemoryOf<Heard>>(actor).unwrap().len());
+        app.update();
+        assert!(app.world().get::<MemoryOf<Heard>>(actor).unwrap().is_empty());
+    }
+}
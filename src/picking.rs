@@ -4,28 +4,71 @@
 //!
 //! - [`FirstToScore`]: Picks the first action to reach a certain score.
 //! - [`Highest`]: Picks the action with the highest score.
-//! - [`Random`] (requires `rand` feature): Picks a random action.
+//! - [`HighestAboveThreshold`]: Picks the action with the highest score, if it reaches a certain threshold.
+//! - [`PickDualUtility`]: Picks the action with the highest score within the highest priority tier.
+//! - [`PickMultiple`]: Picks multiple compatible actions with the highest scores, to perform concurrently.
+//! - [`PickerChain`]: Tries multiple of the above in order, until one picks a non-default action.
+//! - [`PickRandom`] (requires `rand` feature): Picks a random action.
 //!
-//! [`Score`]: crate::scoring::Score
+//! Choices can also be [`PickerGroup`]s, which pick a concrete action with their own [`Picker`].
+//!
+//! The [`DirectPickingPlugin`] is an opt-in alternative picking path that picks for all queued actors
+//! in a [`System`] per strategy, instead of triggering [`OnPick`] for each actor.
+
+use std::time::Duration;
 
 use bevy::{
-    ecs::{component::ComponentId, entity::EntityHashMap},
+    ecs::{
+        archetype::Archetypes,
+        component::{ComponentHooks, ComponentId, StorageType},
+        entity::{Entities, EntityHashMap, EntityMapper, MapEntities},
+        system::SystemParam,
+        world::Command,
+    },
     prelude::*,
 };
 
+mod brain;
+mod chain;
+mod cost;
+mod direct;
+mod dual_utility;
 mod first_to_score;
+mod group;
 mod highest;
+mod highest_above_threshold;
+mod jitter;
+mod mask;
+mod multiple;
 #[cfg(feature = "rand")]
 mod random;
+mod sticky;
 
+pub use brain::*;
+pub use chain::*;
+pub use cost::*;
+pub use direct::*;
+pub use dual_utility::*;
 pub use first_to_score::*;
+pub use group::*;
 pub use highest::*;
+pub use highest_above_threshold::*;
+pub use jitter::*;
+pub use mask::*;
+pub use multiple::*;
 #[cfg(feature = "rand")]
 pub use random::*;
+pub use sticky::*;
 
 use crate::{
-    ecs::TriggerGetEntity,
-    event::{OnPick, OnPicked, RunPicking},
+    acting::{ActionCooldowns, CurrentAction},
+    diagnostics::{AiDiagnosticsState, MeasurePhase},
+    ecs::{InsertIfMissing, TriggerGetEntity},
+    event::{OnPick, OnPicked, PickCandidate, PickReport, RunPicking, RunPickingMany},
+    rng::{UtilityRng, UtilityRngs},
+    scoring::{score_parent, PrioritizedChildren, Score, ScoreHierarchy, ScoreOf, ScorePriority},
+    targeting::BestTarget,
+    AiDisabled, AiPaused, AiTickRate,
 };
 
 /// [`Plugin`] for picking actions based on the scores of child entities.
@@ -34,49 +77,386 @@ pub struct PickingPlugin;
 
 impl Plugin for PickingPlugin {
     fn build(&self, app: &mut App) {
-        app.observe(Self::run_picking);
+        app.observe(Self::run_picking)
+            .observe(Self::run_picking_many)
+            .observe(Self::on_parent_inserted_register_choice)
+            .observe(Self::on_score_of_inserted_register_choice)
+            .observe(Self::on_score_removed_cleanup_choices)
+            .observe(Self::on_parent_removed_cleanup_choices)
+            .observe(Self::on_score_of_removed_cleanup_choices);
+
+        app.init_resource::<UtilityRng>();
 
         app.register_type::<Picker>()
+            .register_type::<PickerChoices>()
+            .register_type::<ChoiceBias>()
+            .register_type::<ActionCosts>()
+            .register_type::<CostMode>()
+            .register_type::<ActionMask>()
+            .register_type::<ActionFor>()
+            .register_type::<RepickOnChoiceRemoved>()
+            .register_type::<ReportPicks>()
+            .register_type::<DespawnScoreTreeWithActor>()
             .register_type::<FirstToScore>()
-            .register_type::<Highest>();
+            .register_type::<Highest>()
+            .register_type::<HighestAboveThreshold>()
+            .register_type::<Sticky>()
+            .register_type::<PickJitter>()
+            .register_type::<PickMultiple>()
+            .register_type::<PickerGroup>()
+            .register_type::<PickedActions>();
 
         #[cfg(feature = "rand")]
-        app.register_type::<Random>();
+        app.register_type::<PickRandom>();
 
         app.register_type::<RunPicking>()
+            .register_type::<RunPickingMany>()
             .register_type::<OnPick>()
-            .register_type::<OnPicked>();
+            .register_type::<OnPicked>()
+            .register_type::<PickReport>()
+            .register_type::<PickCandidate>();
     }
 }
 
 impl PickingPlugin {
     /// [`Observer`] that triggers the [`OnPick`] event for one specific or all [`Picker`] entities.
-    pub fn run_picking(trigger: Trigger<RunPicking>, mut commands: Commands, pickers: Query<Entity, With<Picker>>) {
-        fn trigger_picking(target: Entity, mut commands: Commands) {
-            commands.trigger_targets(OnPick, target);
+    ///
+    /// [`PickerGroup`]s are skipped when picking for all entities, as they're picked by their parent instead,
+    /// and so are entities with an [`AiTickRate`], as they're picked for at their own rate.
+    /// Nothing is picked for while [`AiPaused`], or for [`AiDisabled`] entities.
+    ///
+    /// With the [`DirectPickingPlugin`], entities with a directly picked strategy are queued instead.
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::type_complexity)]
+    pub fn run_picking(
+        trigger: Trigger<RunPicking>,
+        mut commands: Commands,
+        paused: Option<Res<AiPaused>>,
+        diagnostics: Option<Res<AiDiagnosticsState>>,
+        mut direct: Option<ResMut<DirectPicking>>,
+        pickers: Query<
+            Entity,
+            (
+                With<Picker>,
+                Without<PickerGroup>,
+                Without<AiDisabled>,
+                Without<AiTickRate>,
+            ),
+        >,
+        disabled: Query<(), With<AiDisabled>>,
+        groups: Query<(), With<PickerGroup>>,
+        entities: &Entities,
+        archetypes: &Archetypes,
+    ) {
+        utility_span!("PickingPlugin::run_picking", target = ?trigger.get_entity());
+        if paused.is_some() {
+            return;
         }
 
+        let measured = diagnostics.is_some();
+        if measured {
+            commands.add(MeasurePhase::StartPicking);
+        }
         if let Some(target) = trigger.get_entity() {
-            trigger_picking(target, commands.reborrow());
+            if !disabled.contains(target) {
+                trigger_picking(
+                    target,
+                    commands.reborrow(),
+                    direct.as_deref_mut(),
+                    &groups,
+                    entities,
+                    archetypes,
+                );
+            }
         } else {
             for target in pickers.iter() {
-                trigger_picking(target, commands.reborrow());
+                trigger_picking(
+                    target,
+                    commands.reborrow(),
+                    direct.as_deref_mut(),
+                    &groups,
+                    entities,
+                    archetypes,
+                );
+            }
+        }
+        if measured {
+            commands.add(MeasurePhase::StopPicking);
+        }
+    }
+
+    /// Same as [`PickingPlugin::run_picking`], but for each entity in the [`RunPickingMany`] event, in order.
+    /// [`AiDisabled`] entities are skipped.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_picking_many(
+        trigger: Trigger<RunPickingMany>,
+        mut commands: Commands,
+        paused: Option<Res<AiPaused>>,
+        diagnostics: Option<Res<AiDiagnosticsState>>,
+        mut direct: Option<ResMut<DirectPicking>>,
+        disabled: Query<(), With<AiDisabled>>,
+        groups: Query<(), With<PickerGroup>>,
+        entities: &Entities,
+        archetypes: &Archetypes,
+    ) {
+        utility_span!("PickingPlugin::run_picking_many", targets = trigger.event().0.len());
+        if paused.is_some() {
+            return;
+        }
+
+        let measured = diagnostics.is_some();
+        if measured {
+            commands.add(MeasurePhase::StartPicking);
+        }
+        for &target in &trigger.event().0 {
+            if !disabled.contains(target) {
+                trigger_picking(
+                    target,
+                    commands.reborrow(),
+                    direct.as_deref_mut(),
+                    &groups,
+                    entities,
+                    archetypes,
+                );
+            }
+        }
+        if measured {
+            commands.add(MeasurePhase::StopPicking);
+        }
+    }
+
+    /// [`Observer`] that registers [`ActionFor`] score entities into their parent's [`Picker`]
+    /// when they're parented.
+    pub fn on_parent_inserted_register_choice(
+        trigger: Trigger<OnInsert, Parent>,
+        mut commands: Commands,
+        choices: Query<(), With<ActionFor>>,
+    ) {
+        utility_span!("PickingPlugin::on_parent_inserted_register_choice", entity = ?trigger.entity());
+        let entity = trigger.entity();
+        if choices.contains(entity) {
+            commands.add(RegisterChoice(entity));
+        }
+    }
+
+    /// [`Observer`] that registers [`ActionFor`] score entities into their [`ScoreOf`] target's [`Picker`]
+    /// when they're (re)linked.
+    pub fn on_score_of_inserted_register_choice(
+        trigger: Trigger<OnInsert, ScoreOf>,
+        mut commands: Commands,
+        choices: Query<(), With<ActionFor>>,
+    ) {
+        utility_span!("PickingPlugin::on_score_of_inserted_register_choice", entity = ?trigger.entity());
+        let entity = trigger.entity();
+        if choices.contains(entity) {
+            commands.add(RegisterChoice(entity));
+        }
+    }
+
+    /// [`Observer`] that removes despawned (or no longer scoring) entities from their parent [`Picker`]'s choices.
+    ///
+    /// If the removed choice was the last picked action and the actor has [`RepickOnChoiceRemoved`],
+    /// [`RunPicking`] is triggered for that actor.
+    pub fn on_score_removed_cleanup_choices(
+        trigger: Trigger<OnRemove, Score>,
+        commands: Commands,
+        pickers: Query<(&mut Picker, Has<RepickOnChoiceRemoved>)>,
+        hierarchy: ScoreHierarchy,
+    ) {
+        utility_span!("PickingPlugin::on_score_removed_cleanup_choices", entity = ?trigger.entity());
+        let score_entity = trigger.entity();
+        if let Some(actor) = hierarchy.parent(score_entity) {
+            remove_choice(commands, pickers, actor, score_entity);
+        }
+    }
+
+    /// [`Observer`] that removes [`ActionFor`] score entities from their parent [`Picker`]'s choices
+    /// when they're unparented, since they're registered again wherever they're parented next.
+    pub fn on_parent_removed_cleanup_choices(
+        trigger: Trigger<OnRemove, Parent>,
+        commands: Commands,
+        pickers: Query<(&mut Picker, Has<RepickOnChoiceRemoved>)>,
+        parents: Query<&Parent, (With<ActionFor>, Without<ScoreOf>)>,
+    ) {
+        utility_span!("PickingPlugin::on_parent_removed_cleanup_choices", entity = ?trigger.entity());
+        let score_entity = trigger.entity();
+        if let Ok(parent) = parents.get(score_entity) {
+            remove_choice(commands, pickers, parent.get(), score_entity);
+        }
+    }
+
+    /// [`Observer`] that removes [`ActionFor`] score entities from their [`ScoreOf`] target [`Picker`]'s choices
+    /// when they're unlinked, since they're registered again wherever they're linked next.
+    pub fn on_score_of_removed_cleanup_choices(
+        trigger: Trigger<OnRemove, ScoreOf>,
+        commands: Commands,
+        pickers: Query<(&mut Picker, Has<RepickOnChoiceRemoved>)>,
+        score_ofs: Query<&ScoreOf, With<ActionFor>>,
+    ) {
+        utility_span!("PickingPlugin::on_score_of_removed_cleanup_choices", entity = ?trigger.entity());
+        let score_entity = trigger.entity();
+        if let Ok(score_of) = score_ofs.get(score_entity) {
+            remove_choice(commands, pickers, score_of.get(), score_entity);
+        }
+    }
+}
+
+/// Removes the given score entity from the given actor entity's [`Picker`] choices, if it's one of them.
+///
+/// If the removed choice was the last picked action and the actor has [`RepickOnChoiceRemoved`],
+/// [`RunPicking`] is triggered for that actor.
+fn remove_choice(
+    mut commands: Commands,
+    mut pickers: Query<(&mut Picker, Has<RepickOnChoiceRemoved>)>,
+    actor: Entity,
+    score_entity: Entity,
+) {
+    let Ok((mut picker, repick)) = pickers.get_mut(actor) else {
+        return;
+    };
+    let Some(&action) = picker.choices.get(&score_entity) else {
+        return;
+    };
+    picker.choices.remove(&score_entity);
+    if repick && Some(action) == picker.picked {
+        commands.trigger_targets(RunPicking, actor);
+    }
+}
+
+/// Triggers [`OnPick`] for the given actor entity, or queues it if its strategy is picked directly.
+fn trigger_picking(
+    target: Entity,
+    mut commands: Commands,
+    direct: Option<&mut DirectPicking>,
+    groups: &Query<(), With<PickerGroup>>,
+    entities: &Entities,
+    archetypes: &Archetypes,
+) {
+    if let Some(direct) = direct {
+        let handled = !groups.contains(target)
+            && entities.get(target).is_some_and(|location| {
+                archetypes[location.archetype_id]
+                    .components()
+                    .any(|component| direct.handles(component))
+            });
+        if handled {
+            if direct.queue(target) {
+                commands.add(DirectPicking::run_queued);
             }
+            return;
         }
     }
+    commands.trigger_targets(OnPick, target);
+}
+
+/// [`Bundle`] for the components that an actor entity needs to pick and perform actions:
+/// a [`Picker`] and a picker kind component such as [`Highest`] or [`FirstToScore`].
+/// The [`Picker`] adds a [`CurrentAction`] starting at its default action, if it has one.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct MyAction;
+/// #[derive(Component)]
+/// pub struct IdleAction;
+///
+/// let my_action = world.init_component::<MyAction>();
+/// let idle_action = world.init_component::<IdleAction>();
+///
+/// # let mut commands = world.commands();
+/// let scorer = commands.spawn(ScorerBundle::new(FixedScore::new(0.7))).id();
+///
+/// let actor = commands
+///     .spawn(ActorBundle::new(Picker::new(idle_action).with(scorer, my_action), Highest))
+///     .add_child(scorer)
+///     .id();
+/// # world.flush();
+/// # assert_eq!(idle_action, world.get::<CurrentAction>(actor).unwrap().0);
+/// ```
+#[derive(Bundle)]
+pub struct ActorBundle<P: Component> {
+    /// The [`Picker`] that configures the actions to pick from.
+    pub picker: Picker,
+    /// The picker kind component that decides how to pick, such as [`Highest`] or [`FirstToScore`].
+    pub picker_kind: P,
+}
+
+impl<P: Component> ActorBundle<P> {
+    /// Creates a new [`ActorBundle`] with the given [`Picker`] and picker kind.
+    ///
+    /// The [`Picker`] inserts a [`CurrentAction`] set to its default action, if it has one.
+    /// Actors with a [`Picker::without_default`] have no [`CurrentAction`] until their first pick is requested.
+    #[must_use]
+    pub fn new(picker: Picker, picker_kind: P) -> Self {
+        Self { picker, picker_kind }
+    }
 }
 
 /// [`Component`] for configuring the action to pick based on the scores of child entities.
-#[derive(Component, Reflect)]
+///
+/// A [`CurrentAction`] set to the default action is automatically inserted alongside it, if not already present.
+///
+/// Pickers created with [`Picker::without_default`] have no default action to fall back to.
+/// When none of their choices win, they pick nothing: [`Picker::picked`] and [`OnPicked::action`] are `None`,
+/// and [`RequestAction::picked`](crate::event::RequestAction::picked) keeps the actor's current action.
+#[derive(Reflect)]
 #[derive(Clone, PartialEq, Debug)]
 #[reflect(Component, PartialEq, Debug)]
 pub struct Picker {
-    /// The default action [`ComponentId`] to pick if the picker fails to pick an action.
-    pub default: ComponentId,
-    /// Map of child [`Score`](crate::scoring::Score) [`Entity`]s to action [`ComponentId`]s.
-    pub choices: EntityHashMap<ComponentId>,
-    /// The last action [`ComponentId`] picked by the picker.
-    pub picked: ComponentId,
+    /// The default action [`ComponentId`] to pick if the picker fails to pick an action, if any.
+    pub default: Option<ComponentId>,
+    /// Insertion-ordered map of child [`Score`] [`Entity`]s to action [`ComponentId`]s.
+    pub choices: PickerChoices,
+    /// The last action [`ComponentId`] picked by the picker, or `None` if nothing was picked.
+    pub picked: Option<ComponentId>,
+    /// The choice score [`Entity`] that won the last pick, or `None` if the default action (or nothing) was picked.
+    pub picked_score_entity: Option<Entity>,
+    /// The [`BestTarget`] of the choice score [`Entity`] that won the last pick, if any.
+    pub picked_target: Option<Entity>,
+    /// Choices added with [`Picker::with_action`] whose action [`ComponentId`]s are resolved on insertion.
+    #[reflect(ignore)]
+    pending: PendingChoices,
+}
+
+impl Component for Picker {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, _component| {
+            let Some(default) = world.get::<Picker>(entity).and_then(|picker| picker.default) else {
+                return;
+            };
+            world
+                .commands()
+                .add(InsertIfMissing::new(entity, CurrentAction(default)));
+        });
+        hooks.on_insert(|mut world, entity, _component| {
+            if world
+                .get::<Picker>(entity)
+                .is_some_and(|picker| !picker.pending.0.is_empty())
+            {
+                world.commands().add(ResolvePendingChoices(entity));
+            }
+        });
+    }
+}
+
+impl MapEntities for Picker {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.choices.map_entities(entity_mapper);
+        self.picked_score_entity = self.picked_score_entity.map(|entity| entity_mapper.map_entity(entity));
+        self.picked_target = self.picked_target.map(|entity| entity_mapper.map_entity(entity));
+        for (_, entity, _) in &mut self.pending.0 {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+    }
 }
 
 impl Picker {
@@ -84,9 +464,52 @@ impl Picker {
     #[must_use]
     pub fn new(default: ComponentId) -> Self {
         Self {
-            default,
-            choices: EntityHashMap::default(),
-            picked: default,
+            default: Some(default),
+            choices: PickerChoices::default(),
+            picked: Some(default),
+            picked_score_entity: None,
+            picked_target: None,
+            pending: PendingChoices::default(),
+        }
+    }
+
+    /// Creates a new [`Picker`] without a default action, which picks nothing if none of its choices win.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+    /// # let mut world = app.world_mut();
+    /// #[derive(Component)]
+    /// pub struct Attack;
+    ///
+    /// let attack = world.init_component::<Attack>();
+    /// let scorer = world.spawn(FixedScore::new(0.3)).id();
+    /// let actor = world
+    ///     .spawn((Picker::without_default().with(scorer, attack), FirstToScore::new(0.5)))
+    ///     .add_child(scorer)
+    ///     .id();
+    /// # world.flush();
+    /// # world.trigger(RunScoring);
+    /// # world.flush();
+    /// # world.trigger_targets(RunPicking, actor);
+    /// # world.flush();
+    /// assert_eq!(None, world.get::<Picker>(actor).unwrap().picked);
+    /// assert!(world.get::<CurrentAction>(actor).is_none());
+    /// ```
+    #[must_use]
+    pub fn without_default() -> Self {
+        Self {
+            default: None,
+            choices: PickerChoices::default(),
+            picked: None,
+            picked_score_entity: None,
+            picked_target: None,
+            pending: PendingChoices::default(),
         }
     }
 
@@ -97,36 +520,806 @@ impl Picker {
         self
     }
 
-    /// Grab the action [`ComponentId`] to pick based on the score [`Entity`] and the picker's choices.
-    pub fn pick(&mut self, score_entity: Option<Entity>) -> ComponentId {
+    /// Adds the action [`Component`] `A` to pick based on the provided score [`Entity`].
+    ///
+    /// The action's [`ComponentId`] is resolved when the [`Picker`] is inserted,
+    /// registering the action [`Component`] if needed. Its position in the iteration order is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+    /// # let mut world = app.world_mut();
+    /// #[derive(Component)]
+    /// pub struct Drinking;
+    /// #[derive(Component)]
+    /// pub struct Idle;
+    ///
+    /// let idle = world.init_component::<Idle>();
+    /// let scorer = world.spawn(FixedScore::new(0.7)).id();
+    /// let actor = world
+    ///     .spawn((Picker::new(idle).with_action::<Drinking>(scorer), Highest))
+    ///     .add_child(scorer)
+    ///     .id();
+    /// # world.flush();
+    /// # let drinking = world.init_component::<Drinking>();
+    /// # assert_eq!(Some(&drinking), world.get::<Picker>(actor).unwrap().choices.get(&scorer));
+    /// ```
+    #[must_use]
+    pub fn with_action<A: Component>(mut self, score_entity: Entity) -> Self {
+        let index = self.choices.len() + self.pending.0.len();
+        self.pending.0.push((index, score_entity, World::init_component::<A>));
+        self
+    }
+
+    /// Adds the [`PickerGroup`] entity as a choice, which picks a concrete action with its own [`Picker`]
+    /// whenever it's picked. See [`PickerGroup`] for more information.
+    #[must_use]
+    pub fn with_group(self, group: Entity) -> Self {
+        self.with_action::<PickerGroup>(group)
+    }
+
+    /// Adds an action [`ComponentId`] to pick based on the provided score [`Entity`],
+    /// with the given [`ChoiceBias`] applied to its score at pick time.
+    #[must_use]
+    pub fn with_bias(mut self, score_entity: Entity, action: ComponentId, bias: ChoiceBias) -> Self {
+        self.choices.insert(score_entity, action);
+        self.choices.set_bias(&score_entity, bias);
+        self
+    }
+
+    /// Grab the action [`ComponentId`] to pick based on the score [`Entity`] and the picker's choices,
+    /// falling back to the default action, if any. Clears the [`Picker::picked_target`].
+    pub fn pick(&mut self, score_entity: Option<Entity>) -> Option<ComponentId> {
+        self.pick_with_target(score_entity, None)
+    }
+
+    /// Like [`Picker::pick`], but also stores the entity the picked action targets,
+    /// which is dropped if the default action (or nothing) is picked.
+    pub fn pick_with_target(&mut self, score_entity: Option<Entity>, target: Option<Entity>) -> Option<ComponentId> {
+        let score_entity = score_entity.filter(|entity| self.choices.contains_key(entity));
         let action = score_entity
             .and_then(|entity| self.choices.get(&entity).copied())
-            .unwrap_or(self.default);
+            .or(self.default);
         self.picked = action;
+        self.picked_score_entity = score_entity;
+        self.picked_target = target.filter(|_| score_entity.is_some());
         action
     }
 
     /// Returns `true` if the given action is the default action.
     #[must_use]
     pub fn is_default(&self, action: ComponentId) -> bool {
-        action == self.default
+        self.default == Some(action)
     }
 
     /// Returs `true` if the last picked action is the default action.
     #[must_use]
     pub fn picked_default(&self) -> bool {
-        self.picked == self.default
+        self.picked.is_some_and(|picked| self.is_default(picked))
+    }
+}
+
+/// Strategy used by [`Picker`] kind [`Component`]s (such as [`Highest`] or [`FirstToScore`])
+/// to select the winning child [`Score`] entity of an actor.
+///
+/// Implementors are picked with [`pick_with_strategy`], and can be combined with [`PickerChain`].
+pub trait PickStrategy: Send + Sync + 'static {
+    /// Selects the winning [`Score`] entity among the actor's children, sorted by descending [`ScorePriority`],
+    /// or returns `None` to pick the [`Picker`]'s default action.
+    fn select(&mut self, actor: Entity, children: &[Entity], picker: &Picker, scores: &ChoiceScores) -> Option<Entity>;
+
+    /// Returns the [`Score`] thresholds this strategy applies, for [`PickReport`]s. Empty by default.
+    fn thresholds(&self) -> Vec<Score> {
+        Vec::new()
+    }
+}
+
+/// [`Observer`] for [`Picker`] kind [`Component`]s that implement [`PickStrategy`]:
+/// picks the action selected by the strategy and triggers [`OnPicked`].
+///
+/// If the selected choice is a [`PickerGroup`], the group picks instead, and [`OnPicked`] is triggered once it's resolved.
+#[allow(clippy::too_many_arguments)]
+pub fn pick_with_strategy<S: PickStrategy + Component>(
+    trigger: Trigger<OnPick>,
+    mut commands: Commands,
+    mut targets: Query<(Entity, &mut Picker, &mut S, Has<ReportPicks>)>,
+    mut groups: Query<&mut PickerGroup>,
+    scores: ChoiceScores,
+    hierarchy: ScoreHierarchy,
+    action_fors: Query<&ActionFor>,
+    mut children: Local<Vec<Entity>>,
+) {
+    utility_span!("pick_with_strategy", target = ?trigger.get_entity(), strategy = std::any::type_name::<S>());
+    if let Some(target) = trigger.get_entity() {
+        let Ok((target, picker, strategy, report)) = targets.get_mut(target) else {
+            return;
+        };
+        pick_for(
+            target,
+            commands.reborrow(),
+            picker,
+            strategy.into_inner(),
+            report,
+            &mut groups,
+            &scores,
+            &hierarchy,
+            &action_fors,
+            &mut children,
+        );
+    } else {
+        for (target, picker, strategy, report) in &mut targets {
+            pick_for(
+                target,
+                commands.reborrow(),
+                picker,
+                strategy.into_inner(),
+                report,
+                &mut groups,
+                &scores,
+                &hierarchy,
+                &action_fors,
+                &mut children,
+            );
+        }
+    }
+}
+
+/// Picks the action selected by the strategy for the given actor entity and triggers [`OnPicked`],
+/// or lets the selected [`PickerGroup`] pick instead. Shared by [`pick_with_strategy`] and the direct picking path.
+///
+/// The actor's children are sorted by [`ScorePriority`] into the given reusable buffer.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pick_for<S: PickStrategy>(
+    target: Entity,
+    mut commands: Commands,
+    mut picker: Mut<Picker>,
+    strategy: &mut S,
+    report: bool,
+    groups: &mut Query<&mut PickerGroup>,
+    scores: &ChoiceScores,
+    hierarchy: &ScoreHierarchy,
+    action_fors: &Query<&ActionFor>,
+    children: &mut Vec<Entity>,
+) {
+    children.clear();
+    children.extend(hierarchy.children(target));
+    sync_action_choices(&mut picker, children, action_fors);
+    scores.sort_by_priority(children);
+    let selected = strategy
+        .select(target, children, &picker, scores)
+        .filter(|score_entity| picker.choices.contains_key(score_entity));
+    if let Some(group_entity) = selected.filter(|&score_entity| groups.contains(score_entity)) {
+        // Let the group pick the concrete action
+        if let Ok(mut group) = groups.get_mut(group_entity) {
+            group.request(target, picker.picked);
+        }
+        commands.trigger_targets(OnPick, group_entity);
+        return;
+    }
+    let previous = picker.picked;
+    let action = picker.pick_with_target(selected, selected.and_then(|score_entity| scores.target(score_entity)));
+    commands.trigger_targets(
+        OnPicked {
+            action,
+            score_entity: selected,
+            score: selected.and_then(|score_entity| scores.score(score_entity)),
+            changed: action != previous,
+            target: picker.picked_target,
+            extra: false,
+        },
+        target,
+    );
+    if report {
+        commands.trigger_targets(pick_report(target, &picker, strategy.thresholds(), scores), target);
+    }
+}
+
+/// Registers the actor entity's [`ActionFor`] children that are missing from its [`Picker`]'s choices,
+/// and removes the [`ActionFor`] choices that aren't among its `children` anymore.
+///
+/// Catches reparenting with [`BuildChildren::set_parent`], which changes an existing [`Parent`] in place
+/// without triggering [`OnInsert`], so the choice isn't moved to the new parent's [`Picker`] until the next pick.
+/// Only touches the [`Picker`] when a choice actually changed, and doesn't allocate.
+pub(crate) fn sync_action_choices(picker: &mut Mut<Picker>, children: &[Entity], action_fors: &Query<&ActionFor>) {
+    for &child in children {
+        if let Ok(&ActionFor(action)) = action_fors.get(child) {
+            if picker.choices.get(&child) != Some(&action) {
+                picker.choices.insert(child, action);
+            }
+        }
+    }
+
+    let moved = |score_entity: Entity| action_fors.contains(score_entity) && !children.contains(&score_entity);
+    if picker.choices.keys().any(|&score_entity| moved(score_entity)) {
+        picker.choices.retain(|score_entity, _| !moved(score_entity));
+    }
+}
+
+/// Builds the [`PickReport`] for the given actor entity's last pick.
+pub(crate) fn pick_report(actor: Entity, picker: &Picker, thresholds: Vec<Score>, scores: &ChoiceScores) -> PickReport {
+    let candidates = picker
+        .choices
+        .iter()
+        .map(|(score_entity, action)| PickCandidate {
+            score_entity,
+            action,
+            score: scores.score(score_entity),
+            adjusted: scores
+                .iter(actor, &[score_entity], picker)
+                .next()
+                .map(|(_, score)| score),
+        })
+        .collect();
+    PickReport {
+        action: picker.picked,
+        score_entity: picker.picked_score_entity,
+        candidates,
+        thresholds,
+    }
+}
+
+/// [`SystemParam`] for [`Picker`] implementations that yields the [`Score`] of each child entity of an actor,
+/// adjusted by the choice's [`ChoiceBias`], the action's [`ActionCosts`],
+/// and the actor's pick-time modifiers (such as [`Sticky`] and [`PickJitter`]).
+///
+/// Choices whose action is unavailable (masked by an [`ActionMask`] or cooling down according to [`ActionCooldowns`])
+/// are skipped.
+///
+/// Children are yielded in the given order, which is [`ScorePriority`] order for the children
+/// passed to [`PickStrategy::select`], and the adjusted scores are not clamped, so they may exceed `1`.
+/// The stored [`Score`]s are never modified.
+#[derive(SystemParam)]
+pub struct ChoiceScores<'w, 's> {
+    scores: Query<'w, 's, &'static Score>,
+    priorities: PrioritizedChildren<'w, 's>,
+    sticky: Query<'w, 's, &'static Sticky>,
+    jitter: Query<'w, 's, &'static PickJitter>,
+    cooldowns: Query<'w, 's, &'static ActionCooldowns>,
+    time: Option<Res<'w, Time>>,
+    masks: Query<'w, 's, &'static ActionMask>,
+    costs: Query<'w, 's, &'static ActionCosts>,
+    global_costs: Option<Res<'w, ActionCosts>>,
+    rngs: UtilityRngs<'w, 's>,
+    targets: Query<'w, 's, &'static BestTarget>,
+}
+
+impl ChoiceScores<'_, '_> {
+    /// Returns an iterator over the child [`Score`] entities of the given actor and their adjusted score values.
+    ///
+    /// Adjusted scores that aren't finite, such as after applying a NaN cost, are treated as 0.
+    pub fn iter<'a>(
+        &'a self,
+        actor: Entity,
+        children: &'a [Entity],
+        picker: &'a Picker,
+    ) -> impl Iterator<Item = (Entity, f32)> + 'a {
+        let sticky = self.sticky.get(actor).ok();
+        let jitter = self.jitter.get(actor).ok().map(|jitter| (jitter, self.rng(actor)));
+        let now = self.time.as_ref().map_or(Duration::ZERO, |time| time.elapsed());
+        let costs = self.costs.get(actor).ok().or(self.global_costs.as_deref());
+
+        children.iter().filter_map(move |&score_entity| {
+            let score = self.scores.get(score_entity).ok()?.get();
+            let mut score = picker.choices.bias(&score_entity).apply(score);
+            let action = picker.choices.get(&score_entity).copied();
+
+            if let Some(action) = action {
+                if !self.is_available(actor, action) {
+                    return None;
+                }
+                if let Some(costs) = costs {
+                    score = costs.apply(action, score);
+                }
+            }
+
+            if let Some(sticky) = sticky {
+                if Some(score_entity) == picker.picked_score_entity {
+                    score += sticky.margin().get();
+                }
+            }
+
+            if let Some((jitter, rng)) = jitter {
+                score += jitter.offset(rng, actor, score_entity, now);
+            }
+
+            if !score.is_finite() {
+                // A NaN or infinite adjustment would break the ordering of choices
+                score = 0.;
+            }
+
+            Some((score_entity, score))
+        })
+    }
+
+    /// Returns the stored [`Score`] of the given score entity, without any pick-time modifiers applied.
+    #[must_use]
+    pub fn score(&self, score_entity: Entity) -> Option<Score> {
+        self.scores.get(score_entity).ok().copied()
+    }
+
+    /// Returns the [`BestTarget`] of the given score entity, if it has one.
+    #[must_use]
+    pub fn target(&self, score_entity: Entity) -> Option<Entity> {
+        self.targets.get(score_entity).ok().map(|target| target.0)
+    }
+
+    /// Returns the [`UtilityRng`] to draw from for the given actor entity. See [`UtilityRngs::get`].
+    #[must_use]
+    pub fn rng(&self, actor: Entity) -> &UtilityRng {
+        self.rngs.get(Some(actor))
+    }
+
+    /// Sorts the given sibling score entities in place by descending [`ScorePriority`],
+    /// keeping the given order for equal priorities.
+    pub fn sort_by_priority(&self, children: &mut [Entity]) {
+        self.priorities.sort(children);
+    }
+
+    /// Returns the [`ScorePriority`] of the given score entity.
+    #[must_use]
+    pub fn priority(&self, score_entity: Entity) -> ScorePriority {
+        self.priorities.priority(score_entity)
+    }
+
+    /// Returns `true` if the given action can currently be picked by the given actor.
+    #[must_use]
+    pub fn is_available(&self, actor: Entity, action: ComponentId) -> bool {
+        if self.masks.get(actor).is_ok_and(|mask| mask.is_masked(action)) {
+            return false;
+        }
+
+        let now = self.time.as_ref().map(|time| time.elapsed());
+        match (self.cooldowns.get(actor), now) {
+            (Ok(cooldowns), Some(now)) => !cooldowns.is_cooling_down(action, now),
+            _ => true,
+        }
+    }
+}
+
+/// Insertion-ordered map of [`Score`] [`Entity`]s to action [`ComponentId`]s, used by [`Picker::choices`].
+///
+/// Unlike a hash map, iteration order is stable across runs and platforms,
+/// which keeps picking (including ties and [`PickRandom`] with a seeded [`UtilityRng`]) reproducible
+/// for replays and lockstep networking. Lookups by score [`Entity`] go through an index,
+/// so they don't get slower with the number of choices.
+///
+/// [`PickRandom`]: crate::picking::PickRandom
+#[derive(Reflect)]
+#[derive(Clone, Debug, Default)]
+#[reflect(PartialEq, Debug, Default)]
+pub struct PickerChoices {
+    /// The choices, in iteration order.
+    entries: Vec<(Entity, ComponentId, ChoiceBias)>,
+    /// The position of each score entity in `entries`.
+    ///
+    /// Not reflected, so it's empty for choices created through reflection, such as when loading a scene.
+    /// Lookups fall back to searching `entries` until it's rebuilt by the next change.
+    #[reflect(ignore)]
+    index: EntityHashMap<usize>,
+}
+
+impl PickerChoices {
+    /// Inserts the action [`ComponentId`] for the given score [`Entity`], returning the previous action if any.
+    ///
+    /// Replacing an existing entry keeps its position in the iteration order and its [`ChoiceBias`].
+    pub fn insert(&mut self, score_entity: Entity, action: ComponentId) -> Option<ComponentId> {
+        if let Some(position) = self.position(&score_entity) {
+            Some(std::mem::replace(&mut self.entries[position].1, action))
+        } else {
+            self.reindex_from(self.entries.len());
+            self.index.insert(score_entity, self.entries.len());
+            self.entries.push((score_entity, action, ChoiceBias::default()));
+            None
+        }
+    }
+
+    /// Inserts the action [`ComponentId`] for the given score [`Entity`] at the given position in the iteration order,
+    /// or replaces it in place if it's already a choice.
+    pub(crate) fn insert_at(&mut self, index: usize, score_entity: Entity, action: ComponentId) {
+        if self.insert(score_entity, action).is_none() {
+            let entry = self.entries.pop().expect("just inserted");
+            let index = index.min(self.entries.len());
+            self.entries.insert(index, entry);
+            self.reindex_from(index);
+        }
+    }
+
+    /// Removes the given score [`Entity`], returning its action if any, preserving the order of the other entries.
+    pub fn remove(&mut self, score_entity: &Entity) -> Option<ComponentId> {
+        let position = self.position(score_entity)?;
+        let (_, action, _) = self.entries.remove(position);
+        self.index.remove(score_entity);
+        self.reindex_from(position);
+        Some(action)
+    }
+
+    /// Returns the action [`ComponentId`] for the given score [`Entity`], if any.
+    #[must_use]
+    pub fn get(&self, score_entity: &Entity) -> Option<&ComponentId> {
+        self.position(score_entity).map(|position| &self.entries[position].1)
+    }
+
+    /// Returns the [`ChoiceBias`] for the given score [`Entity`], or the neutral default if it isn't a choice.
+    #[must_use]
+    pub fn bias(&self, score_entity: &Entity) -> ChoiceBias {
+        self.position(score_entity)
+            .map(|position| self.entries[position].2)
+            .unwrap_or_default()
+    }
+
+    /// Sets the [`ChoiceBias`] for the given score [`Entity`], returning `false` if it isn't a choice.
+    pub fn set_bias(&mut self, score_entity: &Entity, bias: ChoiceBias) -> bool {
+        let Some(position) = self.position(score_entity) else {
+            return false;
+        };
+        self.entries[position].2 = bias;
+        true
+    }
+
+    /// Returns `true` if the given score [`Entity`] is a choice.
+    #[must_use]
+    pub fn contains_key(&self, score_entity: &Entity) -> bool {
+        self.position(score_entity).is_some()
+    }
+
+    /// Returns an iterator over the score [`Entity`]s, in insertion order.
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &Entity> + DoubleEndedIterator {
+        self.entries.iter().map(|(entity, _, _)| entity)
+    }
+
+    /// Returns an iterator over the action [`ComponentId`]s, in insertion order.
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &ComponentId> + DoubleEndedIterator {
+        self.entries.iter().map(|(_, action, _)| action)
+    }
+
+    /// Returns an iterator over the score [`Entity`]s and their action [`ComponentId`]s, in insertion order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Entity, ComponentId)> + DoubleEndedIterator + '_ {
+        self.entries.iter().map(|&(entity, action, _)| (entity, action))
+    }
+
+    /// Returns the number of choices.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no choices.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all choices.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.index.clear();
+    }
+
+    /// Retains only the choices for which the predicate returns `true`, preserving their order.
+    pub fn retain(&mut self, mut f: impl FnMut(Entity, ComponentId) -> bool) {
+        let len = self.entries.len();
+        self.entries.retain(|&(entity, action, _)| f(entity, action));
+        if self.entries.len() != len {
+            self.index.clear();
+            self.reindex_from(0);
+        }
+    }
+
+    /// Returns the position of the given score [`Entity`] in the iteration order, if it's a choice.
+    fn position(&self, score_entity: &Entity) -> Option<usize> {
+        if self.index.len() == self.entries.len() {
+            self.index.get(score_entity).copied()
+        } else {
+            // The index is out of date, such as after being created through reflection.
+            self.entries.iter().position(|(entity, _, _)| entity == score_entity)
+        }
+    }
+
+    /// Updates the index for the entries from the given position onwards,
+    /// or rebuilds it entirely if it's out of date.
+    fn reindex_from(&mut self, start: usize) {
+        let start = if self.index.len() == self.entries.len() {
+            start
+        } else {
+            self.index.clear();
+            0
+        };
+        for (position, (entity, _, _)) in self.entries.iter().enumerate().skip(start) {
+            self.index.insert(*entity, position);
+        }
+    }
+}
+
+impl PartialEq for PickerChoices {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl Extend<(Entity, ComponentId)> for PickerChoices {
+    fn extend<T: IntoIterator<Item = (Entity, ComponentId)>>(&mut self, iter: T) {
+        for (score_entity, action) in iter {
+            self.insert(score_entity, action);
+        }
+    }
+}
+
+impl FromIterator<(Entity, ComponentId)> for PickerChoices {
+    fn from_iter<T: IntoIterator<Item = (Entity, ComponentId)>>(iter: T) -> Self {
+        let mut choices = Self::default();
+        choices.extend(iter);
+        choices
+    }
+}
+
+impl MapEntities for PickerChoices {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for (entity, _, _) in &mut self.entries {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+        self.index.clear();
+        self.reindex_from(0);
+    }
+}
+
+/// Per-choice multiplier and additive bias, applied to a [`Picker`] choice's score at pick time.
+///
+/// This allows tuning per-actor preferences (e.g. "this guard likes patrolling")
+/// without duplicating score trees. The stored [`Score`] is unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Patrol;
+/// #[derive(Component)]
+/// pub struct Rest;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let patrol = world.init_component::<Patrol>();
+/// let rest = world.init_component::<Rest>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let patrol_scorer = world.spawn(FixedScore::new(0.5)).id();
+/// let rest_scorer = world.spawn(FixedScore::new(0.6)).id();
+/// let actor = world
+///     .spawn((
+///         Picker::new(idle)
+///             // This guard really likes patrolling.
+///             .with_bias(patrol_scorer, patrol, ChoiceBias::new(1.5, 0.))
+///             .with(rest_scorer, rest),
+///         Highest,
+///     ))
+///     .push_children(&[patrol_scorer, rest_scorer])
+///     .id();
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(patrol), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[reflect(PartialEq, Debug, Default)]
+pub struct ChoiceBias {
+    /// The multiplier applied to the score.
+    pub multiplier: f32,
+    /// The bias added to the score, after multiplying.
+    pub bias: f32,
+}
+
+impl ChoiceBias {
+    /// The neutral [`ChoiceBias`] that leaves scores unchanged.
+    pub const NONE: ChoiceBias = ChoiceBias {
+        multiplier: 1.,
+        bias: 0.,
+    };
+
+    /// Creates a new [`ChoiceBias`] with the given multiplier and additive bias.
+    #[must_use]
+    pub fn new(multiplier: f32, bias: f32) -> Self {
+        Self { multiplier, bias }
+    }
+
+    /// Applies the multiplier and bias to the given score value.
+    #[must_use]
+    pub fn apply(&self, score: f32) -> f32 {
+        score * self.multiplier + self.bias
+    }
+}
+
+impl Default for ChoiceBias {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// Marker [`Component`] for actor entities that should pick again when the [`Score`] entity of their
+/// last picked action is removed from the [`Picker`]'s choices, for example because it was despawned.
+///
+/// Without this, the actor keeps its last picked action until the next [`RunPicking`].
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct RepickOnChoiceRemoved;
+
+/// Marker [`Component`] for actor entities that should trigger a [`PickReport`] after each pick,
+/// listing every choice's score alongside the thresholds applied and the winner.
+///
+/// Only [`Picker`] kinds that implement [`PickStrategy`] report, including with the [`DirectPickingPlugin`].
+/// A [`PickerGroup`] reports its own picks if it has this component too.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ReportPicks;
+
+/// [`Component`] that marks a [`Score`] entity as a choice for the given action
+/// [`ComponentId`], automatically registering it into its parent's [`Picker`] choices.
+///
+/// This happens both when the component is inserted and when the entity is (re)parented,
+/// which makes growing score trees at runtime much less error-prone than mutating the [`Picker`] by hand.
+/// Only direct children of the actor entity are registered, since those are the ones [`Picker`]s pick between.
+/// Reparenting an entity that already has a parent is picked up the next time either actor picks.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct MyAction;
+/// #[derive(Component)]
+/// pub struct IdleAction;
+///
+/// let my_action = world.init_component::<MyAction>();
+/// let idle_action = world.init_component::<IdleAction>();
+///
+/// # let mut commands = world.commands();
+/// let actor = commands.spawn((Picker::new(idle_action), Highest)).id();
+///
+/// // No need to add the choice to the Picker manually.
+/// let scorer = commands
+///     .spawn((ActionFor(my_action), FixedScore::new(0.7)))
+///     .set_parent(actor)
+///     .id();
+/// # world.flush();
+/// # assert_eq!(Some(&my_action), world.get::<Picker>(actor).unwrap().choices.get(&scorer));
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct ActionFor(pub ComponentId);
+
+impl Component for ActionFor {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_insert(|mut world, entity, _component| {
+            world.commands().add(RegisterChoice(entity));
+        });
+    }
+}
+
+/// [`Command`] that registers an [`ActionFor`] score entity into its parent's [`Picker`] choices.
+struct RegisterChoice(Entity);
+
+impl Command for RegisterChoice {
+    fn apply(self, world: &mut World) {
+        utility_span!("RegisterChoice::apply", entity = ?self.0);
+        let Some(&ActionFor(action)) = world.get::<ActionFor>(self.0) else {
+            // The entity was despawned or the component was removed in the meantime.
+            return;
+        };
+
+        let Some(parent) = score_parent(world, self.0) else {
+            return;
+        };
+        if let Some(mut picker) = world.get_mut::<Picker>(parent) {
+            picker.choices.insert(self.0, action);
+        }
+    }
+}
+
+/// Choices added with [`Picker::with_action`] that are waiting for their action [`ComponentId`]s to be resolved,
+/// alongside their position in the [`PickerChoices`] iteration order.
+#[derive(Clone, Default)]
+struct PendingChoices(Vec<(usize, Entity, ResolveAction)>);
+
+/// Function that resolves (registering if necessary) the [`ComponentId`] of a typed action.
+type ResolveAction = fn(&mut World) -> ComponentId;
+
+impl PartialEq for PendingChoices {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|((a_index, a_entity, _), (b_index, b_entity, _))| a_index == b_index && a_entity == b_entity)
+    }
+}
+
+impl std::fmt::Debug for PendingChoices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(_, score_entity, _)| score_entity))
+            .finish()
+    }
+}
+
+/// [`Command`] that resolves the [`PendingChoices`] of a [`Picker`] into its [`PickerChoices`].
+struct ResolvePendingChoices(Entity);
+
+impl Command for ResolvePendingChoices {
+    fn apply(self, world: &mut World) {
+        let Some(mut picker) = world.get_mut::<Picker>(self.0) else {
+            // The entity was despawned or the component was removed in the meantime.
+            return;
+        };
+        let pending = std::mem::take(&mut picker.pending);
+        if pending.0.is_empty() {
+            return;
+        }
+
+        let resolved: Vec<_> = pending
+            .0
+            .into_iter()
+            .map(|(index, score_entity, init)| (index, score_entity, init(world)))
+            .collect();
+
+        let Some(mut picker) = world.get_mut::<Picker>(self.0) else {
+            return;
+        };
+        for (index, score_entity, action) in resolved {
+            picker.choices.insert_at(index, score_entity, action);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use bevy::prelude::*;
+    use std::time::Duration;
+
+    use bevy::{
+        diagnostic::DiagnosticsStore,
+        ecs::{schedule::ScheduleLabel, system::RunSystemOnce},
+        prelude::*,
+    };
 
     use crate::{
-        event::{RunPicking, RunScoring},
-        picking::{FirstToScore, Highest, Picker},
+        acting::{ActionState, CurrentAction, CurrentTarget},
+        builder::SpawnActorExt,
+        diagnostics::AiDiagnosticsPlugin,
+        event::{
+            OnActionInitiated, OnPicked, PickReport, RequestAction, RunPicking, RunPickingMany, RunScoring,
+            RunScoringMany,
+        },
+        picking::{
+            ActionCosts, ActionFor, ActorBundle, ChoiceScores, CostMode, DirectPicking, DirectPickingPlugin,
+            FirstToScore, Highest, HighestAboveThreshold, PickJitter, PickMultiple, PickedActions, Picker,
+            PickerChoices, PickerGroup, RepickOnChoiceRemoved, ReportPicks, Sticky,
+        },
+        rng::{RngComponent, UtilityRng},
         scoring::{FixedScore, Score},
+        targeting::BestTarget,
+        AiDisabled, AiPaused, AiTickRate, WorldUtilityExt,
     };
 
     #[derive(Component)]
@@ -156,7 +1349,7 @@ mod tests {
         commands.trigger_targets(RunPicking, actor);
         world.flush();
 
-        assert_eq!(my_action, world.get::<Picker>(actor).unwrap().picked);
+        assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
     }
 
     #[test]
@@ -180,6 +1373,840 @@ mod tests {
         commands.trigger_targets(RunPicking, actor);
         world.flush();
 
-        assert_eq!(my_action, world.get::<Picker>(actor).unwrap().picked);
+        assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+    }
+
+    #[test]
+    fn on_picked_payload() {
+        #[derive(Resource, Default)]
+        struct LastPicked(Option<OnPicked>);
+
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime)
+            .init_resource::<LastPicked>()
+            .observe(|trigger: Trigger<OnPicked>, mut last: ResMut<LastPicked>| {
+                last.0 = Some(*trigger.event());
+            });
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let mut commands = world.commands();
+
+        let scorer = commands.spawn((FixedScore::new(0.7), Score::default())).id();
+        let actor = commands
+            .spawn((Picker::new(idle_action).with(scorer, my_action), Highest))
+            .add_child(scorer)
+            .id();
+
+        commands.trigger_targets(RunScoring, scorer);
+        commands.trigger_targets(RunPicking, actor);
+        world.flush();
+
+        let picked = world.resource::<LastPicked>().0.unwrap();
+        assert_eq!(Some(my_action), picked.action);
+        assert_eq!(Some(scorer), picked.score_entity);
+        assert_eq!(Some(Score::new(0.7)), picked.score);
+        assert!(picked.changed);
+
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+
+        assert!(!world.resource::<LastPicked>().0.unwrap().changed);
+    }
+
+    #[test]
+    fn without_default_keeps_current_action() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+
+        let scorer = world.spawn((FixedScore::new(0.3), Score::default())).id();
+        let actor = world
+            .spawn((
+                Picker::without_default().with(scorer, my_action),
+                FirstToScore::new(0.5),
+                CurrentAction(my_action),
+            ))
+            .add_child(scorer)
+            .id();
+        world.flush();
+
+        world.trigger_targets(RunScoring, scorer);
+
+        world.flush();
+
+        world.trigger_targets(RunPicking, actor);
+        world.trigger_targets(RequestAction::picked(), actor);
+        world.flush();
+
+        assert_eq!(None, world.get::<Picker>(actor).unwrap().picked);
+        assert_eq!(my_action, world.get::<CurrentAction>(actor).unwrap().0);
+    }
+
+    #[test]
+    fn picked_target_is_carried_to_current_action() {
+        #[derive(Resource, Default)]
+        struct Initiated(Vec<Option<Entity>>);
+
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        app.init_resource::<Initiated>();
+        let world = app.world_mut();
+        world.observe(
+            |trigger: Trigger<OnActionInitiated>, mut initiated: ResMut<Initiated>| {
+                initiated.0.push(trigger.event().target);
+            },
+        );
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let scorer = world.spawn((FixedScore::new(0.7), BestTarget(first))).id();
+        let actor = world
+            .spawn((Picker::new(idle_action).with(scorer, my_action), Highest))
+            .add_child(scorer)
+            .id();
+        world.flush();
+
+        world.trigger_targets(RunScoring, actor);
+        world.flush();
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+        world.trigger_targets(RequestAction::picked(), actor);
+        world.flush();
+        assert_eq!(Some(first), world.get::<Picker>(actor).unwrap().picked_target);
+        assert_eq!(Some(&CurrentAction(my_action)), world.get::<CurrentAction>(actor));
+        assert_eq!(Some(&CurrentTarget(first)), world.get::<CurrentTarget>(actor));
+
+        // Picking the same action and target again doesn't re-initiate it.
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+        world.trigger_targets(RequestAction::picked(), actor);
+        world.flush();
+        assert_eq!(vec![Some(first)], world.resource::<Initiated>().0);
+
+        // Picking the same action for another target does.
+        world.entity_mut(scorer).insert(BestTarget(second));
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+        world.trigger_targets(RequestAction::picked(), actor);
+        world.flush();
+        assert_eq!(Some(&CurrentAction(my_action)), world.get::<CurrentAction>(actor));
+        assert_eq!(Some(&CurrentTarget(second)), world.get::<CurrentTarget>(actor));
+        assert_eq!(vec![Some(first), Some(second)], world.resource::<Initiated>().0);
+    }
+
+    #[test]
+    fn disabled_actor_is_paused_and_not_picked_for() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = world
+            .spawn((Picker::new(idle_action).with(scorer, my_action), Highest))
+            .add_child(scorer)
+            .id();
+        world.trigger_targets(RequestAction::action(idle_action), actor);
+        world.flush();
+        assert_eq!(Some(&ActionState::Running), world.get::<ActionState>(actor));
+
+        world.entity_mut(actor).insert(AiDisabled);
+        world.flush();
+        assert_eq!(Some(&ActionState::Paused), world.get::<ActionState>(actor));
+
+        world.trigger(RunScoring);
+        world.trigger(RunPicking);
+        world.flush();
+        assert_eq!(0., world.get::<Score>(scorer).unwrap().get());
+        assert_eq!(Some(idle_action), world.get::<Picker>(actor).unwrap().picked);
+
+        world.insert_resource(AiPaused);
+        world.entity_mut(actor).remove::<AiDisabled>();
+        world.flush();
+        assert_eq!(Some(&ActionState::Running), world.get::<ActionState>(actor));
+
+        world.trigger_targets(RunScoring, actor);
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+        assert_eq!(Some(idle_action), world.get::<Picker>(actor).unwrap().picked);
+
+        world.remove_resource::<AiPaused>();
+        world.trigger(RunScoring);
+        world.trigger(RunPicking);
+        world.flush();
+        assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+    }
+
+    #[test]
+    fn tick_rate_actor_is_skipped_between_ticks() {
+        let mut app = App::new();
+        app.add_plugins((
+            crate::ObservedUtilityPlugins::TurnBased,
+            crate::RealtimeLifecyclePlugin {
+                score_pick_perform_in: Update.intern(),
+            },
+        ));
+        app.init_resource::<Time>();
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let spawn_actor = |world: &mut World| {
+            let scorer = world.spawn(FixedScore::new(0.7)).id();
+            let actor = world
+                .spawn((
+                    Picker::new(idle_action).with(scorer, my_action),
+                    HighestAboveThreshold::new(0.5),
+                ))
+                .add_child(scorer)
+                .id();
+            (actor, scorer)
+        };
+        let (rated, rated_scorer) = spawn_actor(world);
+        let (unrated, unrated_scorer) = spawn_actor(world);
+        world.entity_mut(rated).insert(AiTickRate::new(Duration::from_secs(1)));
+        let unowned = world.spawn(FixedScore::new(0.4)).id();
+
+        // Rated actors think on the first tick.
+        app.update();
+        let world = app.world_mut();
+        for actor in [rated, unrated] {
+            assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+        }
+        assert_eq!(0.4, world.get::<Score>(unowned).unwrap().get());
+
+        world.entity_mut(rated_scorer).insert(FixedScore::new(0.2));
+        world.entity_mut(unrated_scorer).insert(FixedScore::new(0.2));
+        world.entity_mut(unowned).insert(FixedScore::new(0.1));
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(500));
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(0.7, world.get::<Score>(rated_scorer).unwrap().get());
+        assert_eq!(Some(my_action), world.get::<Picker>(rated).unwrap().picked);
+        assert_eq!(Some(idle_action), world.get::<Picker>(unrated).unwrap().picked);
+        assert_eq!(0.1, world.get::<Score>(unowned).unwrap().get());
+
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(500));
+        app.update();
+        let world = app.world();
+        assert_eq!(0.2, world.get::<Score>(rated_scorer).unwrap().get());
+        assert_eq!(Some(idle_action), world.get::<Picker>(rated).unwrap().picked);
+    }
+
+    #[test]
+    fn run_many_scores_and_picks_only_given_actors() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let spawn_actor = |world: &mut World| {
+            let scorer = world.spawn(FixedScore::new(0.7)).id();
+            world
+                .spawn((Picker::new(idle_action).with(scorer, my_action), Highest))
+                .add_child(scorer)
+                .id()
+        };
+        let active = [spawn_actor(world), spawn_actor(world)];
+        let waiting = spawn_actor(world);
+        world.flush();
+
+        world.trigger(RunScoringMany(active.to_vec()));
+        world.trigger(RunPickingMany(active.to_vec()));
+        world.flush();
+        for actor in active {
+            assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+        }
+        assert_eq!(Some(idle_action), world.get::<Picker>(waiting).unwrap().picked);
+    }
+
+    #[test]
+    fn report_picks_lists_candidates_and_thresholds() {
+        #[derive(Resource, Default)]
+        struct Reports(Vec<PickReport>);
+
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased)
+            .init_resource::<Reports>()
+            .observe(|trigger: Trigger<PickReport>, mut reports: ResMut<Reports>| {
+                reports.0.push(trigger.event().clone());
+            });
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let low = world.spawn(FixedScore::new(0.3)).id();
+        let high = world.spawn(FixedScore::new(0.7)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle_action).with(low, idle_action).with(high, my_action),
+                FirstToScore::new(0.5),
+                ReportPicks,
+            ))
+            .push_children(&[low, high])
+            .id();
+        world.flush();
+
+        world.trigger_targets(RunScoring, actor);
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+
+        let reports = &world.resource::<Reports>().0;
+        assert_eq!(1, reports.len());
+        let report = &reports[0];
+        assert_eq!(Some(my_action), report.action);
+        assert_eq!(Some(high), report.score_entity);
+        assert_eq!(
+            vec![0.5],
+            report.thresholds.iter().map(|score| score.get()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(low, Some(0.3)), (high, Some(0.7))],
+            report
+                .candidates
+                .iter()
+                .map(|candidate| (candidate.score_entity, candidate.adjusted))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn pick_multiple_picks_with_strategy() {
+        #[derive(Component)]
+        struct EatAction;
+
+        #[derive(Resource, Default)]
+        struct Reports(Vec<PickReport>);
+
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased)
+            .init_resource::<Reports>()
+            .observe(|trigger: Trigger<PickReport>, mut reports: ResMut<Reports>| {
+                reports.0.push(trigger.event().clone());
+            });
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let eat_action = world.init_component::<EatAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let my_scorer = world.spawn(FixedScore::new(0.8)).id();
+        let group = world
+            .spawn((
+                PickerGroup::default(),
+                FixedScore::new(0.8),
+                Picker::without_default().with(my_scorer, my_action),
+                Highest,
+            ))
+            .push_children(&[my_scorer])
+            .id();
+        let eat_scorer = world.spawn(FixedScore::new(0.5)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle_action).with_group(group).with(eat_scorer, eat_action),
+                PickMultiple::new(2),
+            ))
+            .push_children(&[group, eat_scorer])
+            .id();
+        let reported_scorer = world.spawn(FixedScore::new(0.5)).id();
+        let reported = world
+            .spawn((
+                Picker::new(idle_action).with(reported_scorer, eat_action),
+                PickMultiple::new(2).with_threshold(0.2),
+                ReportPicks,
+            ))
+            .push_children(&[reported_scorer])
+            .id();
+        world.flush();
+
+        world.trigger(RunScoring);
+        world.trigger(RunPicking);
+        world.flush();
+
+        // The group picks the concrete action, with the other compatible action picked alongside it
+        assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+        assert_eq!(&[my_action, eat_action], &*world.get::<PickedActions>(actor).unwrap().0);
+        assert_eq!(&[eat_action], &*world.get::<PickedActions>(reported).unwrap().0);
+        let reports = &world.resource::<Reports>().0;
+        assert_eq!(1, reports.len());
+        assert_eq!(Some(reported_scorer), reports[0].score_entity);
+        assert_eq!(
+            vec![0.2],
+            reports[0]
+                .thresholds
+                .iter()
+                .map(|score| score.get())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn pick_multiple_counts_one_pick_per_pass() {
+        #[derive(Component)]
+        struct EatAction;
+
+        let mut app = App::new();
+        app.add_plugins((crate::ObservedUtilityPlugins::TurnBased, AiDiagnosticsPlugin));
+        #[cfg(feature = "recording")]
+        app.add_plugins(crate::recording::DecisionRecorderPlugin);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let eat_action = world.init_component::<EatAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let my_scorer = world.spawn(FixedScore::new(0.8)).id();
+        let eat_scorer = world.spawn(FixedScore::new(0.5)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle_action)
+                    .with(my_scorer, my_action)
+                    .with(eat_scorer, eat_action),
+                PickMultiple::new(2),
+            ))
+            .push_children(&[my_scorer, eat_scorer])
+            .id();
+        world.flush();
+
+        world.trigger(RunScoring);
+        world.trigger(RunPicking);
+        world.flush();
+        assert_eq!(&[my_action, eat_action], &*world.get::<PickedActions>(actor).unwrap().0);
+
+        app.update();
+        let store = app.world().resource::<DiagnosticsStore>();
+        let picks = store
+            .get(&AiDiagnosticsPlugin::PICKS)
+            .and_then(|diagnostic| diagnostic.value());
+        assert_eq!(Some(1.), picks);
+
+        #[cfg(feature = "recording")]
+        {
+            use crate::recording::{DecisionEvent, DecisionRecorder};
+
+            let log = app.world().resource::<DecisionRecorder>().log();
+            let picked: Vec<_> = log
+                .0
+                .iter()
+                .filter_map(|record| match &record.event {
+                    DecisionEvent::Picked { score_entity, .. } => Some(*score_entity),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(vec![Some(my_scorer)], picked);
+        }
+    }
+
+    #[test]
+    fn direct_picking_writes_picked() {
+        #[derive(Resource, Default)]
+        struct Picked(Vec<Entity>);
+
+        let mut app = App::new();
+        app.add_plugins((crate::ObservedUtilityPlugins::RealTime, DirectPickingPlugin))
+            .init_resource::<Picked>()
+            .observe(|trigger: Trigger<OnPicked>, mut picked: ResMut<Picked>| {
+                picked.0.push(trigger.entity());
+            });
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = world
+            .spawn((Picker::new(idle_action).with(scorer, my_action), Highest))
+            .add_child(scorer)
+            .id();
+        world.flush();
+
+        world.trigger(RunScoring);
+        world.trigger(RunPicking);
+        assert!(world.resource::<DirectPicking>().is_queued(actor));
+
+        world.flush();
+        assert!(!world.resource::<DirectPicking>().is_queued(actor));
+        assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+        assert_eq!(vec![actor], world.resource::<Picked>().0);
+
+        world.run_schedule(FixedPostUpdate);
+        assert_eq!(my_action, world.get::<CurrentAction>(actor).unwrap().0);
+    }
+
+    #[test]
+    fn direct_picking_turn_based() {
+        let mut app = App::new();
+        app.add_plugins((crate::ObservedUtilityPlugins::TurnBased, DirectPickingPlugin));
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = world
+            .spawn((Picker::new(idle_action).with(scorer, my_action), FirstToScore::new(0.5)))
+            .add_child(scorer)
+            .id();
+
+        assert_eq!(Some(my_action), world.score_and_pick(actor));
+    }
+
+    #[test]
+    fn actors_without_default_have_no_current_action() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+
+        let mut commands = world.commands();
+        let bundled = commands
+            .spawn(ActorBundle::new(Picker::without_default(), Highest))
+            .id();
+        let built = commands
+            .spawn_actor(Highest, None, |tree| {
+                tree.choice(my_action, FixedScore::new(0.7));
+            })
+            .id();
+        world.flush();
+
+        assert!(world.get::<CurrentAction>(bundled).is_none());
+        assert!(world.get::<CurrentAction>(built).is_none());
+        let picker = world.get::<Picker>(built).unwrap();
+        assert_eq!(None, picker.default);
+        assert_eq!(1, picker.choices.len());
+
+        assert_eq!(Some(my_action), world.score_and_pick(built));
+    }
+
+    #[test]
+    fn sticky_margin_only_applies_to_the_incumbent_choice() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let incumbent = world.spawn(FixedScore::new(0.5)).id();
+        let other = world.spawn(FixedScore::new(0.4)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle_action)
+                    .with(incumbent, my_action)
+                    .with(other, my_action),
+                Highest,
+                Sticky::new(0.2),
+            ))
+            .push_children(&[incumbent, other])
+            .id();
+
+        world.score_and_pick(actor);
+        assert_eq!(Some(incumbent), world.get::<Picker>(actor).unwrap().picked_score_entity);
+
+        // The other choice for the same action doesn't get the margin, so it doesn't win yet.
+        world.entity_mut(other).insert(FixedScore::new(0.6));
+        world.score_and_pick(actor);
+        assert_eq!(Some(incumbent), world.get::<Picker>(actor).unwrap().picked_score_entity);
+
+        world.entity_mut(other).insert(FixedScore::new(0.8));
+        world.score_and_pick(actor);
+        assert_eq!(Some(other), world.get::<Picker>(actor).unwrap().picked_score_entity);
+    }
+
+    #[test]
+    fn current_action_inserted_if_missing() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let idling = world.spawn(Picker::new(idle_action)).id();
+        let busy = world.spawn((Picker::new(idle_action), CurrentAction(my_action))).id();
+        world.flush();
+
+        assert_eq!(idle_action, world.get::<CurrentAction>(idling).unwrap().0);
+        assert_eq!(my_action, world.get::<CurrentAction>(busy).unwrap().0);
+    }
+
+    #[test]
+    fn action_for_registers_choice() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let actor = world.spawn((Picker::new(idle_action), Highest)).id();
+        let scorer = world
+            .spawn((ActionFor(my_action), FixedScore::new(0.7)))
+            .set_parent(actor)
+            .id();
+        let group = world.spawn(Score::default()).set_parent(actor).id();
+        let nested = world
+            .spawn((ActionFor(my_action), FixedScore::new(0.7)))
+            .set_parent(group)
+            .id();
+        world.flush();
+
+        let picker = world.get::<Picker>(actor).unwrap();
+        assert_eq!(Some(&my_action), picker.choices.get(&scorer));
+        // Pickers only pick between their direct children
+        assert!(!picker.choices.contains_key(&nested));
+    }
+
+    #[test]
+    fn action_for_follows_reparenting() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let first = world.spawn((Picker::new(idle_action), Highest)).id();
+        let second = world.spawn((Picker::new(idle_action), Highest)).id();
+        let scorer = world
+            .spawn((ActionFor(my_action), FixedScore::new(0.7)))
+            .set_parent(first)
+            .id();
+        world.flush();
+
+        // Reparenting an entity that already has a parent changes its Parent in place
+        world.entity_mut(scorer).set_parent(second);
+        world.flush();
+
+        world.trigger(RunScoring);
+        world.trigger(RunPicking);
+        world.flush();
+        assert_eq!(Some(idle_action), world.get::<Picker>(first).unwrap().picked);
+        assert_eq!(Some(my_action), world.get::<Picker>(second).unwrap().picked);
+        assert!(!world.get::<Picker>(first).unwrap().choices.contains_key(&scorer));
+
+        // And back again
+        world.entity_mut(scorer).set_parent(first);
+        world.trigger(RunScoring);
+        world.trigger(RunPicking);
+        world.flush();
+        assert_eq!(Some(my_action), world.get::<Picker>(first).unwrap().picked);
+        assert_eq!(Some(idle_action), world.get::<Picker>(second).unwrap().picked);
+    }
+
+    #[test]
+    fn despawned_choice_removed() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let actor = world
+            .spawn((Picker::new(idle_action), Highest, RepickOnChoiceRemoved))
+            .id();
+        let scorer = world
+            .spawn((ActionFor(my_action), FixedScore::new(0.7)))
+            .set_parent(actor)
+            .id();
+        world.flush();
+
+        world.trigger(RunScoring);
+
+        world.flush();
+
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+        assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+
+        world.entity_mut(actor).remove_children(&[scorer]);
+        world.despawn(scorer);
+        world.flush();
+
+        let picker = world.get::<Picker>(actor).unwrap();
+        assert!(picker.choices.is_empty());
+        assert_eq!(Some(idle_action), picker.picked);
+    }
+
+    #[test]
+    fn non_finite_adjusted_scores_are_zeroed() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        world.insert_resource(ActionCosts::new(CostMode::Subtract).with_cost(my_action, f32::NAN));
+        let costly = world.spawn(FixedScore::new(0.9)).id();
+        let free = world.spawn(FixedScore::new(0.3)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle_action).with(costly, my_action).with(free, idle_action),
+                Highest,
+            ))
+            .push_children(&[costly, free])
+            .id();
+
+        world.score_and_pick(actor);
+        let adjusted = world.run_system_once(move |scores: ChoiceScores, pickers: Query<(&Picker, &Children)>| {
+            let (picker, children) = pickers.get(actor).unwrap();
+            scores.iter(actor, children, picker).collect::<Vec<_>>()
+        });
+        assert_eq!(vec![(costly, 0.), (free, 0.3)], adjusted);
+        assert_eq!(Some(free), world.get::<Picker>(actor).unwrap().picked_score_entity);
+    }
+
+    #[test]
+    fn choices_keep_insertion_order() {
+        let mut world = World::new();
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+        let [a, b, c] = [
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+        ];
+
+        let mut choices: PickerChoices = [(c, my_action), (a, idle_action), (b, my_action)].into_iter().collect();
+        assert_eq!(Some(idle_action), choices.insert(a, my_action));
+        assert_eq!(Some(my_action), choices.remove(&c));
+        choices.insert(c, idle_action);
+
+        assert_eq!(
+            vec![(a, my_action), (b, my_action), (c, idle_action)],
+            choices.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&my_action), choices.get(&b));
+        assert_eq!(Some(&idle_action), choices.get(&c));
+    }
+
+    #[test]
+    fn with_action_keeps_insertion_order() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime);
+        let world = app.world_mut();
+
+        let idle_action = world.init_component::<IdleAction>();
+        let [a, b, c] = [
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+        ];
+
+        let actor = world
+            .spawn(
+                Picker::new(idle_action)
+                    .with_action::<MyAction>(a)
+                    .with(b, idle_action)
+                    .with_action::<MyAction>(c),
+            )
+            .id();
+        world.flush();
+
+        let my_action = world.init_component::<MyAction>();
+        assert_eq!(
+            vec![(a, my_action), (b, idle_action), (c, my_action)],
+            world.get::<Picker>(actor).unwrap().choices.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn pick_highest_above_threshold() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime);
+        let world = app.world_mut();
+
+        let my_action = world.init_component::<MyAction>();
+        let idle_action = world.init_component::<IdleAction>();
+
+        let scorer = world.spawn(FixedScore::new(0.3)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle_action).with(scorer, my_action),
+                HighestAboveThreshold::new(0.5),
+            ))
+            .add_child(scorer)
+            .id();
+        world.flush();
+
+        world.trigger_targets(RunScoring, scorer);
+
+        world.flush();
+
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+        assert_eq!(Some(idle_action), world.get::<Picker>(actor).unwrap().picked);
+
+        world.entity_mut(scorer).insert(FixedScore::new(0.6));
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        world.trigger_targets(RunPicking, actor);
+        world.flush();
+        assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
+    }
+
+    #[test]
+    fn seeded_rngs_are_reproducible_per_actor() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::RealTime)
+            .insert_resource(UtilityRng::new(42));
+        let world = app.world_mut();
+
+        let shared = world.spawn_empty().id();
+        let own = world.spawn(RngComponent::new(7)).id();
+
+        let draws = world
+            .run_system_once(move |scores: ChoiceScores| [scores.rng(shared).next_u64(), scores.rng(own).next_u64()]);
+        assert_eq!(draws, [UtilityRng::new(42).next_u64(), UtilityRng::new(7).next_u64()]);
+
+        // Drawing advanced the shared state.
+        let next = world.run_system_once(move |scores: ChoiceScores| scores.rng(shared).next_u64());
+        assert_ne!(next, draws[0]);
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds_and_is_deterministic_per_seed() {
+        let mut world = World::new();
+        let actor = world.spawn_empty().id();
+        let score_entities: Vec<Entity> = (0..64).map(|_| world.spawn_empty().id()).collect();
+        let offsets = |jitter: PickJitter, rng: &UtilityRng| -> Vec<f32> {
+            score_entities
+                .iter()
+                .enumerate()
+                .map(|(frame, &score_entity)| {
+                    jitter.offset(rng, actor, score_entity, Duration::from_millis(frame as u64 * 16))
+                })
+                .collect()
+        };
+
+        let jitter = PickJitter::new(0.05).with_seed(3);
+        let rng = UtilityRng::new(42);
+        let first = offsets(jitter, &rng);
+        assert!(first.iter().all(|offset| offset.abs() <= 0.05));
+        assert!(first.iter().any(|&offset| offset > 0.) && first.iter().any(|&offset| offset < 0.));
+
+        // Drawing from the rng doesn't change the noise, only the seeds do.
+        rng.next_u64();
+        assert_eq!(first, offsets(jitter, &rng));
+        assert_eq!(first, offsets(jitter, &UtilityRng::new(42)));
+        assert_ne!(first, offsets(jitter.with_seed(4), &rng));
+        assert_ne!(first, offsets(jitter, &UtilityRng::new(7)));
     }
 }
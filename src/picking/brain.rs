@@ -0,0 +1,181 @@
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, StorageType},
+        system::EntityCommands,
+        world::Command,
+    },
+    prelude::*,
+};
+
+use crate::{
+    picking::Picker,
+    scoring::{despawn_score_tree, score_children, Score, ScoreOf},
+};
+
+/// Marker [`Component`] for actor entities whose child [`Score`] entities should be despawned (recursively)
+/// along with the actor.
+///
+/// Without this, despawning an actor non-recursively leaves its score tree orphaned.
+/// Removing this component without despawning the actor leaves the score tree untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// let actor = world
+///     .spawn((Picker::new(idle), Highest, DespawnScoreTreeWithActor))
+///     .id();
+/// let scorer = world.spawn(FixedScore::new(0.5)).set_parent(actor).id();
+///
+/// world.despawn(actor);
+/// # world.flush();
+/// assert!(world.get_entity(scorer).is_none());
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct DespawnScoreTreeWithActor;
+
+impl Component for DespawnScoreTreeWithActor {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_remove(|mut world, entity, _component| {
+            let scorers = score_children(&world, entity)
+                .filter(|&child| world.get::<Score>(child).is_some())
+                .collect();
+            world.commands().add(DespawnScoreTree { actor: entity, scorers });
+        });
+    }
+}
+
+/// [`Command`] that recursively despawns the given score entities, but only if their actor was despawned.
+struct DespawnScoreTree {
+    actor: Entity,
+    scorers: Vec<Entity>,
+}
+
+impl Command for DespawnScoreTree {
+    fn apply(self, world: &mut World) {
+        if world.get_entity(self.actor).is_some() {
+            // Only the component was removed, the actor is still alive.
+            return;
+        }
+        for scorer in self.scorers {
+            despawn_score_tree(world, scorer);
+        }
+    }
+}
+
+/// [`EntityCommands`] extension trait for moving a "brain" (a [`Picker`] and its child [`Score`] entities)
+/// between actor entities.
+///
+/// Picker kind components (such as [`Highest`](crate::picking::Highest)) are not moved,
+/// so the receiving actor needs its own.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// # let mut commands = world.commands();
+/// let old_actor = commands.spawn((Picker::new(idle), Highest)).id();
+/// let new_actor = commands.spawn(Highest).id();
+/// let scorer = commands.spawn(FixedScore::new(0.5)).set_parent(old_actor).id();
+///
+/// // Move the picker and its score tree from one actor to the other.
+/// let brain = commands.entity(old_actor).detach_brain();
+/// commands.entity(new_actor).attach_brain(brain);
+/// # world.flush();
+/// # assert!(world.get::<Picker>(old_actor).is_none());
+/// # assert!(world.get::<Picker>(new_actor).is_some());
+/// # assert_eq!(world.get::<Parent>(scorer).unwrap().get(), new_actor);
+/// # assert!(world.get_entity(brain).is_none());
+/// ```
+pub trait BrainExt {
+    /// Removes the [`Picker`] and child [`Score`] entities from this actor entity,
+    /// and moves them onto a new "brain" entity, which is returned.
+    ///
+    /// The brain entity can be passed to [`BrainExt::attach_brain`] to move the brain to another actor.
+    fn detach_brain(&mut self) -> Entity;
+
+    /// Moves the [`Picker`] and child [`Score`] entities from the given brain entity onto this actor entity,
+    /// and despawns the brain entity.
+    ///
+    /// Any [`Picker`] or child [`Score`] entities already on this actor entity are replaced and despawned.
+    fn attach_brain(&mut self, brain: Entity) -> &mut Self;
+}
+
+impl BrainExt for EntityCommands<'_> {
+    fn detach_brain(&mut self) -> Entity {
+        let actor = self.id();
+        let brain = self.commands().spawn_empty().id();
+        self.commands().add(MoveBrain { from: actor, to: brain });
+        brain
+    }
+
+    fn attach_brain(&mut self, brain: Entity) -> &mut Self {
+        let actor = self.id();
+        self.commands().add(move |world: &mut World| {
+            let scorers: Vec<Entity> = score_children(world, actor)
+                .filter(|&child| world.get::<Score>(child).is_some())
+                .collect();
+            for scorer in scorers {
+                despawn_score_tree(world, scorer);
+            }
+
+            MoveBrain { from: brain, to: actor }.apply(world);
+
+            if let Some(brain) = world.get_entity_mut(brain) {
+                brain.despawn_recursive();
+            }
+        });
+        self
+    }
+}
+
+/// [`Command`] that moves the [`Picker`] and child [`Score`] entities from one entity to another.
+struct MoveBrain {
+    from: Entity,
+    to: Entity,
+}
+
+impl Command for MoveBrain {
+    fn apply(self, world: &mut World) {
+        if world.get_entity(self.to).is_none() {
+            return;
+        }
+        if world.get_entity(self.from).is_none() {
+            return;
+        }
+
+        let (linked, parented): (Vec<Entity>, Vec<Entity>) = score_children(world, self.from)
+            .filter(|&child| world.get::<Score>(child).is_some())
+            .partition(|&child| world.get::<ScoreOf>(child).is_some());
+        let picker = world.entity_mut(self.from).take::<Picker>();
+
+        let mut to = world.entity_mut(self.to);
+        if let Some(picker) = picker {
+            to.insert(picker);
+        }
+        to.push_children(&parented);
+        for scorer in linked {
+            world.entity_mut(scorer).insert(ScoreOf::new(self.to));
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    ecs::CommandsExt,
+    picking::{pick_with_strategy, ChoiceScores, PickStrategy, Picker},
+    scoring::Score,
+};
+
+/// [`Picker`] [`Component`] that tries multiple [`PickStrategy`]s in order,
+/// falling back to the next one only if the previous one picked the default action.
+///
+/// Adding multiple picker kind components (such as [`FirstToScore`] and [`Highest`]) to the same actor
+/// makes them all pick, and whichever happens to run last wins. Use a [`PickerChain`] instead.
+///
+/// [`FirstToScore`]: crate::picking::FirstToScore
+/// [`Highest`]: crate::picking::Highest
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Flee;
+/// #[derive(Component)]
+/// pub struct Eat;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let flee = world.init_component::<Flee>();
+/// let eat = world.init_component::<Eat>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let flee_scorer = world.spawn(FixedScore::new(0.3)).id();
+/// let eat_scorer = world.spawn(FixedScore::new(0.4)).id();
+/// let actor = world
+///     .spawn((
+///         Picker::new(idle).with(flee_scorer, flee).with(eat_scorer, eat),
+///         // Flee or eat if either is urgent, otherwise do whatever scores highest.
+///         PickerChain::new().then(FirstToScore::new(0.8)).then(Highest),
+///     ))
+///     .push_children(&[flee_scorer, eat_scorer])
+///     .id();
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(eat), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Default)]
+pub struct PickerChain {
+    /// The strategies to try, in order.
+    strategies: Vec<Box<dyn PickStrategy>>,
+}
+
+impl PickerChain {
+    /// Creates a new empty [`PickerChain`], which always picks the default action.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a [`PickStrategy`] to try if all previous strategies picked the default action.
+    #[must_use]
+    pub fn then(mut self, strategy: impl PickStrategy) -> Self {
+        self.push(strategy);
+        self
+    }
+
+    /// Appends a [`PickStrategy`] to try if all previous strategies picked the default action.
+    pub fn push(&mut self, strategy: impl PickStrategy) {
+        self.strategies.push(Box::new(strategy));
+    }
+
+    /// Returns the number of strategies in the chain.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strategies.len()
+    }
+
+    /// Returns `true` if the chain has no strategies.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strategies.is_empty()
+    }
+}
+
+impl PickStrategy for PickerChain {
+    fn select(&mut self, actor: Entity, children: &[Entity], picker: &Picker, scores: &ChoiceScores) -> Option<Entity> {
+        self.strategies.iter_mut().find_map(|strategy| {
+            strategy.select(actor, children, picker, scores).filter(|score_entity| {
+                picker
+                    .choices
+                    .get(score_entity)
+                    .is_some_and(|&action| !picker.is_default(action))
+            })
+        })
+    }
+
+    fn thresholds(&self) -> Vec<Score> {
+        self.strategies
+            .iter()
+            .flat_map(|strategy| strategy.thresholds())
+            .collect()
+    }
+}
+
+impl Component for PickerChain {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, _entity, _component| {
+            world.commands().observe_once(pick_with_strategy::<Self>);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(pick_with_strategy::<Self>);
+        });
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::{ecs::component::ComponentId, prelude::*, utils::HashMap};
+
+/// Per-action costs that the built-in pickers take into account before comparing scores,
+/// to make utility-vs-cost tradeoffs without baking the cost into every score tree.
+///
+/// Can be inserted as a [`Component`] on an actor entity, or as a [`Resource`] for all actors.
+/// An actor's component takes precedence over the resource.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Teleport;
+/// #[derive(Component)]
+/// pub struct Walk;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let teleport = world.init_component::<Teleport>();
+/// let walk = world.init_component::<Walk>();
+/// let idle = world.init_component::<Idle>();
+///
+/// // Teleporting is great, but expensive.
+/// world.insert_resource(ActionCosts::new(CostMode::Subtract).with_cost(teleport, 0.5));
+///
+/// let teleport_scorer = world.spawn(FixedScore::new(0.9)).id();
+/// let walk_scorer = world.spawn(FixedScore::new(0.6)).id();
+/// let actor = world
+///     .spawn((
+///         Picker::new(idle).with(teleport_scorer, teleport).with(walk_scorer, walk),
+///         Highest,
+///     ))
+///     .push_children(&[teleport_scorer, walk_scorer])
+///     .id();
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(walk), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Component, Resource, Reflect)]
+#[derive(Clone, PartialEq, Debug, Default)]
+#[reflect(Component, Resource, PartialEq, Debug, Default)]
+pub struct ActionCosts {
+    /// How costs are applied to scores.
+    mode: CostMode,
+    /// The cost per action. Actions without a cost are unaffected.
+    costs: HashMap<ComponentId, f32>,
+}
+
+impl ActionCosts {
+    /// Creates a new empty [`ActionCosts`] with the given [`CostMode`].
+    #[must_use]
+    pub fn new(mode: CostMode) -> Self {
+        Self {
+            mode,
+            costs: HashMap::default(),
+        }
+    }
+
+    /// Sets the cost of the given action.
+    #[must_use]
+    pub fn with_cost(mut self, action: ComponentId, cost: f32) -> Self {
+        self.costs.insert(action, cost);
+        self
+    }
+
+    /// Returns how costs are applied to scores.
+    #[must_use]
+    pub fn mode(&self) -> CostMode {
+        self.mode
+    }
+
+    /// Sets how costs are applied to scores.
+    pub fn set_mode(&mut self, mode: CostMode) {
+        self.mode = mode;
+    }
+
+    /// Returns the cost of the given action, if any.
+    #[must_use]
+    pub fn cost(&self, action: ComponentId) -> Option<f32> {
+        self.costs.get(&action).copied()
+    }
+
+    /// Sets the cost of the given action.
+    pub fn set_cost(&mut self, action: ComponentId, cost: f32) {
+        self.costs.insert(action, cost);
+    }
+
+    /// Removes the cost of the given action.
+    pub fn remove_cost(&mut self, action: ComponentId) {
+        self.costs.remove(&action);
+    }
+
+    /// Applies the cost of the given action to the given score value.
+    #[must_use]
+    pub fn apply(&self, action: ComponentId, score: f32) -> f32 {
+        match (self.mode, self.cost(action)) {
+            (CostMode::Subtract, Some(cost)) => score - cost,
+            (CostMode::Divide, Some(cost)) if cost > 0. => score / cost,
+            _ => score,
+        }
+    }
+}
+
+/// How [`ActionCosts`] are applied to scores.
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(PartialEq, Debug, Default)]
+pub enum CostMode {
+    /// The cost is subtracted from the score.
+    #[default]
+    Subtract,
+    /// The score is divided by the cost, i.e. utility per unit of cost. Non-positive costs are ignored.
+    Divide,
+}
@@ -0,0 +1,204 @@
+use bevy::{
+    ecs::{component::ComponentId, entity::EntityHashSet, schedule::ScheduleLabel},
+    prelude::*,
+};
+
+use crate::{
+    picking::{
+        pick_for, ActionFor, ChoiceScores, FirstToScore, Highest, HighestAboveThreshold, PickDualUtility, PickStrategy,
+        Picker, PickerChain, PickerGroup, ReportPicks,
+    },
+    scoring::ScoreHierarchy,
+};
+
+/// [`Plugin`] that switches picking to the direct path for [`Picker`]s with a built-in [`PickStrategy`],
+/// or one added with [`DirectPickingExt::add_direct_picker`].
+///
+/// Instead of triggering [`OnPick`] for each actor entity and picking in an [`Observer`] per actor,
+/// [`RunPicking`] queues the actor, and the queued actors are picked for in one go by a [`System`] per strategy
+/// in the [`DirectPickingSchedule`]. The schedule runs in the same command flush that [`RunPicking`]'s
+/// [`OnPick`] triggers would have, so this works the same in real-time and turn-based games,
+/// including with [`WorldUtilityExt::score_and_pick`](crate::WorldUtilityExt::score_and_pick).
+///
+/// [`OnPicked`] and [`PickReport`] are triggered for directly picked actors just like for the [`Observer`] path.
+/// Actors whose strategy isn't handled directly, such as [`PickMultiple`], keep using the [`Observer`] path,
+/// as do [`PickerGroup`]s picked by a directly picked actor.
+///
+/// [`OnPick`]: crate::event::OnPick
+/// [`OnPicked`]: crate::event::OnPicked
+/// [`PickReport`]: crate::event::PickReport
+/// [`PickMultiple`]: crate::picking::PickMultiple
+/// [`RunPicking`]: crate::event::RunPicking
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::TurnBased, DirectPickingPlugin));
+/// # let world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Attack;
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let attack = world.init_component::<Attack>();
+/// # let idle = world.init_component::<Idle>();
+///
+/// let scorer = world.spawn(FixedScore::new(0.7)).id();
+/// let actor = world
+///     .spawn((Picker::new(idle).with(scorer, attack), Highest))
+///     .add_child(scorer)
+///     .id();
+///
+/// assert_eq!(Some(attack), world.score_and_pick(actor));
+/// ```
+#[derive(Default)]
+pub struct DirectPickingPlugin;
+
+impl Plugin for DirectPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DirectPicking>()
+            .init_schedule(DirectPickingSchedule);
+
+        app.add_direct_picker::<Highest>()
+            .add_direct_picker::<FirstToScore>()
+            .add_direct_picker::<HighestAboveThreshold>()
+            .add_direct_picker::<PickDualUtility>()
+            .add_direct_picker::<PickerChain>();
+
+        #[cfg(feature = "rand")]
+        app.add_direct_picker::<crate::picking::PickRandom>();
+    }
+}
+
+/// [`ScheduleLabel`] for the [`Schedule`] that picks directly for the actor entities queued by
+/// [`RunPicking`](crate::event::RunPicking). See [`DirectPickingPlugin`].
+///
+/// It's run by [`DirectPicking::run_queued`] whenever actors are queued, rather than by the app.
+#[derive(ScheduleLabel, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DirectPickingSchedule;
+
+/// [`SystemSet`] in the [`DirectPickingSchedule`] for the [`System`]s that pick directly for queued actor entities.
+#[derive(SystemSet, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DirectPickingSet;
+
+/// [`Resource`] for the [`DirectPickingPlugin`]: the strategies that are picked directly,
+/// and the actor entities queued by [`RunPicking`](crate::event::RunPicking) to be picked for.
+#[derive(Resource, Default)]
+pub struct DirectPicking {
+    /// The [`ComponentId`]s of the strategies that are picked directly.
+    strategies: Vec<ComponentId>,
+    /// The actor entities to pick for the next time the [`DirectPickingSchedule`] runs, in the order they were queued.
+    queued: Vec<Entity>,
+    /// The actor entities in `queued`, to queue each actor only once.
+    queued_set: EntityHashSet,
+    /// The actor entities being picked for while the [`DirectPickingSchedule`] runs.
+    picking: Vec<Entity>,
+}
+
+impl DirectPicking {
+    /// Returns `true` if the given strategy [`Component`] is picked directly.
+    #[must_use]
+    pub fn handles(&self, strategy: ComponentId) -> bool {
+        self.strategies.contains(&strategy)
+    }
+
+    /// Queues the given actor entity to be picked for the next time the [`DirectPickingSchedule`] runs.
+    ///
+    /// Returns `true` if the queue was empty, so [`DirectPicking::run_queued`] has to be scheduled.
+    pub fn queue(&mut self, actor: Entity) -> bool {
+        let was_empty = self.queued.is_empty();
+        if self.queued_set.insert(actor) {
+            self.queued.push(actor);
+        }
+        was_empty
+    }
+
+    /// Returns `true` if the given actor entity is queued to be picked for.
+    #[must_use]
+    pub fn is_queued(&self, actor: Entity) -> bool {
+        self.queued_set.contains(&actor)
+    }
+
+    /// Returns the actor entities being picked for while the [`DirectPickingSchedule`] runs, in the order they were queued.
+    pub fn picking(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        self.picking.iter().copied()
+    }
+
+    /// Exclusive [`System`] that runs the [`DirectPickingSchedule`] for the queued actor entities, and empties the queue.
+    ///
+    /// Added as a [`Command`](bevy::ecs::world::Command) by [`RunPicking`](crate::event::RunPicking)
+    /// whenever it queues the first actor entity. Actors queued while the schedule runs are picked for the next time.
+    pub fn run_queued(world: &mut World) {
+        let Some(mut direct) = world.get_resource_mut::<DirectPicking>() else {
+            return;
+        };
+        direct.picking = std::mem::take(&mut direct.queued);
+        direct.queued_set.clear();
+
+        // The schedule is only missing if the DirectPickingPlugin wasn't added.
+        let _ = world.try_run_schedule(DirectPickingSchedule);
+
+        if let Some(mut direct) = world.get_resource_mut::<DirectPicking>() {
+            direct.picking.clear();
+        }
+    }
+}
+
+/// [`App`] extension trait for picking custom [`PickStrategy`]s directly with the [`DirectPickingPlugin`].
+pub trait DirectPickingExt {
+    /// Picks directly for actor entities with the strategy [`Component`] `S`, by adding [`pick_directly::<S>`]
+    /// to the [`DirectPickingSet`]. The [`DirectPickingPlugin`] must be added first.
+    fn add_direct_picker<S: PickStrategy + Component>(&mut self) -> &mut Self;
+}
+
+impl DirectPickingExt for App {
+    fn add_direct_picker<S: PickStrategy + Component>(&mut self) -> &mut Self {
+        let strategy = self.world_mut().init_component::<S>();
+        let mut direct = self.world_mut().resource_mut::<DirectPicking>();
+        if direct.handles(strategy) {
+            return self;
+        }
+        direct.strategies.push(strategy);
+        self.add_systems(DirectPickingSchedule, pick_directly::<S>.in_set(DirectPickingSet))
+    }
+}
+
+/// [`System`] for [`Picker`] kind [`Component`]s that implement [`PickStrategy`]:
+/// picks the action selected by the strategy for each queued actor entity and triggers
+/// [`OnPicked`](crate::event::OnPicked), like [`pick_with_strategy`](crate::picking::pick_with_strategy) does.
+///
+/// If the selected choice is a [`PickerGroup`], the group picks instead with [`OnPick`](crate::event::OnPick),
+/// and [`OnPicked`](crate::event::OnPicked) is triggered once it's resolved.
+#[allow(clippy::too_many_arguments)]
+pub fn pick_directly<S: PickStrategy + Component>(
+    mut commands: Commands,
+    direct: Res<DirectPicking>,
+    mut targets: Query<(&mut Picker, &mut S, Has<ReportPicks>), Without<PickerGroup>>,
+    mut groups: Query<&mut PickerGroup>,
+    scores: ChoiceScores,
+    hierarchy: ScoreHierarchy,
+    action_fors: Query<&ActionFor>,
+    mut children: Local<Vec<Entity>>,
+) {
+    for target in direct.picking() {
+        let Ok((picker, strategy, report)) = targets.get_mut(target) else {
+            // Picked by another strategy's system.
+            continue;
+        };
+        pick_for(
+            target,
+            commands.reborrow(),
+            picker,
+            strategy.into_inner(),
+            report,
+            &mut groups,
+            &scores,
+            &hierarchy,
+            &action_fors,
+            &mut children,
+        );
+    }
+}
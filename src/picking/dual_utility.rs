@@ -0,0 +1,171 @@
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use crate::{
+    ecs::CommandsExt,
+    picking::{pick_with_strategy, ChoiceScores, PickStrategy, Picker},
+    scoring::Score,
+};
+
+/// [`Picker`] [`Component`] that implements dual utility picking:
+/// each child [`Score`] entity has a priority tier (its [`ScorePriority`]) and a score.
+///
+/// The highest tier with at least one eligible choice (scoring above the threshold) is selected first,
+/// then the highest scoring choice within that tier is picked.
+/// With the `rand` feature, [`PickDualUtility::with_weighted_random`] picks a weighted random choice within the tier instead.
+///
+/// If no choice is eligible, the default action is picked.
+///
+/// [`ScorePriority`]: crate::scoring::ScorePriority
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Flee;
+/// #[derive(Component)]
+/// pub struct Eat;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let flee = world.init_component::<Flee>();
+/// let eat = world.init_component::<Eat>();
+/// let idle = world.init_component::<Idle>();
+///
+/// # let mut commands = world.commands();
+/// // Fleeing is in a higher tier, so it wins whenever it scores at all, even though eating scores higher.
+/// let flee_scorer = commands.spawn((FixedScore::new(0.2), ScorePriority(1))).id();
+/// let eat_scorer = commands.spawn(FixedScore::new(0.9)).id();
+///
+/// let actor = commands
+///     .spawn((
+///         Picker::new(idle).with(flee_scorer, flee).with(eat_scorer, eat),
+///         PickDualUtility::default(),
+///     ))
+///     .push_children(&[flee_scorer, eat_scorer])
+///     .id();
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(flee), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct PickDualUtility {
+    /// Choices need to score above this threshold to be eligible.
+    threshold: Score,
+    /// Whether to pick a weighted random choice within the selected tier.
+    #[cfg(feature = "rand")]
+    weighted_random: bool,
+}
+
+impl PickDualUtility {
+    /// Creates a new [`PickDualUtility`] with the given eligibility threshold.
+    #[must_use]
+    pub fn new(threshold: impl Into<Score>) -> Self {
+        Self {
+            threshold: threshold.into(),
+            #[cfg(feature = "rand")]
+            weighted_random: false,
+        }
+    }
+
+    /// Picks a weighted random choice within the selected tier, drawn from the actor entity's
+    /// [`UtilityRng`](crate::rng::UtilityRng), instead of the highest scoring one.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    pub fn with_weighted_random(mut self) -> Self {
+        self.weighted_random = true;
+        self
+    }
+
+    /// Returns the eligibility threshold.
+    #[must_use]
+    pub fn threshold(&self) -> Score {
+        self.threshold
+    }
+
+    /// Sets the eligibility threshold.
+    pub fn set_threshold(&mut self, threshold: impl Into<Score>) {
+        self.threshold = threshold.into();
+    }
+
+    /// Selects a choice from the eligible choices of the selected tier.
+    #[cfg_attr(not(feature = "rand"), allow(unused_variables))]
+    fn select_in_tier(&self, actor: Entity, candidates: &[(Entity, f32)], scores: &ChoiceScores) -> Option<Entity> {
+        #[cfg(feature = "rand")]
+        if self.weighted_random {
+            let mut rng = scores.rng(actor);
+            let total: f32 = candidates.iter().map(|(_, score)| score).sum();
+            let mut roll = rng.gen::<f32>() * total;
+            for &(score_entity, score) in candidates {
+                if roll < score {
+                    return Some(score_entity);
+                }
+                roll -= score;
+            }
+            return candidates.last().map(|(score_entity, _)| *score_entity);
+        }
+
+        let mut highest_score_entity: Option<(Entity, f32)> = None;
+        for &(score_entity, score) in candidates {
+            if let Some((_, highest_score)) = highest_score_entity {
+                if score > highest_score {
+                    highest_score_entity = Some((score_entity, score));
+                }
+            } else {
+                highest_score_entity = Some((score_entity, score));
+            }
+        }
+        highest_score_entity.map(|(score_entity, _)| score_entity)
+    }
+}
+
+impl PickStrategy for PickDualUtility {
+    fn select(&mut self, actor: Entity, children: &[Entity], picker: &Picker, scores: &ChoiceScores) -> Option<Entity> {
+        // Children are sorted by descending priority, so the first eligible choice determines the tier
+        let mut tier = None;
+        let mut candidates = Vec::new();
+        for (score_entity, score) in scores.iter(actor, children, picker) {
+            if score <= self.threshold().get() {
+                continue;
+            }
+            let priority = scores.priority(score_entity);
+            if tier.is_some_and(|tier| tier != priority) {
+                break;
+            }
+            tier = Some(priority);
+            candidates.push((score_entity, score));
+        }
+
+        self.select_in_tier(actor, &candidates, scores)
+    }
+
+    fn thresholds(&self) -> Vec<Score> {
+        vec![self.threshold]
+    }
+}
+
+impl Component for PickDualUtility {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, _entity, _component| {
+            world.commands().observe_once(pick_with_strategy::<Self>);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(pick_with_strategy::<Self>);
+        });
+    }
+}
@@ -4,14 +4,16 @@ use bevy::{
 };
 
 use crate::{
-    ecs::{CommandsExt, TriggerGetEntity},
-    event::{OnPick, OnPicked},
-    picking::Picker,
+    ecs::CommandsExt,
+    picking::{pick_with_strategy, ChoiceScores, PickStrategy, Picker},
     scoring::Score,
 };
 
 /// [`Picker`] [`Component`] that picks the first [`Score`] entity to reach a certain threshold.
 ///
+/// Child [`Score`] entities are checked in [`ScorePriority`](crate::scoring::ScorePriority) order: highest priority first,
+/// then in [`Children`] order for equal priorities.
+///
 /// # Example
 ///
 /// ```rust
@@ -52,7 +54,7 @@ use crate::{
 /// commands.trigger_targets(RunScoring, scorer);
 /// commands.trigger_targets(RunPicking, actor);
 /// # world.flush();
-/// # assert_eq!(my_action, world.get::<Picker>(actor).unwrap().picked);
+/// # assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
 /// ```
 #[derive(Reflect)]
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
@@ -81,45 +83,18 @@ impl FirstToScore {
     pub fn set_threshold(&mut self, threshold: impl Into<Score>) {
         self.threshold = threshold.into();
     }
+}
 
-    /// [`Observer`] for the [`FirstToScore`] [`Picker`] that picks the first entity to reach a certain
-    /// [`Score`](crate::scoring::Score) threshold.
-    fn observer(
-        trigger: Trigger<OnPick>,
-        mut commands: Commands,
-        mut targets: Query<(Entity, &Children, &mut Picker, &FirstToScore)>,
-        scores: Query<(Entity, &Score)>,
-    ) {
-        fn run(
-            target: Entity,
-            mut commands: Commands,
-            children: &Children,
-            mut picker: Mut<Picker>,
-            settings: &FirstToScore,
-            scores: &Query<(Entity, &Score)>,
-        ) {
-            for (score_entity, score) in scores.iter_many(children) {
-                if *score >= settings.threshold() {
-                    picker.pick(Some(score_entity));
-                    return;
-                }
-            }
-
-            // If no score entity reached the threshold, pick the default action
-            let action = picker.pick(None);
-            commands.trigger_targets(OnPicked { action }, target);
-        }
+impl PickStrategy for FirstToScore {
+    fn select(&mut self, actor: Entity, children: &[Entity], picker: &Picker, scores: &ChoiceScores) -> Option<Entity> {
+        scores
+            .iter(actor, children, picker)
+            .find(|&(_, score)| score >= self.threshold().get())
+            .map(|(score_entity, _)| score_entity)
+    }
 
-        if let Some(target) = trigger.get_entity() {
-            let Ok((target, children, picker, settings)) = targets.get_mut(target) else {
-                return;
-            };
-            run(target, commands.reborrow(), children, picker, settings, &scores);
-        } else {
-            for (target, children, picker, settings) in &mut targets {
-                run(target, commands.reborrow(), children, picker, settings, &scores);
-            }
-        }
+    fn thresholds(&self) -> Vec<Score> {
+        vec![self.threshold]
     }
 }
 
@@ -128,13 +103,10 @@ impl Component for FirstToScore {
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct FirstToScoreObserverSpawned;
-
-            world
-                .commands()
-                .once::<FirstToScoreObserverSpawned>()
-                .observe(Self::observer);
+            world.commands().observe_once(pick_with_strategy::<Self>);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(pick_with_strategy::<Self>);
         });
     }
 }
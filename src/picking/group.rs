@@ -0,0 +1,136 @@
+use bevy::{
+    ecs::component::{ComponentHooks, ComponentId, StorageType},
+    prelude::*,
+};
+
+use crate::{ecs::CommandsExt, event::OnPicked, picking::Picker, scoring::Score};
+
+/// [`Component`] for behavior group entities: [`Picker`] choices that pick a concrete action with their own [`Picker`],
+/// so that actors first choose a category (such as combat, survival, or social) and then an action within it.
+///
+/// A group entity is a [`Score`] entity that's a choice of its parent's [`Picker`], added with [`Picker::with_group`],
+/// and which has its own [`Picker`] and picker kind (such as [`Highest`]) with [`Score`] children of its own.
+/// Groups can be nested to any depth.
+///
+/// When the parent picks the group, the group picks next, and the parent's [`Picker::picked`] is set to the
+/// group's concrete action, with [`OnPicked`] triggered for the parent once the chain is resolved.
+/// If the group picks nothing, the parent falls back to its own default action.
+/// Groups only pick when their parent picks them. A [`PickMultiple`] parent only picks a group as its highest scoring choice.
+///
+/// Use [`Picker::without_default`] for groups that should defer to their parent's default action,
+/// and a scorer such as [`Winning`] to score the group by its best choice.
+///
+/// [`Highest`]: crate::picking::Highest
+/// [`PickMultiple`]: crate::picking::PickMultiple
+/// [`Winning`]: crate::scoring::Winning
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Attack;
+/// #[derive(Component)]
+/// pub struct Block;
+/// #[derive(Component)]
+/// pub struct Eat;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let attack = world.init_component::<Attack>();
+/// let block = world.init_component::<Block>();
+/// let eat = world.init_component::<Eat>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let attack_scorer = world.spawn(FixedScore::new(0.8)).id();
+/// let block_scorer = world.spawn(FixedScore::new(0.4)).id();
+/// let combat = world
+///     .spawn((
+///         PickerGroup::default(),
+///         Winning::new(0.),
+///         Picker::without_default().with(attack_scorer, attack).with(block_scorer, block),
+///         Highest,
+///     ))
+///     .push_children(&[attack_scorer, block_scorer])
+///     .id();
+/// let eat_scorer = world.spawn(FixedScore::new(0.5)).id();
+/// let actor = world
+///     .spawn((Picker::new(idle).with_group(combat).with(eat_scorer, eat), Highest))
+///     .push_children(&[combat, eat_scorer])
+///     .id();
+///
+/// assert_eq!(Some(attack), world.score_and_pick(actor));
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct PickerGroup {
+    /// The parent actor entity waiting for this group to pick, and its previously picked action.
+    requested_by: Option<(Entity, Option<ComponentId>)>,
+}
+
+impl PickerGroup {
+    /// Records that the given parent actor entity picked this group, and is waiting for it to pick.
+    pub(crate) fn request(&mut self, parent: Entity, previous: Option<ComponentId>) {
+        self.requested_by = Some((parent, previous));
+    }
+
+    /// [`Observer`] for [`PickerGroup`] entities that resolves their parent's pick to the group's picked action.
+    fn observer(
+        trigger: Trigger<OnPicked>,
+        mut commands: Commands,
+        mut groups: Query<&mut PickerGroup>,
+        mut pickers: Query<&mut Picker>,
+        scores: Query<&Score>,
+    ) {
+        utility_span!("PickerGroup::observer", entity = ?trigger.entity());
+        let group = trigger.entity();
+        let Ok(mut settings) = groups.get_mut(group) else {
+            // The entity is not a group.
+            return;
+        };
+        let Some((parent, previous)) = settings.requested_by.take() else {
+            // The group wasn't picked by its parent.
+            return;
+        };
+        let Ok(mut picker) = pickers.get_mut(parent) else {
+            return;
+        };
+
+        let action = trigger.event().action.or(picker.default);
+        picker.picked = action;
+        picker.picked_score_entity = trigger.event().action.map(|_| group);
+        picker.picked_target = trigger.event().target;
+        commands.trigger_targets(
+            OnPicked {
+                action,
+                score_entity: picker.picked_score_entity,
+                score: picker
+                    .picked_score_entity
+                    .and_then(|score_entity| scores.get(score_entity).ok().copied()),
+                changed: action != previous,
+                target: picker.picked_target,
+                extra: false,
+            },
+            parent,
+        );
+    }
+}
+
+impl Component for PickerGroup {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, _entity, _component| {
+            world.commands().observe_once(Self::observer);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
@@ -4,14 +4,14 @@ use bevy::{
 };
 
 use crate::{
-    ecs::{CommandsExt, TriggerGetEntity},
-    event::{OnPick, OnPicked},
-    picking::Picker,
-    scoring::Score,
+    ecs::CommandsExt,
+    picking::{pick_with_strategy, ChoiceScores, PickStrategy, Picker},
 };
 
 /// [`Picker`] [`Component`] that picks the highest [`Score`](crate::scoring::Score).
 ///
+/// Ties are won by the first child in [`ScorePriority`](crate::scoring::ScorePriority) order.
+///
 /// # Example
 ///
 /// ```rust
@@ -52,53 +52,26 @@ use crate::{
 /// commands.trigger_targets(RunScoring, scorer);
 /// commands.trigger_targets(RunPicking, actor);
 /// # world.flush();
-/// # assert_eq!(my_action, world.get::<Picker>(actor).unwrap().picked);
+/// # assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
 /// ```
 #[derive(Reflect)]
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 #[reflect(Component)]
 pub struct Highest;
 
-impl Highest {
-    /// [`Observer`] for the [`Highest`] [`Picker`] that picks the highest [`Score`](crate::scoring::Score).
-    fn observer(
-        trigger: Trigger<OnPick>,
-        mut commands: Commands,
-        mut targets: Query<(Entity, &Children, &mut Picker), With<Highest>>,
-        scores: Query<(Entity, &Score)>,
-    ) {
-        fn run(
-            target: Entity,
-            mut commands: Commands,
-            children: &Children,
-            mut picker: Mut<Picker>,
-            scores: &Query<(Entity, &Score)>,
-        ) {
-            let mut highest_score_entity: Option<(Entity, &Score)> = None;
-            for (score_entity, score) in scores.iter_many(children) {
-                if let Some((_, highest_score)) = highest_score_entity {
-                    if score.get() > highest_score.get() {
-                        highest_score_entity = Some((score_entity, score));
-                    }
-                } else {
+impl PickStrategy for Highest {
+    fn select(&mut self, actor: Entity, children: &[Entity], picker: &Picker, scores: &ChoiceScores) -> Option<Entity> {
+        let mut highest_score_entity: Option<(Entity, f32)> = None;
+        for (score_entity, score) in scores.iter(actor, children, picker) {
+            if let Some((_, highest_score)) = highest_score_entity {
+                if score > highest_score {
                     highest_score_entity = Some((score_entity, score));
                 }
-            }
-
-            let action = picker.pick(highest_score_entity.map(|(entity, _)| entity));
-            commands.trigger_targets(OnPicked { action }, target);
-        }
-
-        if let Some(target) = trigger.get_entity() {
-            let Ok((target, children, picker)) = targets.get_mut(target) else {
-                return;
-            };
-            run(target, commands.reborrow(), children, picker, &scores);
-        } else {
-            for (target, children, picker) in &mut targets {
-                run(target, commands.reborrow(), children, picker, &scores);
+            } else {
+                highest_score_entity = Some((score_entity, score));
             }
         }
+        highest_score_entity.map(|(entity, _)| entity)
     }
 }
 
@@ -107,13 +80,10 @@ impl Component for Highest {
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct HighestObserverSpawned;
-
-            world
-                .commands()
-                .once::<HighestObserverSpawned>()
-                .observe(Self::observer);
+            world.commands().observe_once(pick_with_strategy::<Self>);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(pick_with_strategy::<Self>);
         });
     }
 }
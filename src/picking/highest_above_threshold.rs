@@ -0,0 +1,115 @@
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    ecs::CommandsExt,
+    picking::{pick_with_strategy, ChoiceScores, Highest, PickStrategy, Picker},
+    scoring::Score,
+};
+
+/// [`Picker`] [`Component`] that picks the highest [`Score`], but only if it reaches a certain threshold.
+/// Otherwise, the default action is picked.
+///
+/// Ties are won by the first child in [`ScorePriority`](crate::scoring::ScorePriority) order.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct MyAction;
+/// #[derive(Component)]
+/// pub struct IdleAction;
+///
+/// // We need the ComponentIds to initialize the Picker.
+/// // It's recommended to use a resource to store these.
+/// let my_action = world.init_component::<MyAction>();
+/// let idle_action = world.init_component::<IdleAction>();
+///
+/// # let mut commands = world.commands();
+/// // Spawn the scorer entity that will be picked by the actor.
+/// let scorer = commands
+///     .spawn((FixedScore::new(0.3), Score::default()))
+///     .id();
+///
+/// // Spawn the actor entity that will pick an action based on all of its children scores.
+/// let actor = commands
+///     .spawn((
+///         // All pickers need a default action to pick if they fail to pick an action.
+///         Picker::new(idle_action)
+///             // if the score entity is selected, my_action will be picked.
+///             .with(scorer, my_action),
+///         HighestAboveThreshold::new(0.5),
+///     ))
+///     .add_child(scorer)
+///     .id();
+///
+/// commands.trigger_targets(RunScoring, scorer);
+/// commands.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// // The highest score is below the threshold, so the default action is picked.
+/// # assert_eq!(Some(idle_action), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct HighestAboveThreshold {
+    /// The minimum [`Score`] the highest entity needs to be picked.
+    threshold: Score,
+}
+
+impl HighestAboveThreshold {
+    /// Creates a new [`HighestAboveThreshold`] with the given threshold.
+    #[must_use]
+    pub fn new(threshold: impl Into<Score>) -> Self {
+        Self {
+            threshold: threshold.into(),
+        }
+    }
+
+    /// Returns the minimum score the highest entity needs to be picked.
+    #[must_use]
+    pub fn threshold(&self) -> Score {
+        self.threshold
+    }
+
+    /// Sets the minimum score the highest entity needs to be picked.
+    pub fn set_threshold(&mut self, threshold: impl Into<Score>) {
+        self.threshold = threshold.into();
+    }
+}
+
+impl PickStrategy for HighestAboveThreshold {
+    fn select(&mut self, actor: Entity, children: &[Entity], picker: &Picker, scores: &ChoiceScores) -> Option<Entity> {
+        let highest_score_entity = Highest.select(actor, children, picker, scores)?;
+        let highest_score = scores
+            .iter(actor, &[highest_score_entity], picker)
+            .next()
+            .map(|(_, score)| score)?;
+        (highest_score >= self.threshold().get()).then_some(highest_score_entity)
+    }
+
+    fn thresholds(&self) -> Vec<Score> {
+        vec![self.threshold]
+    }
+}
+
+impl Component for HighestAboveThreshold {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, _entity, _component| {
+            world.commands().observe_once(pick_with_strategy::<Self>);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(pick_with_strategy::<Self>);
+        });
+    }
+}
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::rng::UtilityRng;
+
+/// [`Component`] for actor entities that adds a small amount of noise to each choice's score at pick time,
+/// breaking ties and adding variety.
+///
+/// The noise is only applied while picking, so the stored [`Score`](crate::scoring::Score)s that other systems read
+/// are unaffected. It's uniformly distributed in `[-amplitude, amplitude]`,
+/// and derived from the `seed` combined with the seed of the actor's [`UtilityRng`],
+/// the entities involved and the elapsed [`Time`], so it's reproducible for replays and lockstep networking.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// world.spawn((Picker::new(idle), Highest, PickJitter::new(0.05)));
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct PickJitter {
+    /// The maximum amount of noise added to or subtracted from a score.
+    amplitude: f32,
+    /// The seed for the noise.
+    seed: u64,
+}
+
+impl PickJitter {
+    /// Creates a new [`PickJitter`] with the given amplitude.
+    #[must_use]
+    pub fn new(amplitude: f32) -> Self {
+        Self {
+            amplitude: amplitude.abs(),
+            seed: 0,
+        }
+    }
+
+    /// Sets the seed for the noise.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the maximum amount of noise added to or subtracted from a score.
+    #[must_use]
+    pub fn amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    /// Sets the maximum amount of noise added to or subtracted from a score.
+    pub fn set_amplitude(&mut self, amplitude: f32) {
+        self.amplitude = amplitude.abs();
+    }
+
+    /// Returns the seed for the noise.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Sets the seed for the noise.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Returns the noise for the given actor and score entity at the given elapsed time,
+    /// drawn from a [`UtilityRng`] seeded with them, this jitter's seed, and the seed of the given [`UtilityRng`].
+    ///
+    /// The given [`UtilityRng`] isn't advanced, so the noise is the same for every call during a pick.
+    #[must_use]
+    pub fn offset(&self, rng: &UtilityRng, actor: Entity, score_entity: Entity, now: Duration) -> f32 {
+        let bits = [actor.to_bits(), score_entity.to_bits(), now.as_nanos() as u64]
+            .into_iter()
+            .fold(self.seed ^ rng.seed(), |seed, value| {
+                UtilityRng::new(seed ^ value).next_u64()
+            });
+        // Use the upper 24 bits for a uniform value in [0, 1)
+        let unit = (bits >> 40) as f32 / (1u64 << 24) as f32;
+        (unit * 2. - 1.) * self.amplitude
+    }
+}
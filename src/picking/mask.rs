@@ -0,0 +1,120 @@
+use bevy::{
+    ecs::component::{ComponentId, Components},
+    prelude::*,
+};
+
+/// [`Component`] for actor entities that temporarily disables actions,
+/// e.g. being disarmed disables "attack", being silenced disables "cast".
+///
+/// The built-in pickers skip choices for masked actions.
+/// Use [`mask_action_on`] and [`unmask_action_on`] to update the mask from gameplay events.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// #[derive(Component)]
+/// pub struct Attack;
+///
+/// #[derive(Event)]
+/// pub struct Disarmed;
+/// #[derive(Event)]
+/// pub struct Rearmed;
+///
+/// // Disarming an actor disables attacking until they're rearmed.
+/// app.observe(mask_action_on::<Disarmed, Attack>)
+///     .observe(unmask_action_on::<Rearmed, Attack>);
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # let attack = world.init_component::<Attack>();
+/// # let actor = world.spawn(ActionMask::default()).id();
+/// # world.trigger_targets(Disarmed, actor);
+/// # world.flush();
+/// # assert!(world.get::<ActionMask>(actor).unwrap().is_masked(attack));
+/// # world.trigger_targets(Rearmed, actor);
+/// # world.flush();
+/// # assert!(!world.get::<ActionMask>(actor).unwrap().is_masked(attack));
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ActionMask {
+    /// The masked actions.
+    masked: Vec<ComponentId>,
+}
+
+impl ActionMask {
+    /// Masks the given action.
+    #[must_use]
+    pub fn with(mut self, action: ComponentId) -> Self {
+        self.mask(action);
+        self
+    }
+
+    /// Masks the given action, so it can't be picked.
+    pub fn mask(&mut self, action: ComponentId) {
+        if !self.masked.contains(&action) {
+            self.masked.push(action);
+        }
+    }
+
+    /// Unmasks the given action, so it can be picked again.
+    pub fn unmask(&mut self, action: ComponentId) {
+        self.masked.retain(|&masked| masked != action);
+    }
+
+    /// Unmasks all actions.
+    pub fn clear(&mut self) {
+        self.masked.clear();
+    }
+
+    /// Returns `true` if the given action is masked.
+    #[must_use]
+    pub fn is_masked(&self, action: ComponentId) -> bool {
+        self.masked.contains(&action)
+    }
+
+    /// Returns an iterator over the masked actions.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = ComponentId> + '_ {
+        self.masked.iter().copied()
+    }
+}
+
+/// [`Observer`] that listens for the [`Event`] `E` targeting an actor entity and masks the `Action`
+/// [`Component`] in its [`ActionMask`], inserting one if needed.
+pub fn mask_action_on<E: Event, Action: Component>(
+    trigger: Trigger<E>,
+    mut commands: Commands,
+    components: &Components,
+    mut masks: Query<&mut ActionMask>,
+) {
+    let actor = trigger.entity();
+    let Some(action) = components.component_id::<Action>() else {
+        // The action was never registered, so it can't be picked anyway.
+        return;
+    };
+
+    if let Ok(mut mask) = masks.get_mut(actor) {
+        mask.mask(action);
+    } else if let Some(mut actor) = commands.get_entity(actor) {
+        actor.insert(ActionMask::default().with(action));
+    }
+}
+
+/// [`Observer`] that listens for the [`Event`] `E` targeting an actor entity and unmasks the `Action`
+/// [`Component`] in its [`ActionMask`].
+pub fn unmask_action_on<E: Event, Action: Component>(
+    trigger: Trigger<E>,
+    components: &Components,
+    mut masks: Query<&mut ActionMask>,
+) {
+    let Some(action) = components.component_id::<Action>() else {
+        return;
+    };
+    if let Ok(mut mask) = masks.get_mut(trigger.entity()) {
+        mask.unmask(action);
+    }
+}
@@ -0,0 +1,261 @@
+use bevy::{
+    ecs::component::{ComponentHooks, ComponentId, StorageType},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    ecs::CommandsExt,
+    event::OnPicked,
+    picking::{pick_with_strategy, ChoiceScores, PickStrategy, Picker, PickerGroup},
+    scoring::Score,
+};
+
+/// [`Picker`] [`Component`] that picks up to `max` compatible actions at once, highest scores first,
+/// so they can be performed concurrently (e.g. moving and shouting).
+///
+/// Actions are compatible unless they share a channel (see [`PickMultiple::with_channel`]).
+/// Actions without a channel are compatible with every other action.
+/// Only choices that reach the threshold are picked. If none do, the default action is picked.
+///
+/// The highest scoring action is picked through [`PickStrategy`] like any other [`Picker`] kind,
+/// so it respects [`PickerGroup`]s and [`ReportPicks`], and the other actions are picked alongside it.
+/// Only the highest scoring choice can be a [`PickerGroup`]; other groups are skipped.
+///
+/// The picked actions are stored in [`PickedActions`], with an [`OnPicked`] event triggered for each one,
+/// and [`Picker::picked`] set to the highest scoring one. The [`OnPicked`] events of the other actions
+/// are marked as [`extra`](OnPicked::extra).
+/// When requesting actions, the [`ActionPlugin`](crate::acting::ActionPlugin) keeps the actor's
+/// [`CurrentActions`](crate::acting::CurrentActions) in sync with the [`PickedActions`].
+///
+/// [`ReportPicks`]: crate::picking::ReportPicks
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Walk;
+/// #[derive(Component)]
+/// pub struct Run;
+/// #[derive(Component)]
+/// pub struct Shout;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let walk = world.init_component::<Walk>();
+/// let run = world.init_component::<Run>();
+/// let shout = world.init_component::<Shout>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let walk_scorer = world.spawn(FixedScore::new(0.5)).id();
+/// let run_scorer = world.spawn(FixedScore::new(0.7)).id();
+/// let shout_scorer = world.spawn(FixedScore::new(0.6)).id();
+///
+/// let actor = world
+///     .spawn((
+///         Picker::new(idle)
+///             .with(walk_scorer, walk)
+///             .with(run_scorer, run)
+///             .with(shout_scorer, shout),
+///         // Walking and running both need the legs, so only one of them can be picked.
+///         PickMultiple::new(2).with_channel(walk, 0).with_channel(run, 0),
+///     ))
+///     .push_children(&[walk_scorer, run_scorer, shout_scorer])
+///     .id();
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(&[run, shout], &*world.get::<PickedActions>(actor).unwrap().0);
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct PickMultiple {
+    /// The maximum number of actions to pick at once.
+    max: usize,
+    /// The [`Score`] threshold for a choice to be picked.
+    threshold: Score,
+    /// The channel of each action. Actions in the same channel are incompatible.
+    channels: HashMap<ComponentId, u32>,
+    /// The choices selected by the last pick, highest scoring first, until they're stored in [`PickedActions`].
+    #[reflect(ignore)]
+    selection: Vec<(Entity, ComponentId)>,
+}
+
+impl PickMultiple {
+    /// Creates a new [`PickMultiple`] that picks up to `max` actions, with any non-zero score.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        Self {
+            max: max.max(1),
+            threshold: Score::MIN,
+            channels: HashMap::default(),
+            selection: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Score`] threshold for a choice to be picked.
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: impl Into<Score>) -> Self {
+        self.threshold = threshold.into();
+        self
+    }
+
+    /// Puts the given action into the given channel. Actions in the same channel are never picked together.
+    #[must_use]
+    pub fn with_channel(mut self, action: ComponentId, channel: u32) -> Self {
+        self.channels.insert(action, channel);
+        self
+    }
+
+    /// Returns the maximum number of actions to pick at once.
+    #[must_use]
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Sets the maximum number of actions to pick at once.
+    pub fn set_max(&mut self, max: usize) {
+        self.max = max.max(1);
+    }
+
+    /// Returns the [`Score`] threshold for a choice to be picked.
+    #[must_use]
+    pub fn threshold(&self) -> Score {
+        self.threshold
+    }
+
+    /// Sets the [`Score`] threshold for a choice to be picked.
+    pub fn set_threshold(&mut self, threshold: impl Into<Score>) {
+        self.threshold = threshold.into();
+    }
+
+    /// Returns the channel of the given action, if any.
+    #[must_use]
+    pub fn channel(&self, action: ComponentId) -> Option<u32> {
+        self.channels.get(&action).copied()
+    }
+
+    /// Returns `true` if the given actions can be performed at the same time.
+    #[must_use]
+    pub fn compatible(&self, a: ComponentId, b: ComponentId) -> bool {
+        if a == b {
+            return false;
+        }
+        match (self.channel(a), self.channel(b)) {
+            (Some(a), Some(b)) => a != b,
+            _ => true,
+        }
+    }
+
+    /// [`Observer`] that stores the actions picked alongside the highest scoring one in [`PickedActions`],
+    /// once [`pick_with_strategy`] has picked it, and triggers [`OnPicked`] for each of them.
+    fn on_picked(
+        trigger: Trigger<OnPicked>,
+        mut commands: Commands,
+        mut targets: Query<(&mut PickMultiple, Option<&PickedActions>)>,
+        groups: Query<(), With<PickerGroup>>,
+        scores: ChoiceScores,
+    ) {
+        utility_span!("PickMultiple::on_picked", target = ?trigger.entity());
+        let target = trigger.entity();
+        let Ok((mut settings, previous)) = targets.get_mut(target) else {
+            return;
+        };
+        let event = trigger.event();
+        if event.extra || event.score_entity != settings.selection.first().map(|&(score_entity, _)| score_entity) {
+            // Triggered for one of the other picked actions, or not by this picker.
+            return;
+        }
+        let selection = std::mem::take(&mut settings.selection);
+
+        let extras: Vec<(Entity, ComponentId)> = selection
+            .into_iter()
+            .skip(1)
+            .filter(|&(score_entity, _)| !groups.contains(score_entity))
+            .collect();
+        if event.action.is_none() && extras.is_empty() {
+            // Nothing was picked and there's no default, so keep the current actions
+            return;
+        }
+
+        for &(score_entity, action) in &extras {
+            commands.trigger_targets(
+                OnPicked {
+                    action: Some(action),
+                    score_entity: Some(score_entity),
+                    score: scores.score(score_entity),
+                    changed: !previous.is_some_and(|previous| previous.0.contains(&action)),
+                    target: scores.target(score_entity),
+                    extra: true,
+                },
+                target,
+            );
+        }
+        let actions = event
+            .action
+            .into_iter()
+            .chain(extras.into_iter().map(|(_, action)| action))
+            .collect();
+        commands.entity(target).insert(PickedActions(actions));
+    }
+}
+
+impl PickStrategy for PickMultiple {
+    fn select(&mut self, actor: Entity, children: &[Entity], picker: &Picker, scores: &ChoiceScores) -> Option<Entity> {
+        let mut candidates: Vec<(Entity, ComponentId, f32)> = scores
+            .iter(actor, children, picker)
+            .filter(|&(_, score)| score >= self.threshold.get() && score > 0.)
+            .filter_map(|(score_entity, score)| {
+                let action = picker.choices.get(&score_entity).copied()?;
+                Some((score_entity, action, score))
+            })
+            .collect();
+        // Stable sort, so ties keep their priority order
+        candidates.sort_by(|(_, _, a), (_, _, b)| b.total_cmp(a));
+
+        self.selection.clear();
+        for (score_entity, action, _) in candidates {
+            if self.selection.len() >= self.max {
+                break;
+            }
+            if self.selection.iter().all(|&(_, other)| self.compatible(action, other)) {
+                self.selection.push((score_entity, action));
+            }
+        }
+        self.selection.first().map(|&(score_entity, _)| score_entity)
+    }
+
+    fn thresholds(&self) -> Vec<Score> {
+        vec![self.threshold]
+    }
+}
+
+impl Component for PickMultiple {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, _entity, _component| {
+            world.commands().observe_once(pick_with_strategy::<Self>);
+            world.commands().observe_once(Self::on_picked);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(pick_with_strategy::<Self>);
+            world.commands().unobserve_if_unused(Self::on_picked);
+        });
+    }
+}
+
+/// [`Component`] that stores the actions last picked by a [`PickMultiple`] [`Picker`], highest scoring first.
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct PickedActions(pub Vec<ComponentId>);
@@ -2,22 +2,21 @@ use bevy::{
     ecs::component::{ComponentHooks, StorageType},
     prelude::*,
 };
-use rand::{seq::IteratorRandom, RngCore};
+use rand::seq::IteratorRandom;
 
 use crate::{
-    ecs::{CommandsExt, TriggerGetEntity},
-    event::{OnPick, OnPicked},
-    picking::Picker,
+    ecs::CommandsExt,
+    picking::{pick_with_strategy, ChoiceScores, PickStrategy, Picker},
 };
 
-/// [`Picker`] [`Component`] that picks randomly.
+/// [`Picker`] [`Component`] that picks randomly,
+/// drawing from the actor entity's [`UtilityRng`](crate::rng::UtilityRng).
 ///
 /// # Example
 ///
 /// ```rust
 /// use bevy::prelude::*;
 /// use bevy_observed_utility::prelude::*;
-/// use rand::prelude::{StdRng, SeedableRng};
 ///
 /// # let mut app = App::new();
 /// # app.add_plugins(ObservedUtilityPlugins::RealTime);
@@ -45,7 +44,7 @@ use crate::{
 ///         Picker::new(idle_action)
 ///             // if the score entity is selected, my_action will be picked.
 ///             .with(scorer, my_action),
-///         PickRandom::new(StdRng::from_entropy()),
+///         PickRandom,
 ///     ))
 ///     .add_child(scorer)
 ///     .id();
@@ -53,57 +52,27 @@ use crate::{
 /// commands.trigger_targets(RunScoring, scorer);
 /// commands.trigger_targets(RunPicking, actor);
 /// # world.flush();
-/// # assert_eq!(my_action, world.get::<Picker>(actor).unwrap().picked);
+/// # assert_eq!(Some(my_action), world.get::<Picker>(actor).unwrap().picked);
 /// ```
-pub struct PickRandom {
-    /// The random number generator to use.
-    pub rng: Box<dyn RngCore + Send + Sync + 'static>,
-}
-
-impl PickRandom {
-    /// Creates a new [`Random`] with the given random number generator.
-    pub fn new(rng: impl RngCore + Send + Sync + 'static) -> Self {
-        Self { rng: Box::new(rng) }
-    }
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct PickRandom;
 
-    /// Returns a reference to the random number generator.
-    pub fn rng(&mut self) -> &mut (impl RngCore + Send + Sync + 'static) {
-        &mut self.rng
-    }
-
-    /// Sets the random number generator.
-    pub fn set_rng(&mut self, rng: impl RngCore + Send + Sync + 'static) {
-        self.rng = Box::new(rng);
-    }
-
-    /// [`Observer`] for the [`Random`] [`Picker`] that picks randomly.
-    fn observer(
-        trigger: Trigger<OnPick>,
-        mut commands: Commands,
-        mut targets: Query<(Entity, &mut Picker, &mut PickRandom)>,
-    ) {
-        fn run(target: Entity, mut commands: Commands, mut picker: Mut<Picker>, settings: &mut PickRandom) {
-            let random = picker.choices.keys().choose(&mut *settings.rng()).copied();
-            let action = picker.pick(random);
-            commands.trigger_targets(OnPicked { action }, target);
-        }
-
-        if let Some(target) = trigger.get_entity() {
-            let Ok((target, picker, settings)) = targets.get_mut(target) else {
-                return;
-            };
-            run(target, commands.reborrow(), picker, settings.into_inner());
-        } else {
-            for (target, picker, settings) in targets.iter_mut() {
-                run(target, commands.reborrow(), picker, settings.into_inner());
-            }
-        }
-    }
-}
-
-impl<R: RngCore + Send + Sync + 'static> From<R> for PickRandom {
-    fn from(rng: R) -> Self {
-        Self::new(rng)
+impl PickStrategy for PickRandom {
+    fn select(
+        &mut self,
+        actor: Entity,
+        _children: &[Entity],
+        picker: &Picker,
+        scores: &ChoiceScores,
+    ) -> Option<Entity> {
+        picker
+            .choices
+            .iter()
+            .filter(|&(_, action)| scores.is_available(actor, action))
+            .map(|(score_entity, _)| score_entity)
+            .choose(&mut scores.rng(actor))
     }
 }
 
@@ -112,10 +81,10 @@ impl Component for PickRandom {
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct RandomObserverSpawned;
-
-            world.commands().once::<RandomObserverSpawned>().observe(Self::observer);
+            world.commands().observe_once(pick_with_strategy::<Self>);
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(pick_with_strategy::<Self>);
         });
     }
 }
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use crate::scoring::Score;
+
+/// [`Component`] for actor entities that makes their [`Picker`](crate::picking::Picker)
+/// stick with the last picked choice, unless a competitor beats it by at least `margin`.
+///
+/// This is done by adding `margin` to the incumbent choice score entity's [`Score`] at pick time (the stored [`Score`] is unchanged),
+/// which prevents oscillating between two actions with similar scores.
+/// Supported by all built-in pickers that compare scores.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Eat;
+/// #[derive(Component)]
+/// pub struct Drink;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let eat = world.init_component::<Eat>();
+/// let drink = world.init_component::<Drink>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let eat_scorer = world.spawn(FixedScore::new(0.5)).id();
+/// let drink_scorer = world.spawn(FixedScore::new(0.4)).id();
+/// let actor = world
+///     .spawn((
+///         Picker::new(idle).with(eat_scorer, eat).with(drink_scorer, drink),
+///         Highest,
+///         Sticky::new(0.2),
+///     ))
+///     .push_children(&[eat_scorer, drink_scorer])
+///     .id();
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(eat), world.get::<Picker>(actor).unwrap().picked);
+///
+/// // Drinking now scores slightly higher, but not by enough to switch away from eating.
+/// world.entity_mut(drink_scorer).insert(FixedScore::new(0.6));
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(eat), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct Sticky {
+    /// The bonus added to the last picked choice's score at pick time.
+    margin: Score,
+}
+
+impl Sticky {
+    /// Creates a new [`Sticky`] with the given switching margin.
+    #[must_use]
+    pub fn new(margin: impl Into<Score>) -> Self {
+        Self { margin: margin.into() }
+    }
+
+    /// Returns the switching margin.
+    #[must_use]
+    pub fn margin(&self) -> Score {
+        self.margin
+    }
+
+    /// Sets the switching margin.
+    pub fn set_margin(&mut self, margin: impl Into<Score>) {
+        self.margin = margin.into();
+    }
+}
@@ -0,0 +1,598 @@
+//! Lightweight goal-oriented action planning (GOAP) on top of scoring, for multi-step behaviors
+//! that are easier to describe by their requirements than by hand-written [`ActionQueue`]s.
+//!
+//! Requires the `planning` feature.
+//!
+//! Add the [`PlanningPlugin`] and give actor entities a [`Planner`] with their goal and the [`PlanAction`]s they can
+//! perform, each with the [`Facts`] it requires and the [`Facts`] it changes, alongside the actor's current [`Facts`].
+//! Triggering [`RunPlanning`] searches for the cheapest sequence of actions that reaches the goal,
+//! using the [`Score`]s of the actions' score entities to make high scoring actions cheaper.
+//! The first action of the plan is requested, and the rest are queued in the actor's [`ActionQueue`],
+//! so the plan is carried out by the usual action lifecycle.
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use bevy::{ecs::component::ComponentId, prelude::*, utils::HashSet};
+
+use crate::{acting::ActionQueue, ecs::TriggerGetEntity, event::RequestAction, scoring::Score, AiDisabled, AiPaused};
+
+/// [`Plugin`] for planning action sequences with [`Planner`]s. See the [module docs](self) for more information.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// const HAS_AXE: Fact = Fact::new(0);
+/// const HAS_WOOD: Fact = Fact::new(1);
+///
+/// #[derive(Component)]
+/// pub struct GetAxe;
+/// #[derive(Component)]
+/// pub struct ChopWood;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::TurnBased, PlanningPlugin));
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// let get_axe = world.init_component::<GetAxe>();
+/// let chop_wood = world.init_component::<ChopWood>();
+/// # let idle = world.init_component::<Idle>();
+///
+/// let planner = Planner::new(Facts::new().with(HAS_WOOD))
+///     .with(PlanAction::new(chop_wood).requires(HAS_AXE).adds(HAS_WOOD))
+///     .with(PlanAction::new(get_axe).adds(HAS_AXE));
+/// let actor = world.spawn((Picker::new(idle), Highest, planner, Facts::new())).id();
+///
+/// world.trigger_targets(RunPlanning, actor);
+/// # world.flush();
+/// assert_eq!(get_axe, world.get::<CurrentAction>(actor).unwrap().0);
+/// assert_eq!(Some(chop_wood), world.get::<ActionQueue>(actor).unwrap().peek());
+/// ```
+pub struct PlanningPlugin;
+
+impl Plugin for PlanningPlugin {
+    fn build(&self, app: &mut App) {
+        app.observe(Self::run_planning);
+
+        app.register_type::<Facts>()
+            .register_type::<Planner>()
+            .register_type::<RunPlanning>()
+            .register_type::<OnPlanned>();
+    }
+}
+
+impl PlanningPlugin {
+    /// [`Observer`] that plans for one specific or all actor entities with a [`Planner`] and [`Facts`].
+    ///
+    /// If a plan is found, its first action is requested, the rest are queued in the actor's [`ActionQueue`]
+    /// (replacing what was queued), and [`OnPlanned`] is triggered. Otherwise the actor is left alone.
+    /// Nothing is planned for while [`AiPaused`], or for [`AiDisabled`] entities.
+    pub fn run_planning(
+        trigger: Trigger<RunPlanning>,
+        mut commands: Commands,
+        paused: Option<Res<AiPaused>>,
+        planners: Query<(Entity, &Planner, &Facts), Without<AiDisabled>>,
+        scores: Query<&Score>,
+    ) {
+        if paused.is_some() {
+            return;
+        }
+
+        let targets: Vec<_> = match trigger.get_entity() {
+            Some(target) => planners.get(target).into_iter().collect(),
+            None => planners.iter().collect(),
+        };
+        for (actor, planner, facts) in targets {
+            let Some(plan) = planner.plan(*facts, |score_entity| scores.get(score_entity).ok().copied()) else {
+                continue;
+            };
+            if let Some((&first, rest)) = plan.actions.split_first() {
+                commands
+                    .entity(actor)
+                    .insert(rest.iter().copied().collect::<ActionQueue>());
+                commands.trigger_targets(RequestAction::action(first), actor);
+            }
+            commands.trigger_targets(OnPlanned(plan), actor);
+        }
+    }
+}
+
+/// A single fact about the world from an actor entity's point of view, such as "has an axe".
+/// Up to 64 facts are supported, numbered from 0 to 63.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Fact(u8);
+
+impl Fact {
+    /// Creates a new [`Fact`] with the given number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is 64 or above, as [`Facts`] only holds 64 facts.
+    #[must_use]
+    pub const fn new(id: u8) -> Self {
+        assert!(id < 64, "facts are numbered from 0 to 63");
+        Self(id)
+    }
+
+    /// Returns the number of this [`Fact`].
+    #[must_use]
+    pub const fn id(self) -> u8 {
+        self.0
+    }
+}
+
+/// [`Component`] for the [`Fact`]s that currently hold for an actor entity, kept up to date by gameplay code.
+/// Also used for the goals, requirements, and changes of [`Planner`]s and [`PlanAction`]s.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct Facts(u64);
+
+impl Facts {
+    /// Creates a new empty [`Facts`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given [`Fact`].
+    #[must_use]
+    pub fn with(mut self, fact: Fact) -> Self {
+        self.insert(fact);
+        self
+    }
+
+    /// Adds the given [`Fact`].
+    pub fn insert(&mut self, fact: Fact) {
+        self.0 |= Self::bit(fact);
+    }
+
+    /// Removes the given [`Fact`].
+    pub fn remove(&mut self, fact: Fact) {
+        self.0 &= !Self::bit(fact);
+    }
+
+    /// Returns `true` if the given [`Fact`] holds.
+    #[must_use]
+    pub fn contains(&self, fact: Fact) -> bool {
+        self.0 & Self::bit(fact) != 0
+    }
+
+    /// Returns `true` if all of the other [`Facts`] hold.
+    #[must_use]
+    pub fn contains_all(&self, other: Facts) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the bit for the given [`Fact`].
+    fn bit(fact: Fact) -> u64 {
+        1 << fact.0
+    }
+}
+
+/// An action that a [`Planner`] can use in its plans.
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[reflect(PartialEq, Debug)]
+pub struct PlanAction {
+    /// [`ComponentId`] of the action.
+    action: ComponentId,
+    /// The [`Facts`] that must hold to perform the action.
+    requires: Facts,
+    /// The [`Facts`] that hold after performing the action.
+    adds: Facts,
+    /// The [`Facts`] that no longer hold after performing the action.
+    removes: Facts,
+    /// The base cost of performing the action.
+    cost: f32,
+    /// The score entity whose [`Score`] makes the action cheaper, if any.
+    score_entity: Option<Entity>,
+}
+
+impl PlanAction {
+    /// Creates a new [`PlanAction`] for the given action, without any requirements or changes, and a cost of 1.
+    #[must_use]
+    pub fn new(action: ComponentId) -> Self {
+        Self {
+            action,
+            requires: Facts::new(),
+            adds: Facts::new(),
+            removes: Facts::new(),
+            cost: 1.,
+            score_entity: None,
+        }
+    }
+
+    /// Requires the given [`Fact`] to hold to perform the action.
+    #[must_use]
+    pub fn requires(mut self, fact: Fact) -> Self {
+        self.requires.insert(fact);
+        self
+    }
+
+    /// Makes the given [`Fact`] hold after performing the action.
+    #[must_use]
+    pub fn adds(mut self, fact: Fact) -> Self {
+        self.adds.insert(fact);
+        self.removes.remove(fact);
+        self
+    }
+
+    /// Makes the given [`Fact`] no longer hold after performing the action.
+    #[must_use]
+    pub fn removes(mut self, fact: Fact) -> Self {
+        self.removes.insert(fact);
+        self.adds.remove(fact);
+        self
+    }
+
+    /// Sets the base cost of performing the action. Negative costs are treated as 0.
+    #[must_use]
+    pub fn with_cost(mut self, cost: f32) -> Self {
+        self.cost = cost.max(0.);
+        self
+    }
+
+    /// Makes the action cheaper the higher the given score entity's [`Score`] is,
+    /// adding `1 - score` to its base cost.
+    #[must_use]
+    pub fn scored_by(mut self, score_entity: Entity) -> Self {
+        self.score_entity = Some(score_entity);
+        self
+    }
+
+    /// Returns the [`ComponentId`] of the action.
+    #[must_use]
+    pub fn action(&self) -> ComponentId {
+        self.action
+    }
+
+    /// Returns `true` if the action can be performed with the given [`Facts`].
+    #[must_use]
+    pub fn is_applicable(&self, facts: Facts) -> bool {
+        facts.contains_all(self.requires)
+    }
+
+    /// Returns the [`Facts`] after performing the action with the given [`Facts`].
+    #[must_use]
+    pub fn apply(&self, facts: Facts) -> Facts {
+        Facts((facts.0 & !self.removes.0) | self.adds.0)
+    }
+}
+
+/// [`Component`] for actor entities that plan sequences of [`PlanAction`]s to reach a goal with [`RunPlanning`].
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct Planner {
+    /// The [`Facts`] that should hold once the plan is carried out.
+    goal: Facts,
+    /// The actions to plan with.
+    actions: Vec<PlanAction>,
+    /// The maximum number of actions in a plan.
+    max_depth: usize,
+}
+
+impl Planner {
+    /// Creates a new [`Planner`] for the given goal, without any actions, and plans of at most 8 actions.
+    #[must_use]
+    pub fn new(goal: Facts) -> Self {
+        Self {
+            goal,
+            actions: Vec::new(),
+            max_depth: 8,
+        }
+    }
+
+    /// Adds the given [`PlanAction`] to plan with.
+    #[must_use]
+    pub fn with(mut self, action: PlanAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Sets the maximum number of actions in a plan.
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns the goal.
+    #[must_use]
+    pub fn goal(&self) -> Facts {
+        self.goal
+    }
+
+    /// Sets the goal.
+    pub fn set_goal(&mut self, goal: Facts) {
+        self.goal = goal;
+    }
+
+    /// Returns the maximum number of actions in a plan.
+    #[must_use]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns the actions to plan with.
+    #[must_use]
+    pub fn actions(&self) -> &[PlanAction] {
+        &self.actions
+    }
+
+    /// Searches for the cheapest sequence of actions that reaches the goal from the given [`Facts`],
+    /// reading the [`Score`]s of the actions' score entities with `score`.
+    /// Returns `None` if the goal can't be reached within the maximum number of actions.
+    pub fn plan(&self, facts: Facts, score: impl Fn(Entity) -> Option<Score>) -> Option<Plan> {
+        let costs: Vec<f32> = self
+            .actions
+            .iter()
+            .map(|action| {
+                let score = action.score_entity.and_then(&score).map_or(0., |score| score.get());
+                action.cost + (1. - score).max(0.)
+            })
+            .collect();
+
+        // Uniform-cost search over the reachable facts, remembering how each node was reached
+        let mut nodes: Vec<PlanNode> = vec![PlanNode {
+            facts,
+            parent: None,
+            action: None,
+            cost: 0.,
+            depth: 0,
+        }];
+        let mut open = BinaryHeap::from([OpenNode { cost: 0., node: 0 }]);
+        let mut closed = HashSet::default();
+        while let Some(OpenNode { node, .. }) = open.pop() {
+            let PlanNode { facts, cost, depth, .. } = nodes[node];
+            if facts.contains_all(self.goal) {
+                return Some(self.reconstruct(&nodes, node));
+            }
+            if !closed.insert(facts) || depth >= self.max_depth {
+                continue;
+            }
+            for (index, action) in self.actions.iter().enumerate() {
+                if !action.is_applicable(facts) {
+                    continue;
+                }
+                let next = action.apply(facts);
+                if closed.contains(&next) {
+                    continue;
+                }
+                let cost = cost + costs[index];
+                nodes.push(PlanNode {
+                    facts: next,
+                    parent: Some(node),
+                    action: Some(index),
+                    cost,
+                    depth: depth + 1,
+                });
+                open.push(OpenNode {
+                    cost,
+                    node: nodes.len() - 1,
+                });
+            }
+        }
+        None
+    }
+
+    /// Builds the [`Plan`] that reaches the given node.
+    fn reconstruct(&self, nodes: &[PlanNode], node: usize) -> Plan {
+        let cost = nodes[node].cost;
+        let mut actions = Vec::new();
+        let mut current = Some(node);
+        while let Some(PlanNode {
+            parent,
+            action: Some(action),
+            ..
+        }) = current.map(|node| nodes[node])
+        {
+            actions.push(self.actions[action].action);
+            current = parent;
+        }
+        actions.reverse();
+        Plan { actions, cost }
+    }
+}
+
+/// A sequence of actions found by [`Planner::plan`].
+#[derive(Reflect)]
+#[derive(Clone, PartialEq, Debug, Default)]
+#[reflect(PartialEq, Debug, Default)]
+pub struct Plan {
+    /// [`ComponentId`]s of the actions to perform, in order. Empty if the goal already holds.
+    pub actions: Vec<ComponentId>,
+    /// The total cost of the actions.
+    pub cost: f32,
+}
+
+/// A node in the [`Planner::plan`] search.
+#[derive(Clone, Copy)]
+struct PlanNode {
+    /// The facts at this node.
+    facts: Facts,
+    /// The node this one was reached from.
+    parent: Option<usize>,
+    /// The index of the action that reached this node.
+    action: Option<usize>,
+    /// The total cost to reach this node.
+    cost: f32,
+    /// The number of actions to reach this node.
+    depth: usize,
+}
+
+/// An entry in the [`Planner::plan`] open set, ordered so that the cheapest node is popped first.
+struct OpenNode {
+    /// The total cost to reach the node.
+    cost: f32,
+    /// The index of the node.
+    node: usize,
+}
+
+impl PartialEq for OpenNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OpenNode {}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, since BinaryHeap is a max-heap, and ties go to the earliest node for determinism
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+/// Trigger this [`Event`] to make the target actor entity plan with its [`Planner`],
+/// or all actor entities if no target is specified.
+#[derive(Event, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct RunPlanning;
+
+/// Listen to this [`Event`] to check which [`Plan`] was found for the target actor entity.
+/// This [`Event`] isn't triggered if no plan was found.
+#[derive(Event, Reflect)]
+#[derive(Clone, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct OnPlanned(pub Plan);
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::{
+        acting::{ActionQueue, CurrentAction},
+        picking::{Highest, Picker},
+        planning::{Fact, Facts, OnPlanned, Plan, PlanAction, Planner, PlanningPlugin, RunPlanning},
+        scoring::Score,
+    };
+
+    const HAS_AXE: Fact = Fact::new(0);
+    const HAS_WOOD: Fact = Fact::new(1);
+
+    #[derive(Component)]
+    struct GetAxe;
+
+    #[derive(Component)]
+    struct ChopWood;
+
+    #[derive(Component)]
+    struct BuyWood;
+
+    #[derive(Component)]
+    struct Idle;
+
+    #[test]
+    fn plans_the_cheapest_sequence() {
+        let mut world = World::new();
+        let get_axe = world.init_component::<GetAxe>();
+        let chop_wood = world.init_component::<ChopWood>();
+        let buy_wood = world.init_component::<BuyWood>();
+        let merchant = world.spawn_empty().id();
+
+        let planner = Planner::new(Facts::new().with(HAS_WOOD))
+            .with(
+                PlanAction::new(buy_wood)
+                    .adds(HAS_WOOD)
+                    .with_cost(3.5)
+                    .scored_by(merchant),
+            )
+            .with(PlanAction::new(chop_wood).requires(HAS_AXE).adds(HAS_WOOD))
+            .with(PlanAction::new(get_axe).adds(HAS_AXE));
+
+        // Without a score, buying wood costs 4.5, more than the 2 + 2 of getting an axe and chopping.
+        assert_eq!(
+            Some(Plan {
+                actions: vec![get_axe, chop_wood],
+                cost: 4.
+            }),
+            planner.plan(Facts::new(), |_| None)
+        );
+        // A high score makes buying wood cheaper.
+        assert_eq!(
+            Some(Plan {
+                actions: vec![buy_wood],
+                cost: 3.5
+            }),
+            planner.plan(Facts::new(), |entity| (entity == merchant).then_some(Score::MAX))
+        );
+        // Nothing to do once the goal holds.
+        assert_eq!(
+            Some(Plan::default()),
+            planner.plan(Facts::new().with(HAS_WOOD), |_| None)
+        );
+        // Getting an axe and chopping is out of reach within one action.
+        let planner = Planner::new(Facts::new().with(HAS_WOOD))
+            .with(PlanAction::new(chop_wood).requires(HAS_AXE).adds(HAS_WOOD))
+            .with(PlanAction::new(get_axe).adds(HAS_AXE))
+            .with_max_depth(1);
+        assert_eq!(None, planner.plan(Facts::new(), |_| None));
+    }
+
+    #[test]
+    fn replans_when_facts_change() {
+        #[derive(Resource, Default)]
+        struct Planned(Vec<Plan>);
+
+        let mut app = App::new();
+        app.add_plugins((crate::ObservedUtilityPlugins::TurnBased, PlanningPlugin))
+            .init_resource::<Planned>()
+            .observe(|trigger: Trigger<OnPlanned>, mut planned: ResMut<Planned>| {
+                planned.0.push(trigger.event().0.clone());
+            });
+        let world = app.world_mut();
+
+        let get_axe = world.init_component::<GetAxe>();
+        let chop_wood = world.init_component::<ChopWood>();
+        let idle = world.init_component::<Idle>();
+
+        let planner = Planner::new(Facts::new().with(HAS_WOOD))
+            .with(PlanAction::new(chop_wood).requires(HAS_AXE).adds(HAS_WOOD))
+            .with(PlanAction::new(get_axe).adds(HAS_AXE));
+        let actor = world.spawn((Picker::new(idle), Highest, planner, Facts::new())).id();
+
+        world.trigger_targets(RunPlanning, actor);
+        world.flush();
+        assert_eq!(Some(&CurrentAction(get_axe)), world.get::<CurrentAction>(actor));
+        assert_eq!(Some(chop_wood), world.get::<ActionQueue>(actor).unwrap().peek());
+
+        // The actor found an axe on its own, so the plan gets shorter.
+        world.get_mut::<Facts>(actor).unwrap().insert(HAS_AXE);
+        world.trigger_targets(RunPlanning, actor);
+        world.flush();
+        assert_eq!(Some(&CurrentAction(chop_wood)), world.get::<CurrentAction>(actor));
+        assert_eq!(None, world.get::<ActionQueue>(actor).unwrap().peek());
+
+        assert_eq!(
+            vec![vec![get_axe, chop_wood], vec![chop_wood]],
+            world
+                .resource::<Planned>()
+                .0
+                .iter()
+                .map(|plan| plan.actions.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic = "facts are numbered from 0 to 63"]
+    fn fact_ids_above_63_panic() {
+        let id = 64;
+        let _ = Fact::new(id);
+    }
+}
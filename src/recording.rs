@@ -0,0 +1,464 @@
+//! Decision recording and replay, for turning "the AI behaved weirdly once" into a reproducible test case.
+//!
+//! Requires the `recording` feature.
+//!
+//! Add the [`DecisionRecorderPlugin`] to log the [`Score`]s, picks, and action events of every actor entity
+//! into the [`DecisionRecorder`] resource, as a compact [`DecisionLog`] that can be serialized with `serde`.
+//! Then, in a test that rebuilds the same score trees, [`ReplayDecisionsExt::replay_decisions`] re-feeds the recorded
+//! [`Score`]s, picks again, and reports the first pick that differs from the recording.
+//!
+//! Actions are recorded by their names in the [`ActionRegistry`], or by their type names if they aren't registered.
+//! Score entities are recorded as-is, so either rebuild the score trees in the same order,
+//! or map them with [`MapEntities`].
+
+use std::fmt;
+
+use bevy::{
+    ecs::{
+        component::{ComponentId, Components},
+        entity::{EntityMapper, MapEntities},
+    },
+    prelude::*,
+    utils::HashMap,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    acting::ActionRegistry,
+    event::{OnActionEnded, OnActionInitiated, OnPicked, OnScoringComplete, RunPicking},
+    picking::Picker,
+    scoring::{Score, ScoreHierarchy},
+};
+
+/// [`Plugin`] that records decisions into the [`DecisionRecorder`] resource.
+/// See the [module docs](self) for more information.
+pub struct DecisionRecorderPlugin;
+
+impl Plugin for DecisionRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DecisionRecorder>()
+            .add_systems(First, Self::advance_tick)
+            .observe(Self::on_scoring_complete_record)
+            .observe(Self::on_picked_record)
+            .observe(Self::on_action_initiated_record)
+            .observe(Self::on_action_ended_record);
+    }
+}
+
+impl DecisionRecorderPlugin {
+    /// [`System`] that advances the [`DecisionRecorder`]'s tick once per frame.
+    pub fn advance_tick(mut recorder: ResMut<DecisionRecorder>) {
+        recorder.tick += 1;
+    }
+
+    /// [`Observer`] that records the [`Score`]s of the target score tree once it's scored for an actor entity.
+    pub fn on_scoring_complete_record(
+        trigger: Trigger<OnScoringComplete>,
+        mut recorder: ResMut<DecisionRecorder>,
+        hierarchy: ScoreHierarchy,
+        scores: Query<&Score>,
+    ) {
+        let Some(actor) = trigger.event().actor else {
+            return;
+        };
+        let mut recorded = Vec::new();
+        let mut stack = vec![trigger.entity()];
+        while let Some(entity) = stack.pop() {
+            if let Ok(score) = scores.get(entity) {
+                recorded.push((entity, score.get()));
+            }
+            stack.extend(hierarchy.children(entity));
+        }
+        recorder.record(actor, DecisionEvent::Scored(recorded));
+    }
+
+    /// [`Observer`] that records the action picked for the target actor entity.
+    /// [Extra](OnPicked::extra) picks are skipped, so one pick is recorded per picking pass.
+    pub fn on_picked_record(
+        trigger: Trigger<OnPicked>,
+        mut recorder: ResMut<DecisionRecorder>,
+        components: &Components,
+        registry: Option<Res<ActionRegistry>>,
+    ) {
+        let OnPicked {
+            action,
+            score_entity,
+            extra,
+            ..
+        } = *trigger.event();
+        if extra {
+            return;
+        }
+        recorder.record(
+            trigger.entity(),
+            DecisionEvent::Picked {
+                action: action.map(|action| action_name(components, registry.as_deref(), action)),
+                score_entity,
+            },
+        );
+    }
+
+    /// [`Observer`] that records the action initiated for the target actor entity.
+    pub fn on_action_initiated_record(
+        trigger: Trigger<OnActionInitiated>,
+        mut recorder: ResMut<DecisionRecorder>,
+        components: &Components,
+        registry: Option<Res<ActionRegistry>>,
+    ) {
+        let action = action_name(components, registry.as_deref(), trigger.event().action);
+        recorder.record(trigger.entity(), DecisionEvent::ActionInitiated(action));
+    }
+
+    /// [`Observer`] that records the action ended for the target actor entity.
+    pub fn on_action_ended_record(
+        trigger: Trigger<OnActionEnded>,
+        mut recorder: ResMut<DecisionRecorder>,
+        components: &Components,
+        registry: Option<Res<ActionRegistry>>,
+    ) {
+        let action = action_name(components, registry.as_deref(), trigger.event().action);
+        recorder.record(trigger.entity(), DecisionEvent::ActionEnded(action));
+    }
+}
+
+/// [`Resource`] that holds the [`DecisionLog`] recorded by the [`DecisionRecorderPlugin`].
+#[derive(Resource)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct DecisionRecorder {
+    /// Whether decisions are recorded. Defaults to `true`.
+    pub recording: bool,
+    /// The current tick, advanced once per frame.
+    tick: u64,
+    /// The recorded decisions.
+    log: DecisionLog,
+}
+
+impl DecisionRecorder {
+    /// Returns the current tick.
+    #[must_use]
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns the decisions recorded so far.
+    #[must_use]
+    pub fn log(&self) -> &DecisionLog {
+        &self.log
+    }
+
+    /// Returns the decisions recorded so far, and starts a new empty [`DecisionLog`].
+    pub fn take(&mut self) -> DecisionLog {
+        std::mem::take(&mut self.log)
+    }
+
+    /// Records the given event for the given actor entity at the current tick, if [recording](Self::recording).
+    pub fn record(&mut self, actor: Entity, event: DecisionEvent) {
+        if self.recording {
+            self.log.0.push(DecisionRecord {
+                tick: self.tick,
+                actor,
+                event,
+            });
+        }
+    }
+}
+
+impl Default for DecisionRecorder {
+    fn default() -> Self {
+        Self {
+            recording: true,
+            tick: 0,
+            log: DecisionLog::default(),
+        }
+    }
+}
+
+/// Serializable log of decisions, in the order they were made.
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DecisionLog(pub Vec<DecisionRecord>);
+
+impl MapEntities for DecisionLog {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for record in &mut self.0 {
+            record.actor = entity_mapper.map_entity(record.actor);
+            match &mut record.event {
+                DecisionEvent::Scored(scores) => {
+                    for (entity, _) in scores {
+                        *entity = entity_mapper.map_entity(*entity);
+                    }
+                }
+                DecisionEvent::Picked { score_entity, .. } => {
+                    *score_entity = score_entity.map(|entity| entity_mapper.map_entity(entity));
+                }
+                DecisionEvent::ActionInitiated(_) | DecisionEvent::ActionEnded(_) => {}
+            }
+        }
+    }
+}
+
+/// A single entry of a [`DecisionLog`].
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct DecisionRecord {
+    /// The [`DecisionRecorder`] tick it was recorded at.
+    pub tick: u64,
+    /// The actor entity it was recorded for.
+    pub actor: Entity,
+    /// What happened.
+    pub event: DecisionEvent,
+}
+
+/// What happened in a [`DecisionRecord`].
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Debug)]
+pub enum DecisionEvent {
+    /// A score tree of the actor was scored, with the resulting [`Score`]s of all of its score entities.
+    Scored(Vec<(Entity, f32)>),
+    /// An action was picked for the actor, as in [`OnPicked`].
+    Picked {
+        /// Name of the picked action, if any.
+        action: Option<String>,
+        /// The winning score entity, if any.
+        score_entity: Option<Entity>,
+    },
+    /// The named action was initiated for the actor.
+    ActionInitiated(String),
+    /// The named action was ended for the actor.
+    ActionEnded(String),
+}
+
+/// [`World`] extension trait for replaying [`DecisionLog`]s.
+pub trait ReplayDecisionsExt {
+    /// Replays the given [`DecisionLog`]: for each recorded pick, sets the [`Score`]s recorded for the actor entity
+    /// since its previous pick, triggers [`RunPicking`] for it, and compares the picked action and score entity
+    /// with the recorded ones. Returns the number of matching picks, or the first [`ReplayMismatch`].
+    ///
+    /// The score trees aren't scored during the replay, so the recorded [`Score`]s are the inputs,
+    /// and the [`Picker`]s are what's tested. Recording is paused while replaying.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// pub struct Drinking;
+    /// #[derive(Component)]
+    /// pub struct Idle;
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins((ObservedUtilityPlugins::TurnBased, DecisionRecorderPlugin));
+    /// # let mut world = app.world_mut();
+    /// let idle = world.init_component::<Idle>();
+    /// let drinking = world.init_component::<Drinking>();
+    ///
+    /// let scorer = world.spawn(FixedScore::new(0.7)).id();
+    /// let actor = world
+    ///     .spawn((Picker::new(idle).with(scorer, drinking), Highest))
+    ///     .add_child(scorer)
+    ///     .id();
+    /// world.score_and_pick(actor);
+    ///
+    /// let log = world.resource_mut::<DecisionRecorder>().take();
+    /// assert_eq!(Ok(1), world.replay_decisions(&log));
+    ///
+    /// // A stricter picker decides differently given the same scores
+    /// world.entity_mut(actor).remove::<Highest>().insert(HighestAboveThreshold::new(0.9));
+    /// let mismatch = world.replay_decisions(&log).unwrap_err();
+    /// assert_eq!(actor, mismatch.actor);
+    /// assert_eq!(Some(scorer), mismatch.expected_score_entity);
+    /// assert_eq!(None, mismatch.actual_score_entity);
+    /// ```
+    fn replay_decisions(&mut self, log: &DecisionLog) -> Result<usize, ReplayMismatch>;
+}
+
+impl ReplayDecisionsExt for World {
+    fn replay_decisions(&mut self, log: &DecisionLog) -> Result<usize, ReplayMismatch> {
+        let recording = self
+            .get_resource_mut::<DecisionRecorder>()
+            .map(|mut recorder| std::mem::replace(&mut recorder.recording, false));
+        let result = replay(self, log);
+        if let (Some(recording), Some(mut recorder)) = (recording, self.get_resource_mut::<DecisionRecorder>()) {
+            recorder.recording = recording;
+        }
+        result
+    }
+}
+
+/// Replays the given [`DecisionLog`]. See [`ReplayDecisionsExt::replay_decisions`].
+fn replay(world: &mut World, log: &DecisionLog) -> Result<usize, ReplayMismatch> {
+    world.flush();
+
+    let mut pending: HashMap<Entity, Vec<(Entity, f32)>> = HashMap::default();
+    let mut matched = 0;
+    for record in &log.0 {
+        let (expected_action, expected_score_entity) = match &record.event {
+            DecisionEvent::Scored(scores) => {
+                pending.entry(record.actor).or_default().extend(scores.iter().copied());
+                continue;
+            }
+            DecisionEvent::Picked { action, score_entity } => (action, *score_entity),
+            DecisionEvent::ActionInitiated(_) | DecisionEvent::ActionEnded(_) => continue,
+        };
+
+        for (entity, score) in pending.remove(&record.actor).unwrap_or_default() {
+            if let Some(mut live) = world.get_mut::<Score>(entity) {
+                live.set(score);
+            }
+        }
+        world.trigger_targets(RunPicking, record.actor);
+        world.flush();
+
+        let (actual_action, actual_score_entity) = world
+            .get::<Picker>(record.actor)
+            .map(|picker| (picker.picked, picker.picked_score_entity))
+            .unwrap_or_default();
+        let registry = world.get_resource::<ActionRegistry>();
+        let actual_action = actual_action.map(|action| action_name(world.components(), registry, action));
+        if actual_action != *expected_action || actual_score_entity != expected_score_entity {
+            return Err(ReplayMismatch {
+                tick: record.tick,
+                actor: record.actor,
+                expected_action: expected_action.clone(),
+                expected_score_entity,
+                actual_action,
+                actual_score_entity,
+            });
+        }
+        matched += 1;
+    }
+    Ok(matched)
+}
+
+/// A pick that differs from the recording, returned by [`ReplayDecisionsExt::replay_decisions`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReplayMismatch {
+    /// The [`DecisionRecorder`] tick the pick was recorded at.
+    pub tick: u64,
+    /// The actor entity that picked.
+    pub actor: Entity,
+    /// Name of the recorded action, if any.
+    pub expected_action: Option<String>,
+    /// The recorded winning score entity, if any.
+    pub expected_score_entity: Option<Entity>,
+    /// Name of the replayed action, if any.
+    pub actual_action: Option<String>,
+    /// The replayed winning score entity, if any.
+    pub actual_score_entity: Option<Entity>,
+}
+
+impl fmt::Display for ReplayMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tick {}: {} picked {:?} from {:?}, but {:?} from {:?} was recorded",
+            self.tick,
+            self.actor,
+            self.actual_action,
+            self.actual_score_entity,
+            self.expected_action,
+            self.expected_score_entity,
+        )
+    }
+}
+
+impl std::error::Error for ReplayMismatch {}
+
+/// Returns the name of the given action in the [`ActionRegistry`], or its type name.
+fn action_name(components: &Components, registry: Option<&ActionRegistry>, action: ComponentId) -> String {
+    if let Some(name) = registry.and_then(|registry| registry.name(action)) {
+        return name.to_string();
+    }
+    components
+        .get_info(action)
+        .map_or_else(|| format!("{action:?}"), |info| info.name().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::component::ComponentId, prelude::*};
+
+    use crate::{
+        acting::CurrentAction,
+        event::{OnActionInitiated, OnPicked, RequestAction},
+        picking::{Highest, Picker},
+        recording::{DecisionEvent, DecisionRecorder, DecisionRecorderPlugin, ReplayDecisionsExt},
+        scoring::FixedScore,
+        WorldUtilityExt,
+    };
+
+    #[derive(Component)]
+    struct Attack;
+
+    #[derive(Component)]
+    struct Flee;
+
+    #[derive(Component)]
+    struct Idle;
+
+    /// The actions initiated for the actor, in order.
+    #[derive(Resource, Default)]
+    struct Initiated(Vec<ComponentId>);
+
+    #[test]
+    fn replaying_a_recording_reaches_the_same_current_actions() {
+        let mut app = App::new();
+        app.add_plugins((crate::ObservedUtilityPlugins::TurnBased, DecisionRecorderPlugin))
+            .init_resource::<Initiated>()
+            .observe(|trigger: Trigger<OnPicked>, mut commands: Commands| {
+                commands.trigger_targets(RequestAction::picked(), trigger.entity());
+            })
+            .observe(
+                |trigger: Trigger<OnActionInitiated>, mut initiated: ResMut<Initiated>| {
+                    initiated.0.push(trigger.event().action);
+                },
+            );
+        let world = app.world_mut();
+
+        let attack = world.init_component::<Attack>();
+        let flee = world.init_component::<Flee>();
+        let idle = world.init_component::<Idle>();
+
+        let attack_scorer = world.spawn(FixedScore::new(0.7)).id();
+        let flee_scorer = world.spawn(FixedScore::new(0.2)).id();
+        let actor = world
+            .spawn((
+                Picker::new(idle).with(attack_scorer, attack).with(flee_scorer, flee),
+                Highest,
+            ))
+            .push_children(&[attack_scorer, flee_scorer])
+            .id();
+
+        let mut recorded = Vec::new();
+        for (attack_score, flee_score) in [(0.7, 0.2), (0.1, 0.8), (0.6, 0.3)] {
+            world.entity_mut(attack_scorer).insert(FixedScore::new(attack_score));
+            world.entity_mut(flee_scorer).insert(FixedScore::new(flee_score));
+            world.score_and_pick(actor);
+            world.flush();
+            recorded.push(world.get::<CurrentAction>(actor).unwrap().0);
+        }
+        assert_eq!(vec![attack, flee, attack], recorded);
+        assert_eq!(recorded, world.resource::<Initiated>().0);
+
+        let log = world.resource_mut::<DecisionRecorder>().take();
+        let picks = log
+            .0
+            .iter()
+            .filter(|record| matches!(record.event, DecisionEvent::Picked { .. }))
+            .count();
+        assert_eq!(3, picks);
+
+        // Replaying on a fresh state, with scorers that would pick differently, re-feeds the recorded scores.
+        world.entity_mut(attack_scorer).insert(FixedScore::new(0.));
+        world.entity_mut(flee_scorer).insert(FixedScore::new(0.));
+        world.entity_mut(actor).remove::<CurrentAction>();
+        world.resource_mut::<Initiated>().0.clear();
+        assert_eq!(Ok(3), world.replay_decisions(&log));
+        assert_eq!(recorded, world.resource::<Initiated>().0);
+        assert_eq!(Some(&CurrentAction(attack)), world.get::<CurrentAction>(actor));
+        // Nothing was recorded while replaying.
+        assert!(world.resource::<DecisionRecorder>().log().0.is_empty());
+    }
+}
@@ -0,0 +1,427 @@
+//! Network replication support for AI state, so networking crates such as `bevy_replicon`
+//! can replicate decisions to clients for prediction and visualization.
+//!
+//! Requires the `replication` feature.
+//!
+//! Action [`ComponentId`]s depend on registration order, so they aren't stable across processes.
+//! Instead, this module provides serializable mirrors of the AI state that refer to actions by their names
+//! in the [`ActionRegistry`]:
+//!
+//! - [`ReplicatedPicker`] mirrors the [`Picker`].
+//! - [`ReplicatedCurrentAction`] mirrors the [`CurrentAction`].
+//! - [`CurrentTarget`](crate::acting::CurrentTarget) is replicated as is.
+//! - [`Score`] is serializable itself.
+//!
+//! Register your actions with [`RegisterActionExt::register_action`] under the same names on both sides,
+//! add the [`AiReplicationPlugin`] with the matching role, and replicate the components above.
+//! Actions that aren't registered are left out of the mirrors.
+//! [`Picker`], [`ReplicatedPicker`], [`CurrentTarget`](crate::acting::CurrentTarget), [`TargetRef`](crate::scoring::TargetRef),
+//! and [`ScoreOf`](crate::scoring::ScoreOf) implement [`MapEntities`] for mapping score and target entities
+//! to the client's.
+//!
+//! [`RegisterActionExt::register_action`]: crate::acting::RegisterActionExt::register_action
+
+use bevy::{
+    ecs::{
+        component::ComponentId,
+        entity::{EntityMapper, MapEntities},
+    },
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    acting::{ActionRegistry, CurrentAction},
+    picking::Picker,
+    scoring::Score,
+};
+
+/// [`Plugin`] that keeps the serializable mirrors of the AI state in sync.
+/// See the [module docs](self) for more information.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Drinking;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::TurnBased, AiReplicationPlugin::server()))
+///     .register_action::<Drinking>("drink")
+///     .register_action::<Idle>("idle");
+/// # let mut world = app.world_mut();
+/// let idle = world.init_component::<Idle>();
+/// let drinking = world.init_component::<Drinking>();
+///
+/// let scorer = world.spawn(FixedScore::new(0.5)).id();
+/// let actor = world
+///     .spawn((Picker::new(idle).with(scorer, drinking), Highest))
+///     .add_child(scorer)
+///     .id();
+/// # world.flush();
+/// app.update();
+///
+/// let replicated = app.world().get::<ReplicatedPicker>(actor).unwrap();
+/// assert_eq!(Some("idle"), replicated.default.as_deref());
+/// assert_eq!(vec![(scorer, "drink".to_string())], replicated.choices);
+/// assert_eq!(
+///     Some(&ReplicatedCurrentAction("idle".to_string())),
+///     app.world().get::<ReplicatedCurrentAction>(actor)
+/// );
+/// ```
+pub struct AiReplicationPlugin {
+    /// Which side of the connection this app is on.
+    pub role: ReplicationRole,
+}
+
+impl AiReplicationPlugin {
+    /// Creates a new [`AiReplicationPlugin`] for the authoritative side, which writes the mirrors.
+    #[must_use]
+    pub fn server() -> Self {
+        Self {
+            role: ReplicationRole::Server,
+        }
+    }
+
+    /// Creates a new [`AiReplicationPlugin`] for the receiving side, which applies the mirrors.
+    #[must_use]
+    pub fn client() -> Self {
+        Self {
+            role: ReplicationRole::Client,
+        }
+    }
+}
+
+impl Plugin for AiReplicationPlugin {
+    fn build(&self, app: &mut App) {
+        match self.role {
+            ReplicationRole::Server => {
+                app.add_systems(PostUpdate, Self::write_replicated);
+            }
+            ReplicationRole::Client => {
+                app.add_systems(PreUpdate, Self::apply_replicated);
+            }
+        }
+
+        app.register_type::<ReplicatedPicker>()
+            .register_type::<ReplicatedCurrentAction>()
+            .register_type::<Score>();
+    }
+}
+
+impl AiReplicationPlugin {
+    /// [`System`] that writes the [`ReplicatedPicker`] and [`ReplicatedCurrentAction`] of actor entities
+    /// whose [`Picker`] or [`CurrentAction`] changed, only touching them if the mirrored values differ.
+    pub fn write_replicated(
+        mut commands: Commands,
+        registry: Res<ActionRegistry>,
+        pickers: Query<(Entity, &Picker, Option<&ReplicatedPicker>), Changed<Picker>>,
+        actions: Query<(Entity, &CurrentAction, Option<&ReplicatedCurrentAction>), Changed<CurrentAction>>,
+        mut removed: RemovedComponents<CurrentAction>,
+    ) {
+        for (actor, picker, replicated) in &pickers {
+            let next = ReplicatedPicker::new(picker, &registry);
+            if replicated != Some(&next) {
+                commands.entity(actor).insert(next);
+            }
+        }
+
+        for (actor, current, replicated) in &actions {
+            match ReplicatedCurrentAction::new(current.0, &registry) {
+                Some(next) if replicated != Some(&next) => {
+                    commands.entity(actor).insert(next);
+                }
+                Some(_) => {}
+                None => {
+                    commands.entity(actor).remove::<ReplicatedCurrentAction>();
+                }
+            }
+        }
+        for actor in removed.read() {
+            if let Some(mut actor) = commands.get_entity(actor) {
+                actor.remove::<ReplicatedCurrentAction>();
+            }
+        }
+    }
+
+    /// [`System`] that applies changed [`ReplicatedPicker`]s and [`ReplicatedCurrentAction`]s
+    /// to the [`Picker`]s and [`CurrentAction`]s of actor entities.
+    pub fn apply_replicated(
+        mut commands: Commands,
+        registry: Res<ActionRegistry>,
+        pickers: Query<(Entity, &ReplicatedPicker), Changed<ReplicatedPicker>>,
+        actions: Query<(Entity, &ReplicatedCurrentAction), Changed<ReplicatedCurrentAction>>,
+        mut removed: RemovedComponents<ReplicatedCurrentAction>,
+    ) {
+        for (actor, replicated) in &pickers {
+            commands.entity(actor).insert(replicated.to_picker(&registry));
+        }
+
+        for (actor, replicated) in &actions {
+            match replicated.action(&registry) {
+                Some(action) => {
+                    commands.entity(actor).insert(CurrentAction(action));
+                }
+                None => {
+                    commands.entity(actor).remove::<CurrentAction>();
+                }
+            }
+        }
+        for actor in removed.read() {
+            if let Some(mut actor) = commands.get_entity(actor) {
+                actor.remove::<CurrentAction>();
+            }
+        }
+    }
+}
+
+/// Which side of the connection an [`AiReplicationPlugin`] is on.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ReplicationRole {
+    /// The authoritative side, which runs the AI and writes the mirrors.
+    Server,
+    /// The receiving side, which applies the mirrors.
+    Client,
+}
+
+/// Serializable mirror of a [`Picker`], referring to actions by their names in the [`ActionRegistry`].
+#[derive(Component, Reflect, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ReplicatedPicker {
+    /// Name of the default action, if any.
+    pub default: Option<String>,
+    /// Choice score [`Entity`]s and the names of their actions, in iteration order.
+    pub choices: Vec<(Entity, String)>,
+    /// Name of the last picked action, if any.
+    pub picked: Option<String>,
+    /// The choice score [`Entity`] that won the last pick, if any.
+    pub picked_score_entity: Option<Entity>,
+    /// The entity targeted by the last pick, if any.
+    pub picked_target: Option<Entity>,
+}
+
+impl ReplicatedPicker {
+    /// Creates a new [`ReplicatedPicker`] mirroring the given [`Picker`], leaving out unregistered actions.
+    #[must_use]
+    pub fn new(picker: &Picker, registry: &ActionRegistry) -> Self {
+        let name = |action: ComponentId| registry.name(action).map(ToString::to_string);
+        Self {
+            default: picker.default.and_then(name),
+            choices: picker
+                .choices
+                .iter()
+                .filter_map(|(score_entity, action)| Some((score_entity, name(action)?)))
+                .collect(),
+            picked: picker.picked.and_then(name),
+            picked_score_entity: picker.picked_score_entity,
+            picked_target: picker.picked_target,
+        }
+    }
+
+    /// Returns the [`Picker`] this mirrors, leaving out actions that aren't registered.
+    #[must_use]
+    pub fn to_picker(&self, registry: &ActionRegistry) -> Picker {
+        let id = |name: &String| registry.id(name);
+        let mut picker = match self.default.as_ref().and_then(id) {
+            Some(default) => Picker::new(default),
+            None => Picker::without_default(),
+        };
+        picker.choices.extend(
+            self.choices
+                .iter()
+                .filter_map(|(score_entity, action)| Some((*score_entity, id(action)?))),
+        );
+        picker.picked = self.picked.as_ref().and_then(id);
+        picker.picked_score_entity = self.picked_score_entity;
+        picker.picked_target = self.picked_target;
+        picker
+    }
+}
+
+impl MapEntities for ReplicatedPicker {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for (entity, _) in &mut self.choices {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+        self.picked_score_entity = self.picked_score_entity.map(|entity| entity_mapper.map_entity(entity));
+        self.picked_target = self.picked_target.map(|entity| entity_mapper.map_entity(entity));
+    }
+}
+
+/// Serializable mirror of a [`CurrentAction`], referring to the action by its name in the [`ActionRegistry`].
+#[derive(Component, Reflect, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[reflect(Component, PartialEq, Hash, Debug)]
+pub struct ReplicatedCurrentAction(pub String);
+
+impl ReplicatedCurrentAction {
+    /// Creates a new [`ReplicatedCurrentAction`] for the given action, or `None` if it isn't registered.
+    #[must_use]
+    pub fn new(action: ComponentId, registry: &ActionRegistry) -> Option<Self> {
+        registry.name(action).map(|name| Self(name.to_string()))
+    }
+
+    /// Returns the action [`ComponentId`] this refers to, or `None` if it isn't registered.
+    #[must_use]
+    pub fn action(&self, registry: &ActionRegistry) -> Option<ComponentId> {
+        registry.id(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        ecs::entity::{EntityMapper, MapEntities},
+        prelude::*,
+        utils::HashMap,
+    };
+
+    use crate::{
+        acting::{CurrentAction, CurrentTarget, RegisterActionExt},
+        event::RequestAction,
+        picking::{Highest, Picker},
+        replication::{AiReplicationPlugin, ReplicatedCurrentAction, ReplicatedPicker},
+        scoring::FixedScore,
+        WorldUtilityExt,
+    };
+
+    #[derive(Component)]
+    struct Attack;
+
+    #[derive(Component)]
+    struct Idle;
+
+    #[derive(Component)]
+    struct Unregistered;
+
+    /// Maps server entities to client entities, as a networking crate would.
+    struct Mapper(HashMap<Entity, Entity>);
+
+    impl EntityMapper for Mapper {
+        fn map_entity(&mut self, entity: Entity) -> Entity {
+            self.0[&entity]
+        }
+    }
+
+    #[test]
+    fn mirrors_map_entities() {
+        let mut world = World::new();
+        let [server_scorer, server_target, client_scorer, client_target] = [(); 4].map(|()| world.spawn_empty().id());
+        let mut mapper = Mapper(HashMap::from_iter([
+            (server_scorer, client_scorer),
+            (server_target, client_target),
+        ]));
+
+        let mut picker = ReplicatedPicker {
+            default: Some("idle".to_string()),
+            choices: vec![(server_scorer, "attack".to_string())],
+            picked: Some("attack".to_string()),
+            picked_score_entity: Some(server_scorer),
+            picked_target: Some(server_target),
+        };
+        picker.map_entities(&mut mapper);
+        assert_eq!(vec![(client_scorer, "attack".to_string())], picker.choices);
+        assert_eq!(Some(client_scorer), picker.picked_score_entity);
+        assert_eq!(Some(client_target), picker.picked_target);
+
+        let mut target = CurrentTarget(server_target);
+        target.map_entities(&mut mapper);
+        assert_eq!(CurrentTarget(client_target), target);
+    }
+
+    #[test]
+    fn server_writes_and_client_applies() {
+        let mut server = App::new();
+        server
+            .add_plugins((crate::ObservedUtilityPlugins::TurnBased, AiReplicationPlugin::server()))
+            .register_action::<Attack>("attack")
+            .register_action::<Idle>("idle");
+        // The client registers its components in a different order, so the ComponentIds differ.
+        let mut client = App::new();
+        client
+            .add_plugins((crate::ObservedUtilityPlugins::TurnBased, AiReplicationPlugin::client()))
+            .register_action::<Idle>("idle");
+        client.world_mut().init_component::<Unregistered>();
+        client.register_action::<Attack>("attack");
+
+        let world = server.world_mut();
+        let attack = world.init_component::<Attack>();
+        let idle = world.init_component::<Idle>();
+        let server_scorer = world.spawn(FixedScore::new(0.7)).id();
+        let server_actor = world
+            .spawn((Picker::new(idle).with(server_scorer, attack), Highest))
+            .add_child(server_scorer)
+            .id();
+        world.score_and_pick(server_actor);
+        world.trigger_targets(RequestAction::picked(), server_actor);
+        world.flush();
+        server.update();
+
+        let world = server.world();
+        let replicated_picker = world.get::<ReplicatedPicker>(server_actor).unwrap().clone();
+        let replicated_action = world.get::<ReplicatedCurrentAction>(server_actor).unwrap().clone();
+        assert_eq!(Some("attack"), replicated_picker.picked.as_deref());
+        assert_eq!(ReplicatedCurrentAction("attack".to_string()), replicated_action);
+
+        // Replicate the mirrors to the client's entities.
+        let world = client.world_mut();
+        let client_attack = world.init_component::<Attack>();
+        let client_idle = world.init_component::<Idle>();
+        assert_ne!(attack, client_attack);
+        let client_scorer = world.spawn_empty().id();
+        let client_actor = world.spawn_empty().id();
+        let mut mapper = Mapper(HashMap::from_iter([(server_scorer, client_scorer)]));
+        let mut replicated_picker = replicated_picker;
+        replicated_picker.map_entities(&mut mapper);
+        world
+            .entity_mut(client_actor)
+            .insert((replicated_picker, replicated_action));
+        client.update();
+
+        let world = client.world();
+        let picker = world.get::<Picker>(client_actor).unwrap();
+        assert_eq!(Some(client_idle), picker.default);
+        assert_eq!(
+            vec![(client_scorer, client_attack)],
+            picker.choices.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(client_attack), picker.picked);
+        assert_eq!(
+            Some(&CurrentAction(client_attack)),
+            world.get::<CurrentAction>(client_actor)
+        );
+        // The client doesn't write mirrors of its own.
+        let local_actor = client
+            .world_mut()
+            .spawn((Picker::new(client_idle), CurrentAction(client_idle)))
+            .id();
+        client.update();
+        assert!(client.world().get::<ReplicatedPicker>(local_actor).is_none());
+        assert!(client.world().get::<ReplicatedCurrentAction>(local_actor).is_none());
+
+        // Removing the mirror on the client ends the current action there.
+        client
+            .world_mut()
+            .entity_mut(client_actor)
+            .remove::<ReplicatedCurrentAction>();
+        client.update();
+        assert!(client.world().get::<CurrentAction>(client_actor).is_none());
+
+        // And the server doesn't apply mirrors.
+        server
+            .world_mut()
+            .entity_mut(server_actor)
+            .insert(ReplicatedCurrentAction("idle".to_string()));
+        server.update();
+        assert_eq!(
+            Some(&CurrentAction(attack)),
+            server.world().get::<CurrentAction>(server_actor)
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Crate-managed random number generation, so that randomness in scoring and picking can be seeded
+//! for deterministic tests, replays, and lockstep multiplayer.
+//!
+//! [`RandomScore`], [`NoiseScore`], [`PickRandom`], [`PickDualUtility::with_weighted_random`],
+//! and [`PickJitter`] all draw from the [`UtilityRng`] resource,
+//! or from the actor entity's [`RngComponent`] if it has one.
+//! Read them in your own scorers and pickers with the [`UtilityRngs`] [`SystemParam`].
+//!
+//! The [`UtilityRng`] resource is seeded from entropy by default. Insert a seeded one to make runs reproducible:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_observed_utility::prelude::*;
+//!
+//! # let mut app = App::new();
+//! app.add_plugins(ObservedUtilityPlugins::RealTime)
+//!     .insert_resource(UtilityRng::new(42));
+//! ```
+//!
+//! Drawing advances a shared state, so the sequence of values depends on the order things are drawn in.
+//! Observers run in a deterministic order, but systems drawing from the same [`UtilityRng`] in parallel don't.
+//!
+//! [`RandomScore`]: crate::scoring::RandomScore
+//! [`NoiseScore`]: crate::scoring::NoiseScore
+//! [`PickRandom`]: crate::picking::PickRandom
+//! [`PickDualUtility::with_weighted_random`]: crate::picking::PickDualUtility::with_weighted_random
+//! [`PickJitter`]: crate::picking::PickJitter
+
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    hash::{BuildHasher, Hasher},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+/// The `SplitMix64` state increment.
+const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// [`Resource`] for the random number generator shared by scoring and picking. See the [module docs](self).
+///
+/// This is a small, fast, and portable `SplitMix64` generator, so the same seed produces the same values
+/// on every platform. It's drawn from through a shared reference, so read-only [`SystemParam`]s can use it.
+/// With the `rand` feature, `&UtilityRng` implements [`rand::RngCore`].
+#[derive(Resource)]
+pub struct UtilityRng {
+    /// The seed this was created with.
+    seed: u64,
+    /// The current `SplitMix64` state.
+    state: AtomicU64,
+}
+
+impl UtilityRng {
+    /// Creates a new [`UtilityRng`] with the given seed.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    /// Creates a new [`UtilityRng`] seeded from entropy.
+    #[must_use]
+    pub fn from_entropy() -> Self {
+        Self::new(RandomState::new().build_hasher().finish())
+    }
+
+    /// Returns the seed this was created with.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the next random `u64`, advancing the state.
+    pub fn next_u64(&self) -> u64 {
+        // splitmix64 adds GAMMA itself, so mix the previous state
+        splitmix64(self.state.fetch_add(GAMMA, Ordering::Relaxed))
+    }
+}
+
+impl Default for UtilityRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+impl Clone for UtilityRng {
+    fn clone(&self) -> Self {
+        Self {
+            seed: self.seed,
+            state: AtomicU64::new(self.state.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl fmt::Debug for UtilityRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UtilityRng")
+            .field("seed", &self.seed)
+            .field("state", &self.state.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::RngCore for &UtilityRng {
+    fn next_u32(&mut self) -> u32 {
+        (UtilityRng::next_u64(self) >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        UtilityRng::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = UtilityRng::next_u64(self).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// The `SplitMix64` mixing function, a fast and well-distributed 64-bit hash.
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// [`Component`] for actor entities that draw from their own [`UtilityRng`] instead of the shared resource,
+/// e.g. to keep one actor's decisions reproducible regardless of what the others do.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// # #[cfg(feature = "rand")]
+/// world.spawn((Picker::new(idle), PickRandom, RngComponent::new(7)));
+/// ```
+#[derive(Component)]
+#[derive(Clone, Debug)]
+pub struct RngComponent(pub UtilityRng);
+
+impl RngComponent {
+    /// Creates a new [`RngComponent`] with the given seed.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self(UtilityRng::new(seed))
+    }
+}
+
+/// [`SystemParam`] for drawing from the [`UtilityRng`] of an actor entity:
+/// its [`RngComponent`] if it has one, or else the [`UtilityRng`] resource.
+///
+/// The [`UtilityRng`] resource is required; it's added by the [`ScoringPlugin`] and [`PickingPlugin`].
+///
+/// [`ScoringPlugin`]: crate::scoring::ScoringPlugin
+/// [`PickingPlugin`]: crate::picking::PickingPlugin
+#[derive(SystemParam)]
+pub struct UtilityRngs<'w, 's> {
+    global: Res<'w, UtilityRng>,
+    overrides: Query<'w, 's, &'static RngComponent>,
+}
+
+impl UtilityRngs<'_, '_> {
+    /// Returns the [`UtilityRng`] to draw from for the given actor entity, or the shared one if it's `None`.
+    #[must_use]
+    pub fn get(&self, actor: Option<Entity>) -> &UtilityRng {
+        actor
+            .and_then(|actor| self.overrides.get(actor).ok())
+            .map_or(&*self.global, |rng| &rng.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+    use crate::rng::{RngComponent, UtilityRng, UtilityRngs};
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let a = UtilityRng::new(42);
+        let b = UtilityRng::new(42);
+        let a: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let b: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, (0..8).map(|_| UtilityRng::new(43).next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn rng_component_overrides_resource() {
+        let mut world = World::new();
+        world.insert_resource(UtilityRng::new(1));
+        let own = world.spawn(RngComponent::new(2)).id();
+        let shared = world.spawn_empty().id();
+
+        let drawn = world.run_system_once(move |rngs: UtilityRngs| {
+            [
+                rngs.get(Some(own)).next_u64(),
+                rngs.get(Some(shared)).next_u64(),
+                rngs.get(None).next_u64(),
+            ]
+        });
+        let expected_own = UtilityRng::new(2);
+        let expected_shared = UtilityRng::new(1);
+        assert_eq!(
+            [
+                expected_own.next_u64(),
+                expected_shared.next_u64(),
+                expected_shared.next_u64()
+            ],
+            drawn
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_scores_are_reproducible_across_apps() {
+        use crate::{
+            event::RunScoring,
+            picking::{Highest, Picker},
+            scoring::{RandomScore, Score},
+        };
+
+        #[derive(Component)]
+        struct Idle;
+
+        fn run(seed: u64) -> Vec<f32> {
+            let mut app = App::new();
+            app.add_plugins(crate::ObservedUtilityPlugins::TurnBased)
+                .insert_resource(UtilityRng::new(seed));
+            let world = app.world_mut();
+
+            let idle = world.init_component::<Idle>();
+            let scorers: Vec<Entity> = (0..2)
+                .map(|_| {
+                    let scorer = world.spawn(RandomScore::new()).id();
+                    world
+                        .spawn((Picker::new(idle).with(scorer, idle), Highest))
+                        .add_child(scorer);
+                    scorer
+                })
+                .collect();
+            let mut scores = Vec::new();
+            for _ in 0..3 {
+                for &scorer in &scorers {
+                    world.trigger_targets(RunScoring, scorer);
+                    world.flush();
+                    scores.push(world.get::<Score>(scorer).unwrap().get());
+                }
+            }
+            scores
+        }
+
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+    }
+}
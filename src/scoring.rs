@@ -3,9 +3,15 @@
 //! # Provided [`Score`] implementations
 //!
 //! - [`AllOrNothing`]: Scores the sum of all child scores, but only if the sum reaches a certain threshold. Otherwise, the score is 0.
+//! - [`CooldownScore`]: Scores how far along an action's cooldown is on the actor, from 0 to 1 when it's available again.
+//! - [`Consideration`]: Scores a value read from the closest ancestor's component, evaluated and weighted in a single entity.
+//! - [`DistanceScore`] (requires `spatial` feature): Scores the distance between the actor and its [`TargetRef`] entity.
 //! - [`Evaluated`]: Scores a single child entity based on an [`Evaluator`] function. See the struct docs for the list of provided evaluators.
 //! - [`FixedScore`]: Scores a fixed value.
+//! - [`IdleTimeScore`]: Scores how long the actor has been performing its default action.
 //! - [`Measured`]: Scores all child entities based on a [`Measure`] function. See the struct docs for the list of provided measures.
+//! - [`NoiseScore`] (requires `rand` feature): Scores a smoothly varying random value over time, optionally within a range.
+//! - [`Normalized`]: Scores all child entities based on a [`Measure`] function, after rescaling them with a [`Normalization`].
 //! - [`Product`]: Scores the product of all child scores.
 //! - [`Random`] (requires `rand` feature): Scores a random value, optionally within a range.
 //! - [`Sum`]: Scores the sum of all child scores.
@@ -14,38 +20,105 @@
 //! # Provided [`Observer`] utilities
 //!
 //! - [`score_ancestor`]: Does the busy work of scoring a child entity based on its closest ancestor entity with a given component.
+//! - [`score_ancestor_with`]: Same as [`score_ancestor`], but scores using a closure instead of an [`Into<Score>`] impl.
+//! - [`score_target`]: Does the busy work of scoring an entity based on a given component on its [`TargetRef`] entity.
+//! - [`score_component`]: Does the busy work of scoring an entity based on a given component on the score entity itself.
+//! - [`score_resource`]: Does the busy work of scoring an entity based on a given resource.
+//!
+//! The [`BatchedScoringPlugin`] is an opt-in alternative scoring backend that calls registered scorer functions
+//! directly, instead of triggering [`OnScore`] for each score entity.
+//!
+//! [`sample_evaluator`] samples the response curve of an [`Evaluator`] for display, and with the `plot` feature,
+//! `plot_evaluator` and `evaluator_mesh` turn it into an image or line strip mesh.
+//!
+//! [`ScoreSimulator::simulate`] scores a tree as if some components were changed, without touching the live [`Score`]s.
+//!
+//! [`TargetSelector`](crate::targeting::TargetSelector) scores the best of a set of candidate entities,
+//! and remembers which one it was.
+//!
+//! [`MemoryScore`](crate::memory::MemoryScore) scores how fresh or confident an actor's memories are.
+//!
+//! Score trees are usually linked with [`Parent`]/[`Children`], but can be linked with [`ScoreOf`] instead,
+//! which keeps score entities out of the transform hierarchy. [`ScoreHierarchy`] walks either linkage.
+//!
+//! [`AddScorerExt::add_scorer`] registers [`score_ancestor`] in one step, and pairs with [`macro@Scorer`]
+//! to derive the [`Into<Score>`] impl it needs.
 
 use std::{
     cmp::Ordering,
     ops::{Bound, RangeBounds},
+    time::Duration,
 };
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::entity::{EntityMapper, MapEntities},
+    hierarchy::HierarchyEvent,
+    prelude::*,
+};
 
 use crate::{
-    ecs::{AncestorQuery, DFSPostTraversal, TriggerGetEntity},
-    event::{OnScore, RunScoring},
+    diagnostics::{AiDiagnosticsState, MeasurePhase},
+    ecs::{CleanupUnusedObservers, TriggerGetEntity},
+    event::{OnScore, OnScoringComplete, RunScoring, RunScoringMany},
+    rng::UtilityRng,
+    targeting::{BestTarget, TargetCandidates},
+    AiDisabled, AiPaused, AiTickRate,
 };
 
 mod all_or_nothing;
+mod ancestor;
+mod batched;
+mod consideration;
+mod cooldown;
 mod evaluator;
 mod fixed;
+mod idle_time;
+mod label;
 mod measured;
+#[cfg(feature = "rand")]
+mod noise;
+mod normalized;
+mod plot;
+mod priority;
 mod product;
 #[cfg(feature = "rand")]
 mod random;
+mod relationship;
+mod scorers;
+mod simulate;
+#[cfg(feature = "spatial")]
+mod spatial;
 mod sum;
+mod traversal;
 mod winning;
 
 pub use self::all_or_nothing::*;
+pub use self::ancestor::*;
+pub use self::batched::*;
+pub use self::consideration::*;
+pub use self::cooldown::*;
 pub use self::evaluator::*;
 pub use self::fixed::*;
+pub use self::idle_time::*;
+pub use self::label::*;
 pub use self::measured::*;
+#[cfg(feature = "rand")]
+pub use self::noise::*;
+pub use self::normalized::*;
+pub use self::plot::*;
+pub use self::priority::*;
 pub use self::product::*;
 #[cfg(feature = "rand")]
 pub use self::random::*;
+pub use self::relationship::*;
+pub use self::scorers::*;
+pub use self::simulate::*;
+#[cfg(feature = "spatial")]
+pub use self::spatial::*;
 pub use self::sum::*;
+pub use self::traversal::*;
 pub use self::winning::*;
+pub use bevy_observed_utility_macros::Scorer;
 
 /// [`Plugin`] for scoring entities.
 #[derive(Default)]
@@ -53,9 +126,22 @@ pub struct ScoringPlugin;
 
 impl Plugin for ScoringPlugin {
     fn build(&self, app: &mut App) {
-        app.observe(Self::run_scoring_post_order_dfs);
+        app.observe(Self::run_scoring_post_order_dfs)
+            .observe(Self::run_scoring_many);
+
+        app.init_resource::<ScorerComponents>()
+            .init_resource::<UtilityRng>()
+            // Lets AncestorQuery detect reparenting
+            .add_event::<HierarchyEvent>();
 
         app.register_type::<Score>()
+            .register_type::<HierarchyGeneration>()
+            .register_type::<CleanupUnusedObservers>()
+            .register_type::<TargetRef>()
+            .register_type::<LastScored>()
+            .register_type::<ScoreLabel>()
+            .register_type::<ScoreOf>()
+            .register_type::<ScoreChildren>()
             .register_type::<AllOrNothing>()
             // .register_type::<Evaluated>() // TODO: Implement reflection for Evaluated
             .register_type::<LinearEvaluator>()
@@ -64,7 +150,10 @@ impl Plugin for ScoringPlugin {
             .register_type::<ExponentialEvaluator>()
             .register_type::<LogarithmicEvaluator>()
             .register_type::<FixedScore>()
+            .register_type::<CooldownScore>()
             // .register_type::<Measured>() // TODO: Implement reflection for Measured
+            // .register_type::<Normalized>() // TODO: Implement reflection for Normalized
+            .register_type::<Normalization>()
             .register_type::<Weighted>()
             .register_type::<WeightedSum>()
             .register_type::<WeightedProduct>()
@@ -72,60 +161,251 @@ impl Plugin for ScoringPlugin {
             .register_type::<WeightedRMS>()
             .register_type::<Product>()
             .register_type::<Sum>()
-            .register_type::<Winning>();
-
-        #[cfg(feature = "rand")]
-        app.register_type::<RandomScore>();
-
-        app.register_type::<RunScoring>().register_type::<OnScore>();
+            .register_type::<Winning>()
+            .register_type::<ScorePriority>()
+            .register_type::<BestTarget>()
+            .register_type::<TargetCandidates>();
+
+        app.register_type::<RunScoring>()
+            .register_type::<RunScoringMany>()
+            .register_type::<OnScore>()
+            .register_type::<OnScoringComplete>();
     }
 }
 
 impl ScoringPlugin {
     /// For each scoreable root entity, perform post-order depth-first traversal,
     /// triggering [`OnScore`] for each entity on the way back up.
+    ///
+    /// Targeting an entity without a [`Score`], such as an actor entity, scores each of its child score trees.
+    ///
+    /// Nothing is scored while [`AiPaused`], and [`AiDisabled`] actor entities' score trees aren't scored.
+    /// Scoring globally also skips the score trees of actor entities with an [`AiTickRate`].
+    ///
+    /// With the [`BatchedScoringPlugin`], a single [`Command`] scores all entities in the same order instead.
+    ///
+    /// [`Command`]: bevy::ecs::world::Command
+    #[allow(clippy::too_many_arguments)]
     pub fn run_scoring_post_order_dfs(
         trigger: Trigger<RunScoring>,
         mut commands: Commands,
-        scoreable_roots: Query<(Entity, Option<&Parent>), With<Score>>,
-        root_parents: Query<(), Without<Score>>,
+        paused: Option<Res<AiPaused>>,
+        diagnostics: Option<Res<AiDiagnosticsState>>,
+        batched: Option<Res<ScorerRegistry>>,
+        scoreable_roots: Query<Entity, With<Score>>,
+        root_parents: Query<(), GlobalRootParentFilter>,
+        actors: Query<(), ScoreActorFilter>,
+        disabled: Query<(), With<AiDisabled>>,
+        hierarchy: ScoreHierarchy,
         mut dfs: DFSPostTraversal<With<Score>>,
+        time: Option<Res<Time>>,
+        mut last_scored: Query<&mut LastScored>,
     ) {
-        fn trigger_in_order(root: Entity, mut commands: Commands, dfs: &mut DFSPostTraversal<With<Score>>) {
-            let sorted = dfs.iter(root);
-
-            for entity in sorted {
-                commands.trigger_targets(OnScore, entity);
-            }
+        utility_span!("ScoringPlugin::run_scoring_post_order_dfs", target = ?trigger.get_entity());
+        if paused.is_some() {
+            return;
         }
 
+        let mut roots = Vec::new();
         if let Some(targeted_root) = trigger.get_entity() {
-            // Do scoring for the given entity
-            trigger_in_order(targeted_root, commands.reborrow(), &mut dfs);
+            if disabled.contains(targeted_root) {
+                return;
+            }
+            push_target_roots(targeted_root, &mut roots, &scoreable_roots, &actors, &hierarchy);
         } else {
             // Do scoring globally
             // Find all score entities that have no parents at all, or whose parents are not score entities
-            let roots = scoreable_roots.iter().filter_map(|(entity, parent)| {
-                if let Some(parent) = parent {
-                    if root_parents.contains(**parent) {
-                        Some(entity)
-                    } else {
-                        None
-                    }
-                } else {
-                    Some(entity)
-                }
-            });
-            for root in roots {
-                trigger_in_order(root, commands.reborrow(), &mut dfs);
+            roots.extend(
+                scoreable_roots
+                    .iter()
+                    .filter_map(|entity| match hierarchy.parent(entity) {
+                        Some(parent) if root_parents.contains(parent) => Some((entity, Some(parent))),
+                        Some(_) => None,
+                        None => Some((entity, None)),
+                    }),
+            );
+        }
+
+        let now = time.map(|time| time.elapsed()).unwrap_or_default();
+        let scored = score_post_order(&roots, now, &mut last_scored, &mut dfs);
+        queue_scoring(scored, roots, &mut commands, batched.is_some(), diagnostics.is_some());
+    }
+
+    /// Same as [`ScoringPlugin::run_scoring_post_order_dfs`], but for each entity in the [`RunScoringMany`] event,
+    /// in order. [`AiDisabled`] entities are skipped.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_scoring_many(
+        trigger: Trigger<RunScoringMany>,
+        mut commands: Commands,
+        paused: Option<Res<AiPaused>>,
+        diagnostics: Option<Res<AiDiagnosticsState>>,
+        batched: Option<Res<ScorerRegistry>>,
+        scoreable_roots: Query<Entity, With<Score>>,
+        actors: Query<(), ScoreActorFilter>,
+        disabled: Query<(), With<AiDisabled>>,
+        hierarchy: ScoreHierarchy,
+        mut dfs: DFSPostTraversal<With<Score>>,
+        time: Option<Res<Time>>,
+        mut last_scored: Query<&mut LastScored>,
+    ) {
+        utility_span!("ScoringPlugin::run_scoring_many", targets = trigger.event().0.len());
+        if paused.is_some() {
+            return;
+        }
+
+        let mut roots = Vec::new();
+        for &target in &trigger.event().0 {
+            if !disabled.contains(target) {
+                push_target_roots(target, &mut roots, &scoreable_roots, &actors, &hierarchy);
             }
         }
+
+        let now = time.map(|time| time.elapsed()).unwrap_or_default();
+        let scored = score_post_order(&roots, now, &mut last_scored, &mut dfs);
+        queue_scoring(scored, roots, &mut commands, batched.is_some(), diagnostics.is_some());
+    }
+}
+
+/// [`QueryFilter`](bevy::ecs::query::QueryFilter) for actor entities with child score trees.
+type ScoreActorFilter = (Without<Score>, Or<(With<Children>, With<ScoreChildren>)>);
+
+/// [`QueryFilter`](bevy::ecs::query::QueryFilter) for the parents of score trees scored by a global [`RunScoring`].
+type GlobalRootParentFilter = (Without<Score>, Without<AiDisabled>, Without<AiTickRate>);
+
+/// Pushes the roots to score for the given target entity, alongside their actor entity:
+/// each of its child score trees if it's an actor entity, or else the entity itself.
+fn push_target_roots(
+    target: Entity,
+    roots: &mut Vec<(Entity, Option<Entity>)>,
+    scoreable_roots: &Query<Entity, With<Score>>,
+    actors: &Query<(), ScoreActorFilter>,
+    hierarchy: &ScoreHierarchy,
+) {
+    if actors.contains(target) {
+        // Do scoring for each score tree of the given actor
+        roots.extend(
+            hierarchy
+                .children(target)
+                .filter(|&child| scoreable_roots.contains(child))
+                .map(|child| (child, Some(target))),
+        );
+    } else {
+        // Do scoring for the given entity, whose actor is its closest ancestor without a score
+        let mut actor = hierarchy.parent(target);
+        while let Some(ancestor) = actor.filter(|&ancestor| scoreable_roots.contains(ancestor)) {
+            actor = hierarchy.parent(ancestor);
+        }
+        roots.push((target, actor));
+    }
+}
+
+/// Returns the entities of the given roots' score trees in depth-first post-order,
+/// alongside their [`OnScore`] event, updating the [`LastScored`] times of the entities that track them.
+fn score_post_order(
+    roots: &[(Entity, Option<Entity>)],
+    now: Duration,
+    last_scored: &mut Query<&mut LastScored>,
+    dfs: &mut DFSPostTraversal<With<Score>>,
+) -> Vec<(Entity, OnScore)> {
+    utility_span!("score_post_order", roots = roots.len());
+    let mut scored = Vec::new();
+    for &(root, actor) in roots {
+        for entity in dfs.iter(root) {
+            let delta = last_scored
+                .get_mut(entity)
+                .ok()
+                .and_then(|mut last| last.0.replace(now))
+                .map_or(Duration::ZERO, |last| now.saturating_sub(last));
+            scored.push((entity, OnScore { actor, delta }));
+        }
+    }
+    scored
+}
+
+/// Scores the given entities in order, either by triggering their [`OnScore`] events,
+/// or with a single [`ScoreBatch`] if `batched`, then triggers [`OnScoringComplete`] for each of the given roots.
+///
+/// The scoring is timed with [`MeasurePhase`] if `measured`.
+fn queue_scoring(
+    scored: Vec<(Entity, OnScore)>,
+    roots: Vec<(Entity, Option<Entity>)>,
+    commands: &mut Commands,
+    batched: bool,
+    measured: bool,
+) {
+    utility_span!("queue_scoring", scored = scored.len(), batched);
+    if measured {
+        commands.add(MeasurePhase::StartScoring);
+    }
+    let count = scored.len();
+    if batched {
+        commands.add(ScoreBatch(scored));
+    } else {
+        for (entity, event) in scored {
+            commands.trigger_targets(event, entity);
+        }
+    }
+    if measured {
+        commands.add(MeasurePhase::StopScoring(count));
+    }
+    for (root, actor) in roots {
+        commands.trigger_targets(OnScoringComplete { actor }, root);
+    }
+}
+
+/// [`Bundle`] for a [`Score`] entity: a default [`Score`] and the marker or settings component that calculates it,
+/// such as [`FixedScore`], [`Sum`], or a custom marker scored by [`score_ancestor`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// # let scorer =
+/// commands
+///     .spawn(ScorerBundle::new(Winning::new(0.5)))
+///     .with_children(|parent| {
+///         parent.spawn(ScorerBundle::new(FixedScore::new(0.7)));
+///         parent.spawn(ScorerBundle::new(FixedScore::new(0.3)));
+///     })
+/// #   .id();
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # assert_eq!(world.get::<Score>(scorer).unwrap().get(), 0.7);
+/// ```
+#[derive(Bundle)]
+pub struct ScorerBundle<M: Component> {
+    /// The calculated score.
+    pub score: Score,
+    /// The marker or settings component that calculates the score.
+    pub marker: M,
+}
+
+impl<M: Component> ScorerBundle<M> {
+    /// Creates a new [`ScorerBundle`] with the given marker and a default [`Score`].
+    #[must_use]
+    pub fn new(marker: M) -> Self {
+        Self {
+            score: Score::default(),
+            marker,
+        }
     }
 }
 
 /// [`Component`] for an entity's score for a given score type, ranging from 0 to 1.
+///
+/// All built-in scoring components (e.g. [`FixedScore`], [`Sum`], [`Evaluated`]) automatically insert
+/// a default [`Score`] alongside themselves, if not already present.
+/// Custom marker components scored by observers like [`score_ancestor`] still need it inserted manually.
 #[derive(Component, Reflect)]
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[cfg_attr(feature = "replication", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "replication", serde(from = "f32", into = "f32"))]
 #[reflect(Component, PartialEq, Debug, Default)]
 pub struct Score {
     /// The score value, clamped to the range `[0, 1]`.
@@ -141,18 +421,31 @@ impl Score {
     pub const MAX: Score = unsafe { Score::new_unchecked(1.) };
 
     /// Creates a new score with the given value, clamped to the range `[0, 1]`.
+    ///
+    /// NaN values are replaced with `0`, logging a warning the first time in debug builds,
+    /// so that they can never poison comparisons and aggregations further up the score tree.
+    /// Use [`Score::try_new`] to reject them instead.
     #[must_use]
     pub fn new(value: f32) -> Self {
         Self {
-            value: value.clamp(0., 1.),
+            value: Self::sanitize(value),
         }
     }
 
+    /// Creates a new score with the given value, clamped to the range `[0, 1]`,
+    /// or returns `None` if the value is NaN.
+    #[must_use]
+    pub fn try_new(value: f32) -> Option<Self> {
+        (!value.is_nan()).then(|| Self {
+            value: value.clamp(0., 1.),
+        })
+    }
+
     /// Creates a new score with the given value, without clamping.
     ///
     /// # Safety
     ///
-    /// The value must be in the range `[0, 1]`.
+    /// The value must be in the range `[0, 1]`, and must not be NaN.
     #[must_use]
     pub const unsafe fn new_unchecked(value: f32) -> Self {
         Self { value }
@@ -165,9 +458,22 @@ impl Score {
     }
 
     /// Sets the score's value, clamped to the range `[0, 1]`.
+    ///
+    /// NaN values are replaced with `0`, logging a warning the first time it happens in debug builds.
     #[inline]
     pub fn set(&mut self, value: f32) {
-        self.value = value.clamp(0., 1.);
+        self.value = Self::sanitize(value);
+    }
+
+    /// Clamps the value to the range `[0, 1]`, replacing NaN with `0`.
+    #[inline]
+    fn sanitize(value: f32) -> f32 {
+        if value.is_nan() {
+            #[cfg(debug_assertions)]
+            warn_once!("NaN score value replaced with 0");
+            return 0.;
+        }
+        value.clamp(0., 1.)
     }
 }
 
@@ -232,6 +538,31 @@ impl std::iter::Sum<Score> for f32 {
     }
 }
 
+/// [`Component`] that references the target [`Entity`] of a consideration, such as the enemy being evaluated.
+///
+/// This can be placed on the [`Score`] entity itself, or on any of its ancestors (usually the actor entity).
+/// The closest one is used. See [`score_target`] for an example.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct TargetRef(pub Entity);
+
+impl MapEntities for TargetRef {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// [`Component`] for [`Score`] entities that stores the [`Time::elapsed`] time they were last scored at,
+/// or `None` if they haven't been scored yet, used to calculate [`OnScore::delta`].
+///
+/// Tracking is opt-in: insert [`LastScored::default`] on the score entities of time-aware scorers.
+/// Other score entities always get a zero [`OnScore::delta`].
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct LastScored(pub Option<Duration>);
+
 // TODO: implement Reflect when Bound is reflectable
 /// A range of [`Score`]s.
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -316,10 +647,64 @@ impl RangeBounds<Score> for ScoreRange {
     }
 }
 
+/// [`App`] extension trait for registering common scoring [`Observer`]s.
+pub trait AddScorerExt {
+    /// Registers [`score_ancestor`] to score entities marked with `ScoreMarker`
+    /// based on the [`Component`] `T` on their closest ancestor entity.
+    ///
+    /// `ScoreMarker` is also registered in the [`ScorerComponents`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// /// This goes on the actor entity.
+    /// #[derive(Component)]
+    /// struct Thirst {
+    ///     value: f32,
+    /// }
+    ///
+    /// /// This goes on the score entity, and derives the `From<&Thirst> for Score` impl.
+    /// #[derive(Component, Scorer)]
+    /// #[scorer(source = Thirst, score = |thirst| thirst.value / 100.)]
+    /// pub struct Thirsty;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+    /// app.add_scorer::<Thirst, Thirsty>();
+    ///
+    /// # let mut world = app.world_mut();
+    /// let scorer = world.spawn((Thirsty, Score::default())).id();
+    /// let actor = world.spawn(Thirst { value: 50. }).add_child(scorer).id();
+    /// # world.trigger_targets(RunScoring, scorer);
+    /// # world.flush();
+    /// # assert_eq!(0.5, world.get::<Score>(scorer).unwrap().get());
+    /// ```
+    fn add_scorer<T: Component, ScoreMarker: Component>(&mut self) -> &mut Self
+    where
+        for<'a> &'a T: Into<Score>;
+}
+
+impl AddScorerExt for App {
+    fn add_scorer<T: Component, ScoreMarker: Component>(&mut self) -> &mut Self
+    where
+        for<'a> &'a T: Into<Score>,
+    {
+        let marker = self.world_mut().init_component::<ScoreMarker>();
+        self.world_mut()
+            .get_resource_or_insert_with(ScorerComponents::default)
+            .register(marker, ScorerKind::Scorer);
+        self.observe(score_ancestor::<T, ScoreMarker>)
+    }
+}
+
 /// [`Observer`] helper function that calculates the score of a child [`Score`] entity marked with `ScoreMarker`
 /// based on the [`Component`] `T` on its closest ancestor entity, usually the actor entity.
 ///
-/// The [`Component`] `T` must implement [`Into<Score>`] for its reference type `&T`.
+/// The [`Component`] `T` must implement [`Into<Score>`] for its reference type `&T`,
+/// which can be derived with [`macro@Scorer`].
 ///
 /// # Example
 ///
@@ -370,6 +755,7 @@ pub fn score_ancestor<T: Component, ScoreMarker: Component>(
 ) where
     for<'a> &'a T: Into<Score>,
 {
+    utility_span!("score_ancestor", entity = ?trigger.entity(), source = std::any::type_name::<T>());
     let scorer = trigger.entity();
     let Ok(mut score) = scores.get_mut(scorer) else {
         return;
@@ -383,23 +769,524 @@ pub fn score_ancestor<T: Component, ScoreMarker: Component>(
     }
 }
 
+/// [`Observer`] helper function that calculates the score of a child [`Score`] entity marked with `ScoreMarker`
+/// by passing the [`Component`] `T` on its closest ancestor entity into the given `scorer` closure.
+///
+/// Unlike [`score_ancestor`], this doesn't require `&T` to implement [`Into<Score>`],
+/// so a single component can drive multiple differently-shaped considerations without newtype wrappers.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// /// This goes on the actor entity.
+/// #[derive(Component)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// /// These go on the score entities.
+/// #[derive(Component)]
+/// pub struct Healthy;
+/// #[derive(Component)]
+/// pub struct Wounded;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.observe(score_ancestor_with::<Health, Healthy>(|health| {
+///     Score::new(health.current / health.max)
+/// }));
+/// app.observe(score_ancestor_with::<Health, Wounded>(|health| {
+///     Score::new(1. - health.current / health.max)
+/// }));
+///
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// let healthy = commands.spawn((Healthy, Score::default())).id();
+/// let wounded = commands.spawn((Wounded, Score::default())).id();
+///
+/// let actor = commands
+///     .spawn(Health { current: 25., max: 100. })
+///     .push_children(&[healthy, wounded])
+///     .id();
+/// # commands.trigger_targets(RunScoring, healthy);
+/// # commands.trigger_targets(RunScoring, wounded);
+/// # world.flush();
+/// # assert_eq!(0.25, world.get::<Score>(healthy).unwrap().get());
+/// # assert_eq!(0.75, world.get::<Score>(wounded).unwrap().get());
+/// ```
+pub fn score_ancestor_with<T: Component, ScoreMarker: Component>(
+    scorer: impl Fn(&T) -> Score + Send + Sync + 'static,
+) -> impl FnMut(Trigger<OnScore>, Query<&'static mut Score, With<ScoreMarker>>, AncestorQuery<&'static T>) {
+    move |trigger, mut scores, mut ancestors| {
+        utility_span!("score_ancestor_with", entity = ?trigger.entity(), source = std::any::type_name::<T>());
+        let scorer_entity = trigger.entity();
+        let Ok(mut score) = scores.get_mut(scorer_entity) else {
+            return;
+        };
+
+        if let Ok(ancestor) = ancestors.get(scorer_entity) {
+            *score = scorer(ancestor);
+        } else {
+            // If there is no ancestor, set the score to the minimum.
+            *score = Score::MIN;
+        }
+    }
+}
+
+/// [`Observer`] helper function that calculates the score of a [`Score`] entity marked with `ScoreMarker`
+/// based on the [`Component`] `T` on the entity referenced by its closest [`TargetRef`],
+/// rather than on the actor entity.
+///
+/// The [`Component`] `T` must implement [`Into<Score>`] for its reference type `&T`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// /// This goes on the target entity.
+/// #[derive(Component)]
+/// struct Health {
+///     value: f32,
+/// }
+///
+/// /// This impl is required for the `score_target` observer.
+/// impl From<&Health> for Score {
+///    fn from(health: &Health) -> Self {
+///       Score::new(1. - health.value / 100.)
+///    }
+/// }
+///
+/// /// This goes on the score entity.
+/// #[derive(Component)]
+/// pub struct EnemyWeakened;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.observe(score_target::<Health, EnemyWeakened>);
+///
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// let enemy = commands.spawn(Health { value: 25. }).id();
+///
+/// let scorer = commands
+///     .spawn((EnemyWeakened, Score::default()))
+///     .id();
+///
+/// let actor = commands
+///     .spawn(TargetRef(enemy))
+///     .add_child(scorer)
+///     .id();
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # assert_eq!(0.75, world.get::<Score>(scorer).unwrap().get());
+/// ```
+pub fn score_target<T: Component, ScoreMarker: Component>(
+    trigger: Trigger<OnScore>,
+    mut scores: Query<&mut Score, With<ScoreMarker>>,
+    mut target_refs: AncestorQuery<&'static TargetRef>,
+    targets: Query<&T>,
+) where
+    for<'a> &'a T: Into<Score>,
+{
+    utility_span!("score_target", entity = ?trigger.entity(), source = std::any::type_name::<T>());
+    let scorer = trigger.entity();
+    let Ok(mut score) = scores.get_mut(scorer) else {
+        return;
+    };
+
+    let target = target_refs
+        .get(scorer)
+        .ok()
+        .and_then(|target_ref| targets.get(target_ref.0).ok());
+
+    if let Some(target) = target {
+        *score = target.into();
+    } else {
+        // If there is no target, set the score to the minimum.
+        *score = Score::MIN;
+    }
+}
+
+/// [`Observer`] helper function that calculates the score of a [`Score`] entity marked with `ScoreMarker`
+/// based on the [`Component`] `T` on the score entity itself.
+///
+/// This is useful when the consideration's data lives on the score entity, such as per-consideration configuration.
+/// The [`Component`] `T` must implement [`Into<Score>`] for its reference type `&T`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// /// This goes on the score entity.
+/// #[derive(Component)]
+/// struct Courage {
+///     value: f32,
+/// }
+///
+/// /// This impl is required for the `score_component` observer.
+/// impl From<&Courage> for Score {
+///    fn from(courage: &Courage) -> Self {
+///       Score::new(courage.value)
+///    }
+/// }
+///
+/// /// This also goes on the score entity.
+/// #[derive(Component)]
+/// pub struct Brave;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.observe(score_component::<Courage, Brave>);
+///
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// let scorer = commands
+///     .spawn((Brave, Courage { value: 0.8 }, Score::default()))
+///     .id();
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # assert_eq!(0.8, world.get::<Score>(scorer).unwrap().get());
+/// ```
+pub fn score_component<T: Component, ScoreMarker: Component>(
+    trigger: Trigger<OnScore>,
+    mut scores: Query<(&mut Score, Option<&T>), With<ScoreMarker>>,
+) where
+    for<'a> &'a T: Into<Score>,
+{
+    utility_span!("score_component", entity = ?trigger.entity(), source = std::any::type_name::<T>());
+    let Ok((mut score, component)) = scores.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    if let Some(component) = component {
+        *score = component.into();
+    } else {
+        // If the component is missing, set the score to the minimum.
+        *score = Score::MIN;
+    }
+}
+
+/// [`Observer`] helper function that calculates the score of a [`Score`] entity marked with `ScoreMarker`
+/// based on the [`Resource`] `R`, such as a global alert level or the time of day.
+///
+/// The [`Resource`] `R` must implement [`Into<Score>`] for its reference type `&R`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// /// A global resource shared by all actors.
+/// #[derive(Resource)]
+/// struct AlertLevel {
+///     value: f32,
+/// }
+///
+/// /// This impl is required for the `score_resource` observer.
+/// impl From<&AlertLevel> for Score {
+///    fn from(alert: &AlertLevel) -> Self {
+///       Score::new(alert.value / 10.)
+///    }
+/// }
+///
+/// /// This goes on the score entity.
+/// #[derive(Component)]
+/// pub struct Alerted;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// app.insert_resource(AlertLevel { value: 3. });
+/// app.observe(score_resource::<AlertLevel, Alerted>);
+///
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// let scorer = commands
+///     .spawn((Alerted, Score::default()))
+///     .id();
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # assert_eq!(0.3, world.get::<Score>(scorer).unwrap().get());
+/// ```
+pub fn score_resource<R: Resource, ScoreMarker: Component>(
+    trigger: Trigger<OnScore>,
+    mut scores: Query<&mut Score, With<ScoreMarker>>,
+    resource: Option<Res<R>>,
+) where
+    for<'a> &'a R: Into<Score>,
+{
+    utility_span!("score_resource", entity = ?trigger.entity(), source = std::any::type_name::<R>());
+    let Ok(mut score) = scores.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    if let Some(resource) = resource {
+        *score = resource.as_ref().into();
+    } else {
+        // If the resource doesn't exist, set the score to the minimum.
+        *score = Score::MIN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use approx::assert_relative_eq;
     use bevy::{
         app::App,
-        ecs::observer::ObserverState,
-        prelude::{BuildWorldChildren, With, World},
+        ecs::{observer::ObserverState, system::RunSystemOnce},
+        prelude::{
+            BuildWorldChildren, Children, Component, Entity, ResMut, Resource, Time, Trigger, With, Without, World,
+        },
     };
 
     use crate::{
-        event::RunScoring,
+        acting::CurrentAction,
+        ecs::CleanupUnusedObservers,
+        event::{OnScore, RunScoring},
+        memory::{MemoryOf, MemoryScore},
+        picking::Picker,
         scoring::{
-            AllOrNothing, Evaluated, FixedScore, Measured, PowerEvaluator, Product, Score, ScoringPlugin, Sum,
-            Weighted, WeightedMax, WeightedProduct, WeightedRMS, WeightedSum, Winning,
+            score_ancestor, AllOrNothing, AncestorQuery, BatchedScorerExt, BatchedScoringPlugin, Evaluated, FixedScore,
+            HierarchyGeneration, IdleTimeScore, LastScored, Measured, Normalization, Normalized, PowerEvaluator,
+            Product, Score, ScoreChildren, ScoreOf, ScoreOverrides, ScoreSimulator, ScoringPlugin, Sum, Weighted,
+            WeightedMax, WeightedProduct, WeightedRMS, WeightedSum, Winning,
         },
+        targeting::{BestTarget, TargetCandidates, TargetConsideration, TargetSelector},
     };
 
+    #[test]
+    fn batched_matches_observers() {
+        fn spawn_tree(world: &mut World) -> Entity {
+            world
+                .spawn((Score::default(), Product::new(0.)))
+                .with_children(|parent| {
+                    parent
+                        .spawn((Score::default(), Winning::new(0.)))
+                        .with_children(|parent| {
+                            parent.spawn((Score::default(), FixedScore::new(0.7)));
+                            parent.spawn((Score::default(), FixedScore::new(0.3)));
+                        });
+                    parent.spawn((Score::default(), Sum::new(0.))).with_children(|parent| {
+                        parent.spawn((Score::default(), FixedScore::new(0.2)));
+                        parent.spawn((Score::default(), FixedScore::new(0.4)));
+                    });
+                })
+                .id()
+        }
+
+        let mut observed = App::new();
+        observed.add_plugins(ScoringPlugin);
+        let observed_root = spawn_tree(observed.world_mut());
+        observed.world_mut().trigger(RunScoring);
+        observed.world_mut().flush();
+
+        let mut batched = App::new();
+        batched.add_plugins((ScoringPlugin, BatchedScoringPlugin));
+        let batched_root = spawn_tree(batched.world_mut());
+        batched.world_mut().trigger(RunScoring);
+        batched.world_mut().flush();
+
+        let observed_score = observed.world().get::<Score>(observed_root).unwrap().get();
+        let batched_score = batched.world().get::<Score>(batched_root).unwrap().get();
+        assert_relative_eq!(0.42, observed_score);
+        assert_relative_eq!(observed_score, batched_score);
+    }
+
+    #[test]
+    fn batched_ambiguous_scorer_falls_back() {
+        #[derive(Component)]
+        struct Low;
+
+        #[derive(Component)]
+        struct High;
+
+        let mut app = App::new();
+        app.add_plugins((ScoringPlugin, BatchedScoringPlugin))
+            .add_batched_scorer::<Low>(|world, entity| {
+                if let Some(mut score) = world.get_mut::<Score>(entity) {
+                    score.set(0.2);
+                }
+            })
+            .add_batched_scorer::<High>(|world, entity| {
+                if let Some(mut score) = world.get_mut::<Score>(entity) {
+                    score.set(0.8);
+                }
+            });
+
+        let world = app.world_mut();
+        let scorer = world.spawn((Score::default(), Low, High)).id();
+        world.trigger(RunScoring);
+        world.flush();
+
+        // Neither batched scorer wins, and there are no OnScore observers for them.
+        assert_eq!(0., world.get::<Score>(scorer).unwrap().get());
+
+        // The cached scorer is forgotten once the entity's scorer components change.
+        world.entity_mut(scorer).remove::<High>();
+        world.trigger(RunScoring);
+        world.flush();
+        assert_eq!(0.2, world.get::<Score>(scorer).unwrap().get());
+    }
+
+    #[test]
+    fn ancestor_cache_invalidated_on_reparent() {
+        #[derive(Component)]
+        struct Level(f32);
+
+        impl From<&Level> for Score {
+            fn from(level: &Level) -> Self {
+                Score::new(level.0)
+            }
+        }
+
+        #[derive(Component)]
+        struct Leveled;
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+        app.update();
+        // The hierarchy is only tracked once an AncestorQuery is used
+        assert!(!app.world().contains_resource::<HierarchyGeneration>());
+        app.observe(score_ancestor::<Level, Leveled>);
+
+        let world = app.world_mut();
+        let low = world.spawn(Level(0.2)).id();
+        let high = world.spawn(Level(0.8)).id();
+        let scorer = world.spawn((Score::default(), Leveled)).set_parent(low).id();
+
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert!(world.contains_resource::<HierarchyGeneration>());
+        assert_relative_eq!(0.2, world.get::<Score>(scorer).unwrap().get());
+
+        // Reparenting an entity that already has a parent changes its Parent in place
+        world.entity_mut(scorer).set_parent(high);
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_relative_eq!(0.8, world.get::<Score>(scorer).unwrap().get());
+
+        // Removing and inserting a Parent
+        world.entity_mut(scorer).remove_parent().set_parent(low);
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_relative_eq!(0.2, world.get::<Score>(scorer).unwrap().get());
+
+        // A closer ancestor gaining the component
+        let middle = world.spawn_empty().set_parent(low).id();
+        world.entity_mut(scorer).set_parent(middle);
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_relative_eq!(0.2, world.get::<Score>(scorer).unwrap().get());
+        world.entity_mut(middle).insert(Level(0.5));
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_relative_eq!(0.5, world.get::<Score>(scorer).unwrap().get());
+    }
+
+    #[test]
+    fn iter_ancestors_yields_nearest_to_farthest() {
+        #[derive(Component)]
+        struct Modifier(f32);
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+        let world = app.world_mut();
+
+        let platoon = world.spawn(Modifier(0.5)).id();
+        let squad = world.spawn(Modifier(0.8)).set_parent(platoon).id();
+        // Entities without the component are skipped
+        let team = world.spawn_empty().set_parent(squad).id();
+        // ScoreOf links are preferred over Parents
+        let scorer = world
+            .spawn((Score::default(), Modifier(0.9), ScoreOf::new(team)))
+            .set_parent(platoon)
+            .id();
+        world.flush();
+
+        let found = world.run_system_once(move |ancestors: AncestorQuery<&'static Modifier>| {
+            ancestors
+                .iter_ancestors(scorer)
+                .map(|(entity, modifier)| (entity, modifier.0))
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(vec![(scorer, 0.9), (squad, 0.8), (platoon, 0.5)], found);
+
+        // Ancestors that don't match the filter are skipped
+        let found = world.run_system_once(move |ancestors: AncestorQuery<&'static Modifier, Without<Score>>| {
+            ancestors
+                .iter_ancestors(scorer)
+                .map(|(entity, _)| entity)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(vec![squad, platoon], found);
+    }
+
+    #[test]
+    fn score_of_links_score_tree() {
+        #[derive(Component)]
+        struct Level(f32);
+
+        impl From<&Level> for Score {
+            fn from(level: &Level) -> Self {
+                Score::new(level.0)
+            }
+        }
+
+        #[derive(Component)]
+        struct Leveled;
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin).observe(score_ancestor::<Level, Leveled>);
+
+        let world = app.world_mut();
+        let actor = world.spawn(Level(0.3)).id();
+        let sum = world.spawn((Sum::new(0.), ScoreOf::new(actor))).id();
+        world.spawn((FixedScore::new(0.4), ScoreOf::new(sum)));
+        world.spawn((Score::default(), Leveled, ScoreOf::new(sum)));
+        world.flush();
+
+        assert!(world.get::<Children>(actor).is_none());
+        assert_eq!(
+            vec![sum],
+            world.get::<ScoreChildren>(actor).unwrap().iter().collect::<Vec<_>>()
+        );
+
+        world.trigger_targets(RunScoring, actor);
+        world.flush();
+        assert_relative_eq!(0.7, world.get::<Score>(sum).unwrap().get());
+    }
+
+    #[test]
+    fn score_of_relink_removes_old_score_children() {
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+
+        let world = app.world_mut();
+        let first = world.spawn(Sum::new(0.)).id();
+        let second = world.spawn(Sum::new(0.)).id();
+        let scorer = world.spawn((FixedScore::new(0.4), ScoreOf::new(first))).id();
+        world.flush();
+
+        world.entity_mut(scorer).insert(ScoreOf::new(second));
+        world.flush();
+
+        assert_eq!(0, world.get::<ScoreChildren>(first).unwrap().iter().count());
+        assert_eq!(
+            vec![scorer],
+            world.get::<ScoreChildren>(second).unwrap().iter().collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn all_or_nothing() {
         let mut app = App::new();
@@ -423,7 +1310,7 @@ mod tests {
             world.get::<Score>(parent).unwrap().get(),
             "Parent score should be 1.0."
         );
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
     }
 
     #[test]
@@ -459,7 +1346,221 @@ mod tests {
         world.flush();
 
         assert_eq!(0.5, world.get::<Score>(entity).unwrap().get(), "Score should be 0.5.");
-        assert_eq!(2, count_observers(world));
+        assert_eq!(3, count_observers(world));
+    }
+
+    #[test]
+    fn unused_observers_cleaned_up() {
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin).insert_resource(CleanupUnusedObservers);
+
+        let world = app.world_mut();
+        let first = world.spawn(FixedScore::new(0.5)).id();
+        let second = world.spawn(FixedScore::new(0.5)).id();
+        world.flush();
+        assert_eq!(3, count_observers(world));
+
+        world.despawn(first);
+        world.flush();
+        assert_eq!(3, count_observers(world), "Observer should be kept while in use.");
+
+        world.despawn(second);
+        world.flush();
+        assert_eq!(2, count_observers(world), "Observer should be despawned once unused.");
+
+        let third = world.spawn(FixedScore::new(0.5)).id();
+        world.trigger_targets(RunScoring, third);
+        world.flush();
+        assert_eq!(
+            0.5,
+            world.get::<Score>(third).unwrap().get(),
+            "Observer should be spawned again."
+        );
+        assert_eq!(3, count_observers(world));
+    }
+
+    #[test]
+    fn on_score_carries_actor_and_delta() {
+        #[derive(Resource, Default)]
+        struct Seen(Vec<OnScore>);
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin)
+            .init_resource::<Time>()
+            .init_resource::<Seen>()
+            .observe(|trigger: Trigger<OnScore>, mut seen: ResMut<Seen>| {
+                seen.0.push(*trigger.event());
+            });
+
+        let world = app.world_mut();
+        let scorer = world.spawn((FixedScore::new(0.5), LastScored::default())).id();
+        let actor = world.spawn_empty().add_child(scorer).id();
+
+        world.trigger_targets(RunScoring, actor);
+        world.flush();
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(2));
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_eq!(Some(Duration::from_secs(2)), world.get::<LastScored>(scorer).unwrap().0);
+
+        // Untracked score entities always get a zero delta
+        let untracked = world.spawn(FixedScore::new(0.5)).id();
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+        world.trigger_targets(RunScoring, untracked);
+        world.flush();
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+        world.trigger_targets(RunScoring, untracked);
+        world.flush();
+        assert!(world.get::<LastScored>(untracked).is_none());
+
+        let seen = &world.resource::<Seen>().0;
+        assert_eq!(
+            &[
+                OnScore {
+                    actor: Some(actor),
+                    delta: Duration::ZERO,
+                },
+                OnScore {
+                    actor: Some(actor),
+                    delta: Duration::from_secs(2),
+                },
+                OnScore {
+                    actor: None,
+                    delta: Duration::ZERO,
+                },
+                OnScore {
+                    actor: None,
+                    delta: Duration::ZERO,
+                },
+            ],
+            &seen[..]
+        );
+    }
+
+    #[test]
+    fn simulate_restores_live_state() {
+        #[derive(Component)]
+        struct Level(f32);
+
+        impl From<&Level> for Score {
+            fn from(level: &Level) -> Self {
+                Score::new(level.0)
+            }
+        }
+
+        #[derive(Component)]
+        struct Leveled;
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin)
+            .init_resource::<Time>()
+            .observe(score_ancestor::<Level, Leveled>);
+
+        let world = app.world_mut();
+        let scorer = world.spawn((Score::default(), Leveled)).id();
+        let fixed = world.spawn(FixedScore::new(0.1)).id();
+        let root = world
+            .spawn((Score::default(), Sum::new(0.)))
+            .push_children(&[scorer, fixed])
+            .id();
+        let actor = world.spawn(Level(0.2)).add_child(root).id();
+
+        let snapshot = world.simulate(actor, ScoreOverrides::new().with(actor, Level(0.6)));
+        assert_relative_eq!(0.6, snapshot.get(scorer).unwrap().get());
+        assert_relative_eq!(0.7, snapshot.get(root).unwrap().get());
+        assert_eq!(3, snapshot.len());
+        assert_eq!(0., world.get::<Score>(root).unwrap().get());
+        assert_eq!(0.2, world.get::<Level>(actor).unwrap().0);
+        assert!(world.get::<LastScored>(root).is_none());
+
+        let snapshot = world.simulate(actor, ScoreOverrides::new().without::<Level>(actor));
+        assert_relative_eq!(0.1, snapshot.get(root).unwrap().get());
+        assert_eq!(0.2, world.get::<Level>(actor).unwrap().0);
+    }
+
+    #[test]
+    fn idle_time() {
+        #[derive(Component)]
+        struct Wander;
+
+        #[derive(Component)]
+        struct Idle;
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+        app.init_resource::<Time>();
+
+        let world = app.world_mut();
+
+        let wander = world.init_component::<Wander>();
+        let idle = world.init_component::<Idle>();
+
+        let scorer = world
+            .spawn((Score::default(), IdleTimeScore::new(Duration::from_secs(10))))
+            .id();
+        let actor = world
+            .spawn((Picker::new(idle).with(scorer, wander), CurrentAction(idle)))
+            .add_child(scorer)
+            .id();
+
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_eq!(0., world.get::<Score>(scorer).unwrap().get());
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(5));
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_relative_eq!(0.5, world.get::<Score>(scorer).unwrap().get());
+
+        world.entity_mut(actor).insert(CurrentAction(wander));
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_eq!(0., world.get::<Score>(scorer).unwrap().get());
+    }
+
+    #[test]
+    fn score_inserted_if_missing() {
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+
+        let world = app.world_mut();
+
+        let without_score = world.spawn(FixedScore::new(0.5)).id();
+        let with_score = world.spawn((FixedScore::new(0.5), Score::new(0.2))).id();
+        world.flush();
+
+        assert_eq!(0.0, world.get::<Score>(without_score).unwrap().get());
+        assert_eq!(0.2, world.get::<Score>(with_score).unwrap().get());
+
+        world.trigger_targets(RunScoring, without_score);
+        world.flush();
+
+        assert_eq!(0.5, world.get::<Score>(without_score).unwrap().get());
+    }
+
+    #[test]
+    fn nan_score_rejected() {
+        assert_eq!(0., Score::new(f32::NAN).get());
+        assert_eq!(None, Score::try_new(f32::NAN));
+        assert_eq!(Some(Score::MAX), Score::try_new(2.));
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+
+        let world = app.world_mut();
+
+        let parent = world
+            .spawn(Evaluated::new(|_: f32| f32::NAN))
+            .with_children(|parent| {
+                parent.spawn(FixedScore::new(0.5));
+            })
+            .id();
+        world.flush();
+
+        world.trigger_targets(RunScoring, parent);
+        world.flush();
+
+        assert_eq!(0., world.get::<Score>(parent).unwrap().get());
     }
 
     #[test]
@@ -481,7 +1582,7 @@ mod tests {
         world.flush();
 
         assert_relative_eq!(0.89, world.get::<Score>(parent).unwrap().get());
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
     }
 
     #[test]
@@ -503,7 +1604,7 @@ mod tests {
         world.flush();
 
         assert_relative_eq!(0.0648, world.get::<Score>(parent).unwrap().get());
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
     }
 
     #[test]
@@ -525,7 +1626,7 @@ mod tests {
         world.flush();
 
         assert_relative_eq!(0.81, world.get::<Score>(parent).unwrap().get());
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
     }
 
     #[test]
@@ -547,7 +1648,29 @@ mod tests {
         world.flush();
 
         assert_relative_eq!(0.8905055, world.get::<Score>(parent).unwrap().get());
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
+    }
+
+    #[test]
+    fn normalized_sum() {
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+
+        let world = app.world_mut();
+
+        let parent = world
+            .spawn((Score::default(), Normalized::new(Normalization::Sum, WeightedMax)))
+            .with_children(|parent| {
+                parent.spawn((Score::default(), FixedScore::new(0.2)));
+                parent.spawn((Score::default(), FixedScore::new(0.4)));
+            })
+            .id();
+
+        world.trigger_targets(RunScoring, parent);
+        world.flush();
+
+        assert_relative_eq!(2. / 3., world.get::<Score>(parent).unwrap().get());
+        assert_eq!(4, count_observers(world));
     }
 
     #[test]
@@ -569,7 +1692,7 @@ mod tests {
         world.flush();
 
         assert_relative_eq!(0.72, world.get::<Score>(parent).unwrap().get(),);
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
     }
 
     #[test]
@@ -595,7 +1718,7 @@ mod tests {
             world.get::<Score>(parent).unwrap().get(),
             "Parent score should be 1.0."
         );
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
     }
 
     #[test]
@@ -621,10 +1744,119 @@ mod tests {
             world.get::<Score>(parent).unwrap().get(),
             "Parent score should be 0.9."
         );
-        assert_eq!(3, count_observers(world));
+        assert_eq!(4, count_observers(world));
+    }
+
+    #[test]
+    fn target_selector_picks_best_listed_candidate() {
+        #[derive(Component)]
+        struct Health(f32);
+        #[derive(Component)]
+        struct Attack;
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+
+        let world = app.world_mut();
+
+        let attack = world.init_component::<Attack>();
+
+        let healthy = world.spawn(Health(0.9)).id();
+        let wounded = world.spawn(Health(0.3)).id();
+        let unlisted = world.spawn(Health(0.)).id();
+
+        let selector = TargetSelector::<()>::new()
+            .with_consideration(TargetConsideration::new(|target| {
+                target.candidate.get::<Health>().map_or(0., |health| 1. - health.0)
+            }))
+            .with_threshold(0.5);
+        let scorer = world.spawn((selector, TargetCandidates(vec![healthy, wounded]))).id();
+        let actor = world
+            .spawn((Health(0.), Picker::new(attack).with(scorer, attack)))
+            .add_child(scorer)
+            .id();
+        world.get_mut::<TargetCandidates>(scorer).unwrap().0.push(actor);
+
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_eq!(Some(&BestTarget(wounded)), world.get::<BestTarget>(scorer));
+        assert_relative_eq!(0.7, world.get::<Score>(scorer).unwrap().get());
+        assert_ne!(Some(&BestTarget(unlisted)), world.get::<BestTarget>(scorer));
+
+        world.entity_mut(wounded).insert(Health(0.8));
+        world.trigger_targets(RunScoring, scorer);
+        world.flush();
+        assert_eq!(
+            None,
+            world.get::<BestTarget>(scorer),
+            "No candidate is above the threshold."
+        );
+        assert_eq!(0., world.get::<Score>(scorer).unwrap().get());
+    }
+
+    #[test]
+    fn memory_score_decays_with_age() {
+        #[derive(Clone, Debug)]
+        struct Heard;
+        #[derive(Component)]
+        struct Investigate;
+
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+        app.init_resource::<Time>();
+
+        let world = app.world_mut();
+
+        let investigate = world.init_component::<Investigate>();
+
+        let noisy = world.spawn_empty().id();
+        let quiet = world.spawn_empty().id();
+        let freshest = world.spawn(MemoryScore::<Heard>::freshness()).id();
+        let confident = world.spawn(MemoryScore::<Heard>::confidence().with_subject(quiet)).id();
+        let mut memory = MemoryOf::<Heard>::new(Duration::from_secs(10));
+        memory.record_with_confidence(noisy, Heard, 1., Duration::ZERO);
+        memory.record_with_confidence(quiet, Heard, 0.5, Duration::from_secs(5));
+        world
+            .spawn((
+                Picker::new(investigate)
+                    .with(freshest, investigate)
+                    .with(confident, investigate),
+                memory,
+            ))
+            .push_children(&[freshest, confident]);
+        world.flush();
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(6));
+        world.trigger_targets(RunScoring, freshest);
+        world.trigger_targets(RunScoring, confident);
+        world.flush();
+        assert_relative_eq!(0.9, world.get::<Score>(freshest).unwrap().get());
+        assert_relative_eq!(0.45, world.get::<Score>(confident).unwrap().get());
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(10));
+        world.trigger_targets(RunScoring, freshest);
+        world.trigger_targets(RunScoring, confident);
+        world.flush();
+        assert_eq!(0., world.get::<Score>(freshest).unwrap().get());
+        assert_eq!(0., world.get::<Score>(confident).unwrap().get());
     }
 
     fn count_observers(world: &mut World) -> usize {
         world.query_filtered::<(), With<ObserverState>>().iter(world).count()
     }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn generated_score_trees_uphold_invariants(spec in crate::testing::score_tree()) {
+            let mut app = App::new();
+            app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+            let world = app.world_mut();
+
+            let root = spec.spawn(world);
+            if let Err(violation) = crate::testing::check_score_tree(world, root) {
+                proptest::prop_assert!(false, "{}", violation);
+            }
+        }
+    }
 }
@@ -3,7 +3,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ecs::CommandsExt, event::OnScore, scoring::Score};
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScoreChildren, ScoreHierarchy, ScorerComponents, ScorerKind},
+};
 
 /// [`Score`] [`Component`] that scores all-or-nothing based on the sum of its child [`Score`] entities.
 ///
@@ -57,15 +61,22 @@ impl AllOrNothing {
     }
 
     /// [`Observer`] for [`AllOrNothing`] [`Score`] entities that scores based on all child [`Score`] entities.
-    fn observer(trigger: Trigger<OnScore>, target: Query<(&Children, &AllOrNothing)>, mut scores: Query<&mut Score>) {
-        let Ok((children, settings)) = target.get(trigger.entity()) else {
+    #[allow(clippy::type_complexity)]
+    fn observer(
+        trigger: Trigger<OnScore>,
+        target: Query<&AllOrNothing, Or<(With<Children>, With<ScoreChildren>)>>,
+        hierarchy: ScoreHierarchy,
+        mut scores: Query<&mut Score>,
+    ) {
+        utility_span!("AllOrNothing::observer", entity = ?trigger.entity());
+        let Ok(settings) = target.get(trigger.entity()) else {
             // The entity is not scoring for all-or-nothing.
             return;
         };
 
         let mut sum: f32 = 0.;
 
-        for child_score in scores.iter_many(children) {
+        for child_score in scores.iter_many(hierarchy.children(trigger.entity())) {
             if *child_score < settings.threshold() {
                 sum = 0.;
                 break;
@@ -86,14 +97,13 @@ impl Component for AllOrNothing {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct AllOrNothingObserverSpawned;
-
-            world
-                .commands()
-                .once::<AllOrNothingObserverSpawned>()
-                .observe(Self::observer);
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Combinator);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
         });
     }
 }
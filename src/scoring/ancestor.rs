@@ -0,0 +1,280 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::{
+        entity::EntityHashMap,
+        event::ManualEventReader,
+        query::{QueryEntityError, QueryFilter},
+        system::SystemParam,
+    },
+    hierarchy::HierarchyEvent,
+    prelude::*,
+};
+
+use crate::{
+    ecs::{CommandsExt, ReferenceType},
+    scoring::ScoreOf,
+};
+
+/// A [`Query`] wrapper that finds the closest ancestor entity with a given component.
+/// Uses a cache to speed up subsequent queries.
+///
+/// Ancestors are found through [`ScoreOf`] links as well as [`Parent`]s, preferring [`ScoreOf`].
+///
+/// The cache is cleared whenever the [`HierarchyGeneration`] changes, that is when the hierarchy changes
+/// or any entity gains the component, and whenever an entity is reparented with [`BuildWorldChildren`]
+/// or [`BuildChildren`] commands. Cached ancestors that lose the component or stop matching the filter
+/// are found again. Only a closer ancestor starting to match the filter without gaining the component
+/// goes unnoticed, so call [`AncestorQuery::clear_cache`] if that can happen.
+///
+/// The optional [`QueryFilter`] `F` skips ancestors that have the component but don't match the filter,
+/// such as intermediate score entities when only the actor entity's component should be found:
+/// `AncestorQuery<&'static Thirst, With<Picker>>`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::{ecs::system::RunSystemOnce, prelude::*};
+/// use bevy_observed_utility::{prelude::*, scoring::AncestorQuery};
+///
+/// #[derive(Component)]
+/// struct Thirst(f32);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+/// # let mut world = app.world_mut();
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # let idle = world.init_component::<Idle>();
+/// let scorer = world.spawn(FixedScore::new(0.5)).id();
+/// let group = world.spawn(Thirst(10.)).add_child(scorer).id();
+/// let actor = world.spawn((Thirst(50.), Picker::new(idle))).add_child(group).id();
+///
+/// let found = world.run_system_once(move |mut actors: AncestorQuery<&'static Thirst, With<Picker>>| {
+///     actors.get_with_entity(scorer).map(|(actor, thirst)| (actor, thirst.0)).ok()
+/// });
+/// assert_eq!(Some((actor, 50.)), found);
+/// ```
+#[derive(SystemParam)]
+pub struct AncestorQuery<'w, 's, T: ReferenceType, F: QueryFilter + 'static = ()> {
+    /// The query to find the component, crawling up the hierarchy if necessary.
+    check: Query<
+        'w,
+        's,
+        (
+            <T as ReferenceType>::Has,
+            Option<&'static ScoreOf>,
+            Option<&'static Parent>,
+        ),
+    >,
+    /// The query to check whether an ancestor with the component matches the filter.
+    filter: Query<'w, 's, (), F>,
+    /// The query to grab the component. This query wouldn't be necessary if rust wouldn't complain!
+    fetch: Query<'w, 's, T, F>,
+    /// Caches a given entity's closest ancestor entity with the component T.
+    cache: Local<'s, EntityHashMap<Entity>>,
+    /// The current generation of the hierarchy, if tracked.
+    generation: Option<Res<'w, HierarchyGeneration>>,
+    /// The events sent when entities are reparented, if the hierarchy sends them.
+    hierarchy_events: Option<Res<'w, Events<HierarchyEvent>>>,
+    /// The generation of the hierarchy that the cache was built in.
+    cached_generation: Local<'s, CachedGeneration<<T as ReferenceType>::Component>>,
+}
+
+impl<'w, 's, T: ReferenceType, F: QueryFilter + 'static> AncestorQuery<'w, 's, T, F> {
+    /// Crawls up the hierarchy to find the closest ancestor entity with the component `T`.
+    fn find(&mut self, start: Entity) -> Result<Entity, QueryEntityError> {
+        // Crawl up the hierarchy
+        let mut current = start;
+        loop {
+            match self.check.get(current) {
+                Ok((true, _, _)) if self.filter.contains(current) => {
+                    // Found the component, cache it and return
+                    self.cache.insert(start, current);
+                    return Ok(current);
+                }
+                Ok((_, Some(score_of), _)) => {
+                    // Continue searching up the score tree
+                    current = score_of.get();
+                }
+                Ok((_, None, Some(parent))) => {
+                    // Continue searching up the hierarchy
+                    current = **parent;
+                }
+                Ok((_, None, None)) | Err(_) => {
+                    // No parent with the component found
+                    return Err(QueryEntityError::NoSuchEntity(current));
+                }
+            }
+        }
+    }
+
+    /// Returns the closest ancestor entity with the component `T`, checking the cache first.
+    fn resolve(&mut self, start: Entity) -> Result<Entity, QueryEntityError> {
+        if let Some(generation) = self.generation.as_deref() {
+            if *generation != self.cached_generation.generation {
+                // The hierarchy changed since the cache was built
+                self.cache.clear();
+                self.cached_generation.generation = *generation;
+            }
+        }
+        if let Some(events) = self.hierarchy_events.as_deref() {
+            let reader = &mut self.cached_generation.reparented;
+            if !reader.is_empty(events) || reader.missed_events(events) > 0 {
+                // Entities were reparented since the cache was built, possibly in place
+                self.cache.clear();
+                reader.clear(events);
+            }
+        }
+
+        // Check the cache first
+        if let Some(&cached) = self.cache.get(&start) {
+            if self.fetch.contains(cached) {
+                // Cache hit
+                return Ok(cached);
+            }
+
+            // Cache miss
+            self.cache.remove(&start);
+        }
+
+        self.find(start)
+    }
+
+    /// Returns the closest ancestor entity with the component `T`, if any.
+    ///
+    /// Useful for triggering events at the actor entity that a score entity belongs to.
+    #[must_use]
+    pub fn entity_of(&mut self, start: Entity) -> Option<Entity> {
+        self.resolve(start).ok()
+    }
+
+    /// Clears the cache to free up memory, if necessary.
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<'w, 's, T: Component, F: QueryFilter + 'static> AncestorQuery<'w, 's, &'static T, F> {
+    /// Returns a readonly reference to the [`Component`] `T` on the closest ancestor entity, if any.
+    ///
+    /// # Errors
+    ///
+    /// If the entity does not exist or the component is not found.
+    pub fn get(&mut self, start: Entity) -> Result<&T, QueryEntityError> {
+        self.resolve(start).and_then(|found| self.fetch.get(found))
+    }
+
+    /// Returns the closest ancestor entity with the [`Component`] `T`, and a readonly reference to it, if any.
+    ///
+    /// # Errors
+    ///
+    /// If the entity does not exist or the component is not found.
+    pub fn get_with_entity(&mut self, start: Entity) -> Result<(Entity, &T), QueryEntityError> {
+        let found = self.resolve(start)?;
+        self.fetch.get(found).map(|component| (found, component))
+    }
+
+    /// Returns an iterator over all ancestor entities with the [`Component`] `T` and readonly references to it,
+    /// from the closest to the farthest, starting with the given entity itself.
+    ///
+    /// Useful for aggregating over a chain of ancestors, such as squad and platoon modifiers.
+    /// Unlike [`AncestorQuery::get`], this doesn't use the cache.
+    pub fn iter_ancestors(&self, start: Entity) -> impl Iterator<Item = (Entity, &T)> + '_ {
+        std::iter::successors(Some(start), |&current| {
+            self.check
+                .get(current)
+                .ok()
+                .and_then(|(_, score_of, parent)| score_of.map(ScoreOf::get).or_else(|| parent.map(Parent::get)))
+        })
+        .filter_map(|ancestor| self.fetch.get(ancestor).ok().map(|component| (ancestor, component)))
+    }
+}
+
+impl<'w, 's, T: Component, F: QueryFilter + 'static> AncestorQuery<'w, 's, &'static mut T, F> {
+    /// Returns a mutable reference to the [`Component`] `T` on the closest ancestor entity, if any.
+    ///
+    /// # Errors
+    ///
+    /// If the entity does not exist or the component is not found.
+    pub fn get_mut(&mut self, start: Entity) -> Result<Mut<'_, T>, QueryEntityError> {
+        self.resolve(start).and_then(|found| self.fetch.get_mut(found))
+    }
+}
+
+/// [`Resource`] that counts changes to the entity hierarchy, used by [`AncestorQuery`] to invalidate its cache.
+///
+/// Only tracked once an [`AncestorQuery`] is first used, so that apps without one don't pay for it.
+/// From then on, it's incremented immediately whenever a [`Parent`] or [`ScoreOf`] is inserted or removed,
+/// and whenever an entity gains a component looked up by an [`AncestorQuery`].
+///
+/// Reparenting an entity that already has a parent changes its [`Parent`] in place, which doesn't increment
+/// the generation. [`AncestorQuery`] detects that through [`HierarchyEvent`]s instead,
+/// which the [`ScoringPlugin`](crate::scoring::ScoringPlugin) makes sure are sent.
+#[derive(Resource, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[reflect(Resource, PartialEq, Hash, Debug, Default)]
+pub struct HierarchyGeneration(pub u32);
+
+impl HierarchyGeneration {
+    /// Increments the generation, invalidating all [`AncestorQuery`] caches.
+    pub fn increment(&mut self) {
+        self.0 = self.0.wrapping_add(1);
+    }
+
+    /// Starts tracking the generation in the given world, if it isn't tracked yet.
+    pub fn track(world: &mut World) {
+        if world.contains_resource::<HierarchyGeneration>() {
+            return;
+        }
+        world.init_resource::<HierarchyGeneration>();
+        world.spawn(Observer::new(Self::on_parent_inserted));
+        world.spawn(Observer::new(Self::on_parent_removed));
+    }
+
+    /// Starts incrementing the generation whenever an entity gains the [`Component`] `C`, if it isn't yet.
+    pub fn track_component<C: Component>(world: &mut World) {
+        Self::track(world);
+        world.commands().observe_once(Self::on_component_added::<C>);
+        world.flush();
+    }
+
+    /// [`Observer`] that increments the generation when a [`Parent`] is inserted.
+    pub fn on_parent_inserted(_trigger: Trigger<OnInsert, Parent>, mut generation: ResMut<HierarchyGeneration>) {
+        generation.increment();
+    }
+
+    /// [`Observer`] that increments the generation when a [`Parent`] is removed.
+    pub fn on_parent_removed(_trigger: Trigger<OnRemove, Parent>, mut generation: ResMut<HierarchyGeneration>) {
+        generation.increment();
+    }
+
+    /// [`Observer`] that increments the generation when an entity gains the [`Component`] `C`,
+    /// since it may now be a closer ancestor than the cached one.
+    pub fn on_component_added<C: Component>(_trigger: Trigger<OnAdd, C>, mut generation: ResMut<HierarchyGeneration>) {
+        generation.increment();
+    }
+}
+
+/// The [`HierarchyGeneration`] that an [`AncestorQuery`]'s cache was built in,
+/// and how far it has read the [`HierarchyEvent`]s.
+/// Starts tracking the generation when the [`AncestorQuery`] is initialized.
+struct CachedGeneration<C: Component> {
+    generation: HierarchyGeneration,
+    reparented: ManualEventReader<HierarchyEvent>,
+    marker: PhantomData<C>,
+}
+
+impl<C: Component> FromWorld for CachedGeneration<C> {
+    fn from_world(world: &mut World) -> Self {
+        HierarchyGeneration::track_component::<C>(world);
+        Self {
+            generation: *world.resource::<HierarchyGeneration>(),
+            reparented: world
+                .get_resource::<Events<HierarchyEvent>>()
+                .map(Events::get_reader_current)
+                .unwrap_or_default(),
+            marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use bevy::{
+    ecs::{component::ComponentId, world::Command},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    event::OnScore,
+    scoring::{score_children, FixedScore, Product, Score, Sum, Winning},
+};
+
+/// Function that calculates the [`Score`] of a score entity directly, used by [`ScorerRegistry`].
+pub type ScorerFn = fn(&mut World, Entity);
+
+/// [`Plugin`] that switches scoring to the batched backend.
+///
+/// Instead of triggering [`OnScore`] once per score entity, [`RunScoring`] queues a single [`Command`]
+/// that walks each score tree in post-order and calls the [`ScorerFn`] registered in the [`ScorerRegistry`]
+/// for each entity's scorer component directly. This avoids the per-trigger overhead,
+/// which dominates with thousands of score entities.
+///
+/// [`FixedScore`], [`Sum`], [`Product`], and [`Winning`] are registered by this plugin.
+/// Score entities without a registered scorer fall back to triggering [`OnScore`] immediately,
+/// so custom scorers keep working, just without the speedup until they're registered with
+/// [`BatchedScorerExt::add_batched_scorer`].
+///
+/// # Limitations
+///
+/// [`OnScore`] isn't triggered for score entities with a registered scorer, as that would score them twice.
+/// So other [`Observer`]s of [`OnScore`] won't see them, and their [`ScorerFn`]s don't get the
+/// [`OnScore::actor`] or [`OnScore::delta`]. Keep score entities that need either on [`OnScore`] observers,
+/// by not registering their scorer component.
+///
+/// [`RunScoring`]: crate::event::RunScoring
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::RealTime, BatchedScoringPlugin));
+/// # let mut world = app.world_mut();
+/// let scorer = world
+///     .spawn(Sum::new(0.))
+///     .with_children(|parent| {
+///         parent.spawn(FixedScore::new(0.3));
+///         parent.spawn(FixedScore::new(0.4));
+///     })
+///     .id();
+/// # world.flush();
+/// world.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # approx::assert_relative_eq!(0.7, world.get::<Score>(scorer).unwrap().get());
+/// ```
+pub struct BatchedScoringPlugin;
+
+impl Plugin for BatchedScoringPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_batched_scorer::<FixedScore>(|world, entity| {
+            let Some(&settings) = world.get::<FixedScore>(entity) else {
+                return;
+            };
+            set_score(world, entity, settings.value().get());
+        })
+        .add_batched_scorer::<Sum>(|world, entity| {
+            let Some(&settings) = world.get::<Sum>(entity) else {
+                return;
+            };
+            let score = settings.calculate(child_scores(world, entity));
+            set_score(world, entity, score);
+        })
+        .add_batched_scorer::<Product>(|world, entity| {
+            let Some(&settings) = world.get::<Product>(entity) else {
+                return;
+            };
+            let score = settings.calculate(child_scores(world, entity));
+            set_score(world, entity, score);
+        })
+        .add_batched_scorer::<Winning>(|world, entity| {
+            let Some(&settings) = world.get::<Winning>(entity) else {
+                return;
+            };
+            let score = settings.calculate(child_scores(world, entity));
+            set_score(world, entity, score);
+        });
+    }
+}
+
+/// [`Resource`] that maps scorer [`Component`]s to the [`ScorerFn`]s that score them in the batched backend.
+///
+/// Added by the [`BatchedScoringPlugin`] or [`BatchedScorerExt::add_batched_scorer`].
+/// Its presence is what switches scoring to the batched backend.
+///
+/// The scorer function of each score entity is cached the first time it's scored,
+/// until a registered scorer component is added to or removed from it.
+#[derive(Resource, Default)]
+pub struct ScorerRegistry {
+    /// The scorer functions, by the [`ComponentId`] of their scorer component.
+    scorers: HashMap<ComponentId, ScorerFn>,
+    /// The scorer function found for each score entity, if any.
+    entities: HashMap<Entity, Option<ScorerFn>>,
+}
+
+impl ScorerRegistry {
+    /// Registers the given scorer function for score entities with the given scorer component.
+    pub fn register(&mut self, component: ComponentId, scorer: ScorerFn) {
+        self.scorers.insert(component, scorer);
+        self.entities.clear();
+    }
+
+    /// Returns the scorer function registered for the given scorer component, if any.
+    #[must_use]
+    pub fn get(&self, component: ComponentId) -> Option<ScorerFn> {
+        self.scorers.get(&component).copied()
+    }
+
+    /// Returns the scorer function registered for the given score entity's components, if any.
+    ///
+    /// Returns `None` and logs a warning if more than one of its components has a scorer function,
+    /// since it's ambiguous which one should calculate the [`Score`].
+    #[must_use]
+    pub fn find(&self, world: &World, entity: Entity) -> Option<ScorerFn> {
+        let entity_ref = world.get_entity(entity)?;
+        let mut scorers = entity_ref
+            .archetype()
+            .components()
+            .filter_map(|component| self.get(component));
+        let scorer = scorers.next()?;
+        if scorers.next().is_some() {
+            warn_once!(
+                "Score entity {entity} has more than one batched scorer component, falling back to OnScore observers."
+            );
+            return None;
+        }
+        Some(scorer)
+    }
+
+    /// Like [`ScorerRegistry::find`], but cached per score entity.
+    fn find_cached(&mut self, world: &World, entity: Entity) -> Option<ScorerFn> {
+        if let Some(&scorer) = self.entities.get(&entity) {
+            return scorer;
+        }
+        let scorer = self.find(world, entity);
+        self.entities.insert(entity, scorer);
+        scorer
+    }
+
+    /// [`Observer`] that forgets the cached scorer function of a score entity
+    /// when a registered scorer component is added or removed, or when its [`Score`] is removed.
+    fn on_changed_forget<E: Event, B: Bundle>(trigger: Trigger<E, B>, mut registry: ResMut<ScorerRegistry>) {
+        registry.entities.remove(&trigger.entity());
+    }
+}
+
+/// [`App`] extension trait for registering [`ScorerFn`]s for the [`BatchedScoringPlugin`].
+pub trait BatchedScorerExt {
+    /// Registers the given scorer function for score entities with the scorer [`Component`] `C`,
+    /// which should calculate the same [`Score`] as `C`'s [`OnScore`] [`Observer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// /// Always scores the maximum.
+    /// #[derive(Component)]
+    /// pub struct Eager;
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins((ObservedUtilityPlugins::RealTime, BatchedScoringPlugin))
+    ///     .add_batched_scorer::<Eager>(|world, entity| {
+    ///         if let Some(mut score) = world.get_mut::<Score>(entity) {
+    ///             *score = Score::MAX;
+    ///         }
+    ///     });
+    /// # let mut world = app.world_mut();
+    /// # let scorer = world.spawn((Eager, Score::default())).id();
+    /// # world.trigger_targets(RunScoring, scorer);
+    /// # world.flush();
+    /// # assert_eq!(Score::MAX, *world.get::<Score>(scorer).unwrap());
+    /// ```
+    fn add_batched_scorer<C: Component>(&mut self, scorer: ScorerFn) -> &mut Self;
+}
+
+impl BatchedScorerExt for App {
+    fn add_batched_scorer<C: Component>(&mut self, scorer: ScorerFn) -> &mut Self {
+        if !self.world().contains_resource::<ScorerRegistry>() {
+            // Don't keep the cached scorers of despawned score entities around
+            self.init_resource::<ScorerRegistry>()
+                .observe(ScorerRegistry::on_changed_forget::<OnRemove, Score>);
+        }
+        let component = self.world_mut().init_component::<C>();
+        self.world_mut()
+            .resource_mut::<ScorerRegistry>()
+            .register(component, scorer);
+        self.observe(ScorerRegistry::on_changed_forget::<OnAdd, C>)
+            .observe(ScorerRegistry::on_changed_forget::<OnRemove, C>)
+    }
+}
+
+/// [`Command`] that scores the given score entities in order with the [`ScorerRegistry`],
+/// falling back to triggering [`OnScore`] for entities without a registered scorer.
+/// Each score entity is paired with the [`OnScore`] event it would've been triggered with.
+pub(crate) struct ScoreBatch(pub(crate) Vec<(Entity, OnScore)>);
+
+impl Command for ScoreBatch {
+    fn apply(self, world: &mut World) {
+        utility_span!("ScoreBatch::apply", scored = self.0.len());
+        for (entity, event) in self.0 {
+            if world.get_entity(entity).is_none() {
+                continue;
+            }
+            let scorer = if world.contains_resource::<ScorerRegistry>() {
+                world.resource_scope(|world, mut registry: Mut<ScorerRegistry>| registry.find_cached(world, entity))
+            } else {
+                None
+            };
+            match scorer {
+                Some(scorer) => scorer(world, entity),
+                None => world.trigger_targets(event, entity),
+            }
+        }
+    }
+}
+
+/// Returns the [`Score`]s of the given entity's children.
+fn child_scores(world: &World, entity: Entity) -> impl Iterator<Item = f32> + '_ {
+    score_children(world, entity)
+        .filter_map(|child| world.get::<Score>(child))
+        .map(Score::get)
+}
+
+/// Sets the [`Score`] of the given entity, if it has one.
+fn set_score(world: &mut World, entity: Entity, value: f32) {
+    if let Some(mut score) = world.get_mut::<Score>(entity) {
+        score.set(value);
+    }
+}
@@ -0,0 +1,147 @@
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{AncestorQuery, Evaluator, LinearEvaluator, Score, ScorerComponents, ScorerKind},
+};
+
+/// [`Score`] [`Component`] that reads an input value from the [`Component`] `T` on its closest ancestor entity,
+/// passes it through an [`Evaluator`], and multiplies the result by a weight, all in a single entity.
+///
+/// This is the same as an [`Evaluated`](crate::scoring::Evaluated) entity with a
+/// [`score_ancestor`](crate::scoring::score_ancestor) child, in the style of Infinite Axis Utility Systems,
+/// but with half the tree depth and entity count.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// /// This goes on the actor entity.
+/// #[derive(Component)]
+/// struct Thirst {
+///     value: f32,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// let scorer = commands
+///     .spawn((
+///         Consideration::new(|thirst: &Thirst| thirst.value / 100.)
+///             .with_evaluator(PowerEvaluator::from_power(2.))
+///             .with_weight(0.5),
+///         Score::default(),
+///     ))
+///     .id();
+///
+/// commands.spawn(Thirst { value: 50. }).add_child(scorer);
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # assert_eq!(world.get::<Score>(scorer).unwrap().get(), 0.125);
+/// ```
+pub struct Consideration<T: Component> {
+    /// Reads the input value from the ancestor's component.
+    input: Box<dyn Fn(&T) -> f32 + Send + Sync + 'static>,
+    /// The evaluator to use for scoring the input value.
+    evaluator: Box<dyn Evaluator>,
+    /// The weight to multiply the evaluated value by.
+    weight: Score,
+}
+
+impl<T: Component> Consideration<T> {
+    /// Creates a new [`Consideration`] that reads its input value from the closest ancestor's [`Component`] `T`,
+    /// with a linear evaluator and a weight of 1.
+    #[must_use]
+    pub fn new(input: impl Fn(&T) -> f32 + Send + Sync + 'static) -> Self {
+        Self {
+            input: Box::new(input),
+            evaluator: Box::new(LinearEvaluator::default()),
+            weight: Score::MAX,
+        }
+    }
+
+    /// Sets the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn with_evaluator(mut self, evaluator: impl Evaluator) -> Self {
+        self.evaluator = Box::new(evaluator);
+        self
+    }
+
+    /// Sets the weight to multiply the evaluated value by.
+    #[must_use]
+    pub fn with_weight(mut self, weight: impl Into<Score>) -> Self {
+        self.weight = weight.into();
+        self
+    }
+
+    /// Returns the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn evaluator(&self) -> &dyn Evaluator {
+        self.evaluator.as_ref()
+    }
+
+    /// Sets the [`Evaluator`] used for scoring.
+    pub fn set_evaluator(&mut self, evaluator: impl Evaluator) {
+        self.evaluator = Box::new(evaluator);
+    }
+
+    /// Returns the weight to multiply the evaluated value by.
+    #[must_use]
+    pub fn weight(&self) -> Score {
+        self.weight
+    }
+
+    /// Sets the weight to multiply the evaluated value by.
+    pub fn set_weight(&mut self, weight: impl Into<Score>) {
+        self.weight = weight.into();
+    }
+
+    /// Reads, evaluates, and weights the input value from the given component.
+    #[must_use]
+    pub fn calculate(&self, component: &T) -> f32 {
+        self.evaluator.evaluate((self.input)(component)) * self.weight.get()
+    }
+
+    /// [`Observer`] for [`Consideration`] [`Score`] entities that scores based on the closest ancestor's `T`.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut target: Query<(&mut Score, &Consideration<T>)>,
+        mut ancestors: AncestorQuery<&'static T>,
+    ) {
+        utility_span!("Consideration::observer", entity = ?trigger.entity());
+        let scorer = trigger.entity();
+        let Ok((mut actor_score, settings)) = target.get_mut(scorer) else {
+            // The entity is not scoring for this consideration.
+            return;
+        };
+
+        if let Ok(ancestor) = ancestors.get(scorer) {
+            actor_score.set(settings.calculate(ancestor));
+        } else {
+            // If there is no ancestor, set the score to the minimum.
+            *actor_score = Score::MIN;
+        }
+    }
+}
+
+impl<T: Component> Component for Consideration<T> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::{
+    ecs::component::{ComponentHooks, ComponentId, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    acting::ActionCooldowns,
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScorerComponents, ScorerKind},
+};
+
+/// [`Score`] [`Component`] that scores how far along an action's cooldown is on the actor entity's
+/// [`ActionCooldowns`], from 0 when it just started cooling down to 1 when it's available again.
+///
+/// Actions without a cooldown, or actors without [`ActionCooldowns`], always score 1.
+/// This is useful for favoring actions that have been off cooldown, or for multiplying other scores with.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # app.init_resource::<Time>();
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Fireball;
+///
+/// let fireball = world.init_component::<Fireball>();
+///
+/// # let mut commands = world.commands();
+/// let ready = commands.spawn(CooldownScore::new(fireball)).id();
+/// commands
+///     .spawn(ActionCooldowns::default().with_cooldown(fireball, Duration::from_secs(5)))
+///     .add_child(ready);
+/// # commands.trigger_targets(RunScoring, ready);
+/// # world.flush();
+/// # assert_eq!(world.get::<Score>(ready).unwrap().get(), 1.);
+/// ```
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct CooldownScore {
+    /// The action whose cooldown is scored.
+    action: ComponentId,
+}
+
+impl CooldownScore {
+    /// Creates a new [`CooldownScore`] for the given action.
+    #[must_use]
+    pub fn new(action: ComponentId) -> Self {
+        Self { action }
+    }
+
+    /// Returns the action whose cooldown is scored.
+    #[must_use]
+    pub fn action(&self) -> ComponentId {
+        self.action
+    }
+
+    /// Sets the action whose cooldown is scored.
+    pub fn set_action(&mut self, action: ComponentId) {
+        self.action = action;
+    }
+
+    /// [`Observer`] for [`CooldownScore`] [`Score`] entities that scores the progress of the action's cooldown.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut target: Query<(&mut Score, &CooldownScore)>,
+        cooldowns: Query<&ActionCooldowns>,
+        time: Option<Res<Time>>,
+    ) {
+        utility_span!("CooldownScore::observer", entity = ?trigger.entity());
+        let scorer = trigger.entity();
+        let Ok((mut actor_score, settings)) = target.get_mut(scorer) else {
+            // The entity is not scoring for cooldowns.
+            return;
+        };
+
+        let now = time.map(|time| time.elapsed()).unwrap_or_default();
+        let progress = trigger
+            .event()
+            .actor
+            .and_then(|actor| cooldowns.get(actor).ok())
+            .and_then(|cooldowns| {
+                let cooldown = cooldowns.cooldown(settings.action)?.as_secs_f32();
+                let remaining = cooldowns.remaining(settings.action, now).as_secs_f32();
+                (cooldown > 0.).then(|| 1. - remaining / cooldown)
+            })
+            .unwrap_or(1.);
+
+        actor_score.set(progress);
+    }
+}
+
+impl Component for CooldownScore {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
@@ -3,7 +3,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ecs::CommandsExt, event::OnScore, scoring::Score};
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScoreChildren, ScoreHierarchy, ScorerComponents, ScorerKind},
+};
 
 /// [`Score`] [`Component`] that uses an [`Evaluator`] to score a single child entity.
 ///
@@ -69,13 +73,21 @@ impl Evaluated {
     }
 
     /// [`Observer`] for [`Evaluated`] [`Score`] entities that scores a single child [`Score`] entity.
-    fn observer(trigger: Trigger<OnScore>, target: Query<(&Children, &Evaluated)>, mut scores: Query<&mut Score>) {
-        let Ok((children, settings)) = target.get(trigger.entity()) else {
+    #[allow(clippy::type_complexity)]
+    fn observer(
+        trigger: Trigger<OnScore>,
+        target: Query<&Evaluated, Or<(With<Children>, With<ScoreChildren>)>>,
+        hierarchy: ScoreHierarchy,
+        mut scores: Query<&mut Score>,
+    ) {
+        utility_span!("Evaluated::observer", entity = ?trigger.entity());
+        let Ok(settings) = target.get(trigger.entity()) else {
             // The entity is not scoring for evaluated.
             return;
         };
 
-        if let &[child] = &**children {
+        let mut children = hierarchy.children(trigger.entity());
+        if let (Some(child), None) = (children.next(), children.next()) {
             let Ok(child_score) = scores.get_mut(child) else {
                 return;
             };
@@ -93,14 +105,13 @@ impl Component for Evaluated {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct EvaluatedObserverSpawned;
-
-            world
-                .commands()
-                .once::<EvaluatedObserverSpawned>()
-                .observe(Self::observer);
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
         });
     }
 }
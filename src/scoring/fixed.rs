@@ -3,7 +3,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ecs::CommandsExt, event::OnScore, scoring::Score};
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScorerComponents, ScorerKind},
+};
 
 /// [`Score`] [`Component`] that always scores a fixed value.
 ///
@@ -52,6 +56,7 @@ impl FixedScore {
 
     /// [`Observer`] for [`FixedScore`] [`Score`] entities that scores itself.
     fn observer(trigger: Trigger<OnScore>, mut target: Query<(&mut Score, &FixedScore)>) {
+        utility_span!("FixedScore::observer", entity = ?trigger.entity());
         let Ok((mut actor_score, settings)) = target.get_mut(trigger.entity()) else {
             // The entity is not scoring for fixed.
             return;
@@ -65,14 +70,13 @@ impl Component for FixedScore {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct FixedScoreObserverSpawned;
-
-            world
-                .commands()
-                .once::<FixedScoreObserverSpawned>()
-                .observe(Self::observer);
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
         });
     }
 }
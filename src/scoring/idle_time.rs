@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    acting::CurrentAction,
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    picking::Picker,
+    scoring::{AncestorQuery, Evaluator, LinearEvaluator, Score, ScorerComponents, ScorerKind},
+};
+
+/// [`Score`] [`Component`] that scores based on how long the closest ancestor actor entity
+/// has been performing its [`Picker`]'s default action.
+///
+/// The idle duration is normalized to the range `[0, 1]` using `max_duration`, and then passed through the [`Evaluator`].
+/// This is useful for boredom or wander behaviors.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # app.init_resource::<Time>();
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Wander;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let wander = world.init_component::<Wander>();
+/// let idle = world.init_component::<Idle>();
+///
+/// # let mut commands = world.commands();
+/// // Reaches a score of 1 after idling for 30 seconds.
+/// let bored = commands
+///     .spawn((IdleTimeScore::new(Duration::from_secs(30)), Score::default()))
+///     .id();
+///
+/// commands
+///     .spawn((Picker::new(idle).with(bored, wander), Highest, CurrentAction(idle)))
+///     .add_child(bored);
+/// # commands.trigger_targets(RunScoring, bored);
+/// # world.flush();
+/// # assert_eq!(world.get::<Score>(bored).unwrap().get(), 0.);
+/// ```
+pub struct IdleTimeScore {
+    /// The idle duration at which the normalized input reaches 1.
+    max_duration: Duration,
+    /// The evaluator to use for scoring the normalized idle duration.
+    evaluator: Box<dyn Evaluator>,
+    /// The elapsed [`Time`] at which the actor started idling, if it's currently idling.
+    idle_since: Option<Duration>,
+}
+
+impl IdleTimeScore {
+    /// Creates a new [`IdleTimeScore`] with the given maximum duration and a linear evaluator.
+    #[must_use]
+    pub fn new(max_duration: Duration) -> Self {
+        Self::with_evaluator(max_duration, LinearEvaluator::default())
+    }
+
+    /// Creates a new [`IdleTimeScore`] with the given maximum duration and evaluator.
+    #[must_use]
+    pub fn with_evaluator(max_duration: Duration, evaluator: impl Evaluator) -> Self {
+        Self {
+            max_duration,
+            evaluator: Box::new(evaluator),
+            idle_since: None,
+        }
+    }
+
+    /// Returns the idle duration at which the normalized input reaches 1.
+    #[must_use]
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// Sets the idle duration at which the normalized input reaches 1.
+    pub fn set_max_duration(&mut self, max_duration: Duration) {
+        self.max_duration = max_duration;
+    }
+
+    /// Returns the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn evaluator(&self) -> &dyn Evaluator {
+        self.evaluator.as_ref()
+    }
+
+    /// Sets the [`Evaluator`] used for scoring.
+    pub fn set_evaluator(&mut self, evaluator: impl Evaluator) {
+        self.evaluator = Box::new(evaluator);
+    }
+
+    /// Returns the elapsed [`Time`] at which the actor started idling, if it's currently idling.
+    #[must_use]
+    pub fn idle_since(&self) -> Option<Duration> {
+        self.idle_since
+    }
+
+    /// Normalizes the given idle duration and evaluates it.
+    #[must_use]
+    pub fn evaluate(&self, idle: Duration) -> f32 {
+        let max = self.max_duration.as_secs_f32();
+        if max <= 0. {
+            return self.evaluator.evaluate(1.);
+        }
+        self.evaluator.evaluate((idle.as_secs_f32() / max).clamp(0., 1.))
+    }
+
+    /// [`Observer`] for [`IdleTimeScore`] [`Score`] entities that scores how long the actor has been idling.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut target: Query<(&mut Score, &mut IdleTimeScore)>,
+        mut pickers: AncestorQuery<&'static Picker>,
+        mut current_actions: AncestorQuery<&'static CurrentAction>,
+        time: Option<Res<Time>>,
+    ) {
+        utility_span!("IdleTimeScore::observer", entity = ?trigger.entity());
+        let scorer = trigger.entity();
+        let Ok((mut actor_score, mut settings)) = target.get_mut(scorer) else {
+            // The entity is not scoring for idle time.
+            return;
+        };
+
+        let idling = match (pickers.get(scorer), current_actions.get(scorer)) {
+            (Ok(picker), Ok(current_action)) => picker.is_default(current_action.0),
+            (Ok(_), Err(_)) => true,
+            _ => false,
+        };
+        let now = time.map(|time| time.elapsed()).unwrap_or_default();
+
+        let idle = if idling {
+            now.saturating_sub(*settings.idle_since.get_or_insert(now))
+        } else {
+            settings.idle_since = None;
+            Duration::ZERO
+        };
+
+        actor_score.set(settings.evaluate(idle));
+    }
+}
+
+impl Component for IdleTimeScore {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::scoring::ScoreHierarchy;
+
+/// [`Component`] that names a [`Score`](crate::scoring::Score) entity, so that it can be found stably under
+/// its actor entity with [`ScoreTree::find`], even across respawns.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::{ecs::system::RunSystemOnce, prelude::*};
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// let attack = world
+///     .spawn((ScoreLabel::new("attack"), FixedScore::new(0.7), Score::default()))
+///     .id();
+///
+/// let actor = world.spawn_empty().add_child(attack).id();
+///
+/// let found = world.run_system_once(move |tree: ScoreTree| tree.find(actor, "attack"));
+/// assert_eq!(found, Some(attack));
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct ScoreLabel(pub Cow<'static, str>);
+
+impl ScoreLabel {
+    /// Creates a new [`ScoreLabel`] with the given label.
+    #[must_use]
+    pub fn new(label: impl Into<Cow<'static, str>>) -> Self {
+        Self(label.into())
+    }
+
+    /// Returns the label as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for ScoreLabel {
+    fn from(label: &'static str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for ScoreLabel {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// [`SystemParam`] for looking up [`ScoreLabel`]ed entities in an actor entity's tree.
+#[derive(SystemParam)]
+pub struct ScoreTree<'w, 's> {
+    hierarchy: ScoreHierarchy<'w, 's>,
+    labels: Query<'w, 's, &'static ScoreLabel>,
+}
+
+impl ScoreTree<'_, '_> {
+    /// Returns the first entity in the actor entity's tree (including the actor itself) with the given label,
+    /// searching depth-first in [`ScoreHierarchy::children`] order.
+    #[must_use]
+    pub fn find(&self, actor: Entity, label: &str) -> Option<Entity> {
+        let mut stack = vec![actor];
+        while let Some(entity) = stack.pop() {
+            if self.labels.get(entity).is_ok_and(|found| found.as_str() == label) {
+                return Some(entity);
+            }
+            let children: Vec<Entity> = self.hierarchy.children(entity).collect();
+            stack.extend(children.into_iter().rev());
+        }
+        None
+    }
+
+    /// Returns the [`ScoreLabel`] of the given entity, if any.
+    #[must_use]
+    pub fn label(&self, entity: Entity) -> Option<&ScoreLabel> {
+        self.labels.get(entity).ok()
+    }
+}
@@ -3,7 +3,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ecs::CommandsExt, event::OnScore, scoring::Score};
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScoreChildren, ScoreHierarchy, ScorerComponents, ScorerKind},
+};
 
 /// [`Score`] [`Component`] that scores based on a [`Measure`] of its child [`Score`] + [`Weighted`] entities.
 /// Child entities without a [`Weighted`] component are considered fully weighted (1.0).
@@ -71,19 +75,22 @@ impl Measured {
     }
 
     /// [`Observer`] for [`Measured`] [`Score`] entities that scores based on all child [`Score`] entities.
+    #[allow(clippy::type_complexity)]
     fn observer(
         trigger: Trigger<OnScore>,
-        target: Query<(&Children, &Measured)>,
+        target: Query<&Measured, Or<(With<Children>, With<ScoreChildren>)>>,
+        hierarchy: ScoreHierarchy,
         mut scores: Query<(&mut Score, Option<&Weighted>)>,
     ) {
-        let Ok((children, settings)) = target.get(trigger.entity()) else {
+        utility_span!("Measured::observer", entity = ?trigger.entity());
+        let Ok(settings) = target.get(trigger.entity()) else {
             // The entity is not scoring for measured.
             return;
         };
 
         let mut inputs = Vec::new();
 
-        for (child_score, weighted) in scores.iter_many(children) {
+        for (child_score, weighted) in scores.iter_many(hierarchy.children(trigger.entity())) {
             inputs.push((child_score, weighted.unwrap_or(&Weighted::MAX)));
         }
 
@@ -102,14 +109,13 @@ impl Component for Measured {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct MeasuredObserverSpawned;
-
-            world
-                .commands()
-                .once::<MeasuredObserverSpawned>()
-                .observe(Self::observer);
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Combinator);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
         });
     }
 }
@@ -0,0 +1,157 @@
+use std::{ops::RangeBounds, time::Duration};
+
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+use rand::Rng;
+
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    rng::{UtilityRng, UtilityRngs},
+    scoring::{Score, ScoreRange, ScorerComponents, ScorerKind},
+};
+
+/// [`Score`] [`Component`] that scores a smoothly varying pseudo-random value within a range over [`Time`].
+///
+/// Unlike [`RandomScore`](crate::scoring::RandomScore), which scores uncorrelated white noise,
+/// this scores one-dimensional value noise: random values are sampled `frequency` times per second,
+/// and the score is smoothly interpolated between them. This adds organic variation to decisions.
+/// The values are drawn from the actor entity's [`UtilityRng`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// # let scorer =
+/// commands
+///     // Samples a new random value every 2 seconds.
+///     .spawn((NoiseScore::new(0.5), Score::default()))
+/// #   .id();
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NoiseScore {
+    /// The range of scores to generate.
+    pub range: ScoreRange,
+    /// How many random values are sampled per second.
+    frequency: f32,
+    /// The previously sampled value, in the range `[0, 1]`.
+    from: f32,
+    /// The next sampled value, in the range `[0, 1]`.
+    to: f32,
+    /// How far along the interpolation from `from` to `to` is, in the range `[0, 1)`.
+    phase: f32,
+    /// The elapsed [`Time`] at which this was last scored, or `None` if the values haven't been sampled yet.
+    last_scored: Option<Duration>,
+}
+
+impl NoiseScore {
+    /// Creates a new [`NoiseScore`] with the given sampling frequency (per second).
+    #[must_use]
+    pub fn new(frequency: f32) -> Self {
+        Self::with_range(frequency, ScoreRange::FULL)
+    }
+
+    /// Creates a new [`NoiseScore`] with the given sampling frequency (per second) and score range.
+    #[must_use]
+    pub fn with_range(frequency: f32, range: impl RangeBounds<Score>) -> Self {
+        Self {
+            range: ScoreRange::from_bounds(range),
+            frequency: frequency.max(0.),
+            from: 0.,
+            to: 0.,
+            phase: 0.,
+            last_scored: None,
+        }
+    }
+
+    /// Returns how many random values are sampled per second.
+    #[must_use]
+    pub fn frequency(&self) -> f32 {
+        self.frequency
+    }
+
+    /// Sets how many random values are sampled per second.
+    pub fn set_frequency(&mut self, frequency: f32) {
+        self.frequency = frequency.max(0.);
+    }
+
+    /// Advances the noise by the given amount of time, drawing new random values from the given [`UtilityRng`].
+    pub fn advance(&mut self, delta: Duration, mut rng: &UtilityRng) {
+        self.phase += delta.as_secs_f32() * self.frequency;
+        if self.phase >= 2. {
+            // We skipped over at least one whole sample, so both ends are stale.
+            self.from = rng.gen_range(0. ..=1.);
+            self.to = rng.gen_range(0. ..=1.);
+            self.phase = self.phase.fract();
+        } else if self.phase >= 1. {
+            self.from = self.to;
+            self.to = rng.gen_range(0. ..=1.);
+            self.phase -= 1.;
+        }
+    }
+
+    /// Returns the current noise value, mapped into the score range.
+    #[must_use]
+    pub fn value(&self) -> f32 {
+        // Smoothstep the phase so that the noise has no sharp corners at sample points.
+        let t = self.phase * self.phase * (3. - 2. * self.phase);
+        let noise = self.from + (self.to - self.from) * t;
+        let (min, max) = (self.range.min_f32(), self.range.max_f32());
+        min + (max - min) * noise
+    }
+
+    /// [`Observer`] for [`NoiseScore`] [`Score`] entities that scores the current noise value.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut target: Query<(&mut Score, &mut NoiseScore)>,
+        time: Option<Res<Time>>,
+        rngs: UtilityRngs,
+    ) {
+        utility_span!("NoiseScore::observer", entity = ?trigger.entity());
+        let Ok((mut actor_score, mut settings)) = target.get_mut(trigger.entity()) else {
+            // The entity is not scoring for noise.
+            return;
+        };
+
+        let mut rng = rngs.get(trigger.event().actor);
+        let now = time.map(|time| time.elapsed()).unwrap_or_default();
+        let delta = match settings.last_scored {
+            Some(last_scored) => now.saturating_sub(last_scored),
+            None => {
+                // Sample the first values now, so they come from the actor's UtilityRng.
+                settings.from = rng.gen_range(0. ..=1.);
+                settings.to = rng.gen_range(0. ..=1.);
+                Duration::ZERO
+            }
+        };
+        settings.last_scored = Some(now);
+        settings.advance(delta, rng);
+
+        actor_score.set(settings.value());
+    }
+}
+
+impl Component for NoiseScore {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
@@ -0,0 +1,164 @@
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Measure, Score, ScoreChildren, ScoreHierarchy, ScorerComponents, ScorerKind, Weighted},
+};
+
+/// [`Score`] [`Component`] that rescales its child [`Score`] entities using a [`Normalization`]
+/// before scoring them with a [`Measure`], like [`Measured`](crate::scoring::Measured) does.
+///
+/// This keeps relative preferences between children meaningful even when their absolute magnitudes drift.
+/// The child [`Score`]s themselves are left untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// # let scorer =
+/// commands
+///     .spawn((Normalized::new(Normalization::Max, WeightedMax), Score::default()))
+///     .with_children(|parent| {
+///         parent.spawn((FixedScore::new(0.2), Score::default()));
+///         parent.spawn((FixedScore::new(0.4), Score::default()));
+///     })
+/// #   .id();
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # assert_eq!(world.get::<Score>(scorer).unwrap().get(), 1.0);
+/// ```
+pub struct Normalized {
+    /// How the child scores are rescaled.
+    normalization: Normalization,
+    /// The function that calculates the score from the rescaled child scores.
+    measure: Box<dyn Measure>,
+}
+
+impl Normalized {
+    /// Creates a new [`Normalized`] from the given normalization and measure.
+    #[must_use]
+    pub fn new(normalization: Normalization, measure: impl Measure) -> Self {
+        Self {
+            normalization,
+            measure: Box::new(measure),
+        }
+    }
+
+    /// Returns how the child scores are rescaled.
+    #[must_use]
+    pub fn normalization(&self) -> Normalization {
+        self.normalization
+    }
+
+    /// Sets how the child scores are rescaled.
+    pub fn set_normalization(&mut self, normalization: Normalization) {
+        self.normalization = normalization;
+    }
+
+    /// Returns the [`Measure`] used for scoring.
+    #[must_use]
+    pub fn measure(&self) -> &dyn Measure {
+        self.measure.as_ref()
+    }
+
+    /// Sets the [`Measure`] used for scoring.
+    pub fn set_measure(&mut self, measure: impl Measure) {
+        self.measure = Box::new(measure);
+    }
+
+    /// Rescales the input scores using the [`Normalization`], then uses the [`Measure`] to calculate the output score.
+    #[must_use]
+    pub fn calculate(&self, inputs: Vec<(&Score, &Weighted)>) -> Score {
+        let normalized = self.normalization.apply(inputs.iter().map(|(score, _)| **score));
+        let inputs = normalized
+            .iter()
+            .zip(inputs.iter())
+            .map(|(score, (_, weight))| (score, *weight))
+            .collect();
+        self.measure.calculate(inputs)
+    }
+
+    /// [`Observer`] for [`Normalized`] [`Score`] entities that scores based on all child [`Score`] entities.
+    #[allow(clippy::type_complexity)]
+    fn observer(
+        trigger: Trigger<OnScore>,
+        target: Query<&Normalized, Or<(With<Children>, With<ScoreChildren>)>>,
+        hierarchy: ScoreHierarchy,
+        mut scores: Query<(&mut Score, Option<&Weighted>)>,
+    ) {
+        utility_span!("Normalized::observer", entity = ?trigger.entity());
+        let Ok(settings) = target.get(trigger.entity()) else {
+            // The entity is not scoring for normalized.
+            return;
+        };
+
+        let mut inputs = Vec::new();
+
+        for (child_score, weighted) in scores.iter_many(hierarchy.children(trigger.entity())) {
+            inputs.push((child_score, weighted.unwrap_or(&Weighted::MAX)));
+        }
+
+        let result = settings.calculate(inputs);
+
+        let Ok((mut actor_score, _)) = scores.get_mut(trigger.entity()) else {
+            // The entity is not scoring.
+            return;
+        };
+
+        *actor_score = result;
+    }
+}
+
+impl Component for Normalized {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Combinator);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
+
+/// How [`Normalized`] rescales its child [`Score`]s.
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(PartialEq, Debug, Default)]
+pub enum Normalization {
+    /// Rescales the scores so that the highest score equals 1.
+    #[default]
+    Max,
+    /// Rescales the scores so that all scores sum to 1.
+    Sum,
+}
+
+impl Normalization {
+    /// Rescales the given scores. If the scores are all 0, they're returned unchanged.
+    #[must_use]
+    pub fn apply(&self, scores: impl Iterator<Item = Score> + Clone) -> Vec<Score> {
+        let total = match self {
+            Normalization::Max => scores.clone().map(|score| score.get()).fold(0., f32::max),
+            Normalization::Sum => scores.clone().map(|score| score.get()).sum::<f32>(),
+        };
+
+        if total > 0. {
+            scores.map(|score| Score::new(score.get() / total)).collect()
+        } else {
+            scores.collect()
+        }
+    }
+}
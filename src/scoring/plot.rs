@@ -0,0 +1,109 @@
+use std::ops::RangeInclusive;
+
+use bevy::prelude::*;
+#[cfg(feature = "plot")]
+use bevy::render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{Extent3d, PrimitiveTopology, TextureDimension, TextureFormat},
+};
+
+use crate::scoring::Evaluator;
+
+/// Samples the [`Evaluator`] at `samples` (at least 2) evenly spaced input values across the `domain`,
+/// and returns the `(input, output)` points of its response curve, ready to be drawn as a polyline.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+/// # use approx::assert_relative_eq;
+///
+/// let points = sample_evaluator(&PowerEvaluator::from_power(2.), 0. ..=1., 3);
+/// assert_eq!(3, points.len());
+/// assert_relative_eq!(points[1].x, 0.5);
+/// assert_relative_eq!(points[1].y, 0.25);
+/// ```
+#[must_use]
+pub fn sample_evaluator(
+    evaluator: &(impl Evaluator + ?Sized),
+    domain: RangeInclusive<f32>,
+    samples: usize,
+) -> Vec<Vec2> {
+    let samples = samples.max(2);
+    let (start, end) = domain.into_inner();
+    (0..samples)
+        .map(|index| {
+            let x = start + (end - start) * index as f32 / (samples - 1) as f32;
+            Vec2::new(x, evaluator.evaluate(x))
+        })
+        .collect()
+}
+
+/// Plots the response curve of the [`Evaluator`] across the `domain` into an [`Image`] of the given size,
+/// for tools and examples to display the curve a designer configured.
+///
+/// The input runs left to right across the `domain`, and the output bottom to top from 0 to 1,
+/// with outputs outside of that range clamped. The curve is drawn in white over a dark background,
+/// with a faint line at an output of 0.5.
+///
+/// Requires the `plot` feature.
+#[cfg(feature = "plot")]
+#[must_use]
+pub fn plot_evaluator(evaluator: &(impl Evaluator + ?Sized), domain: RangeInclusive<f32>, size: UVec2) -> Image {
+    const BACKGROUND: [u8; 4] = [24, 24, 24, 255];
+    const GRID: [u8; 4] = [64, 64, 64, 255];
+    const CURVE: [u8; 4] = [255, 255, 255, 255];
+
+    let (width, height) = (size.x.max(2) as usize, size.y.max(2) as usize);
+    let mut data = BACKGROUND.repeat(width * height);
+    let mut set = |x: usize, row: usize, color: [u8; 4]| {
+        let index = (row * width + x) * 4;
+        data[index..index + 4].copy_from_slice(&color);
+    };
+
+    // Rows are top to bottom, outputs bottom to top.
+    let row = |output: f32| ((1. - output.clamp(0., 1.)) * (height - 1) as f32).round() as usize;
+    for x in 0..width {
+        set(x, row(0.5), GRID);
+    }
+
+    // Fill the rows between neighbouring samples, so steep curves stay connected.
+    let rows: Vec<usize> = sample_evaluator(evaluator, domain, width)
+        .into_iter()
+        .map(|point| row(point.y))
+        .collect();
+    for (x, &current) in rows.iter().enumerate() {
+        let previous = rows[x.saturating_sub(1)];
+        for y in previous.min(current)..=previous.max(current) {
+            set(x, y, CURVE);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: width as u32,
+            height: height as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Samples the response curve of the [`Evaluator`] across the `domain` into a line strip [`Mesh`],
+/// with each sample's input as the `x` and output as the `y` position. See [`sample_evaluator`].
+///
+/// Requires the `plot` feature.
+#[cfg(feature = "plot")]
+#[must_use]
+pub fn evaluator_mesh(evaluator: &(impl Evaluator + ?Sized), domain: RangeInclusive<f32>, samples: usize) -> Mesh {
+    let positions: Vec<[f32; 3]> = sample_evaluator(evaluator, domain, samples)
+        .into_iter()
+        .map(|point| [point.x, point.y, 0.])
+        .collect();
+    Mesh::new(PrimitiveTopology::LineStrip, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+}
@@ -0,0 +1,79 @@
+use std::cmp::Reverse;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+/// [`Component`] that controls the order in which sibling [`Score`](crate::scoring::Score) entities
+/// are evaluated and iterated.
+///
+/// Siblings are ordered by descending priority, so higher priorities come first.
+/// Siblings without a [`ScorePriority`] have a priority of `0`,
+/// and siblings with equal priorities keep their [`Children`] order (i.e. the order they were added in).
+///
+/// This order is used for:
+/// - Scoring: sibling subtrees are scored in this order (children are still always scored before their parents).
+/// - [`FirstToScore`](crate::picking::FirstToScore): the first sibling in this order to reach the threshold is picked.
+/// - [`Highest`](crate::picking::Highest): ties are won by the first sibling in this order.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// #[derive(Component)]
+/// pub struct Flee;
+/// #[derive(Component)]
+/// pub struct Eat;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// let flee = world.init_component::<Flee>();
+/// let eat = world.init_component::<Eat>();
+/// let idle = world.init_component::<Idle>();
+///
+/// # let mut commands = world.commands();
+/// let eat_scorer = commands.spawn(FixedScore::new(0.8)).id();
+/// // Even though it was added last, fleeing is checked first.
+/// let flee_scorer = commands.spawn((FixedScore::new(0.8), ScorePriority(10))).id();
+///
+/// let actor = commands
+///     .spawn((
+///         Picker::new(idle).with(eat_scorer, eat).with(flee_scorer, flee),
+///         FirstToScore::new(0.5),
+///     ))
+///     .push_children(&[eat_scorer, flee_scorer])
+///     .id();
+/// # world.flush();
+/// # world.trigger(RunScoring);
+/// # world.flush();
+/// # world.trigger_targets(RunPicking, actor);
+/// # world.flush();
+/// # assert_eq!(Some(flee), world.get::<Picker>(actor).unwrap().picked);
+/// ```
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[reflect(Component, PartialEq, Hash, Debug, Default)]
+pub struct ScorePriority(pub i32);
+
+/// [`SystemParam`] that orders sibling entities by their [`ScorePriority`].
+#[derive(SystemParam)]
+pub struct PrioritizedChildren<'w, 's> {
+    priorities: Query<'w, 's, &'static ScorePriority>,
+}
+
+impl PrioritizedChildren<'_, '_> {
+    /// Sorts the given sibling entities in place by descending [`ScorePriority`],
+    /// keeping the given order for equal priorities.
+    pub fn sort(&self, children: &mut [Entity]) {
+        children.sort_by_key(|&child| Reverse(self.priority(child)));
+    }
+
+    /// Returns the [`ScorePriority`] of the given entity, defaulting to `0`.
+    #[must_use]
+    pub fn priority(&self, entity: Entity) -> ScorePriority {
+        self.priorities.get(entity).copied().unwrap_or_default()
+    }
+}
@@ -3,7 +3,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ecs::CommandsExt, event::OnScore, scoring::Score};
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScoreChildren, ScoreHierarchy, ScorerComponents, ScorerKind},
+};
 
 /// [`Score`] [`Component`] that scores the product of all child [`Score`] entities.
 ///
@@ -67,30 +71,43 @@ impl Product {
         self.threshold = threshold.into();
     }
 
-    /// [`Observer`] for [`Product`] [`Score`] entities that scores based on all child [`Score`] entities.
-    fn observer(trigger: Trigger<OnScore>, target: Query<(&Children, &Product)>, mut scores: Query<&mut Score>) {
-        let Ok((children, settings)) = target.get(trigger.entity()) else {
-            // The entity is not scoring for product.
-            return;
-        };
-
+    /// Calculates the score from the given child scores.
+    pub(crate) fn calculate(&self, child_scores: impl Iterator<Item = f32>) -> f32 {
         let mut product: f32 = 1.;
         let mut num_scores = 0;
 
-        for child_score in scores.iter_many(children) {
-            product *= child_score.get();
+        for child_score in child_scores {
+            product *= child_score;
             num_scores += 1;
         }
 
-        if settings.use_compensation && num_scores > 0 {
+        if self.use_compensation && num_scores > 0 {
             let mod_factor = 1. - 1. / (num_scores as f32);
             let makeup = (1. - product) * mod_factor;
             product += makeup * product;
         }
 
-        if product < settings.threshold().get() {
+        if product < self.threshold().get() {
             product = 0.;
         }
+        product
+    }
+
+    /// [`Observer`] for [`Product`] [`Score`] entities that scores based on all child [`Score`] entities.
+    #[allow(clippy::type_complexity)]
+    fn observer(
+        trigger: Trigger<OnScore>,
+        target: Query<&Product, Or<(With<Children>, With<ScoreChildren>)>>,
+        hierarchy: ScoreHierarchy,
+        mut scores: Query<&mut Score>,
+    ) {
+        utility_span!("Product::observer", entity = ?trigger.entity());
+        let Ok(settings) = target.get(trigger.entity()) else {
+            // The entity is not scoring for product.
+            return;
+        };
+
+        let product = settings.calculate(scores.iter_many(hierarchy.children(trigger.entity())).map(Score::get));
 
         let Ok(mut actor_score) = scores.get_mut(trigger.entity()) else {
             // The entity is not scoring.
@@ -105,14 +122,13 @@ impl Component for Product {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct ProductObserverSpawned;
-
-            world
-                .commands()
-                .once::<ProductObserverSpawned>()
-                .observe(Self::observer);
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Combinator);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
         });
     }
 }
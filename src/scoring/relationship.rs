@@ -0,0 +1,216 @@
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, StorageType},
+        entity::{EntityMapper, MapEntities},
+        system::SystemParam,
+    },
+    prelude::*,
+};
+
+use crate::{acting::PerformedBy, scoring::HierarchyGeneration};
+
+/// [`Component`] that links a score entity to its parent score entity or actor entity,
+/// as an alternative to [`Parent`].
+///
+/// Score trees linked with [`ScoreOf`] aren't part of the transform hierarchy, so score entities don't inherit
+/// transforms or visibility, and the actor entity's [`Children`] stay free for gameplay parenting.
+/// All traversals, [`score_ancestor`](crate::scoring::score_ancestor), and pickers work with either linkage,
+/// and both can be mixed within the same tree. If a score entity has both, [`ScoreOf`] takes precedence.
+///
+/// Inserting a [`ScoreOf`] adds the entity to the target entity's [`ScoreChildren`],
+/// so relink a score entity by inserting a new [`ScoreOf`] rather than mutating it in place.
+/// Note that score entities linked with [`ScoreOf`] aren't despawned by
+/// [`despawn_recursive`](DespawnRecursiveExt::despawn_recursive), so despawn them along with the actor yourself.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// let scorer = world.spawn(Sum::new(0.)).id();
+/// world.spawn((FixedScore::new(0.3), ScoreOf::new(scorer)));
+/// world.spawn((FixedScore::new(0.4), ScoreOf::new(scorer)));
+/// # world.flush();
+/// world.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # approx::assert_relative_eq!(0.7, world.get::<Score>(scorer).unwrap().get());
+/// ```
+#[derive(Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct ScoreOf(Entity);
+
+impl ScoreOf {
+    /// Creates a new [`ScoreOf`] linking to the given parent score entity or actor entity.
+    #[must_use]
+    pub fn new(target: Entity) -> Self {
+        Self(target)
+    }
+
+    /// Returns the parent score entity or actor entity.
+    #[must_use]
+    pub fn get(&self) -> Entity {
+        self.0
+    }
+}
+
+impl MapEntities for ScoreOf {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+impl Component for ScoreOf {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_insert(|mut world, entity, _component| {
+            let Some(&ScoreOf(target)) = world.get::<ScoreOf>(entity) else {
+                return;
+            };
+            if let Some(mut generation) = world.get_resource_mut::<HierarchyGeneration>() {
+                generation.increment();
+            }
+            world.commands().add(move |world: &mut World| {
+                let Some(mut linked) = world.get_entity_mut(entity) else {
+                    // The score entity was despawned in the meantime.
+                    return;
+                };
+                // Inserting over an existing ScoreOf doesn't run the on_remove hook, so unlink the previous target here.
+                let previous = linked.get::<LinkedScoreParent>().map(|previous| previous.0);
+                linked.insert(LinkedScoreParent(target));
+                if let Some(previous) = previous.filter(|&previous| previous != target) {
+                    if let Some(mut children) = world.get_mut::<ScoreChildren>(previous) {
+                        children.0.retain(|&child| child != entity);
+                    }
+                }
+
+                let Some(mut target) = world.get_entity_mut(target) else {
+                    // The target was despawned in the meantime.
+                    return;
+                };
+                match target.get_mut::<ScoreChildren>() {
+                    Some(mut children) if !children.0.contains(&entity) => children.0.push(entity),
+                    Some(_) => {}
+                    None => {
+                        target.insert(ScoreChildren(vec![entity]));
+                    }
+                }
+            });
+        });
+        hooks.on_remove(|mut world, entity, _component| {
+            let Some(&ScoreOf(target)) = world.get::<ScoreOf>(entity) else {
+                return;
+            };
+            if let Some(mut generation) = world.get_resource_mut::<HierarchyGeneration>() {
+                generation.increment();
+            }
+            world.commands().add(move |world: &mut World| {
+                if let Some(mut linked) = world.get_entity_mut(entity) {
+                    linked.remove::<LinkedScoreParent>();
+                }
+                if let Some(mut children) = world.get_mut::<ScoreChildren>(target) {
+                    children.0.retain(|&child| child != entity);
+                }
+            });
+        });
+    }
+}
+
+/// [`Component`] that remembers which entity's [`ScoreChildren`] a score entity was added to by its [`ScoreOf`],
+/// so it can be removed from there when a new [`ScoreOf`] is inserted over the old one.
+#[derive(Component)]
+pub(crate) struct LinkedScoreParent(Entity);
+
+/// [`Component`] for the score entities linked to an entity with [`ScoreOf`], maintained automatically.
+///
+/// Entries whose [`ScoreOf`] was mutated in place to point elsewhere linger until they're relinked or removed,
+/// so prefer [`ScoreHierarchy::children`], which skips them.
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct ScoreChildren(Vec<Entity>);
+
+impl ScoreChildren {
+    /// Returns an iterator over the linked score entities, in the order they were linked.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl MapEntities for ScoreChildren {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for entity in &mut self.0 {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+    }
+}
+
+/// [`SystemParam`] for walking score trees linked with either [`ScoreOf`] or [`Parent`].
+///
+/// Action entities with [`PerformedBy`] aren't part of the score tree, so they're skipped as children.
+#[derive(SystemParam)]
+pub struct ScoreHierarchy<'w, 's> {
+    parents: Query<'w, 's, (Option<&'static ScoreOf>, Option<&'static Parent>)>,
+    actions: Query<'w, 's, (), With<PerformedBy>>,
+    children: Query<'w, 's, (Option<&'static Children>, Option<&'static ScoreChildren>)>,
+}
+
+impl ScoreHierarchy<'_, '_> {
+    /// Returns the parent score entity or actor entity of the given entity, preferring [`ScoreOf`] over [`Parent`].
+    #[must_use]
+    pub fn parent(&self, entity: Entity) -> Option<Entity> {
+        let (score_of, parent) = self.parents.get(entity).ok()?;
+        score_of.map(ScoreOf::get).or_else(|| parent.map(Parent::get))
+    }
+
+    /// Returns an iterator over the children of the given entity: its [`Children`], followed by its [`ScoreChildren`].
+    pub fn children(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        let (children, score_children) = self.children.get(entity).unwrap_or_default();
+        children
+            .into_iter()
+            .flatten()
+            .copied()
+            .chain(score_children.into_iter().flat_map(ScoreChildren::iter))
+            .filter(move |&child| self.parent(child) == Some(entity) && !self.actions.contains(child))
+    }
+}
+
+/// Returns the parent score entity or actor entity of the given entity, preferring [`ScoreOf`] over [`Parent`].
+pub(crate) fn score_parent(world: &World, entity: Entity) -> Option<Entity> {
+    world
+        .get::<ScoreOf>(entity)
+        .map(ScoreOf::get)
+        .or_else(|| world.get::<Parent>(entity).map(Parent::get))
+}
+
+/// Returns an iterator over the children of the given entity, linked with either [`ScoreOf`] or [`Parent`].
+pub(crate) fn score_children(world: &World, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+    world
+        .get::<Children>(entity)
+        .into_iter()
+        .flatten()
+        .copied()
+        .chain(
+            world
+                .get::<ScoreChildren>(entity)
+                .into_iter()
+                .flat_map(ScoreChildren::iter),
+        )
+        .filter(move |&child| score_parent(world, child) == Some(entity) && world.get::<PerformedBy>(child).is_none())
+}
+
+/// Despawns the given score entity and its children, linked with either [`ScoreOf`] or [`Parent`], recursively.
+pub(crate) fn despawn_score_tree(world: &mut World, root: Entity) {
+    let children: Vec<Entity> = score_children(world, root).collect();
+    for child in children {
+        despawn_score_tree(world, child);
+    }
+    if let Some(root) = world.get_entity_mut(root) {
+        root.despawn_recursive();
+    }
+}
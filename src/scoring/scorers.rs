@@ -0,0 +1,76 @@
+use bevy::{
+    ecs::{component::ComponentId, world::DeferredWorld},
+    prelude::*,
+    utils::HashMap,
+};
+
+/// [`Resource`] that lists the scorer [`Component`]s in use, such as [`FixedScore`](crate::scoring::FixedScore)
+/// and [`Sum`](crate::scoring::Sum), so tools like the `ScoreTreeValidator` (requires `debug` feature)
+/// can find score entities without hard-coding their types.
+///
+/// Initialized by the [`ScoringPlugin`](crate::scoring::ScoringPlugin). Built-in scorers register themselves from their
+/// `on_add` hooks with [`ScorerComponents::register_in`], and score markers are registered by
+/// [`AddScorerExt::add_scorer`](crate::scoring::AddScorerExt::add_scorer).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::{
+///     prelude::*,
+///     scoring::{ScorerComponents, ScorerKind},
+/// };
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// world.spawn(Sum::new(0.));
+/// # world.flush();
+/// let sum = world.init_component::<Sum>();
+/// assert_eq!(Some(ScorerKind::Combinator), world.resource::<ScorerComponents>().get(sum));
+/// ```
+#[derive(Resource)]
+#[derive(Clone, Debug, Default)]
+pub struct ScorerComponents {
+    /// The kind of each scorer component, by its [`ComponentId`].
+    scorers: HashMap<ComponentId, ScorerKind>,
+}
+
+impl ScorerComponents {
+    /// Registers the given scorer component with the given kind.
+    pub fn register(&mut self, component: ComponentId, kind: ScorerKind) {
+        self.scorers.insert(component, kind);
+    }
+
+    /// Registers the given scorer component with the given kind in the world's [`ScorerComponents`], if present.
+    ///
+    /// Meant to be called from the scorer component's `on_add` hook.
+    pub fn register_in(world: &mut DeferredWorld, component: ComponentId, kind: ScorerKind) {
+        if let Some(mut scorers) = world.get_resource_mut::<ScorerComponents>() {
+            scorers.register(component, kind);
+        }
+    }
+
+    /// Returns the kind of the given scorer component, if it's registered.
+    #[must_use]
+    pub fn get(&self, component: ComponentId) -> Option<ScorerKind> {
+        self.scorers.get(&component).copied()
+    }
+
+    /// Returns `true` if the given component is a registered scorer component.
+    #[must_use]
+    pub fn contains(&self, component: ComponentId) -> bool {
+        self.scorers.contains_key(&component)
+    }
+}
+
+/// The kind of a scorer [`Component`] registered in the [`ScorerComponents`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ScorerKind {
+    /// A scorer that calculates its [`Score`](crate::scoring::Score) on its own,
+    /// or with its own requirements for child score entities, such as [`Evaluated`](crate::scoring::Evaluated).
+    Scorer,
+    /// A scorer that combines the [`Score`](crate::scoring::Score)s of its child score entities,
+    /// such as [`Sum`](crate::scoring::Sum), so it needs at least one.
+    Combinator,
+}
@@ -0,0 +1,203 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    event::RunScoring,
+    scoring::{score_children, LastScored, Score},
+};
+
+/// [`World`] extension trait for "what-if" scoring, such as for planners or AI that evaluates
+/// how good things would be if it did something.
+pub trait ScoreSimulator {
+    /// Scores the given actor entity's score trees (or the given score entity's tree) as if the
+    /// [`ScoreOverrides`] were applied, and returns the resulting [`Score`]s in a [`ScoreSnapshot`].
+    ///
+    /// The overrides are applied, the tree is scored with [`RunScoring`], and then everything is restored:
+    /// the overridden components, the live [`Score`]s, and the [`LastScored`] times.
+    /// Note that [`OnScore`](crate::event::OnScore) [`Observer`]s still run, so scorers with side effects
+    /// besides their [`Score`] will see the simulation, and component hooks run for the overridden components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_observed_utility::prelude::*;
+    ///
+    /// #[derive(Component, Scorer)]
+    /// #[scorer(source = Health, score = |health| 1. - health.0)]
+    /// pub struct Wounded;
+    ///
+    /// #[derive(Component)]
+    /// pub struct Health(f32);
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservedUtilityPlugins::TurnBased)
+    ///     .add_scorer::<Health, Wounded>();
+    /// # let mut world = app.world_mut();
+    /// let scorer = world.spawn((Wounded, Score::default())).id();
+    /// let actor = world.spawn(Health(0.75)).add_child(scorer).id();
+    ///
+    /// // How wounded would the actor be if it took a hit?
+    /// let snapshot = world.simulate(actor, ScoreOverrides::new().with(actor, Health(0.25)));
+    /// assert_eq!(Some(0.75), snapshot.get(scorer).map(|score| score.get()));
+    /// // Nothing actually changed.
+    /// assert_eq!(0., world.get::<Score>(scorer).unwrap().get());
+    /// assert_eq!(0.75, world.get::<Health>(actor).unwrap().0);
+    /// ```
+    fn simulate(&mut self, target: Entity, overrides: ScoreOverrides) -> ScoreSnapshot;
+}
+
+impl ScoreSimulator for World {
+    fn simulate(&mut self, target: Entity, overrides: ScoreOverrides) -> ScoreSnapshot {
+        self.flush();
+
+        // Save the live state of the whole tree
+        let mut tree = Vec::new();
+        let mut stack = vec![target];
+        while let Some(entity) = stack.pop() {
+            tree.push(entity);
+            stack.extend(score_children(self, entity));
+        }
+        let saved: Vec<(Entity, Option<Score>, Option<LastScored>)> = tree
+            .iter()
+            .map(|&entity| {
+                (
+                    entity,
+                    self.get::<Score>(entity).copied(),
+                    self.get::<LastScored>(entity).copied(),
+                )
+            })
+            .collect();
+
+        let restores: Vec<Restore> = overrides.0.into_iter().map(|apply| apply(self)).collect();
+        self.flush();
+        self.trigger_targets(RunScoring, target);
+        self.flush();
+
+        let snapshot = ScoreSnapshot(
+            tree.iter()
+                .filter_map(|&entity| self.get::<Score>(entity).map(|&score| (entity, score)))
+                .collect(),
+        );
+
+        // Restore in reverse, so that overriding the same component twice restores the original
+        for restore in restores.into_iter().rev() {
+            restore(self);
+        }
+        for (entity, score, last_scored) in saved {
+            let Some(mut entity) = self.get_entity_mut(entity) else {
+                continue;
+            };
+            if let (Some(score), Some(mut live)) = (score, entity.get_mut::<Score>()) {
+                *live = score;
+            }
+            match last_scored {
+                Some(last_scored) => {
+                    entity.insert(last_scored);
+                }
+                None => {
+                    // It was scored for the first time during the simulation
+                    entity.remove::<LastScored>();
+                }
+            }
+        }
+        self.flush();
+
+        snapshot
+    }
+}
+
+/// Function that undoes a single override applied by [`ScoreOverrides`].
+type Restore = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// Function that applies a single override for [`ScoreOverrides`], returning how to undo it.
+type Override = Box<dyn FnOnce(&mut World) -> Restore + Send + Sync>;
+
+/// Staged component changes for [`ScoreSimulator::simulate`], applied for the simulation only.
+#[derive(Default)]
+pub struct ScoreOverrides(Vec<Override>);
+
+impl ScoreOverrides {
+    /// Creates a new empty [`ScoreOverrides`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the [`Component`] `C` on the given entity with the given value.
+    #[must_use]
+    pub fn with<C: Component>(mut self, entity: Entity, component: C) -> Self {
+        self.insert(entity, component);
+        self
+    }
+
+    /// Overrides the [`Component`] `C` on the given entity as if it was removed.
+    #[must_use]
+    pub fn without<C: Component>(mut self, entity: Entity) -> Self {
+        self.remove::<C>(entity);
+        self
+    }
+
+    /// Overrides the [`Component`] `C` on the given entity with the given value.
+    pub fn insert<C: Component>(&mut self, entity: Entity, component: C) {
+        self.0.push(Box::new(move |world: &mut World| -> Restore {
+            let previous = world.get_entity_mut(entity).map(|mut entity| {
+                let previous = entity.take::<C>();
+                entity.insert(component);
+                previous
+            });
+            Box::new(move |world: &mut World| restore(world, entity, previous.flatten()))
+        }));
+    }
+
+    /// Overrides the [`Component`] `C` on the given entity as if it was removed.
+    pub fn remove<C: Component>(&mut self, entity: Entity) {
+        self.0.push(Box::new(move |world: &mut World| -> Restore {
+            let previous = world.get_entity_mut(entity).and_then(|mut entity| entity.take::<C>());
+            Box::new(move |world: &mut World| restore(world, entity, previous))
+        }));
+    }
+}
+
+/// Restores the [`Component`] `C` on the given entity to its value from before it was overridden.
+fn restore<C: Component>(world: &mut World, entity: Entity, previous: Option<C>) {
+    let Some(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    match previous {
+        Some(previous) => {
+            entity.insert(previous);
+        }
+        None => {
+            entity.remove::<C>();
+        }
+    }
+}
+
+/// The [`Score`]s calculated by [`ScoreSimulator::simulate`], by score entity.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ScoreSnapshot(HashMap<Entity, Score>);
+
+impl ScoreSnapshot {
+    /// Returns the simulated [`Score`] of the given score entity, if it was part of the simulation.
+    #[must_use]
+    pub fn get(&self, score_entity: Entity) -> Option<Score> {
+        self.0.get(&score_entity).copied()
+    }
+
+    /// Returns an iterator over the score entities and their simulated [`Score`]s, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Score)> + '_ {
+        self.0.iter().map(|(&entity, &score)| (entity, score))
+    }
+
+    /// Returns the number of simulated score entities.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if nothing was simulated.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::{
+    ecs::component::{ComponentHooks, StorageType},
+    prelude::*,
+};
+
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{AncestorQuery, Evaluator, Score, ScorerComponents, ScorerKind, TargetRef},
+};
+
+/// [`Score`] [`Component`] that scores based on the [`GlobalTransform`] distance between
+/// the closest ancestor entity (usually the actor) and the entity referenced by the closest [`TargetRef`].
+///
+/// The distance is normalized to the range `[0, 1]` using `max_distance`, and then passed through the [`Evaluator`].
+/// If either entity can't be found, the score is 0.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ObservedUtilityPlugins::RealTime);
+/// # let mut world = app.world_mut();
+/// # let mut commands = world.commands();
+/// let enemy = commands
+///     .spawn(GlobalTransform::from_translation(Vec3::new(5., 0., 0.)))
+///     .id();
+///
+/// // Scores higher the closer the enemy is.
+/// let scorer = commands
+///     .spawn((DistanceScore::new(10., |distance: f32| 1. - distance), Score::default()))
+///     .id();
+///
+/// commands
+///     .spawn((GlobalTransform::IDENTITY, TargetRef(enemy)))
+///     .add_child(scorer);
+/// # commands.trigger_targets(RunScoring, scorer);
+/// # world.flush();
+/// # assert_eq!(world.get::<Score>(scorer).unwrap().get(), 0.5);
+/// ```
+pub struct DistanceScore {
+    /// The distance at which the normalized input reaches 1.
+    max_distance: f32,
+    /// The evaluator to use for scoring the normalized distance.
+    evaluator: Box<dyn Evaluator>,
+}
+
+impl DistanceScore {
+    /// Creates a new [`DistanceScore`] with the given maximum distance and evaluator.
+    #[must_use]
+    pub fn new(max_distance: f32, evaluator: impl Evaluator) -> Self {
+        Self {
+            max_distance: max_distance.max(f32::EPSILON),
+            evaluator: Box::new(evaluator),
+        }
+    }
+
+    /// Returns the distance at which the normalized input reaches 1.
+    #[must_use]
+    pub fn max_distance(&self) -> f32 {
+        self.max_distance
+    }
+
+    /// Sets the distance at which the normalized input reaches 1.
+    pub fn set_max_distance(&mut self, max_distance: f32) {
+        self.max_distance = max_distance.max(f32::EPSILON);
+    }
+
+    /// Returns the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn evaluator(&self) -> &dyn Evaluator {
+        self.evaluator.as_ref()
+    }
+
+    /// Sets the [`Evaluator`] used for scoring.
+    pub fn set_evaluator(&mut self, evaluator: impl Evaluator) {
+        self.evaluator = Box::new(evaluator);
+    }
+
+    /// Normalizes the given distance and evaluates it.
+    #[must_use]
+    pub fn evaluate(&self, distance: f32) -> f32 {
+        self.evaluator.evaluate((distance / self.max_distance).clamp(0., 1.))
+    }
+
+    /// [`Observer`] for [`DistanceScore`] [`Score`] entities that scores the distance between the actor and its target.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut target: Query<(&mut Score, &DistanceScore)>,
+        mut actors: AncestorQuery<&'static GlobalTransform>,
+        mut target_refs: AncestorQuery<&'static TargetRef>,
+        transforms: Query<&GlobalTransform>,
+    ) {
+        utility_span!("DistanceScore::observer", entity = ?trigger.entity());
+        let scorer = trigger.entity();
+        let Ok((mut actor_score, settings)) = target.get_mut(scorer) else {
+            // The entity is not scoring for distance.
+            return;
+        };
+
+        let Ok(actor_transform) = actors.get(scorer) else {
+            *actor_score = Score::MIN;
+            return;
+        };
+        let Some(target_transform) = target_refs
+            .get(scorer)
+            .ok()
+            .and_then(|target_ref| transforms.get(target_ref.0).ok())
+        else {
+            *actor_score = Score::MIN;
+            return;
+        };
+
+        let distance = actor_transform.translation().distance(target_transform.translation());
+        actor_score.set(settings.evaluate(distance));
+    }
+}
+
+impl Component for DistanceScore {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
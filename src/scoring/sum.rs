@@ -3,7 +3,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ecs::CommandsExt, event::OnScore, scoring::Score};
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScoreChildren, ScoreHierarchy, ScorerComponents, ScorerKind},
+};
 
 /// [`Score`] [`Component`] that scores based on the sum of its child [`Score`] entities.
 ///
@@ -56,22 +60,32 @@ impl Sum {
         self.threshold = threshold.into();
     }
 
+    /// Calculates the score from the given child scores.
+    pub(crate) fn calculate(&self, child_scores: impl Iterator<Item = f32>) -> f32 {
+        let sum: f32 = child_scores.sum();
+
+        if sum < self.threshold().get() {
+            0.
+        } else {
+            sum
+        }
+    }
+
     /// [`Observer`] for [`Sum`] [`Score`] entities that scores based on all child [`Score`] entities.
-    fn observer(trigger: Trigger<OnScore>, target: Query<(&Children, &Sum)>, mut scores: Query<&mut Score>) {
-        let Ok((children, settings)) = target.get(trigger.entity()) else {
+    #[allow(clippy::type_complexity)]
+    fn observer(
+        trigger: Trigger<OnScore>,
+        target: Query<&Sum, Or<(With<Children>, With<ScoreChildren>)>>,
+        hierarchy: ScoreHierarchy,
+        mut scores: Query<&mut Score>,
+    ) {
+        utility_span!("Sum::observer", entity = ?trigger.entity());
+        let Ok(settings) = target.get(trigger.entity()) else {
             // The entity is not scoring for sum.
             return;
         };
 
-        let mut sum: f32 = 0.;
-
-        for child_score in scores.iter_many(children) {
-            sum += child_score.get();
-        }
-
-        if sum < settings.threshold().get() {
-            sum = 0.;
-        }
+        let sum = settings.calculate(scores.iter_many(hierarchy.children(trigger.entity())).map(Score::get));
 
         let Ok(mut actor_score) = scores.get_mut(trigger.entity()) else {
             // The entity is not scoring.
@@ -86,11 +100,13 @@ impl Component for Sum {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct SumObserverSpawned;
-
-            world.commands().once::<SumObserverSpawned>().observe(Self::observer);
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Combinator);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
         });
     }
 }
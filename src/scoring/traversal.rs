@@ -0,0 +1,220 @@
+use std::iter::FusedIterator;
+
+use bevy::{
+    ecs::{query::QueryFilter, system::SystemParam},
+    prelude::*,
+};
+
+use crate::scoring::{PrioritizedChildren, ScoreHierarchy};
+
+/// [`SystemParam`] that provides a depth-first search post-order traversal of the entity hierarchy,
+/// starting from a given root [`Entity`].
+///
+/// Siblings are visited in [`ScorePriority`](crate::scoring::ScorePriority) order.
+/// Children are found through [`ScoreOf`](crate::scoring::ScoreOf) links as well as [`Children`], see [`ScoreHierarchy`].
+/// Only the children of entities matching the [`QueryFilter`] `F` are visited.
+#[derive(SystemParam)]
+pub struct DFSPostTraversal<'w, 's, F: QueryFilter + 'static = ()> {
+    filter: Query<'w, 's, (), F>,
+    hierarchy: ScoreHierarchy<'w, 's>,
+    priorities: PrioritizedChildren<'w, 's>,
+    /// The entities left to visit, with the next one on top.
+    stack: Local<'s, Vec<Entity>>,
+    /// Whether the children of the entity at the same index in the stack have been pushed already.
+    expanded: Local<'s, Vec<bool>>,
+}
+
+impl<'w, 's, F: QueryFilter + 'static> DFSPostTraversal<'w, 's, F> {
+    /// Returns an iterator that provides a depth-first search post-order traversal of the entity hierarchy,
+    /// starting from a given root [`Entity`].
+    ///
+    /// The deepest children are visited first, followed by their parents.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn iter(&mut self, root: Entity) -> DFSPostTraversalIter<'_, 'w, 's, F> {
+        DFSPostTraversalIter::new(self, root)
+    }
+}
+
+/// [`Iterator`] type returned by [`DFSPostTraversal::iter`].
+pub struct DFSPostTraversalIter<'a, 'w, 's, F: QueryFilter + 'static> {
+    param: &'a mut DFSPostTraversal<'w, 's, F>,
+}
+
+impl<'a, 'w, 's, F: QueryFilter + 'static> DFSPostTraversalIter<'a, 'w, 's, F> {
+    fn new(param: &'a mut DFSPostTraversal<'w, 's, F>, root: Entity) -> Self {
+        param.stack.clear();
+        param.stack.push(root);
+        param.expanded.clear();
+        param.expanded.push(false);
+
+        Self { param }
+    }
+}
+
+impl<F: QueryFilter + 'static> Iterator for DFSPostTraversalIter<'_, '_, '_, F> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let param = &mut *self.param;
+            let entity = *param.stack.last()?;
+            let expanded = param.expanded.last_mut()?;
+            if *expanded {
+                // All children have been visited
+                param.stack.pop();
+                param.expanded.pop();
+                return Some(entity);
+            }
+
+            // Visit the entity again after all of its children
+            *expanded = true;
+
+            if param.filter.contains(entity) {
+                let start = param.stack.len();
+                param.stack.extend(param.hierarchy.children(entity));
+                param.priorities.sort(&mut param.stack[start..]);
+                // Reverse, so that the first child is on top of the stack and visited first
+                param.stack[start..].reverse();
+                param.expanded.resize(param.stack.len(), false);
+            }
+        }
+    }
+}
+
+impl<F: QueryFilter + 'static> FusedIterator for DFSPostTraversalIter<'_, '_, '_, F> {}
+
+/// [`SystemParam`] that provides a breadth-first (level-order) traversal of the entity hierarchy,
+/// starting from a given root [`Entity`].
+///
+/// Siblings are visited in [`ScorePriority`](crate::scoring::ScorePriority) order.
+/// Children are found through [`ScoreOf`](crate::scoring::ScoreOf) links as well as [`Children`], see [`ScoreHierarchy`].
+/// Only the children of entities matching the [`QueryFilter`] `F` are visited.
+#[derive(SystemParam)]
+pub struct BFSTraversal<'w, 's, F: QueryFilter + 'static = ()> {
+    filter: Query<'w, 's, (), F>,
+    hierarchy: ScoreHierarchy<'w, 's>,
+    priorities: PrioritizedChildren<'w, 's>,
+    /// The entities found so far, in visiting order.
+    queue: Local<'s, Vec<Entity>>,
+}
+
+impl<'w, 's, F: QueryFilter + 'static> BFSTraversal<'w, 's, F> {
+    /// Returns an iterator that provides a breadth-first traversal of the entity hierarchy,
+    /// starting from a given root [`Entity`].
+    ///
+    /// The root is visited first, followed by its children, then its grandchildren, and so on.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn iter(&mut self, root: Entity) -> BFSTraversalIter<'_, 'w, 's, F> {
+        BFSTraversalIter::new(self, root)
+    }
+}
+
+/// [`Iterator`] type returned by [`BFSTraversal::iter`].
+pub struct BFSTraversalIter<'a, 'w, 's, F: QueryFilter + 'static> {
+    param: &'a mut BFSTraversal<'w, 's, F>,
+    /// The index of the next entity to visit in the queue.
+    front: usize,
+}
+
+impl<'a, 'w, 's, F: QueryFilter + 'static> BFSTraversalIter<'a, 'w, 's, F> {
+    fn new(param: &'a mut BFSTraversal<'w, 's, F>, root: Entity) -> Self {
+        param.queue.clear();
+        param.queue.push(root);
+
+        Self { param, front: 0 }
+    }
+}
+
+impl<F: QueryFilter + 'static> Iterator for BFSTraversalIter<'_, '_, '_, F> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let param = &mut *self.param;
+        let entity = *param.queue.get(self.front)?;
+        self.front += 1;
+
+        if param.filter.contains(entity) {
+            let start = param.queue.len();
+            param.queue.extend(param.hierarchy.children(entity));
+            param.priorities.sort(&mut param.queue[start..]);
+        }
+
+        Some(entity)
+    }
+}
+
+impl<F: QueryFilter + 'static> FusedIterator for BFSTraversalIter<'_, '_, '_, F> {}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+    use crate::scoring::{BFSTraversal, DFSPostTraversal, Score, ScoreOf, ScorePriority, ScoringPlugin};
+
+    #[test]
+    fn dfs_post_traversal_visits_children_first_in_priority_order() {
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+        let world = app.world_mut();
+
+        let root = world.spawn(Score::default()).id();
+        let low = world.spawn((Score::default(), ScorePriority(-1))).set_parent(root).id();
+        let high = world.spawn((Score::default(), ScorePriority(1))).set_parent(root).id();
+        let mid = world.spawn(Score::default()).set_parent(root).id();
+        let low_child = world.spawn(Score::default()).set_parent(low).id();
+        // Unscored entities are visited, but not expanded
+        let unscored = world.spawn_empty().set_parent(high).id();
+        world.spawn(Score::default()).set_parent(unscored);
+        // Linked with ScoreOf instead of Children, and sorted with the Children by priority
+        let second = world
+            .spawn((Score::default(), ScorePriority(1), ScoreOf::new(high)))
+            .id();
+        let first = world.spawn((Score::default(), ScorePriority(2))).set_parent(high).id();
+        world.flush();
+
+        let dfs = world.run_system_once(move |mut dfs: DFSPostTraversal<With<Score>>| {
+            let first = dfs.iter(root).collect::<Vec<_>>();
+            // The traversal state is reset for each iterator
+            assert_eq!(first, dfs.iter(root).collect::<Vec<_>>());
+            first
+        });
+        assert_eq!(vec![first, second, unscored, high, mid, low_child, low, root], dfs);
+
+        // Starting from a leaf visits just the leaf
+        let leaf = world.run_system_once(move |mut dfs: DFSPostTraversal<With<Score>>| {
+            let mut iter = dfs.iter(low_child);
+            (iter.next(), iter.next(), iter.next())
+        });
+        assert_eq!((Some(low_child), None, None), leaf);
+    }
+
+    #[test]
+    fn bfs_traversal_visits_levels_in_priority_order() {
+        let mut app = App::new();
+        app.add_plugins(ScoringPlugin);
+        let world = app.world_mut();
+
+        let root = world.spawn(Score::default()).id();
+        let low = world.spawn((Score::default(), ScorePriority(-1))).set_parent(root).id();
+        let high = world.spawn((Score::default(), ScorePriority(1))).set_parent(root).id();
+        let low_child = world.spawn(Score::default()).set_parent(low).id();
+        // Linked with ScoreOf instead of Children
+        let high_child = world.spawn((Score::default(), ScoreOf::new(high))).id();
+        // Unscored entities are visited, but not expanded
+        let unscored = world.spawn_empty().set_parent(high).id();
+        world.spawn(Score::default()).set_parent(unscored);
+        world.flush();
+
+        let bfs = world.run_system_once(move |mut bfs: BFSTraversal<With<Score>>| bfs.iter(root).collect::<Vec<_>>());
+        assert_eq!(6, bfs.len());
+        assert_eq!(&[root, high, low], &bfs[..3]);
+        assert!(bfs[3..5].contains(&high_child) && bfs[3..5].contains(&unscored));
+        assert_eq!(low_child, bfs[5]);
+
+        // Visits the same entities as the depth-first traversal, in level order
+        let dfs =
+            world.run_system_once(move |mut dfs: DFSPostTraversal<With<Score>>| dfs.iter(root).collect::<Vec<_>>());
+        assert_eq!(bfs.len(), dfs.len());
+        assert!(bfs.iter().all(|entity| dfs.contains(entity)));
+    }
+}
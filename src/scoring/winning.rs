@@ -3,7 +3,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ecs::CommandsExt, event::OnScore, scoring::Score};
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Score, ScoreChildren, ScoreHierarchy, ScorerComponents, ScorerKind},
+};
 
 /// [`Score`] [`Component`] that scores based on the maximum of its child [`Score`] entities.
 ///
@@ -56,23 +60,36 @@ impl Winning {
         self.threshold = threshold;
     }
 
-    /// [`Observer`] for [`Winning`] [`Score`] entities that scores based on all child [`Score`] entities.
-    fn observer(trigger: Trigger<OnScore>, actor: Query<(&Children, &Winning)>, mut scores: Query<&mut Score>) {
-        let Ok((children, settings)) = actor.get(trigger.entity()) else {
-            // The entity is not scoring for winning.
-            return;
-        };
-
+    /// Calculates the score from the given child scores.
+    pub(crate) fn calculate(&self, child_scores: impl Iterator<Item = f32>) -> f32 {
         let mut max: f32 = 0.;
 
-        for child_score in scores.iter_many(children) {
-            if child_score.get() > max {
-                max = child_score.get();
+        for child_score in child_scores {
+            if child_score > max {
+                max = child_score;
             }
         }
-        if max < settings.threshold().get() {
+        if max < self.threshold().get() {
             max = 0.;
         }
+        max
+    }
+
+    /// [`Observer`] for [`Winning`] [`Score`] entities that scores based on all child [`Score`] entities.
+    #[allow(clippy::type_complexity)]
+    fn observer(
+        trigger: Trigger<OnScore>,
+        actor: Query<&Winning, Or<(With<Children>, With<ScoreChildren>)>>,
+        hierarchy: ScoreHierarchy,
+        mut scores: Query<&mut Score>,
+    ) {
+        utility_span!("Winning::observer", entity = ?trigger.entity());
+        let Ok(settings) = actor.get(trigger.entity()) else {
+            // The entity is not scoring for winning.
+            return;
+        };
+
+        let max = settings.calculate(scores.iter_many(hierarchy.children(trigger.entity())).map(Score::get));
 
         let Ok(mut actor_score) = scores.get_mut(trigger.entity()) else {
             // The entity is not scoring.
@@ -87,14 +104,13 @@ impl Component for Winning {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, _entity, _component| {
-            #[derive(Resource, Default)]
-            struct WinningObserverSpawned;
-
-            world
-                .commands()
-                .once::<WinningObserverSpawned>()
-                .observe(Self::observer);
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Combinator);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
         });
     }
 }
@@ -0,0 +1,247 @@
+//! Configurable populations of actors and score trees, for profiling scoring and picking on your own hardware
+//! and settings, and for reproducing performance issues.
+//!
+//! A [`StressScenario`] spawns a number of score trees of a given depth and branching factor,
+//! optionally owned by actor entities with a [`Picker`] of a given [`StressPicker`] type.
+//! It's what the crate's own benchmarks are built on, so numbers are comparable across machines.
+//!
+//! Interior score entities are [`AllOrNothing`]s with a threshold of `0.5`,
+//! and leaf score entities are [`FixedScore`]s with varying values, so pickers don't see ties.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_observed_utility::{prelude::*, stress::{StressPicker, StressScenario}};
+//!
+//! # let mut app = App::new();
+//! app.add_plugins(ObservedUtilityPlugins::TurnBased);
+//! let world = app.world_mut();
+//!
+//! // 100 actors, each choosing between 4 score trees of 3 levels with 2 children per combinator.
+//! let scenario = StressScenario::new(100)
+//!     .with_depth(3)
+//!     .with_branching(2)
+//!     .with_picker(StressPicker::Highest);
+//! let actors = scenario.spawn(world);
+//! assert_eq!(100, actors.len());
+//! assert_eq!(100 * 4 * 7, scenario.score_entity_count());
+//!
+//! world.trigger(RunScoring);
+//! world.trigger(RunPicking);
+//! world.flush();
+//! ```
+
+use bevy::{ecs::world::Command, prelude::*};
+
+use crate::{
+    picking::{FirstToScore, Highest, HighestAboveThreshold, PickDualUtility, Picker},
+    scoring::{AllOrNothing, FixedScore, Score},
+};
+
+/// [`Component`] for the action picked when a [`StressScenario`] actor picks one of its score trees.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct StressAction;
+
+/// [`Component`] for the default action of [`StressScenario`] actors.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct StressIdle;
+
+/// The [`Picker`] type of the actors spawned by a [`StressScenario`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum StressPicker {
+    /// Don't spawn actors, only the score trees themselves.
+    #[default]
+    None,
+    /// Spawn actors with [`FirstToScore`] with a threshold of `0.5`.
+    FirstToScore,
+    /// Spawn actors with [`Highest`].
+    Highest,
+    /// Spawn actors with [`HighestAboveThreshold`] with a threshold of `0.5`.
+    HighestAboveThreshold,
+    /// Spawn actors with [`PickDualUtility`] with a threshold of `0.5`.
+    DualUtility,
+}
+
+/// A population of score trees and actors to spawn for profiling. See the [module docs](self).
+///
+/// Can also be queued as a [`Command`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StressScenario {
+    /// The number of actors, or score trees without a [`StressPicker`].
+    count: usize,
+    /// The number of levels in each score tree, including the root and the leaves.
+    depth: usize,
+    /// The number of children of each non-leaf score entity.
+    branching: usize,
+    /// The number of score trees each actor chooses between.
+    choices: usize,
+    /// The picker type of the actors.
+    picker: StressPicker,
+}
+
+impl StressScenario {
+    /// Creates a new [`StressScenario`] spawning the given number of score trees,
+    /// 3 levels deep with 2 children per combinator, without actors.
+    #[must_use]
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            depth: 3,
+            branching: 2,
+            choices: 4,
+            picker: StressPicker::None,
+        }
+    }
+
+    /// Sets the number of levels in each score tree, including the root and the leaves (at least 1).
+    #[must_use]
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth.max(1);
+        self
+    }
+
+    /// Sets the number of children of each non-leaf score entity (at least 1).
+    #[must_use]
+    pub fn with_branching(mut self, branching: usize) -> Self {
+        self.branching = branching.max(1);
+        self
+    }
+
+    /// Sets the number of score trees each actor chooses between (at least 1).
+    /// Ignored without a [`StressPicker`].
+    #[must_use]
+    pub fn with_choices(mut self, choices: usize) -> Self {
+        self.choices = choices.max(1);
+        self
+    }
+
+    /// Sets the picker type of the actors.
+    #[must_use]
+    pub fn with_picker(mut self, picker: StressPicker) -> Self {
+        self.picker = picker;
+        self
+    }
+
+    /// Returns the number of actors, or score trees without a [`StressPicker`].
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of levels in each score tree, including the root and the leaves.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the number of children of each non-leaf score entity.
+    #[must_use]
+    pub fn branching(&self) -> usize {
+        self.branching
+    }
+
+    /// Returns the number of score trees each actor chooses between.
+    #[must_use]
+    pub fn choices(&self) -> usize {
+        self.choices
+    }
+
+    /// Returns the picker type of the actors.
+    #[must_use]
+    pub fn picker(&self) -> StressPicker {
+        self.picker
+    }
+
+    /// Returns the total number of score entities spawned by [`StressScenario::spawn`].
+    #[must_use]
+    pub fn score_entity_count(&self) -> usize {
+        let per_tree: usize = (0..self.depth)
+            .map(|level| self.branching.saturating_pow(level as u32))
+            .fold(0, usize::saturating_add);
+        let trees = match self.picker {
+            StressPicker::None => self.count,
+            _ => self.count.saturating_mul(self.choices),
+        };
+        per_tree.saturating_mul(trees)
+    }
+
+    /// Spawns the scenario into the world, and returns the actor entities,
+    /// or the root score entities without a [`StressPicker`].
+    pub fn spawn(&self, world: &mut World) -> Vec<Entity> {
+        let action = world.init_component::<StressAction>();
+        let idle = world.init_component::<StressIdle>();
+
+        let mut leaves = 0;
+        let mut roots = Vec::with_capacity(self.count);
+        for _ in 0..self.count {
+            if self.picker == StressPicker::None {
+                roots.push(self.spawn_tree(world, self.depth, &mut leaves));
+                continue;
+            }
+
+            let mut picker = Picker::new(idle);
+            let mut trees = Vec::with_capacity(self.choices);
+            for _ in 0..self.choices {
+                let tree = self.spawn_tree(world, self.depth, &mut leaves);
+                picker = picker.with(tree, action);
+                trees.push(tree);
+            }
+
+            let mut actor = world.spawn(picker);
+            match self.picker {
+                StressPicker::None => {}
+                StressPicker::FirstToScore => {
+                    actor.insert(FirstToScore::new(0.5));
+                }
+                StressPicker::Highest => {
+                    actor.insert(Highest);
+                }
+                StressPicker::HighestAboveThreshold => {
+                    actor.insert(HighestAboveThreshold::new(0.5));
+                }
+                StressPicker::DualUtility => {
+                    actor.insert(PickDualUtility::new(0.5));
+                }
+            }
+            roots.push(actor.push_children(&trees).id());
+        }
+
+        world.flush();
+        roots
+    }
+
+    /// Spawns a score tree with the given number of levels, and returns its root score entity.
+    fn spawn_tree(&self, world: &mut World, depth: usize, leaves: &mut usize) -> Entity {
+        if depth <= 1 {
+            // Spread the leaf values out so pickers don't see ties.
+            let value = (*leaves * 37 % 101) as f32 / 100.;
+            *leaves += 1;
+            return world.spawn((FixedScore::new(value), Score::default())).id();
+        }
+
+        let children: Vec<Entity> = (0..self.branching)
+            .map(|_| self.spawn_tree(world, depth - 1, leaves))
+            .collect();
+        world
+            .spawn((AllOrNothing::new(0.5), Score::default()))
+            .push_children(&children)
+            .id()
+    }
+}
+
+impl Default for StressScenario {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl Command for StressScenario {
+    fn apply(self, world: &mut World) {
+        self.spawn(world);
+    }
+}
@@ -0,0 +1,447 @@
+//! Target selection, for choosing *what* to act on, such as which enemy to attack or which item to pick up.
+//!
+//! A [`TargetSelector`] score entity evaluates each candidate entity matching its [`QueryFilter`]
+//! (or listed in its [`TargetCandidates`]) with a set of [`TargetConsideration`]s, stores the best candidate
+//! in its [`BestTarget`] component, and scores the winner's utility. Use it as a [`Picker`] choice,
+//! so that the action picked for it knows its target, through [`OnPicked::target`],
+//! [`OnActionInitiated::target`] and the actor's [`CurrentTarget`]:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_observed_utility::prelude::*;
+//! # use approx::assert_relative_eq;
+//!
+//! #[derive(Component)]
+//! pub struct Enemy;
+//! #[derive(Component)]
+//! pub struct Health(f32);
+//! #[derive(Component)]
+//! pub struct Attack;
+//! #[derive(Component)]
+//! pub struct Idle;
+//!
+//! # let mut app = App::new();
+//! # app.add_plugins(ObservedUtilityPlugins::TurnBased);
+//! # let mut world = app.world_mut();
+//! let attack = world.init_component::<Attack>();
+//! let idle = world.init_component::<Idle>();
+//!
+//! world.spawn((Enemy, Health(90.)));
+//! let wounded = world.spawn((Enemy, Health(20.))).id();
+//!
+//! // Attack the enemy with the least health.
+//! let attack_target = world
+//!     .spawn(TargetSelector::<With<Enemy>>::new().with_consideration(TargetConsideration::new(|target| {
+//!         target.candidate.get::<Health>().map_or(0., |health| 1. - health.0 / 100.)
+//!     })))
+//!     .id();
+//! let actor = world
+//!     .spawn((Picker::new(idle).with(attack_target, attack), Highest))
+//!     .add_child(attack_target)
+//!     .id();
+//!
+//! world.score_and_pick(actor);
+//! assert_eq!(Some(&BestTarget(wounded)), world.get::<BestTarget>(attack_target));
+//! assert_relative_eq!(0.8, world.get::<Score>(attack_target).unwrap().get());
+//!
+//! world.trigger_targets(RequestAction::picked(), actor);
+//! # world.flush();
+//! assert_eq!(Some(&CurrentTarget(wounded)), world.get::<CurrentTarget>(actor));
+//! ```
+//!
+//! [`QueryFilter`]: bevy::ecs::query::QueryFilter
+//! [`Picker`]: crate::picking::Picker
+//! [`OnPicked::target`]: crate::event::OnPicked::target
+//! [`OnActionInitiated::target`]: crate::event::OnActionInitiated::target
+//! [`CurrentTarget`]: crate::acting::CurrentTarget
+
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, StorageType},
+        entity::{EntityMapper, MapEntities},
+        query::QueryFilter,
+    },
+    prelude::*,
+};
+
+use crate::{
+    ecs::{CommandsExt, InsertIfMissing},
+    event::OnScore,
+    scoring::{Evaluator, LinearEvaluator, Score, ScorerComponents, ScorerKind},
+};
+
+/// [`Score`] [`Component`] that evaluates each candidate entity with its [`TargetConsideration`]s,
+/// stores the best candidate in [`BestTarget`], and scores the best candidate's utility.
+/// See the [module docs](self) for an example.
+///
+/// Candidates are the entities listed in the [`TargetCandidates`] component on the same entity, if any,
+/// or else all entities matching the [`QueryFilter`](bevy::ecs::query::QueryFilter) `F`.
+/// Score entities and the actor entity itself are never candidates.
+///
+/// A candidate's utility is the product of its considerations, so any consideration can veto a candidate
+/// by scoring 0. Ties go to the candidate with the lowest [`Entity`], so selection is deterministic.
+/// Without any candidates, this scores 0 and removes the [`BestTarget`].
+pub struct TargetSelector<F: QueryFilter + 'static = ()> {
+    /// The considerations to evaluate each candidate with.
+    considerations: Vec<TargetConsideration>,
+    /// Candidates need to score above this threshold to be selected.
+    threshold: Score,
+    /// The filter for candidate entities.
+    _filter: PhantomData<fn() -> F>,
+}
+
+impl<F: QueryFilter + 'static> TargetSelector<F> {
+    /// Creates a new [`TargetSelector`] without considerations, so all candidates have a utility of 1.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            considerations: Vec::new(),
+            threshold: Score::MIN,
+            _filter: PhantomData,
+        }
+    }
+
+    /// Adds a [`TargetConsideration`] to evaluate each candidate with.
+    #[must_use]
+    pub fn with_consideration(mut self, consideration: TargetConsideration) -> Self {
+        self.considerations.push(consideration);
+        self
+    }
+
+    /// Sets the threshold candidates need to score above to be selected.
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: impl Into<Score>) -> Self {
+        self.threshold = threshold.into();
+        self
+    }
+
+    /// Returns the considerations each candidate is evaluated with.
+    #[must_use]
+    pub fn considerations(&self) -> &[TargetConsideration] {
+        &self.considerations
+    }
+
+    /// Returns the threshold candidates need to score above to be selected.
+    #[must_use]
+    pub fn threshold(&self) -> Score {
+        self.threshold
+    }
+
+    /// Sets the threshold candidates need to score above to be selected.
+    pub fn set_threshold(&mut self, threshold: impl Into<Score>) {
+        self.threshold = threshold.into();
+    }
+
+    /// Returns the utility of the given candidate: the product of all considerations.
+    #[must_use]
+    pub fn evaluate(&self, target: &TargetContext) -> f32 {
+        self.considerations
+            .iter()
+            .map(|consideration| consideration.calculate(target))
+            .product::<f32>()
+            .clamp(0., 1.)
+    }
+
+    /// [`Observer`] for [`TargetSelector`] [`Score`] entities that selects the best candidate.
+    fn observer(
+        trigger: Trigger<OnScore>,
+        mut commands: Commands,
+        mut target: Query<(&mut Score, &TargetSelector<F>, Option<&TargetCandidates>)>,
+        filtered: Query<Entity, (F, Without<Score>)>,
+        entities: Query<EntityRef, Without<Score>>,
+    ) {
+        utility_span!("TargetSelector::observer", entity = ?trigger.entity());
+        let scorer = trigger.entity();
+        let Ok((mut score, settings, listed)) = target.get_mut(scorer) else {
+            // The entity is not scoring for this selector.
+            return;
+        };
+        let actor = trigger.event().actor;
+
+        let mut candidates: Vec<Entity> = match listed {
+            Some(listed) => listed.0.clone(),
+            None => filtered.iter().collect(),
+        };
+        candidates.sort();
+        candidates.dedup();
+
+        let actor_ref = actor.and_then(|actor| entities.get(actor).ok());
+        let mut best: Option<(Entity, f32)> = None;
+        for candidate in candidates {
+            if Some(candidate) == actor {
+                continue;
+            }
+            let Ok(candidate_ref) = entities.get(candidate) else {
+                continue;
+            };
+            let utility = settings.evaluate(&TargetContext {
+                scorer,
+                actor: actor_ref,
+                candidate: candidate_ref,
+            });
+            if utility > settings.threshold.get() && best.map_or(true, |(_, best)| utility > best) {
+                best = Some((candidate, utility));
+            }
+        }
+
+        if let Some((candidate, utility)) = best {
+            score.set(utility);
+            commands.entity(scorer).insert(BestTarget(candidate));
+        } else {
+            *score = Score::MIN;
+            commands.entity(scorer).remove::<BestTarget>();
+        }
+    }
+}
+
+impl<F: QueryFilter + 'static> Default for TargetSelector<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: QueryFilter + 'static> Component for TargetSelector<F> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, component| {
+            ScorerComponents::register_in(&mut world, component, ScorerKind::Scorer);
+            world.commands().observe_once(Self::observer);
+            world.commands().add(InsertIfMissing::new(entity, Score::default()));
+        });
+        hooks.on_remove(|mut world, _entity, _component| {
+            world.commands().unobserve_if_unused(Self::observer);
+        });
+    }
+}
+
+/// The entities a [`TargetConsideration`] evaluates a candidate with.
+pub struct TargetContext<'w> {
+    /// The [`TargetSelector`] score entity.
+    pub scorer: Entity,
+    /// The actor entity that owns the score tree, if any.
+    pub actor: Option<EntityRef<'w>>,
+    /// The candidate entity being evaluated.
+    pub candidate: EntityRef<'w>,
+}
+
+/// A consideration of a [`TargetSelector`]: reads an input value from a candidate (and the actor),
+/// passes it through an [`Evaluator`], and multiplies the result by a weight,
+/// like a [`Consideration`](crate::scoring::Consideration) does for the actor.
+pub struct TargetConsideration {
+    /// Reads the input value from the candidate and actor.
+    input: Box<dyn Fn(&TargetContext) -> f32 + Send + Sync + 'static>,
+    /// The evaluator to use for scoring the input value.
+    evaluator: Box<dyn Evaluator>,
+    /// The weight to multiply the evaluated value by.
+    weight: Score,
+}
+
+impl TargetConsideration {
+    /// Creates a new [`TargetConsideration`] that reads its input value with the given function,
+    /// with a linear evaluator and a weight of 1.
+    #[must_use]
+    pub fn new(input: impl Fn(&TargetContext) -> f32 + Send + Sync + 'static) -> Self {
+        Self {
+            input: Box::new(input),
+            evaluator: Box::new(LinearEvaluator::default()),
+            weight: Score::MAX,
+        }
+    }
+
+    /// Sets the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn with_evaluator(mut self, evaluator: impl Evaluator) -> Self {
+        self.evaluator = Box::new(evaluator);
+        self
+    }
+
+    /// Sets the weight to multiply the evaluated value by.
+    #[must_use]
+    pub fn with_weight(mut self, weight: impl Into<Score>) -> Self {
+        self.weight = weight.into();
+        self
+    }
+
+    /// Returns the [`Evaluator`] used for scoring.
+    #[must_use]
+    pub fn evaluator(&self) -> &dyn Evaluator {
+        self.evaluator.as_ref()
+    }
+
+    /// Returns the weight to multiply the evaluated value by.
+    #[must_use]
+    pub fn weight(&self) -> Score {
+        self.weight
+    }
+
+    /// Reads, evaluates, and weights the input value for the given candidate.
+    #[must_use]
+    pub fn calculate(&self, target: &TargetContext) -> f32 {
+        self.evaluator.evaluate((self.input)(target)) * self.weight.get()
+    }
+}
+
+/// [`Component`] for [`TargetSelector`] score entities that lists their candidates explicitly,
+/// instead of querying for them.
+#[derive(Component, Reflect)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct TargetCandidates(pub Vec<Entity>);
+
+impl MapEntities for TargetCandidates {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for entity in &mut self.0 {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+    }
+}
+
+/// [`Component`] for [`TargetSelector`] score entities that stores the best candidate of their last scoring.
+/// Removed when there's no candidate above the threshold.
+///
+/// Picking the choice of this score entity copies it to the
+/// [`Picker::picked_target`](crate::picking::Picker::picked_target),
+/// and on to the [`CurrentAction`](crate::acting::CurrentAction) once it's initiated.
+/// Insert it on any other choice score entity to bind its action to a fixed target.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component, PartialEq, Debug)]
+pub struct BestTarget(pub Entity);
+
+impl MapEntities for BestTarget {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::{
+        acting::{CurrentAction, CurrentTarget},
+        event::{OnActionInitiated, OnPicked, RequestAction},
+        picking::{Highest, Picker},
+        scoring::{FixedScore, Score},
+        targeting::{BestTarget, TargetCandidates, TargetConsideration, TargetSelector},
+        WorldUtilityExt,
+    };
+
+    #[derive(Component)]
+    struct Enemy;
+
+    #[derive(Component)]
+    struct Health(f32);
+
+    #[derive(Component)]
+    struct Attack;
+
+    #[derive(Component)]
+    struct Idle;
+
+    /// Considers enemies with less health more attractive.
+    fn weakest() -> TargetSelector<With<Enemy>> {
+        TargetSelector::new().with_consideration(TargetConsideration::new(|target| {
+            target
+                .candidate
+                .get::<Health>()
+                .map_or(0., |health| 1. - health.0 / 100.)
+        }))
+    }
+
+    #[test]
+    fn selects_the_best_target() {
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased);
+        let world = app.world_mut();
+
+        let idle = world.init_component::<Idle>();
+        let healthy = world.spawn((Enemy, Health(90.))).id();
+        let wounded = world.spawn((Enemy, Health(40.))).id();
+        let twin = world.spawn((Enemy, Health(40.))).id();
+        // Not an enemy, so not a candidate, even though it's the weakest.
+        world.spawn(Health(0.));
+
+        let selector = world.spawn(weakest()).id();
+        let actor = world
+            .spawn((Picker::new(idle), Highest, Enemy, Health(0.)))
+            .add_child(selector)
+            .id();
+        world.score_and_pick(actor);
+        // The actor itself isn't a candidate, and ties go to the lowest entity.
+        assert_eq!(Some(&BestTarget(wounded)), world.get::<BestTarget>(selector));
+        assert_eq!(Some(&Score::new(0.6)), world.get::<Score>(selector));
+
+        // Listed candidates replace the filter.
+        world.entity_mut(selector).insert(TargetCandidates(vec![healthy, twin]));
+        world.score_and_pick(actor);
+        assert_eq!(Some(&BestTarget(twin)), world.get::<BestTarget>(selector));
+
+        // Candidates at or below the threshold aren't selected.
+        world.entity_mut(selector).insert(weakest().with_threshold(0.6));
+        world.score_and_pick(actor);
+        assert_eq!(None, world.get::<BestTarget>(selector));
+        assert_eq!(Some(&Score::MIN), world.get::<Score>(selector));
+    }
+
+    #[test]
+    fn picked_target_propagates_to_the_current_target() {
+        #[derive(Resource, Default)]
+        struct Targets {
+            picked: Vec<Option<Entity>>,
+            initiated: Vec<Option<Entity>>,
+        }
+
+        let mut app = App::new();
+        app.add_plugins(crate::ObservedUtilityPlugins::TurnBased)
+            .init_resource::<Targets>()
+            .observe(|trigger: Trigger<OnPicked>, mut targets: ResMut<Targets>| {
+                targets.picked.push(trigger.event().target);
+            })
+            .observe(|trigger: Trigger<OnActionInitiated>, mut targets: ResMut<Targets>| {
+                targets.initiated.push(trigger.event().target);
+            });
+        let world = app.world_mut();
+
+        let attack = world.init_component::<Attack>();
+        let idle = world.init_component::<Idle>();
+        let healthy = world.spawn((Enemy, Health(90.))).id();
+        let wounded = world.spawn((Enemy, Health(40.))).id();
+
+        let selector = world.spawn(weakest()).id();
+        let rest = world.spawn(FixedScore::new(0.1)).id();
+        let actor = world
+            .spawn((Picker::new(idle).with(selector, attack).with(rest, idle), Highest))
+            .push_children(&[selector, rest])
+            .id();
+        let think = |world: &mut World| {
+            world.score_and_pick(actor);
+            world.trigger_targets(RequestAction::picked(), actor);
+            world.flush();
+        };
+
+        think(world);
+        assert_eq!(Some(wounded), world.get::<Picker>(actor).unwrap().picked_target);
+        assert_eq!(Some(&CurrentAction(attack)), world.get::<CurrentAction>(actor));
+        assert_eq!(Some(&CurrentTarget(wounded)), world.get::<CurrentTarget>(actor));
+
+        // The same action on the same target isn't initiated again, but on a new target it is.
+        think(world);
+        world.entity_mut(healthy).insert(Health(10.));
+        think(world);
+        assert_eq!(Some(&CurrentTarget(healthy)), world.get::<CurrentTarget>(actor));
+
+        // Actions without a target clear it.
+        world.entity_mut(selector).insert(weakest().with_threshold(1.));
+        think(world);
+        assert_eq!(Some(&CurrentAction(idle)), world.get::<CurrentAction>(actor));
+        assert_eq!(None, world.get::<CurrentTarget>(actor));
+
+        let targets = world.resource::<Targets>();
+        assert_eq!(vec![Some(wounded), Some(wounded), Some(healthy), None], targets.picked);
+        assert_eq!(vec![Some(wounded), Some(healthy), None], targets.initiated);
+    }
+}
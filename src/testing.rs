@@ -0,0 +1,323 @@
+//! [`proptest`] strategies for random valid score trees, and checks for the invariants every score tree upholds,
+//! so combinators (built-in or your own) can be fuzzed.
+//!
+//! Requires the `proptest` feature.
+//!
+//! - [`score`]: Generates [`Score`] input values, including the `0` and `1` edge cases.
+//! - [`score_tree`] and [`score_tree_with`]: Generate [`ScoreTreeSpec`]s of built-in combinators
+//!   over [`FixedScore`] inputs, which can be spawned into a [`World`] with [`ScoreTreeSpec::spawn`].
+//! - [`check_score_tree`]: Scores a spawned tree and checks that every [`Score`] is within `[0, 1]`,
+//!   that children are scored before their parents, and that scoring again doesn't change any [`Score`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_observed_utility::{prelude::*, testing::{check_score_tree, score_tree}};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(spec in score_tree())| {
+//!     let mut app = App::new();
+//!     app.add_plugins(ObservedUtilityPlugins::TurnBased);
+//!     let world = app.world_mut();
+//!
+//!     let root = spec.spawn(world);
+//!     if let Err(violation) = check_score_tree(world, root) {
+//!         prop_assert!(false, "{violation}");
+//!     }
+//! });
+//! ```
+
+use std::fmt;
+
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use proptest::prelude::*;
+
+use crate::{
+    event::{OnScore, RunScoring},
+    scoring::{
+        score_children, AllOrNothing, Evaluated, FixedScore, Measured, PowerEvaluator, Product, Score, Sum, Weighted,
+        WeightedSum, Winning,
+    },
+};
+
+/// A description of a score tree of built-in combinators over [`FixedScore`] inputs,
+/// generated by [`score_tree`] and spawned with [`ScoreTreeSpec::spawn`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum ScoreTreeSpec {
+    /// A [`FixedScore`] input.
+    Fixed(Score),
+    /// A [`Sum`] of the children.
+    Sum {
+        /// The [`Sum`] threshold.
+        threshold: Score,
+        /// The child score trees.
+        children: Vec<ScoreTreeSpec>,
+    },
+    /// A [`Product`] of the children.
+    Product {
+        /// The [`Product`] threshold.
+        threshold: Score,
+        /// The child score trees.
+        children: Vec<ScoreTreeSpec>,
+    },
+    /// The [`Winning`] child.
+    Winning {
+        /// The [`Winning`] threshold.
+        threshold: Score,
+        /// The child score trees.
+        children: Vec<ScoreTreeSpec>,
+    },
+    /// [`AllOrNothing`] of the children.
+    AllOrNothing {
+        /// The [`AllOrNothing`] threshold.
+        threshold: Score,
+        /// The child score trees.
+        children: Vec<ScoreTreeSpec>,
+    },
+    /// A single child [`Evaluated`] with a [`PowerEvaluator`].
+    Evaluated {
+        /// The [`PowerEvaluator`] power.
+        power: f32,
+        /// The child score tree.
+        child: Box<ScoreTreeSpec>,
+    },
+    /// A [`Measured`] [`WeightedSum`] of the [`Weighted`] children.
+    WeightedSum {
+        /// The child score trees and their weights.
+        children: Vec<(Score, ScoreTreeSpec)>,
+    },
+}
+
+impl ScoreTreeSpec {
+    /// Spawns the score tree into the world, and returns its root score entity.
+    pub fn spawn(&self, world: &mut World) -> Entity {
+        let (entity, children) = match self {
+            ScoreTreeSpec::Fixed(value) => (world.spawn(FixedScore::new(*value)).id(), Vec::new()),
+            ScoreTreeSpec::Sum { threshold, children } => {
+                (world.spawn(Sum::new(*threshold)).id(), spawn_all(world, children))
+            }
+            ScoreTreeSpec::Product { threshold, children } => {
+                (world.spawn(Product::new(*threshold)).id(), spawn_all(world, children))
+            }
+            ScoreTreeSpec::Winning { threshold, children } => {
+                (world.spawn(Winning::new(*threshold)).id(), spawn_all(world, children))
+            }
+            ScoreTreeSpec::AllOrNothing { threshold, children } => (
+                world.spawn(AllOrNothing::new(*threshold)).id(),
+                spawn_all(world, children),
+            ),
+            ScoreTreeSpec::Evaluated { power, child } => {
+                let evaluated = world.spawn(Evaluated::new(PowerEvaluator::from_power(*power))).id();
+                (evaluated, vec![child.spawn(world)])
+            }
+            ScoreTreeSpec::WeightedSum { children } => {
+                let measured = world.spawn(Measured::new(WeightedSum)).id();
+                let children = children
+                    .iter()
+                    .map(|(weight, child)| {
+                        let child = child.spawn(world);
+                        world.entity_mut(child).insert(Weighted::new(*weight));
+                        child
+                    })
+                    .collect();
+                (measured, children)
+            }
+        };
+        world.entity_mut(entity).push_children(&children);
+        world.flush();
+        entity
+    }
+}
+
+/// Spawns the given score trees, and returns their root score entities.
+fn spawn_all(world: &mut World, specs: &[ScoreTreeSpec]) -> Vec<Entity> {
+    specs.iter().map(|spec| spec.spawn(world)).collect()
+}
+
+/// Returns a [`Strategy`] for [`Score`] input values, favoring the `0` and `1` edge cases.
+pub fn score() -> impl Strategy<Value = Score> {
+    prop_oneof![
+        1 => Just(Score::MIN),
+        1 => Just(Score::MAX),
+        8 => (0f32..=1.).prop_map(Score::new),
+    ]
+}
+
+/// Returns a [`Strategy`] for [`ScoreTreeSpec`]s up to 4 levels deep, with up to 4 children per combinator.
+pub fn score_tree() -> impl Strategy<Value = ScoreTreeSpec> {
+    score_tree_with(4, 4)
+}
+
+/// Returns a [`Strategy`] for [`ScoreTreeSpec`]s up to `depth` levels deep,
+/// with up to `width` (at least 1) children per combinator.
+pub fn score_tree_with(depth: u32, width: usize) -> impl Strategy<Value = ScoreTreeSpec> {
+    let width = width.max(1);
+    let size = width.saturating_pow(depth.min(8)) as u32;
+    score()
+        .prop_map(ScoreTreeSpec::Fixed)
+        .prop_recursive(depth, size, width as u32, move |inner| {
+            let children = prop::collection::vec(inner.clone(), 1..=width);
+            prop_oneof![
+                (score(), children.clone())
+                    .prop_map(|(threshold, children)| ScoreTreeSpec::Sum { threshold, children }),
+                (score(), children.clone())
+                    .prop_map(|(threshold, children)| ScoreTreeSpec::Product { threshold, children }),
+                (score(), children.clone())
+                    .prop_map(|(threshold, children)| ScoreTreeSpec::Winning { threshold, children }),
+                (score(), children)
+                    .prop_map(|(threshold, children)| ScoreTreeSpec::AllOrNothing { threshold, children }),
+                (0.1f32..=4., inner.clone()).prop_map(|(power, child)| ScoreTreeSpec::Evaluated {
+                    power,
+                    child: Box::new(child),
+                }),
+                prop::collection::vec((score(), inner), 1..=width)
+                    .prop_map(|children| ScoreTreeSpec::WeightedSum { children }),
+            ]
+        })
+}
+
+/// An invariant broken by a score tree, found by [`check_score_tree`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ScoreTreeViolation {
+    /// A score entity wasn't scored.
+    NotScored {
+        /// The score entity.
+        entity: Entity,
+    },
+    /// A score entity's [`Score`] is outside of `[0, 1]`, or NaN.
+    OutOfRange {
+        /// The score entity.
+        entity: Entity,
+        /// The score value.
+        score: f32,
+    },
+    /// A score entity was scored before one of its children.
+    NotPostOrder {
+        /// The parent score entity.
+        parent: Entity,
+        /// The child score entity scored after it.
+        child: Entity,
+    },
+    /// Scoring the tree again changed a score entity's [`Score`].
+    NotIdempotent {
+        /// The score entity.
+        entity: Entity,
+        /// The score value after the first scoring.
+        first: f32,
+        /// The score value after the second scoring.
+        second: f32,
+    },
+}
+
+impl fmt::Display for ScoreTreeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ScoreTreeViolation::NotScored { entity } => write!(f, "Score entity {entity} wasn't scored."),
+            ScoreTreeViolation::OutOfRange { entity, score } => {
+                write!(f, "Score entity {entity} scored {score}, which is outside of [0, 1].")
+            }
+            ScoreTreeViolation::NotPostOrder { parent, child } => {
+                write!(f, "Score entity {parent} was scored before its child {child}.")
+            }
+            ScoreTreeViolation::NotIdempotent { entity, first, second } => {
+                write!(
+                    f,
+                    "Score entity {entity} scored {first}, then {second} when scored again."
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScoreTreeViolation {}
+
+/// [`Resource`] for the order score entities were scored in by [`check_score_tree`].
+#[derive(Resource, Default)]
+struct ScoredOrder(Vec<Entity>);
+
+/// Scores the tree rooted at the given entity twice, and checks that:
+/// - Every score entity in the tree is scored, and its [`Score`] is within `[0, 1]`.
+/// - Every score entity is scored after all of its children (post-order).
+/// - Scoring again without changing any inputs doesn't change any [`Score`] (idempotence).
+///
+/// The root can be a score entity or an actor entity. The scoring plugins need to be added to the world,
+/// and scorers that depend on [`Time`] or randomness can break idempotence.
+///
+/// # Errors
+///
+/// Returns the first [`ScoreTreeViolation`] found.
+pub fn check_score_tree(world: &mut World, root: Entity) -> Result<(), ScoreTreeViolation> {
+    let first = score_in_order(world, root);
+
+    let mut entities = Vec::new();
+    let mut visited = HashSet::new();
+    let mut stack = vec![root];
+    while let Some(entity) = stack.pop() {
+        if !visited.insert(entity) {
+            // Don't loop forever on hierarchy cycles.
+            continue;
+        }
+        if world.get::<Score>(entity).is_some() {
+            entities.push(entity);
+        }
+        stack.extend(score_children(world, entity));
+    }
+
+    let positions: HashMap<Entity, usize> = first
+        .iter()
+        .enumerate()
+        .map(|(index, &entity)| (entity, index))
+        .collect();
+    for &entity in &entities {
+        let Some(&position) = positions.get(&entity) else {
+            return Err(ScoreTreeViolation::NotScored { entity });
+        };
+        let score = world.get::<Score>(entity).map_or(f32::NAN, Score::get);
+        if !(0. ..=1.).contains(&score) {
+            return Err(ScoreTreeViolation::OutOfRange { entity, score });
+        }
+        for child in score_children(world, entity) {
+            if positions
+                .get(&child)
+                .is_some_and(|&child_position| child_position > position)
+            {
+                return Err(ScoreTreeViolation::NotPostOrder { parent: entity, child });
+            }
+        }
+    }
+
+    let scores: Vec<f32> = entities
+        .iter()
+        .map(|&entity| world.get::<Score>(entity).map_or(f32::NAN, Score::get))
+        .collect();
+    score_in_order(world, root);
+    for (&entity, &first) in entities.iter().zip(&scores) {
+        let second = world.get::<Score>(entity).map_or(f32::NAN, Score::get);
+        if first.to_bits() != second.to_bits() {
+            return Err(ScoreTreeViolation::NotIdempotent { entity, first, second });
+        }
+    }
+
+    Ok(())
+}
+
+/// Scores the tree rooted at the given entity, and returns the score entities in the order they were scored.
+fn score_in_order(world: &mut World, root: Entity) -> Vec<Entity> {
+    world.init_resource::<ScoredOrder>();
+    let observer = world
+        .observe(|trigger: Trigger<OnScore>, mut order: ResMut<ScoredOrder>| {
+            order.0.push(trigger.entity());
+        })
+        .id();
+    world.flush();
+
+    world.trigger_targets(RunScoring, root);
+    world.flush();
+
+    world.despawn(observer);
+    world.remove_resource::<ScoredOrder>().unwrap_or_default().0
+}
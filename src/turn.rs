@@ -0,0 +1,432 @@
+//! Turn scheduling for turn-based games, where actors take turns performing exactly one action each.
+//!
+//! Add the [`TurnBasedPlugin`] alongside [`ObservedUtilityPlugins::TurnBased`] and fill the [`TurnQueue`] resource
+//! with actor entities. Whenever no turn is active, the next actor's turn is started with [`StartTurn`]:
+//! the actor is scored and picked for, and its picked action is requested.
+//! Once that action completes or fails, [`EndTurn`] is triggered and the next actor's turn starts.
+//!
+//! [`ObservedUtilityPlugins::TurnBased`]: crate::ObservedUtilityPlugins::TurnBased
+
+use bevy::{
+    ecs::{
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        world::Command,
+    },
+    prelude::*,
+};
+
+use crate::{
+    acting::{ActionState, CurrentAction, CurrentTarget},
+    event::{ActionEndReason, EndTurn, OnActionEnded, RequestAction, StartTurn},
+    AiDisabled, AiPaused, WorldUtilityExt,
+};
+
+/// [`Plugin`] that takes turns for the actor entities in the [`TurnQueue`], starting the next actor's turn
+/// in the configured [`Schedule`] (default [`Update`]) whenever no turn is active.
+///
+/// At most one turn is started each time the [`Schedule`] runs, so instant actions don't take
+/// all turns at once, and there's room to play animations in between.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// #[derive(Component)]
+/// pub struct Attack;
+/// #[derive(Component)]
+/// pub struct Idle;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((ObservedUtilityPlugins::TurnBased, TurnBasedPlugin::default()))
+///     // Attacks are instant, so they complete as soon as they're initiated.
+///     .observe(|trigger: Trigger<OnActionInitiated>, mut commands: Commands| {
+///         let action = trigger.event().action;
+///         commands.trigger_targets(OnActionEnded::completed(action), trigger.entity());
+///     });
+/// # let world = app.world_mut();
+/// let attack = world.init_component::<Attack>();
+/// let idle = world.init_component::<Idle>();
+///
+/// let spawn_actor = |world: &mut World| {
+///     let scorer = world.spawn(FixedScore::new(0.7)).id();
+///     world
+///         .spawn((Picker::new(idle).with(scorer, attack), Highest))
+///         .add_child(scorer)
+///         .id()
+/// };
+/// let first = spawn_actor(world);
+/// let second = spawn_actor(world);
+/// world.insert_resource(TurnQueue::new([first, second]));
+///
+/// // Each update takes one turn.
+/// app.update();
+/// # let world = app.world_mut();
+/// # assert_eq!(Some(attack), world.get::<CurrentAction>(first).map(|current| current.0));
+/// # assert_eq!(Some(idle), world.get::<CurrentAction>(second).map(|current| current.0));
+/// app.update();
+/// # let world = app.world_mut();
+/// # assert_eq!(Some(attack), world.get::<CurrentAction>(second).map(|current| current.0));
+/// assert_eq!(None, app.world().resource::<TurnQueue>().active());
+/// ```
+pub struct TurnBasedPlugin {
+    /// The [`ScheduleLabel`] to start turns in.
+    pub advance_in: InternedScheduleLabel,
+}
+
+impl Default for TurnBasedPlugin {
+    fn default() -> Self {
+        Self {
+            advance_in: Update.intern(),
+        }
+    }
+}
+
+impl Plugin for TurnBasedPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TurnQueue>()
+            .add_systems(self.advance_in, Self::advance_turns);
+
+        app.observe(Self::on_start_turn)
+            .observe(Self::on_end_turn)
+            .observe(Self::on_action_ended_end_turn);
+
+        app.register_type::<TurnQueue>()
+            .register_type::<Initiative>()
+            .register_type::<StartTurn>()
+            .register_type::<EndTurn>();
+    }
+}
+
+impl TurnBasedPlugin {
+    /// [`System`] that starts the next actor entity's turn with [`StartTurn`] if no turn is active,
+    /// skipping and removing despawned actors. Nothing happens while [`AiPaused`].
+    ///
+    /// If the [`TurnQueue`] is ordered by initiative, it's sorted first.
+    pub fn advance_turns(world: &mut World) {
+        if world.contains_resource::<AiPaused>() {
+            return;
+        }
+
+        // The initiative function needs the actors' components, so the queue is taken out of the world meanwhile
+        let next = world.resource_scope(|world, mut queue: Mut<TurnQueue>| {
+            if queue.active().is_some() {
+                return None;
+            }
+
+            queue.sort_by_initiative(world);
+            while let Some(next) = queue.advance() {
+                if world.get_entity(next).is_some() {
+                    return Some(next);
+                }
+                queue.remove(next);
+            }
+            None
+        });
+        if let Some(next) = next {
+            world.trigger(StartTurn(next));
+        }
+    }
+
+    /// [`Observer`] that makes the [`StartTurn`] actor entity the active one, then scores and picks for it
+    /// and requests its picked action.
+    ///
+    /// [`AiDisabled`] actors, such as player-controlled ones, are only made active,
+    /// so trigger [`EndTurn`] yourself once they're done.
+    pub fn on_start_turn(
+        trigger: Trigger<StartTurn>,
+        mut commands: Commands,
+        mut queue: ResMut<TurnQueue>,
+        disabled: Query<(), With<AiDisabled>>,
+    ) {
+        let StartTurn(actor) = *trigger.event();
+        queue.active = Some(actor);
+        if !disabled.contains(actor) {
+            commands.add(TakeTurn(actor));
+        }
+    }
+
+    /// [`Observer`] that ends the active turn, so that the next one starts the next time
+    /// [`TurnBasedPlugin::advance_turns`] runs.
+    pub fn on_end_turn(_trigger: Trigger<EndTurn>, mut queue: ResMut<TurnQueue>) {
+        queue.active = None;
+    }
+
+    /// [`Observer`] that triggers [`EndTurn`] when the active actor entity's action completes or fails.
+    pub fn on_action_ended_end_turn(trigger: Trigger<OnActionEnded>, mut commands: Commands, queue: Res<TurnQueue>) {
+        if trigger.event().reason == ActionEndReason::Cancelled {
+            // The action was replaced by another one within the same turn
+            return;
+        }
+        if queue.active() == Some(trigger.entity()) {
+            commands.trigger(EndTurn);
+        }
+    }
+}
+
+/// [`Resource`] for the actor entities that take turns with the [`TurnBasedPlugin`], in turn order.
+///
+/// Each round, every actor takes one turn, and then the next round starts from the first actor again.
+/// Actors can be pushed and removed at any time, including mid-round:
+/// pushed actors take their turn at the end of the current round.
+///
+/// With [`TurnQueue::by_initiative`] or [`TurnQueue::with_initiative`], actors are instead ordered by their initiative,
+/// highest first, recomputed at the start of each round by the [`TurnBasedPlugin`].
+/// Ties keep their previous order. Actors pushed mid-round take their turn this round, in initiative order
+/// among the actors that haven't taken their turn yet.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_observed_utility::prelude::*;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((ObservedUtilityPlugins::TurnBased, TurnBasedPlugin::default()));
+/// # let world = app.world_mut();
+/// let slow = world.spawn(Initiative(5.)).id();
+/// let fast = world.spawn(Initiative(12.)).id();
+/// world.insert_resource(TurnQueue::new([slow, fast]).by_initiative());
+///
+/// app.update();
+/// let queue = app.world().resource::<TurnQueue>();
+/// assert_eq!(vec![fast, slow], queue.iter().collect::<Vec<_>>());
+/// ```
+#[derive(Resource, Reflect)]
+#[derive(Clone, Debug, Default)]
+#[reflect(Resource, Debug, Default)]
+pub struct TurnQueue {
+    /// The actor entities in turn order, alongside their initiative for this round, once computed.
+    actors: Vec<(Entity, Option<f32>)>,
+    /// The index of the actor entity to take the next turn this round.
+    next: usize,
+    /// The actor entity whose turn it is, if any.
+    active: Option<Entity>,
+    /// The current round, starting at 0.
+    round: u32,
+    /// The function that computes an actor entity's initiative, if ordering by initiative.
+    #[reflect(ignore)]
+    initiative: Option<fn(EntityRef) -> f32>,
+}
+
+impl TurnQueue {
+    /// Creates a new [`TurnQueue`] with the given actor entities, in turn order.
+    #[must_use]
+    pub fn new(actors: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            actors: actors.into_iter().map(|actor| (actor, None)).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Orders the actor entities by their [`Initiative`] each round, highest first.
+    /// Actors without an [`Initiative`] go last.
+    #[must_use]
+    pub fn by_initiative(self) -> Self {
+        self.with_initiative(|actor| {
+            actor
+                .get::<Initiative>()
+                .map_or(f32::NEG_INFINITY, |initiative| initiative.0)
+        })
+    }
+
+    /// Orders the actor entities by the initiative computed by the given function each round, highest first.
+    #[must_use]
+    pub fn with_initiative(mut self, initiative: fn(EntityRef) -> f32) -> Self {
+        self.initiative = Some(initiative);
+        self
+    }
+
+    /// Returns the initiative of the given actor entity for this round, if it's been computed.
+    #[must_use]
+    pub fn initiative(&self, actor: Entity) -> Option<f32> {
+        self.actors
+            .iter()
+            .find(|&&(entity, _)| entity == actor)
+            .and_then(|&(_, initiative)| initiative)
+    }
+
+    /// Returns the actor entity whose turn it is, if any.
+    #[must_use]
+    pub fn active(&self) -> Option<Entity> {
+        self.active
+    }
+
+    /// Returns the current round, starting at 0.
+    #[must_use]
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// Adds the given actor entity at the end of the turn order, if it isn't in the queue yet.
+    pub fn push(&mut self, actor: Entity) {
+        if !self.actors.iter().any(|&(entity, _)| entity == actor) {
+            self.actors.push((actor, None));
+        }
+    }
+
+    /// Removes the given actor entity from the turn order, returning `true` if it was in the queue.
+    /// If it was the active actor, its turn ends without triggering [`EndTurn`].
+    pub fn remove(&mut self, actor: Entity) -> bool {
+        if self.active == Some(actor) {
+            self.active = None;
+        }
+        let Some(index) = self.actors.iter().position(|&(entity, _)| entity == actor) else {
+            return false;
+        };
+        self.actors.remove(index);
+        if index < self.next {
+            // Keep pointing at the same next actor
+            self.next -= 1;
+        }
+        true
+    }
+
+    /// Returns the actor entity to take the next turn and moves past it,
+    /// starting the next round if every actor has taken their turn this round.
+    /// Returns `None` if the queue is empty.
+    ///
+    /// This doesn't start the turn. Trigger [`StartTurn`] for that, or let the [`TurnBasedPlugin`] do it.
+    pub fn advance(&mut self) -> Option<Entity> {
+        if self.actors.is_empty() {
+            return None;
+        }
+        if self.next >= self.actors.len() {
+            self.next = 0;
+            self.round += 1;
+        }
+        let (actor, _) = self.actors[self.next];
+        self.next += 1;
+        Some(actor)
+    }
+
+    /// Returns an iterator over the actor entities, in turn order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        self.actors.iter().map(|&(actor, _)| actor)
+    }
+
+    /// Returns the number of actor entities.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.actors.len()
+    }
+
+    /// Returns `true` if there are no actor entities.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.actors.is_empty()
+    }
+
+    /// Orders the actor entities by initiative, if ordering by initiative: all of them at the start of a round,
+    /// or only the ones that haven't taken their turn yet otherwise, computing initiative for newly pushed actors.
+    fn sort_by_initiative(&mut self, world: &World) {
+        let Some(initiative) = self.initiative else {
+            return;
+        };
+        let round_start = self.next == 0 || self.next >= self.actors.len();
+        let remaining = if round_start {
+            &mut self.actors[..]
+        } else {
+            &mut self.actors[self.next..]
+        };
+        for (actor, value) in remaining.iter_mut() {
+            if round_start || value.is_none() {
+                *value = Some(world.get_entity(*actor).map_or(f32::NEG_INFINITY, initiative));
+            }
+        }
+        // Stable, so that ties keep their previous order
+        remaining.sort_by(|(_, a), (_, b)| {
+            b.unwrap_or(f32::NEG_INFINITY)
+                .total_cmp(&a.unwrap_or(f32::NEG_INFINITY))
+        });
+    }
+}
+
+/// [`Component`] for an actor entity's initiative, used to order turns by [`TurnQueue::by_initiative`].
+/// Higher initiative takes its turn first.
+#[derive(Component, Reflect)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[reflect(Component, PartialEq, Debug, Default)]
+pub struct Initiative(pub f32);
+
+/// [`Command`] that scores and picks for the given actor entity and requests its picked action,
+/// or triggers [`EndTurn`] if it picked nothing or the request didn't initiate an action.
+struct TakeTurn(Entity);
+
+impl Command for TakeTurn {
+    fn apply(self, world: &mut World) {
+        let Some(mut actor) = world.get_entity_mut(self.0) else {
+            // The actor was despawned in the meantime.
+            world.trigger(EndTurn);
+            return;
+        };
+        let running = actor.get::<ActionState>().is_some_and(|state| !state.is_finished());
+        if !running {
+            // Picking the same action as last turn, or the default action that was never initiated,
+            // should perform it this turn
+            actor.remove::<(CurrentAction, CurrentTarget)>();
+        }
+        let previous = actor.get::<CurrentAction>().copied();
+
+        if world.score_and_pick(self.0).is_none() {
+            world.trigger(EndTurn);
+            return;
+        }
+        world.trigger_targets(RequestAction::picked(), self.0);
+        world.flush();
+
+        let still_active = world.resource::<TurnQueue>().active() == Some(self.0);
+        if still_active && previous.is_some() && world.get::<CurrentAction>(self.0).copied() == previous {
+            // The running action was kept, such as when it was picked again or can't be interrupted yet,
+            // so there's nothing to wait for this turn
+            world.trigger(EndTurn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::component::ComponentId, prelude::*};
+
+    use crate::prelude::*;
+
+    #[derive(Component)]
+    struct Attack;
+    #[derive(Component)]
+    struct Idle;
+
+    #[test]
+    fn picking_the_default_action_takes_the_turn() {
+        #[derive(Resource, Default)]
+        struct Initiated(Vec<ComponentId>);
+
+        let mut app = App::new();
+        app.add_plugins((ObservedUtilityPlugins::TurnBased, TurnBasedPlugin::default()));
+        app.init_resource::<Initiated>();
+        app.observe(
+            |trigger: Trigger<OnActionInitiated>, mut commands: Commands, mut initiated: ResMut<Initiated>| {
+                let action = trigger.event().action;
+                initiated.0.push(action);
+                commands.trigger_targets(OnActionEnded::completed(action), trigger.entity());
+            },
+        );
+        let world = app.world_mut();
+        let attack = world.init_component::<Attack>();
+        let idle = world.init_component::<Idle>();
+
+        // The attack never scores above the threshold, so the default action is picked every turn.
+        let scorer = world.spawn(FixedScore::new(0.7)).id();
+        let actor = world
+            .spawn((Picker::new(idle).with(scorer, attack), HighestAboveThreshold::new(0.9)))
+            .add_child(scorer)
+            .id();
+        world.insert_resource(TurnQueue::new([actor]));
+
+        for _ in 0..3 {
+            app.update();
+            assert_eq!(None, app.world().resource::<TurnQueue>().active());
+        }
+        assert_eq!(vec![idle, idle, idle], app.world().resource::<Initiated>().0);
+    }
+}